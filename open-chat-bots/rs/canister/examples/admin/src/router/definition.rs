@@ -25,6 +25,7 @@ pub async fn get(_request: HttpRequest) -> HttpResponse {
                 community: CommunityEventType::all(),
             }),
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
@@ -13,6 +13,7 @@ pub async fn get(_request: HttpRequest) -> HttpResponse {
             autonomous_config: None,
             default_subscriptions: None,
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
@@ -20,6 +20,7 @@ pub async fn get(_request: HttpRequest) -> HttpResponse {
             }),
             default_subscriptions: None,
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
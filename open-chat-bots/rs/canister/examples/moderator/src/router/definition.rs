@@ -23,6 +23,7 @@ pub async fn get(_request: HttpRequest) -> HttpResponse {
                 chat: HashSet::from_iter(vec![ChatEventType::Message, ChatEventType::MessageEdited]),
             }),
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
@@ -58,12 +58,14 @@ fn handle_lifecycle_event(lifecycle_event: BotLifecycleEvent, api_gateway: Canis
                     api_gateway,
                     granted_command_permissions: event.granted_command_permissions,
                     granted_autonomous_permissions: event.granted_autonomous_permissions,
+                    config: event.config,
                 },
             );
         }
         BotLifecycleEvent::Uninstalled(event) => {
             state.installation_registry.remove(&event.location);
         }
+        BotLifecycleEvent::PermissionsDenied(_) => (),
     });
 }
 
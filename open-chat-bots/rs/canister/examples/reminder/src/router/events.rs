@@ -20,6 +20,7 @@ pub async fn execute(request: HttpRequest) -> HttpResponse {
                         api_gateway: event_wrapper.api_gateway,
                         granted_command_permissions: event.granted_command_permissions,
                         granted_autonomous_permissions: event.granted_autonomous_permissions,
+                        config: event.config,
                     },
                 );
             }
@@ -25,6 +25,7 @@ pub async fn get(_request: HttpRequest) -> HttpResponse {
                 chat: HashSet::from_iter([ChatEventType::MembersJoined]),
             }),
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
@@ -29,6 +29,7 @@ async fn handle_event(event_wrapper: BotEventWrapper) {
         BotEvent::Community(event) => {
             handle_community_event(event, event_wrapper.api_gateway).await
         }
+        BotEvent::Webhook(_) => {}
     }
 }
 
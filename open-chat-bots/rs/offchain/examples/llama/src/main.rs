@@ -109,6 +109,7 @@ async fn bot_definition(State(state): State<Arc<AppState>>, _body: String) -> Js
         autonomous_config: None,
         default_subscriptions: None,
         data_encoding: None,
+        installation_config: None,
     })
 }
 
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use crate::api::command::CommandArgValue;
 use crate::types::{ChatEventType, ChatRole, CommunityEventType};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -14,6 +15,8 @@ pub struct BotDefinition {
     pub autonomous_config: Option<AutonomousConfig>,
     pub default_subscriptions: Option<BotSubscriptions>,
     pub data_encoding: Option<BotDataEncoding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installation_config: Option<BotInstallationConfig>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -32,6 +35,22 @@ pub struct AutonomousConfig {
     pub permissions: BotPermissions,
 }
 
+// Settings which are configured per installation. OpenChat renders these as a form when the bot is installed and
+// passes the chosen values to the bot in the `BotInstalledEvent`.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct BotInstallationConfig {
+    pub fields: Vec<BotInstallationConfigField>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct BotInstallationConfigField {
+    pub name: String,
+    pub description: Option<String>,
+    pub param_type: BotCommandParamType,
+    // If there is no default value then the installer must supply one
+    pub default_value: Option<CommandArgValue>,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct BotCommandParam {
     pub name: String,
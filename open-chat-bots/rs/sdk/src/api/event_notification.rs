@@ -1,8 +1,9 @@
 use crate::{
     oc_api::actions::community_events::CommunityEvent,
     types::{
-        BotInstalledEvent, BotRegisteredEvent, BotUninstalledEvent, CanisterId, Chat, ChatEvent,
-        CommunityId, EventIndex, MessageIndex, TimestampMillis,
+        BotInstalledEvent, BotPermissionsDeniedEvent, BotRegisteredEvent, BotUninstalledEvent,
+        CanisterId, Chat, ChatEvent, CommunityId, EventIndex, MessageIndex, TimestampMillis,
+        UserId,
     },
 };
 use serde::Deserialize;
@@ -25,6 +26,8 @@ pub enum BotEvent {
     Community(BotCommunityEvent),
     #[serde(rename = "l")]
     Lifecycle(BotLifecycleEvent),
+    #[serde(rename = "w")]
+    Webhook(BotWebhookEvent),
 }
 
 #[derive(Deserialize, Debug)]
@@ -53,6 +56,17 @@ pub struct BotCommunityEvent {
     pub latest_event_index: EventIndex,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct BotWebhookEvent {
+    #[serde(rename = "w")]
+    pub webhook_id: UserId,
+    #[serde(rename = "c")]
+    pub chat: Chat,
+    // The raw JSON payload received by the webhook
+    #[serde(rename = "p")]
+    pub payload: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub enum BotLifecycleEvent {
     #[serde(rename = "r")]
@@ -61,4 +75,6 @@ pub enum BotLifecycleEvent {
     Installed(BotInstalledEvent),
     #[serde(rename = "u")]
     Uninstalled(BotUninstalledEvent),
+    #[serde(rename = "d")]
+    PermissionsDenied(BotPermissionsDeniedEvent),
 }
@@ -1,3 +1,4 @@
+use crate::api::command::{CommandArg, CommandArgValue};
 use crate::types::{BotPermissions, CanisterId, InstallationLocation};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -12,6 +13,20 @@ pub struct InstallationRecord {
     pub api_gateway: CanisterId,
     pub granted_command_permissions: BotPermissions,
     pub granted_autonomous_permissions: BotPermissions,
+    #[serde(default)]
+    pub config: Vec<CommandArg>,
+}
+
+impl InstallationRecord {
+    pub fn config_value<T: TryFrom<CommandArgValue>>(&self, name: &str) -> Option<T> {
+        let value = self
+            .config
+            .iter()
+            .find(|arg| arg.name == name)
+            .map(|a| a.value.clone())?;
+
+        T::try_from(value).ok()
+    }
 }
 
 impl InstallationRegistry {
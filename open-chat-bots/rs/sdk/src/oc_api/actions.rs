@@ -5,6 +5,7 @@ use serde::de::DeserializeOwned;
 use std::future::Future;
 use std::sync::Arc;
 
+pub mod active_proposal_tallies;
 pub mod add_reaction;
 pub mod chat_events;
 pub mod chat_summary;
@@ -15,7 +16,9 @@ pub mod delete_channel;
 pub mod delete_messages;
 pub mod invite_users;
 pub mod members;
+pub mod mute_user;
 pub mod remove_user;
+pub mod request_permissions;
 pub mod send_message;
 pub mod subscribe_to_events;
 
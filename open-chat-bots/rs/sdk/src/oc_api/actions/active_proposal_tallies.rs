@@ -0,0 +1,47 @@
+use super::ActionDef;
+use crate::types::{BotChatContext, EventIndex, OCError, TimestampMillis};
+use candid::{CandidType, Deserialize};
+use serde::Serialize;
+
+pub struct ActiveProposalTalliesAction;
+
+impl ActionDef for ActiveProposalTalliesAction {
+    type Args = Args;
+    type Response = Response;
+
+    fn method_name(is_canister_runtime: bool) -> &'static str {
+        // `bot_active_proposal_tallies` is a composite query which means it can't (currently) be
+        // called in replicated mode, so canisters must call `bot_active_proposal_tallies_c2c`
+        // instead which is an update call.
+        if is_canister_runtime {
+            "bot_active_proposal_tallies_c2c"
+        } else {
+            "bot_active_proposal_tallies"
+        }
+    }
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+pub struct Args {
+    pub chat_context: BotChatContext,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct SuccessResult {
+    // The latest tally of each proposal which is still open, keyed by the proposal message's event index
+    pub tallies: Vec<(EventIndex, Tally)>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Tally {
+    pub yes: u64,
+    pub no: u64,
+    pub total: u64,
+    pub timestamp: TimestampMillis,
+}
@@ -0,0 +1,26 @@
+use crate::types::{BotCommunityOrGroupContext, ChannelId, Milliseconds, UnitResult};
+use crate::{oc_api::actions::ActionDef, types::UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub struct MuteUserAction;
+
+impl ActionDef for MuteUserAction {
+    type Args = Args;
+    type Response = Response;
+
+    fn method_name(_: bool) -> &'static str {
+        "bot_mute_user"
+    }
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub community_or_group_context: BotCommunityOrGroupContext,
+    pub channel_id: Option<ChannelId>,
+    pub user_id: UserId,
+    // If None, any existing mute is lifted
+    pub duration: Option<Milliseconds>,
+}
+
+pub type Response = UnitResult;
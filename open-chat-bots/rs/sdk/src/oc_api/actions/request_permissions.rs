@@ -0,0 +1,25 @@
+use crate::oc_api::actions::ActionDef;
+use crate::types::{BotCommunityOrGroupContext, BotPermissions, UnitResult};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub struct RequestPermissionsAction;
+
+impl ActionDef for RequestPermissionsAction {
+    type Args = Args;
+    type Response = Response;
+
+    fn method_name(_: bool) -> &'static str {
+        "bot_request_permissions"
+    }
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub community_or_group_context: BotCommunityOrGroupContext,
+    pub command_permissions: BotPermissions,
+    pub autonomous_permissions: BotPermissions,
+    pub justification: String,
+}
+
+pub type Response = UnitResult;
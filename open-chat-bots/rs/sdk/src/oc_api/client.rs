@@ -3,11 +3,13 @@ use crate::oc_api::actions::community_events::EventsSelectionCriteria as Communi
 use crate::oc_api::actions::members::MemberType;
 use crate::oc_api::client::members::MembersBuilder;
 use crate::oc_api::client::remove_user::RemoveUserBuilder;
+use crate::oc_api::client::request_permissions::RequestPermissionsBuilder;
 use crate::oc_api::Runtime;
 use crate::types::{
     ActionContext, ChannelId, ChatEventType, CommunityEventType, MessageContentInitial, MessageId,
-    Reaction, TextContent, UserId,
+    Milliseconds, Reaction, TextContent, UserId,
 };
+use active_proposal_tallies::ActiveProposalTalliesBuilder;
 use add_reaction::AddReactionBuilder;
 use chat_events::ChatEventsBuilder;
 use chat_summary::ChatSummaryBuilder;
@@ -17,12 +19,14 @@ use create_channel::CreateChannelBuilder;
 use delete_channel::DeleteChannelBuilder;
 use delete_messages::DeleteMessagesBuilder;
 use invite_users::InviteUsersBuilder;
+use mute_user::MuteUserBuilder;
 use send_message::SendMessageBuilder;
 use std::collections::HashSet;
 use std::sync::Arc;
 use subscribe_to_chat_events::SubscribeToChatEventsBuilder;
 use unsubscribe_from_chat_events::UnsubscribeFromChatEventsBuilder;
 
+mod active_proposal_tallies;
 mod add_reaction;
 mod chat_events;
 mod chat_summary;
@@ -33,7 +37,9 @@ mod delete_channel;
 mod delete_messages;
 mod invite_users;
 mod members;
+mod mute_user;
 mod remove_user;
+mod request_permissions;
 mod send_message;
 mod subscribe_to_chat_events;
 mod unsubscribe_from_chat_events;
@@ -70,6 +76,10 @@ impl<R, C> Client<R, C> {
 }
 
 impl<R: Runtime, C: ActionContext> Client<R, C> {
+    pub fn active_proposal_tallies(&self) -> ActiveProposalTalliesBuilder<R, C> {
+        ActiveProposalTalliesBuilder::new(self)
+    }
+
     pub fn add_reaction(
         &self,
         message_id: MessageId,
@@ -117,10 +127,18 @@ impl<R: Runtime, C: ActionContext> Client<R, C> {
         MembersBuilder::new(self, member_types)
     }
 
+    pub fn mute_user(&self, user_id: UserId, duration: Milliseconds) -> MuteUserBuilder<R, C> {
+        MuteUserBuilder::new(self, user_id, Some(duration))
+    }
+
     pub fn remove_user(&self, user_id: UserId) -> RemoveUserBuilder<R, C> {
         RemoveUserBuilder::new(self, user_id)
     }
 
+    pub fn request_permissions(&self, justification: String) -> RequestPermissionsBuilder<R, C> {
+        RequestPermissionsBuilder::new(self, justification)
+    }
+
     pub fn send_message(&self, content: MessageContentInitial) -> SendMessageBuilder<R, C> {
         SendMessageBuilder::new(self, content)
     }
@@ -137,6 +155,10 @@ impl<R: Runtime, C: ActionContext> Client<R, C> {
         SubscribeToChatEventsBuilder::new(self, chat_events, community_events)
     }
 
+    pub fn unmute_user(&self, user_id: UserId) -> MuteUserBuilder<R, C> {
+        MuteUserBuilder::new(self, user_id, None)
+    }
+
     pub fn unsubscribe_from_chat_events(&self) -> UnsubscribeFromChatEventsBuilder<R, C> {
         UnsubscribeFromChatEventsBuilder::new(self)
     }
@@ -0,0 +1,49 @@
+use super::Client;
+use crate::oc_api::actions::active_proposal_tallies::*;
+use crate::oc_api::actions::ActionArgsBuilder;
+use crate::oc_api::Runtime;
+use crate::types::BotChatContext;
+use crate::types::{ActionContext, CanisterId, ChannelId};
+use std::sync::Arc;
+
+pub struct ActiveProposalTalliesBuilder<'c, R, C> {
+    client: &'c Client<R, C>,
+    channel_id: Option<ChannelId>,
+}
+
+impl<'c, R: Runtime, C: ActionContext> ActiveProposalTalliesBuilder<'c, R, C> {
+    pub fn new(client: &'c Client<R, C>) -> Self {
+        ActiveProposalTalliesBuilder {
+            client,
+            channel_id: None,
+        }
+    }
+
+    // This only takes effect for community scope
+    pub fn with_channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+}
+
+impl<R: Runtime, C: ActionContext> ActionArgsBuilder<R> for ActiveProposalTalliesBuilder<'_, R, C> {
+    type Action = ActiveProposalTalliesAction;
+
+    fn runtime(&self) -> Arc<R> {
+        self.client.runtime.clone()
+    }
+
+    fn api_gateway(&self) -> CanisterId {
+        self.client.context.api_gateway()
+    }
+
+    fn into_args(self) -> Args {
+        Args {
+            chat_context: BotChatContext::from_action_context(
+                &self.client.context,
+                self.channel_id,
+            )
+            .unwrap(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::Client;
+use crate::oc_api::actions::mute_user::{Args, MuteUserAction};
+use crate::oc_api::actions::ActionArgsBuilder;
+use crate::oc_api::Runtime;
+use crate::types::{
+    ActionContext, BotCommunityOrGroupContext, CanisterId, ChannelId, Milliseconds, UserId,
+};
+use std::sync::Arc;
+
+pub struct MuteUserBuilder<'c, R, C> {
+    client: &'c Client<R, C>,
+    channel_id: Option<ChannelId>,
+    user_id: UserId,
+    duration: Option<Milliseconds>,
+}
+
+impl<'c, R: Runtime, C: ActionContext> MuteUserBuilder<'c, R, C> {
+    pub fn new(client: &'c Client<R, C>, user_id: UserId, duration: Option<Milliseconds>) -> Self {
+        MuteUserBuilder {
+            client,
+            channel_id: None,
+            user_id,
+            duration,
+        }
+    }
+
+    // This must be set for the user to be muted in a channel rather than the whole community
+    pub fn with_channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+}
+
+impl<R: Runtime, C: ActionContext> ActionArgsBuilder<R> for MuteUserBuilder<'_, R, C> {
+    type Action = MuteUserAction;
+
+    fn runtime(&self) -> Arc<R> {
+        self.client.runtime.clone()
+    }
+
+    fn api_gateway(&self) -> CanisterId {
+        self.client.context.api_gateway()
+    }
+
+    fn into_args(self) -> Args {
+        Args {
+            community_or_group_context: BotCommunityOrGroupContext::from_action_context(
+                &self.client.context,
+            )
+            .expect("Context must be a community or group"),
+            channel_id: self.channel_id,
+            user_id: self.user_id,
+            duration: self.duration,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::Client;
+use crate::oc_api::actions::request_permissions::{Args, RequestPermissionsAction};
+use crate::oc_api::actions::ActionArgsBuilder;
+use crate::oc_api::Runtime;
+use crate::types::{ActionContext, BotCommunityOrGroupContext, BotPermissions, CanisterId};
+use std::sync::Arc;
+
+pub struct RequestPermissionsBuilder<'c, R, C> {
+    client: &'c Client<R, C>,
+    command_permissions: BotPermissions,
+    autonomous_permissions: BotPermissions,
+    justification: String,
+}
+
+impl<'c, R: Runtime, C: ActionContext> RequestPermissionsBuilder<'c, R, C> {
+    pub fn new(client: &'c Client<R, C>, justification: String) -> Self {
+        RequestPermissionsBuilder {
+            client,
+            command_permissions: BotPermissions::default(),
+            autonomous_permissions: BotPermissions::default(),
+            justification,
+        }
+    }
+
+    // Permissions the bot needs when acting on behalf of a user via a command
+    pub fn with_command_permissions(mut self, permissions: BotPermissions) -> Self {
+        self.command_permissions = permissions;
+        self
+    }
+
+    // Permissions the bot needs when acting autonomously
+    pub fn with_autonomous_permissions(mut self, permissions: BotPermissions) -> Self {
+        self.autonomous_permissions = permissions;
+        self
+    }
+}
+
+impl<R: Runtime, C: ActionContext> ActionArgsBuilder<R> for RequestPermissionsBuilder<'_, R, C> {
+    type Action = RequestPermissionsAction;
+
+    fn runtime(&self) -> Arc<R> {
+        self.client.runtime.clone()
+    }
+
+    fn api_gateway(&self) -> CanisterId {
+        self.client.context.api_gateway()
+    }
+
+    fn into_args(self) -> Args {
+        Args {
+            community_or_group_context: BotCommunityOrGroupContext::from_action_context(
+                &self.client.context,
+            )
+            .expect("Context must be a community or group"),
+            command_permissions: self.command_permissions,
+            autonomous_permissions: self.autonomous_permissions,
+            justification: self.justification,
+        }
+    }
+}
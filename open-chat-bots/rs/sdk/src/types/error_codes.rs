@@ -1,3 +1,4 @@
+use super::BotPermissions;
 use candid::CandidType;
 use enum_repr::EnumRepr;
 use serde::{Deserialize, Serialize};
@@ -17,6 +18,16 @@ impl OCError {
     pub fn matches_code(&self, code: OCErrorCode) -> bool {
         self.0 == code as u16
     }
+
+    // If the action failed because the bot hasn't been granted the permissions it needs, this
+    // returns those permissions so that the bot can ask the owners for them (see `request_permissions`)
+    pub fn missing_permissions(&self) -> Option<BotPermissions> {
+        if !self.matches_code(OCErrorCode::BotPermissionsMissing) {
+            return None;
+        }
+
+        serde_json::from_str(self.message()?).ok()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
@@ -33,6 +44,7 @@ pub enum OCErrorCode {
     InitiatorLapsed = 105,
     InitiatorBlocked = 106,
     BotNotAuthenticated = 107,
+    BotPermissionsMissing = 108,
 
     // Invalid
     ChatNotFound = 200,
@@ -176,6 +188,12 @@ pub enum OCErrorCode {
     WebhookNotFound = 339,
     InvalidWebhook = 340,
     InvalidOriginatingCanister = 341,
+    InvalidBotConfig = 342,
+    BotVersionNotFound = 343,
+    InvalidPollBallot = 344,
+    PollNotEnded = 345,
+    BallotAlreadyRevealed = 346,
+    DuplicateBallotCommitment = 347,
 
     // InternalError
     C2CError = 500,
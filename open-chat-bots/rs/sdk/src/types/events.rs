@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::api::command::Command;
+use crate::api::command::{Command, CommandArg};
 
 use super::{
     AccessGateConfig, BotPermissions, CanisterId, Chat, ChatPermissions, ChatRole, EventIndex,
@@ -51,6 +51,8 @@ pub enum ChatEvent {
     BotAdded(BotAdded),
     BotRemoved(BotRemoved),
     BotUpdated(BotUpdated),
+    MemberWarned(Box<MemberWarned>),
+    MemberMuted(Box<MemberMuted>),
     FailedToDeserialize,
 }
 
@@ -346,6 +348,31 @@ pub struct BotUpdated {
     pub updated_by: UserId,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberWarned {
+    pub user_id: UserId,
+    pub warned_by: UserId,
+    pub reason: String,
+    pub active_warnings: u32,
+    // The action taken automatically as a result of the chat's escalation policy
+    pub escalation: Option<EscalationAction>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscalationAction {
+    Mute(Milliseconds),
+    Remove,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberMuted {
+    pub user_id: UserId,
+    pub muted_by: UserId,
+    // None if the member's mute was lifted
+    pub muted_until: Option<TimestampMillis>,
+    pub reason: Option<String>,
+}
+
 impl ChatEvent {
     pub fn event_category(&self) -> Option<ChatEventCategory> {
         match self {
@@ -377,7 +404,9 @@ impl ChatEvent {
             | ChatEvent::MembersAddedToDefaultChannel(_)
             | ChatEvent::BotAdded(_)
             | ChatEvent::BotRemoved(_)
-            | ChatEvent::BotUpdated(_) => Some(ChatEventCategory::Membership),
+            | ChatEvent::BotUpdated(_)
+            | ChatEvent::MemberWarned(_)
+            | ChatEvent::MemberMuted(_) => Some(ChatEventCategory::Membership),
             ChatEvent::Empty | ChatEvent::FailedToDeserialize => None,
         }
     }
@@ -413,6 +442,8 @@ impl ChatEvent {
             ChatEvent::BotAdded(ba) => Some(ba.added_by),
             ChatEvent::BotRemoved(br) => Some(br.removed_by),
             ChatEvent::BotUpdated(bu) => Some(bu.updated_by),
+            ChatEvent::MemberWarned(mw) => Some(mw.warned_by),
+            ChatEvent::MemberMuted(mm) => Some(mm.muted_by),
             ChatEvent::FailedToDeserialize => None,
             ChatEvent::Empty => None,
         }
@@ -445,6 +476,8 @@ pub enum ChatEventType {
     MessageP2pSwapCompleted,
     MessageP2pSwapCancelled,
     MessageVideoCall,
+    MessageProposalCreated,
+    MessageProposalDecided,
 
     // Details category
     NameChanged,
@@ -468,6 +501,8 @@ pub enum ChatEventType {
     UsersInvited,
     UsersBlocked,
     UsersUnblocked,
+    MemberWarned,
+    MemberMuted,
 }
 
 impl From<ChatEventType> for ChatEventCategory {
@@ -484,7 +519,9 @@ impl From<ChatEventType> for ChatEventCategory {
             | ChatEventType::MessagePrizeClaim
             | ChatEventType::MessageP2pSwapCompleted
             | ChatEventType::MessageP2pSwapCancelled
-            | ChatEventType::MessageVideoCall => ChatEventCategory::Message,
+            | ChatEventType::MessageVideoCall
+            | ChatEventType::MessageProposalCreated
+            | ChatEventType::MessageProposalDecided => ChatEventCategory::Message,
             ChatEventType::NameChanged
             | ChatEventType::DescriptionChanged
             | ChatEventType::RulesChanged
@@ -503,7 +540,9 @@ impl From<ChatEventType> for ChatEventCategory {
             | ChatEventType::RoleChanged
             | ChatEventType::UsersInvited
             | ChatEventType::UsersBlocked
-            | ChatEventType::UsersUnblocked => ChatEventCategory::Membership,
+            | ChatEventType::UsersUnblocked
+            | ChatEventType::MemberWarned
+            | ChatEventType::MemberMuted => ChatEventCategory::Membership,
         }
     }
 }
@@ -630,6 +669,8 @@ pub struct BotInstalledEvent {
     pub granted_command_permissions: BotPermissions,
     #[serde(rename = "a")]
     pub granted_autonomous_permissions: BotPermissions,
+    #[serde(rename = "c", default)]
+    pub config: Vec<CommandArg>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -639,3 +680,15 @@ pub struct BotUninstalledEvent {
     #[serde(rename = "l")]
     pub location: InstallationLocation,
 }
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct BotPermissionsDeniedEvent {
+    #[serde(rename = "u")]
+    pub denied_by: UserId,
+    #[serde(rename = "l")]
+    pub location: InstallationLocation,
+    #[serde(rename = "p")]
+    pub requested_command_permissions: BotPermissions,
+    #[serde(rename = "a")]
+    pub requested_autonomous_permissions: BotPermissions,
+}
@@ -1,6 +1,8 @@
-use super::{TimestampMillis, UserId};
+use super::{TimestampMillis, TimestampNanos, UserId};
 use crate::utils::{serialize_large_uint, serialize_principal_as_bytes};
 use candid::{CandidType, Principal};
+use icrc_ledger_types::icrc1::account::Account;
+use icrc_ledger_types::icrc1::transfer::Memo;
 use serde::{Deserialize, Serialize};
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -13,6 +15,8 @@ pub enum MessageContentInitial {
     Poll(PollContent),
     Giphy(GiphyContent),
     Custom(CustomContent),
+    Crypto(CryptoContent),
+    Prize(PrizeContentInitial),
 }
 
 impl MessageContentInitial {
@@ -144,6 +148,55 @@ pub struct PollConfig {
     pub allow_user_to_change_vote: bool,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CryptoContent {
+    pub recipient: UserId,
+    pub transfer: CryptoTransaction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct PrizeContentInitial {
+    pub prizes_v2: Vec<u128>,
+    pub transfer: CryptoTransaction,
+    pub end_date: TimestampMillis,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    pub diamond_only: bool,
+    pub lifetime_diamond_only: bool,
+    pub unique_person_only: bool,
+    pub streak_only: u16,
+    pub requires_captcha: bool,
+}
+
+// Bots can only send funds from their own account which OpenChat transfers using an ICRC-2 approval
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub enum CryptoTransaction {
+    Pending(PendingCryptoTransaction),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub enum PendingCryptoTransaction {
+    ICRC2(Icrc2PendingCryptoTransaction),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Icrc2PendingCryptoTransaction {
+    #[serde(serialize_with = "serialize_principal_as_bytes")]
+    pub ledger: Principal,
+    pub token_symbol: String,
+    #[serde(serialize_with = "serialize_large_uint")]
+    pub amount: u128,
+    pub from: Account,
+    pub to: Account,
+    #[serde(serialize_with = "serialize_large_uint")]
+    pub fee: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<Memo>,
+    pub created: TimestampNanos,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct ThumbnailData(pub String);
 
@@ -10,6 +10,7 @@ import {
     OCErrorCode,
     PollMessage,
     TextMessage,
+    type ActiveProposalTalliesResponse,
     type BotClientConfig,
     type ChatEventsCriteria,
     type ChatEventsResponse,
@@ -295,6 +296,17 @@ export class BotClient {
             });
     }
 
+    activeProposalTallies(channelId?: bigint): Promise<ActiveProposalTalliesResponse> {
+        return this.#botService
+            .activeProposalTallies(this.#actionContext.chatContext(channelId))
+            .then((resp) => {
+                if (resp.kind === "error") {
+                    console.error("OpenChat botClient.activeProposalTallies failed with: ", resp);
+                }
+                return resp;
+            });
+    }
+
     communitySummary(communityId: CommunityIdentifier): Promise<CommunitySummaryResponse> {
         return this.#botService.communitySummary(communityId).then((resp) => {
             if (resp.kind === "error") {
@@ -15,7 +15,7 @@ export type BotEventWrapper = {
     event: BotEvent;
 };
 
-export type BotEvent = BotChatEvent | BotCommunityEvent | BotLifecycleEvent;
+export type BotEvent = BotChatEvent | BotCommunityEvent | BotLifecycleEvent | BotWebhookEvent;
 
 export type ChatEventType =
     | "message"
@@ -30,6 +30,8 @@ export type ChatEventType =
     | "message_p2p_swap_completed"
     | "message_p2p_swap_cancelled"
     | "message_video_call"
+    | "message_proposal_created"
+    | "message_proposal_decided"
     | "message_other"
     | "created"
     | "name_changed"
@@ -54,7 +56,9 @@ export type ChatEventType =
     | "users_unblocked"
     | "bot_added"
     | "bot_removed"
-    | "bot_updated";
+    | "bot_updated"
+    | "member_warned"
+    | "member_muted";
 
 export type CommunityEventType =
     | "created"
@@ -105,6 +109,13 @@ export type BotCommunityEvent = {
     initiatedBy?: string;
 };
 
+export type BotWebhookEvent = {
+    kind: "bot_webhook_event";
+    webhookId: string;
+    chatId: ChatIdentifier;
+    payload: string;
+};
+
 export type BotRegisteredEvent = {
     kind: "bot_registered_event";
     botId: string;
@@ -34,7 +34,9 @@ export type ChatEvent =
     | ExternalUrlUpdated
     | BotAdded
     | BotRemoved
-    | BotUpdated;
+    | BotUpdated
+    | MemberWarned
+    | MemberMuted;
 
 export type EmptyEvent = {
     kind: "empty";
@@ -91,6 +93,25 @@ export type BotUpdated = {
     updatedBy: string;
 };
 
+export type MemberWarned = {
+    kind: "member_warned";
+    userId: string;
+    warnedBy: string;
+    reason: string;
+    activeWarnings: number;
+    escalation: EscalationAction | undefined;
+};
+
+export type MemberMuted = {
+    kind: "member_muted";
+    userId: string;
+    mutedBy: string;
+    mutedUntil: bigint | undefined;
+    reason: string | undefined;
+};
+
+export type EscalationAction = { kind: "mute"; duration: bigint } | { kind: "remove" };
+
 export type MembersAdded = {
     kind: "members_added";
     userIds: string[];
@@ -14,6 +14,8 @@ export const chatPermissionList = [
     "ReadMessages",
     "ReadMembership",
     "ReadChatSummary",
+    "CastDelegateVote",
+    "MuteMembers",
 ] as const;
 type ChatPermissionType = typeof chatPermissionList;
 export type ChatPermission = ChatPermissionType[number];
@@ -57,7 +59,10 @@ type LowercaseFirstLetter<T extends string> = T extends `${infer First}${infer R
     : T;
 
 export type LowercaseChatPermission = LowercaseFirstLetter<
-    Exclude<ChatPermission, "ReadMessages" | "ReadMembership" | "ReadChatSummary">
+    Exclude<
+        ChatPermission,
+        "ReadMessages" | "ReadMembership" | "ReadChatSummary" | "CastDelegateVote" | "MuteMembers"
+    >
 >;
 export type LowercaseMessagePermission = LowercaseFirstLetter<MessagePermission>;
 export type LowercaseCommunityPermission = LowercaseFirstLetter<
@@ -103,6 +108,8 @@ const chatPermissionsMap = {
     ReadMessages: 10,
     ReadMembership: 11,
     ReadChatSummary: 12,
+    CastDelegateVote: 13,
+    MuteMembers: 14,
 };
 const messagePermissionMap = {
     Text: 0,
@@ -1,5 +1,5 @@
 import type { AccessGateConfig } from "./access";
-import type { ChatEvent } from "./event";
+import type { ChatEvent, Tally } from "./event";
 import type { CommunityIdentifier } from "./identifiers";
 import type { CommunityPermissions, GroupPermissions } from "./permissions";
 import type { VersionedRules } from "./rules";
@@ -11,6 +11,12 @@ export type DeleteChannelResponse = DeleteChannelSuccess | OCError;
 export type ChatSummaryResponse = GroupChatSummary | DirectChatSummary | OCError;
 export type ChatEventsResponse = ChatEventsSuccess | OCError;
 export type CommunitySummaryResponse = CommunitySummary | OCError;
+export type ActiveProposalTalliesResponse = ActiveProposalTallies | OCError;
+
+export type ActiveProposalTallies = {
+    kind: "active_proposal_tallies";
+    tallies: [number, Tally][];
+};
 
 export type CommunitySummary = {
     kind: "community_summary";
@@ -134,6 +140,8 @@ export enum OCErrorCode {
     InitiatorNotInCommunity = 104,
     InitiatorLapsed = 105,
     InitiatorBlocked = 106,
+    BotNotAuthenticated = 107,
+    BotPermissionsMissing = 108,
 
     // Invalid
     ChatNotFound = 200,
@@ -278,6 +286,12 @@ export enum OCErrorCode {
     WebhookNotFound = 339,
     InvalidWebhook = 340,
     InvalidOriginatingCanister = 341,
+    InvalidBotConfig = 342,
+    BotVersionNotFound = 343,
+    InvalidPollBallot = 344,
+    PollNotEnded = 345,
+    BallotAlreadyRevealed = 346,
+    DuplicateBallotCommitment = 347,
 
     // InternalError
     C2CError = 500,
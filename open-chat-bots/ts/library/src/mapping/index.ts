@@ -31,6 +31,7 @@ import {
     type DeleteChannelResponse,
     type DeletedContent,
     DirectChatIdentifier,
+    type EscalationAction,
     type FailedCryptocurrencyTransfer,
     type FileContent,
     type FrozenGroupInfo,
@@ -82,6 +83,8 @@ import {
     type VideoContent,
 } from "../domain";
 import type {
+    ActiveProposalTallies,
+    ActiveProposalTalliesResponse,
     ChannelSummary,
     CommunitySummary,
     CommunitySummaryResponse,
@@ -111,6 +114,7 @@ import {
     type CryptoTransaction as ApiCryptoTransaction,
     type CustomContent as ApiCustomContent,
     type DeletedBy as ApiDeletedBy,
+    type EscalationAction as ApiEscalationAction,
     type EventsResponse as ApiEventsResponse,
     type EventWrapperChatEvent as ApiEventWrapperChatEvent,
     type FailedCryptoTransaction as ApiFailedCryptoTransaction,
@@ -153,6 +157,8 @@ import {
     type VideoContent as ApiVideoContent,
     type LocalUserIndexBotChatEventsResponse as BotChatEventsResponse,
     type LocalUserIndexBotChatSummaryResponse as BotChatSummaryResponse,
+    type LocalUserIndexBotActiveProposalTalliesResponse as BotActiveProposalTalliesResponse,
+    type ActiveProposalTalliesSuccessResult as ApiActiveProposalTallies,
     BotCommandArg,
     type LocalUserIndexBotCommunitySummaryResponse as BotCommunitySummaryResponse,
     type LocalUserIndexBotCreateChannelResponse as BotCreateChannelResponse,
@@ -281,6 +287,27 @@ export function chatSummaryResponse(api: BotChatSummaryResponse): ChatSummaryRes
     return mapResult(api, chatSummary);
 }
 
+export function activeProposalTalliesResponse(
+    api: BotActiveProposalTalliesResponse,
+): ActiveProposalTalliesResponse {
+    return mapResult(api, activeProposalTallies);
+}
+
+function activeProposalTallies(api: ApiActiveProposalTallies): ActiveProposalTallies {
+    return {
+        kind: "active_proposal_tallies",
+        tallies: api.tallies.map(([eventIndex, tally]) => [
+            eventIndex,
+            {
+                yes: Number(tally.yes / E8S_AS_BIGINT),
+                no: Number(tally.no / E8S_AS_BIGINT),
+                total: Number(tally.total / E8S_AS_BIGINT),
+                timestamp: tally.timestamp,
+            },
+        ]),
+    };
+}
+
 export function chatEventsResponse(api: BotChatEventsResponse): ChatEventsResponse {
     return mapResult(api, chatEventsSuccessResponse);
 }
@@ -964,9 +991,37 @@ export function event(value: ApiChatEvent): ChatEvent {
         };
     }
 
+    if ("MemberWarned" in value) {
+        return {
+            kind: "member_warned",
+            userId: principalBytesToString(value.MemberWarned.user_id),
+            warnedBy: principalBytesToString(value.MemberWarned.warned_by),
+            reason: value.MemberWarned.reason,
+            activeWarnings: value.MemberWarned.active_warnings,
+            escalation: optional(value.MemberWarned.escalation, escalationAction),
+        };
+    }
+
+    if ("MemberMuted" in value) {
+        return {
+            kind: "member_muted",
+            userId: principalBytesToString(value.MemberMuted.user_id),
+            mutedBy: principalBytesToString(value.MemberMuted.muted_by),
+            mutedUntil: value.MemberMuted.muted_until,
+            reason: value.MemberMuted.reason,
+        };
+    }
+
     throw new UnsupportedValueError("Unexpected ApiEventWrapper type received", value);
 }
 
+function escalationAction(value: ApiEscalationAction): EscalationAction {
+    if (value === "Remove") {
+        return { kind: "remove" };
+    }
+    return { kind: "mute", duration: value.Mute };
+}
+
 export function message(value: ApiMessage): MessageEvent {
     const sender = principalBytesToString(value.sender);
     const content = messageContent(value.content, sender);
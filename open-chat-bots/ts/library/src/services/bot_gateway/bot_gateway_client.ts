@@ -1,5 +1,6 @@
 import { HttpAgent } from "@dfinity/agent";
 import type {
+    ActiveProposalTalliesResponse,
     BotChatContext,
     BotClientConfig,
     ChannelIdentifier,
@@ -16,6 +17,7 @@ import type {
 } from "../../domain";
 import type { Channel } from "../../domain/channel";
 import {
+    activeProposalTalliesResponse,
     apiBotChatContext,
     apiChatEventsCriteria,
     chatEventsResponse,
@@ -29,6 +31,8 @@ import {
 } from "../../mapping";
 import {
     UnitResult as ApiUnitResult,
+    LocalUserIndexBotActiveProposalTalliesArgs as BotActiveProposalTalliesArgs,
+    LocalUserIndexBotActiveProposalTalliesResponse as BotActiveProposalTalliesResponse,
     LocalUserIndexBotAddReactionArgs as BotAddReactionArgs,
     LocalUserIndexBotChatEventsArgs as BotChatEventsArgs,
     LocalUserIndexBotChatEventsResponse as BotChatEventsResponse,
@@ -167,6 +171,19 @@ export class BotGatewayClient extends MsgpackCanisterAgent {
         });
     }
 
+    activeProposalTallies(ctx: BotChatContext): Promise<ActiveProposalTalliesResponse> {
+        return this.executeMsgpackQuery(
+            "bot_active_proposal_tallies",
+            { chat_context: apiBotChatContext(ctx) },
+            activeProposalTalliesResponse,
+            BotActiveProposalTalliesArgs,
+            BotActiveProposalTalliesResponse,
+        ).catch((err) => {
+            console.error("Call to bot_active_proposal_tallies failed with: ", JSON.stringify(err));
+            throw err;
+        });
+    }
+
     chatEvents(
         ctx: BotChatContext,
         criteria: ChatEventsCriteria,
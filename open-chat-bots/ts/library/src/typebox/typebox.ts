@@ -893,6 +893,8 @@ export const ChatEventType = Type.Union([
     Type.Literal("MessageP2pSwapCompleted"),
     Type.Literal("MessageP2pSwapCancelled"),
     Type.Literal("MessageVideoCall"),
+    Type.Literal("MessageProposalCreated"),
+    Type.Literal("MessageProposalDecided"),
     Type.Literal("MessageOther"),
     Type.Literal("Created"),
     Type.Literal("NameChanged"),
@@ -918,6 +920,8 @@ export const ChatEventType = Type.Union([
     Type.Literal("BotAdded"),
     Type.Literal("BotRemoved"),
     Type.Literal("BotUpdated"),
+    Type.Literal("MemberWarned"),
+    Type.Literal("MemberMuted"),
 ]);
 
 export type OptionUpdateGroupPermissionRole = Static<typeof OptionUpdateGroupPermissionRole>;
@@ -1406,6 +1410,11 @@ export const Tally = Type.Object({
     timestamp: Type.BigInt(),
 });
 
+export type ActiveProposalTalliesSuccessResult = Static<typeof ActiveProposalTalliesSuccessResult>;
+export const ActiveProposalTalliesSuccessResult = Type.Object({
+    tallies: Type.Array(Type.Tuple([EventIndex, Tally])),
+});
+
 export type CryptoTransferDetails = Static<typeof CryptoTransferDetails>;
 export const CryptoTransferDetails = Type.Object({
     r: UserId,
@@ -5053,6 +5062,31 @@ export const BotUpdated = Type.Object({
     updated_by: UserId,
 });
 
+export type EscalationAction = Static<typeof EscalationAction>;
+export const EscalationAction = Type.Union([
+    Type.Object({
+        Mute: Type.BigInt(),
+    }),
+    Type.Literal("Remove"),
+]);
+
+export type MemberWarned = Static<typeof MemberWarned>;
+export const MemberWarned = Type.Object({
+    user_id: UserId,
+    warned_by: UserId,
+    reason: Type.String(),
+    active_warnings: Type.Number(),
+    escalation: Type.Optional(EscalationAction),
+});
+
+export type MemberMuted = Static<typeof MemberMuted>;
+export const MemberMuted = Type.Object({
+    user_id: UserId,
+    muted_by: UserId,
+    muted_until: Type.Optional(Type.BigInt()),
+    reason: Type.Optional(Type.String()),
+});
+
 export type OptionUpdateOptionalMessagePermissions = Static<
     typeof OptionUpdateOptionalMessagePermissions
 >;
@@ -7017,6 +7051,13 @@ export const LocalUserIndexBotChatSummaryArgs = Type.Object({
     chat_context: BotChatContext,
 });
 
+export type LocalUserIndexBotActiveProposalTalliesArgs = Static<
+    typeof LocalUserIndexBotActiveProposalTalliesArgs
+>;
+export const LocalUserIndexBotActiveProposalTalliesArgs = Type.Object({
+    chat_context: BotChatContext,
+});
+
 export type LocalUserIndexChatEventsEventsArgs = Static<typeof LocalUserIndexChatEventsEventsArgs>;
 export const LocalUserIndexChatEventsEventsArgs = Type.Object({
     context: LocalUserIndexChatEventsEventsContext,
@@ -7359,31 +7400,6 @@ export const BotMessageContext = Type.Object({
     finalised: Type.Boolean(),
 });
 
-export type BotMessageContent = Static<typeof BotMessageContent>;
-export const BotMessageContent = Type.Union([
-    Type.Object({
-        Text: TextContent,
-    }),
-    Type.Object({
-        Image: ImageContent,
-    }),
-    Type.Object({
-        Video: VideoContent,
-    }),
-    Type.Object({
-        Audio: AudioContent,
-    }),
-    Type.Object({
-        File: FileContent,
-    }),
-    Type.Object({
-        Poll: PollContent,
-    }),
-    Type.Object({
-        Giphy: GiphyContent,
-    }),
-]);
-
 export type CurrentUserSummary = Static<typeof CurrentUserSummary>;
 export const CurrentUserSummary = Type.Object({
     user_id: UserId,
@@ -7557,6 +7573,57 @@ export const LocalUserIndexAccessTokenV2Args = Type.Union([
     }),
 ]);
 
+export type CryptoContent = Static<typeof CryptoContent>;
+export const CryptoContent = Type.Object({
+    recipient: UserId,
+    transfer: CryptoTransaction,
+    caption: Type.Optional(Type.String()),
+});
+
+export type PrizeContentInitial = Static<typeof PrizeContentInitial>;
+export const PrizeContentInitial = Type.Object({
+    prizes_v2: Type.Array(Type.BigInt()),
+    transfer: CryptoTransaction,
+    end_date: Type.BigInt(),
+    caption: Type.Optional(Type.String()),
+    diamond_only: Type.Boolean(),
+    lifetime_diamond_only: Type.Boolean(),
+    unique_person_only: Type.Boolean(),
+    streak_only: Type.Number(),
+    requires_captcha: Type.Boolean(),
+});
+
+export type BotMessageContent = Static<typeof BotMessageContent>;
+export const BotMessageContent = Type.Union([
+    Type.Object({
+        Text: TextContent,
+    }),
+    Type.Object({
+        Image: ImageContent,
+    }),
+    Type.Object({
+        Video: VideoContent,
+    }),
+    Type.Object({
+        Audio: AudioContent,
+    }),
+    Type.Object({
+        File: FileContent,
+    }),
+    Type.Object({
+        Poll: PollContent,
+    }),
+    Type.Object({
+        Giphy: GiphyContent,
+    }),
+    Type.Object({
+        Crypto: CryptoContent,
+    }),
+    Type.Object({
+        Prize: PrizeContentInitial,
+    }),
+]);
+
 export type LocalUserIndexBotSendMessageArgs = Static<typeof LocalUserIndexBotSendMessageArgs>;
 export const LocalUserIndexBotSendMessageArgs = Type.Object({
     chat_context: BotChatContext,
@@ -7617,26 +7684,6 @@ export const UserSendMessageWithTransferToChannelResponse = Type.Union([
     }),
 ]);
 
-export type CryptoContent = Static<typeof CryptoContent>;
-export const CryptoContent = Type.Object({
-    recipient: UserId,
-    transfer: CryptoTransaction,
-    caption: Type.Optional(Type.String()),
-});
-
-export type PrizeContentInitial = Static<typeof PrizeContentInitial>;
-export const PrizeContentInitial = Type.Object({
-    prizes_v2: Type.Array(Type.BigInt()),
-    transfer: CryptoTransaction,
-    end_date: Type.BigInt(),
-    caption: Type.Optional(Type.String()),
-    diamond_only: Type.Boolean(),
-    lifetime_diamond_only: Type.Boolean(),
-    unique_person_only: Type.Boolean(),
-    streak_only: Type.Number(),
-    requires_captcha: Type.Boolean(),
-});
-
 export type OptionUpdateAccessGate = Static<typeof OptionUpdateAccessGate>;
 export const OptionUpdateAccessGate = Type.Union(
     [
@@ -8284,6 +8331,12 @@ export const ChatEvent = Type.Union([
     Type.Object({
         BotUpdated: BotUpdated,
     }),
+    Type.Object({
+        MemberWarned: MemberWarned,
+    }),
+    Type.Object({
+        MemberMuted: MemberMuted,
+    }),
     Type.Literal("FailedToDeserialize"),
 ]);
 
@@ -8372,6 +8425,18 @@ export const LocalUserIndexBotChatSummaryResponse = Type.Union([
     }),
 ]);
 
+export type LocalUserIndexBotActiveProposalTalliesResponse = Static<
+    typeof LocalUserIndexBotActiveProposalTalliesResponse
+>;
+export const LocalUserIndexBotActiveProposalTalliesResponse = Type.Union([
+    Type.Object({
+        Success: ActiveProposalTalliesSuccessResult,
+    }),
+    Type.Object({
+        Error: OCError,
+    }),
+]);
+
 export type CommunityUndeleteMessagesSuccessResult = Static<
     typeof CommunityUndeleteMessagesSuccessResult
 >;
@@ -18,6 +18,7 @@ import type {
     BotLifecycleEvent,
     BotRegisteredEvent,
     BotUninstalledEvent,
+    BotWebhookEvent,
     ChatEventType,
     CommunityEventType,
 } from "../domain/bot_events";
@@ -67,6 +68,10 @@ function parseBotEvent(obj: any): BotEvent {
         return parseBotLifecycleEvent(obj.l);
     }
 
+    if ("w" in obj) {
+        return parseBotWebhookEvent(obj.w);
+    }
+
     throw new Error("Unknown BotEvent type");
 }
 
@@ -147,6 +152,19 @@ function parseBotLifecycleEvent(obj: any): BotLifecycleEvent {
     throw new Error("Unknown BotLifecycleEvent type");
 }
 
+function parseBotWebhookEvent(obj: any): BotWebhookEvent {
+    if (obj == null || typeof obj !== "object" || obj.w == null || obj.c == null || obj.p == null) {
+        throw new Error("Invalid BotWebhookEvent");
+    }
+
+    return {
+        kind: "bot_webhook_event",
+        webhookId: principalBytesToString(obj.w),
+        chatId: parseChatIdentifier(obj.c),
+        payload: obj.p,
+    };
+}
+
 function parseBotRegisteredEvent(obj: any): BotRegisteredEvent {
     if (obj == null || typeof obj !== "object" || obj.i == null || obj.n == null) {
         throw new Error("Invalid BotRegisteredEvent");
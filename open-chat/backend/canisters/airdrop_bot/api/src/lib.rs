@@ -1,12 +1,14 @@
 mod lifecycle;
+mod lottery;
 mod updates;
 
 use candid::CandidType;
 pub use lifecycle::*;
+pub use lottery::*;
 pub use updates::*;
 
 use serde::{Deserialize, Serialize};
-use types::{ChannelId, CommunityId, TimestampMillis};
+use types::{CanisterId, ChannelId, CommunityId, TimestampMillis};
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct AirdropConfig {
@@ -16,6 +18,14 @@ pub struct AirdropConfig {
     pub algorithm: AirdropAlgorithm,
 }
 
+// The token a community airdrop is paid out in. The platform airdrops are always paid in CHAT.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct AirdropToken {
+    pub ledger_canister_id: CanisterId,
+    pub symbol: String,
+    pub fee: u128,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum AirdropAlgorithm {
     V1(V1Algorithm),
@@ -39,3 +49,13 @@ pub struct V2Algorithm {
     pub lottery_min_streak: u16,
     pub min_minutes_online: u16,
 }
+
+impl AirdropAlgorithm {
+    // The total amount paid out by the main airdrop plus the lottery
+    pub fn total_fund(&self) -> u128 {
+        match self {
+            AirdropAlgorithm::V1(v1) => v1.main_chat_fund + v1.lottery_prizes.iter().sum::<u128>(),
+            AirdropAlgorithm::V2(v2) => v2.main_chat_fund + v2.lottery_prizes.iter().sum::<u128>(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use sha256::sha256;
+use types::UserId;
+
+// Bump this if the way tickets are hashed or winners are drawn ever changes so that old draws remain verifiable
+pub const LOTTERY_ALGORITHM_VERSION: u32 = 1;
+
+// Everything needed to independently recompute the lottery winners of an airdrop
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LotteryDraw {
+    pub algorithm_version: u32,
+    pub seed: [u8; 32],
+    pub tickets_hash: [u8; 32],
+    // Each user's number of tickets, sorted by user id
+    pub tickets: Vec<(UserId, u32)>,
+    pub prize_count: u32,
+    pub winners: Vec<UserId>,
+}
+
+impl LotteryDraw {
+    pub fn new(seed: [u8; 32], mut tickets: Vec<(UserId, u32)>, prize_count: u32) -> LotteryDraw {
+        tickets.retain(|(_, count)| *count > 0);
+        tickets.sort();
+
+        LotteryDraw {
+            algorithm_version: LOTTERY_ALGORITHM_VERSION,
+            seed,
+            tickets_hash: tickets_hash(&tickets),
+            winners: draw_lottery_winners(seed, &tickets, prize_count),
+            tickets,
+            prize_count,
+        }
+    }
+
+    pub fn ticket_count(&self) -> u32 {
+        self.tickets.iter().map(|(_, count)| count).sum()
+    }
+}
+
+pub fn tickets_hash(tickets: &[(UserId, u32)]) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    for (user_id, count) in tickets {
+        let user_id_bytes = user_id.as_slice();
+        bytes.push(user_id_bytes.len() as u8);
+        bytes.extend_from_slice(user_id_bytes);
+        bytes.extend_from_slice(&count.to_be_bytes());
+    }
+    sha256(&bytes)
+}
+
+// The tickets are laid out in order, each user holding a run of consecutive tickets. For each prize the winning ticket
+// is taken from sha256(seed || round), then all of the winner's other tickets are removed so that the same user can't
+// win multiple times.
+pub fn draw_lottery_winners(seed: [u8; 32], tickets: &[(UserId, u32)], prize_count: u32) -> Vec<UserId> {
+    let mut tickets: Vec<UserId> = tickets
+        .iter()
+        .flat_map(|(user_id, count)| std::iter::repeat_n(*user_id, *count as usize))
+        .collect();
+    let mut winners = Vec::new();
+
+    for round in 0..prize_count {
+        if tickets.is_empty() {
+            break;
+        }
+
+        let mut input = seed.to_vec();
+        input.extend_from_slice(&round.to_be_bytes());
+        let hash = sha256(&input);
+        let random = u64::from_be_bytes(hash[..8].try_into().unwrap());
+
+        let winner = tickets.remove((random % tickets.len() as u64) as usize);
+        tickets.retain(|u| *u != winner);
+        winners.push(winner);
+    }
+
+    winners
+}
@@ -1,10 +1,16 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::Empty;
 
-pub type Args = Empty;
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // If set, cancels the given community airdrop and refunds its escrowed funds, otherwise cancels the next
+    // platform airdrop
+    pub community_airdrop_id: Option<u32>,
+}
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub enum Response {
     Success,
+    NotFound,
+    NotAuthorized,
 }
@@ -3,3 +3,4 @@ pub mod cancel_airdrop;
 pub mod handle_direct_message;
 pub mod set_airdrop;
 pub mod set_avatar;
+pub mod set_community_airdrop;
@@ -0,0 +1,27 @@
+use crate::{AirdropConfig, AirdropToken};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub config: AirdropConfig,
+    pub token: AirdropToken,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    ChannelUsed,
+    InThePast,
+    ClashesWithPrevious,
+    NotCommunityOwner,
+    InvalidAlgorithm(String),
+    TransferFailed(String),
+    InternalError(String),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub airdrop_id: u32,
+    pub escrow_block_index: u64,
+}
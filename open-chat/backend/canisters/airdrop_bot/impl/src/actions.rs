@@ -1,7 +1,9 @@
 use crate::jobs::execute_airdrop::start_airdrop_timer;
-use crate::{USERNAME, mutate_state, read_state};
-use candid::Deserialize;
-use constants::{CHAT_TRANSFER_FEE, MEMO_CHIT_FOR_CHAT_AIRDROP, MEMO_CHIT_FOR_CHAT_LOTTERY};
+use crate::model::community_airdrops::escrow_subaccount;
+use crate::{RuntimeState, USERNAME, mutate_state, read_state};
+use airdrop_bot_canister::AirdropToken;
+use candid::{Deserialize, Principal};
+use constants::{CHAT_TRANSFER_FEE, MEMO_CHIT_FOR_CHAT_AIRDROP, MEMO_CHIT_FOR_CHAT_LOTTERY, MINUTE_IN_MS};
 use icrc_ledger_types::icrc1::transfer::{TransferArg, TransferError};
 use rand::Rng;
 use serde::Serialize;
@@ -10,7 +12,7 @@ use tracing::{error, info, trace};
 use types::icrc1::{self, Account};
 use types::{
     BotMessage, CanisterId, ChannelId, CommunityId, CompletedCryptoTransaction, CryptoContent, CryptoTransaction,
-    MessageContentInitial, Milliseconds, UserId,
+    Cryptocurrency, MessageContentInitial, Milliseconds, UserId,
 };
 use utils::canister::delay_if_should_retry_failed_c2c_call;
 use utils::time::{MONTHS, MonthKey};
@@ -24,6 +26,7 @@ impl TimerJobItem for Action {
             }
             Action::SendMessage(a) => handle_main_message_action(*a).await,
             Action::Transfer(a) => handle_transfer_action(*a).await,
+            Action::Refund(a) => handle_refund_action(*a).await,
         }
     }
 }
@@ -33,6 +36,7 @@ pub enum Action {
     JoinChannel(CommunityId, ChannelId),
     Transfer(Box<AirdropTransfer>),
     SendMessage(Box<AirdropMessage>),
+    Refund(Box<AirdropRefund>),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -40,6 +44,8 @@ pub struct AirdropTransfer {
     pub recipient: UserId,
     pub amount: u128,
     pub airdrop_type: AirdropType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub community_airdrop_id: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -47,6 +53,17 @@ pub struct AirdropMessage {
     pub recipient: UserId,
     pub transaction: CompletedCryptoTransaction,
     pub airdrop_type: AirdropType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub community_airdrop_id: Option<u32>,
+}
+
+// Returns escrowed funds of a community airdrop to the owner who funded it
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AirdropRefund {
+    pub community_airdrop_id: u32,
+    pub token: AirdropToken,
+    pub recipient: Principal,
+    pub amount: u128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -118,36 +135,79 @@ async fn join_channel(community_id: CommunityId, channel_id: ChannelId) -> Resul
     Ok(())
 }
 
+// Where the funds of an airdrop are paid from
+struct FundingSource {
+    ledger_canister_id: CanisterId,
+    token: Cryptocurrency,
+    fee: u128,
+    from_subaccount: Option<[u8; 32]>,
+}
+
+impl FundingSource {
+    fn for_airdrop(community_airdrop_id: Option<u32>, state: &RuntimeState) -> Option<FundingSource> {
+        match community_airdrop_id {
+            None => Some(FundingSource {
+                ledger_canister_id: state.data.chat_ledger_canister_id,
+                #[expect(deprecated)]
+                token: Cryptocurrency::CHAT,
+                fee: CHAT_TRANSFER_FEE,
+                from_subaccount: None,
+            }),
+            Some(id) => state.data.community_airdrops.get(id).map(|a| FundingSource {
+                ledger_canister_id: a.token.ledger_canister_id,
+                token: Cryptocurrency::from(a.token.symbol.clone()),
+                fee: a.token.fee,
+                from_subaccount: Some(a.escrow_subaccount()),
+            }),
+        }
+    }
+}
+
 async fn handle_transfer_action(action: AirdropTransfer) -> Result<(), Option<Milliseconds>> {
-    let amount = action.amount.into();
+    let Some((this_canister_id, source, now_nanos)) = read_state(|state| {
+        FundingSource::for_airdrop(action.community_airdrop_id, state)
+            .map(|source| (state.env.canister_id(), source, state.env.now_nanos()))
+    }) else {
+        error!(?action, "Airdrop not found");
+        return Err(None);
+    };
 
-    trace!(?amount, "CHAT Transfer");
+    // The platform airdrops pay the transfer fees on top of each prize, whereas for community airdrops the fees
+    // come out of the prizes so that the escrowed funds always cover them
+    let amount = if let Some(id) = action.community_airdrop_id {
+        if action.amount <= source.fee {
+            // The prize can't cover the transfer fee so it is returned to the owner instead
+            mutate_state(|state| refund_unpaid_prize(id, action.amount, state));
+            return Ok(());
+        }
+        action.amount - source.fee
+    } else {
+        action.amount
+    };
 
-    let (this_canister_id, ledger_canister_id, now_nanos) = read_state(|state| {
-        (
-            state.env.canister_id(),
-            state.data.chat_ledger_canister_id,
-            state.env.now_nanos(),
-        )
-    });
+    trace!(?amount, token = source.token.token_symbol(), "Airdrop transfer");
 
     let to = Account::from(action.recipient);
+    let from = Account {
+        owner: this_canister_id,
+        subaccount: source.from_subaccount,
+    };
     let memo = match action.airdrop_type {
         AirdropType::Main(_) => MEMO_CHIT_FOR_CHAT_AIRDROP,
         AirdropType::Lottery(_) => MEMO_CHIT_FOR_CHAT_LOTTERY,
     };
-    let fee = CHAT_TRANSFER_FEE;
+    let fee = source.fee;
 
     let args = TransferArg {
-        from_subaccount: None,
+        from_subaccount: source.from_subaccount,
         to: to.into(),
         fee: Some(fee.into()),
         created_at_time: Some(now_nanos),
         memo: Some(memo.to_vec().into()),
-        amount,
+        amount: amount.into(),
     };
 
-    match icrc_ledger_canister_c2c_client::icrc1_transfer(ledger_canister_id, &args).await {
+    match icrc_ledger_canister_c2c_client::icrc1_transfer(source.ledger_canister_id, &args).await {
         Ok(Ok(block_index)) => {
             mutate_state(|state| {
                 let block_index = block_index.0.try_into().unwrap();
@@ -155,38 +215,54 @@ async fn handle_transfer_action(action: AirdropTransfer) -> Result<(), Option<Mi
                 let message_action = Action::SendMessage(Box::new(AirdropMessage {
                     recipient: action.recipient,
                     transaction: CompletedCryptoTransaction::ICRC1(icrc1::CompletedCryptoTransaction {
-                        ledger: ledger_canister_id,
-                        #[expect(deprecated)]
-                        token: types::Cryptocurrency::CHAT,
-                        amount: action.amount,
+                        ledger: source.ledger_canister_id,
+                        token: source.token,
+                        amount,
                         fee,
-                        from: Account::from(this_canister_id).into(),
+                        from: from.into(),
                         to: to.into(),
                         memo: Some(memo.to_vec().into()),
                         created: now_nanos,
                         block_index,
                     }),
                     airdrop_type: action.airdrop_type.clone(),
+                    community_airdrop_id: action.community_airdrop_id,
                 }));
 
                 state.data.pending_actions_queue.push_front(message_action);
 
-                match action.airdrop_type {
-                    AirdropType::Lottery(LotteryAirdrop { position }) => {
+                match (action.airdrop_type, action.community_airdrop_id) {
+                    (AirdropType::Lottery(LotteryAirdrop { position }), None) => {
                         state.data.airdrops.set_lottery_transaction(position, block_index)
                     }
-                    AirdropType::Main(_) => state.data.airdrops.set_main_transaction(&action.recipient, block_index),
+                    (AirdropType::Main(_), None) => state.data.airdrops.set_main_transaction(&action.recipient, block_index),
+                    (AirdropType::Lottery(LotteryAirdrop { position }), Some(id)) => state
+                        .data
+                        .community_airdrops
+                        .set_lottery_transaction(id, position, block_index),
+                    (AirdropType::Main(_), Some(id)) => {
+                        state
+                            .data
+                            .community_airdrops
+                            .set_main_transaction(id, &action.recipient, block_index)
+                    }
                 }
             });
         }
         Ok(Err(TransferError::InsufficientFunds { balance })) => {
-            error!(?args, ?balance, "Failed to transfer CHAT, insufficient funds");
+            error!(?args, ?balance, "Failed to transfer airdrop, insufficient funds");
         }
         Ok(error) => {
-            error!(?args, ?error, "Failed to transfer CHAT");
+            error!(?args, ?error, "Failed to transfer airdrop");
+            if let Some(id) = action
+                .community_airdrop_id
+                .filter(|_| !matches!(error, Err(TransferError::Duplicate { .. })))
+            {
+                mutate_state(|state| refund_unpaid_prize(id, action.amount, state));
+            }
         }
         Err(error) => {
-            error!(?args, ?error, "Failed to transfer CHAT, retrying");
+            error!(?args, ?error, "Failed to transfer airdrop, retrying");
             return Err(Some(0));
         }
     }
@@ -194,6 +270,82 @@ async fn handle_transfer_action(action: AirdropTransfer) -> Result<(), Option<Mi
     Ok(())
 }
 
+fn refund_unpaid_prize(community_airdrop_id: u32, amount: u128, state: &mut RuntimeState) {
+    if let Some(amount) = state.data.community_airdrops.add_unpaid(community_airdrop_id, amount) {
+        if let Some(airdrop) = state.data.community_airdrops.get(community_airdrop_id) {
+            let refund = Action::Refund(Box::new(AirdropRefund {
+                community_airdrop_id,
+                token: airdrop.token.clone(),
+                recipient: airdrop.owner,
+                amount,
+            }));
+            state.data.pending_actions_queue.push(refund);
+        } else {
+            error!(
+                community_airdrop_id,
+                amount, "Failed to refund unpaid prize, community airdrop not found"
+            );
+        }
+    }
+}
+
+async fn handle_refund_action(action: AirdropRefund) -> Result<(), Option<Milliseconds>> {
+    let fee = action.token.fee;
+    if action.amount <= fee {
+        return Ok(());
+    }
+
+    info!(?action, "Refunding community airdrop");
+
+    let args = TransferArg {
+        from_subaccount: Some(escrow_subaccount(action.community_airdrop_id)),
+        to: Account::from(action.recipient).into(),
+        fee: Some(fee.into()),
+        created_at_time: Some(read_state(|state| state.env.now_nanos())),
+        memo: None,
+        amount: (action.amount - fee).into(),
+    };
+
+    match icrc_ledger_canister_c2c_client::icrc1_transfer(action.token.ledger_canister_id, &args).await {
+        Ok(Ok(_)) | Ok(Err(TransferError::Duplicate { .. })) => Ok(()),
+        Ok(Err(TransferError::BadFee { expected_fee })) => {
+            // Retry with the ledger's current fee
+            let Ok(expected_fee): Result<u128, _> = expected_fee.0.try_into() else {
+                error!(?args, %expected_fee, "Failed to refund community airdrop, invalid fee");
+                return Err(Some(MINUTE_IN_MS));
+            };
+            let mut action = action;
+            action.token.fee = expected_fee;
+            mutate_state(|state| state.data.pending_actions_queue.push(Action::Refund(Box::new(action))));
+            Ok(())
+        }
+        Ok(Err(TransferError::InsufficientFunds { balance })) => {
+            // Refund whatever remains in escrow
+            let Ok(balance): Result<u128, _> = balance.0.try_into() else {
+                error!(?args, %balance, "Failed to refund community airdrop, invalid balance");
+                return Err(Some(MINUTE_IN_MS));
+            };
+            error!(?args, balance, "Failed to refund community airdrop, insufficient funds");
+            if balance > fee {
+                let action = AirdropRefund {
+                    amount: balance,
+                    ..action
+                };
+                mutate_state(|state| state.data.pending_actions_queue.push(Action::Refund(Box::new(action))));
+            }
+            Ok(())
+        }
+        Ok(error) => {
+            error!(?args, ?error, "Failed to refund community airdrop, retrying");
+            Err(Some(MINUTE_IN_MS))
+        }
+        Err(error) => {
+            error!(?args, ?error, "Failed to refund community airdrop, retrying");
+            Err(Some(0))
+        }
+    }
+}
+
 async fn handle_main_message_action(action: AirdropMessage) -> Result<(), Option<Milliseconds>> {
     trace!("Send DM");
 
@@ -201,11 +353,17 @@ async fn handle_main_message_action(action: AirdropMessage) -> Result<(), Option
         return Ok(());
     };
 
-    let Some(month) = read_state(|state| {
-        state.data.airdrops.current(state.env.now()).map(|c| {
-            let mk = MonthKey::from_timestamp(c.start).previous();
-            MONTHS[mk.month() as usize - 1]
-        })
+    let Some((month, airdrop_name)) = read_state(|state| {
+        let (config, airdrop_name) = match action.community_airdrop_id {
+            None => (state.data.airdrops.current(state.env.now())?, "CHIT for CHAT".to_string()),
+            Some(id) => state
+                .data
+                .community_airdrops
+                .get(id)
+                .map(|a| (&a.config, a.token.symbol.clone()))?,
+        };
+        let mk = MonthKey::from_timestamp(config.start).previous();
+        Some((MONTHS[mk.month() as usize - 1], airdrop_name))
     }) else {
         return Ok(());
     };
@@ -219,7 +377,7 @@ async fn handle_main_message_action(action: AirdropMessage) -> Result<(), Option
                 recipient: action.recipient,
                 transfer: CryptoTransaction::Completed(action.transaction.clone()),
                 caption: Some(format!(
-                    "Congratulations! In {month} you earned {chit} CHIT giving you {shares} shares in the CHIT for CHAT airdrop."
+                    "Congratulations! In {month} you earned {chit} CHIT giving you {shares} shares in the {airdrop_name} airdrop."
                 )),
             }),
             message_id: None,
@@ -248,12 +406,40 @@ async fn handle_lottery_message_action(action: AirdropMessage) -> Result<(), Opt
         return Err(None);
     };
 
-    let Some((community_id, channel_id, message_id)) = mutate_state(|state| {
-        state
-            .data
-            .airdrops
-            .current(state.env.now())
-            .map(|c| (c.community_id, c.channel_id, state.env.rng().r#gen()))
+    let Some((community_id, channel_id, message_id, airdrop_name, verification)) = mutate_state(|state| {
+        let now = state.env.now();
+        let canister_id = state.env.canister_id();
+        let (config, draw, airdrop_name, draw_route) = match action.community_airdrop_id {
+            None => (
+                state.data.airdrops.current(now)?,
+                state.data.airdrops.current_lottery_draw(now),
+                "CHIT for CHAT".to_string(),
+                format!(
+                    "https://{canister_id}.raw.icp0.io/lottery?airdrop={}",
+                    state.data.airdrops.latest_index()?
+                ),
+            ),
+            Some(id) => state.data.community_airdrops.get(id).map(|a| {
+                (
+                    &a.config,
+                    a.lottery_draw(),
+                    a.token.symbol.clone(),
+                    format!("https://{canister_id}.raw.icp0.io/lottery?community_airdrop={id}"),
+                )
+            })?,
+        };
+        // The 1st prize is announced last, so that message also publishes what's needed to verify the draw
+        let verification = draw.filter(|_| position == 0).map(|d| {
+            format!(
+                "\n\nThe lottery was drawn using algorithm v{} from {} tickets (sha256: {}) with the random seed {}. The full draw is published at {draw_route}.",
+                d.algorithm_version,
+                d.ticket_count(),
+                hex::encode(d.tickets_hash),
+                hex::encode(d.seed)
+            )
+        });
+        let (community_id, channel_id) = (config.community_id, config.channel_id);
+        Some((community_id, channel_id, state.env.rng().r#gen(), airdrop_name, verification))
     }) else {
         return Err(None);
     };
@@ -276,7 +462,8 @@ async fn handle_lottery_message_action(action: AirdropMessage) -> Result<(), Opt
             recipient: action.recipient,
             transfer: CryptoTransaction::Completed(action.transaction.clone()),
             caption: Some(format!(
-                "Congratulations! You have won {position} prize in the CHIT for CHAT airdrop lottery!"
+                "Congratulations! You have won {position} prize in the {airdrop_name} airdrop lottery!{}",
+                verification.unwrap_or_default()
             )),
         }),
         sender_name: USERNAME.to_string(),
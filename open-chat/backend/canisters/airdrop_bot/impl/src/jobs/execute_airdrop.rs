@@ -1,4 +1,5 @@
 use crate::actions::{Action, AirdropTransfer, AirdropType, LotteryAirdrop, MainAirdrop};
+use crate::model::airdrops::AirdropOutcome;
 use crate::{RuntimeState, mutate_state, read_state};
 use airdrop_bot_canister::{AirdropAlgorithm, AirdropConfig};
 use ic_cdk_timers::TimerId;
@@ -7,6 +8,7 @@ use std::iter::zip;
 use std::time::Duration;
 use tracing::{error, trace};
 use types::{AccessGate, CanisterId, Chit, GroupRole, OptionUpdate, UserId};
+use utils::canister::get_random_seed;
 use utils::time::MonthKey;
 
 thread_local! {
@@ -66,7 +68,9 @@ async fn prepare_airdrop(config: AirdropConfig, user_index_canister_id: Canister
             gate_config: OptionUpdate::SetToSome(AccessGate::Locked.into()),
             public: None,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
             external_url: OptionUpdate::NoChange,
+            slow_mode: OptionUpdate::NoChange,
         },
     )
     .await
@@ -84,6 +88,33 @@ async fn prepare_airdrop(config: AirdropConfig, user_index_canister_id: Canister
         }
     }
 
+    let participants = match fetch_participants(&config, user_index_canister_id).await {
+        Ok(participants) => participants,
+        Err(FetchParticipantsError::Retry) => {
+            let timer_id = ic_cdk_timers::set_timer(Duration::from_secs(60), run);
+            TIMER_ID.set(Some(timer_id));
+            return;
+        }
+        Err(FetchParticipantsError::Failed) => return,
+    };
+
+    // The lottery is drawn from a fresh `raw_rand` seed which is recorded and published so the draw can be verified
+    let seed = get_random_seed().await;
+
+    // Execute the airdrop
+    mutate_state(|state| execute_airdrop(participants, seed, state));
+}
+
+pub(crate) enum FetchParticipantsError {
+    Retry,
+    Failed,
+}
+
+// Fetches the members of the airdrop's channel along with their CHIT for the month before the airdrop
+pub(crate) async fn fetch_participants(
+    config: &AirdropConfig,
+    user_index_canister_id: CanisterId,
+) -> Result<Vec<(UserId, Chit)>, FetchParticipantsError> {
     // Call the configured community canister to fetch the participants of the configured channel
     let members = match community_canister_c2c_client::selected_channel_initial(
         config.community_id.into(),
@@ -96,13 +127,11 @@ async fn prepare_airdrop(config: AirdropConfig, user_index_canister_id: Canister
         Ok(community_canister::selected_channel_initial::Response::Success(success)) => success.members(),
         Ok(resp) => {
             error!(?resp, "Failed to get channel members");
-            return;
+            return Err(FetchParticipantsError::Failed);
         }
         Err(err) => {
             error!("{err:?}");
-            let timer_id = ic_cdk_timers::set_timer(Duration::from_secs(60), run);
-            TIMER_ID.set(Some(timer_id));
-            return;
+            return Err(FetchParticipantsError::Retry);
         }
     };
 
@@ -135,66 +164,66 @@ async fn prepare_airdrop(config: AirdropConfig, user_index_canister_id: Canister
         Ok(user_index_canister::users_chit::Response::Success(result)) => result.chit,
         Err(err) => {
             error!("{err:?}");
-            let timer_id = ic_cdk_timers::set_timer(Duration::from_secs(60), run);
-            TIMER_ID.set(Some(timer_id));
-            return;
+            return Err(FetchParticipantsError::Retry);
         }
     };
 
-    let participants = zip(users, chit).collect();
+    Ok(zip(users, chit).collect())
+}
 
-    // Execute the airdrop
-    mutate_state(|state| execute_airdrop(participants, state));
+fn execute_airdrop(participants: Vec<(UserId, Chit)>, seed: [u8; 32], state: &mut RuntimeState) {
+    if let Some(airdrop) = state.data.airdrops.execute(participants, seed) {
+        let actions = airdrop_transfer_actions(&airdrop.outcome, None);
+        state.data.pending_actions_queue.push_many(actions.into_iter());
+    }
 }
 
-fn execute_airdrop(participants: Vec<(UserId, Chit)>, state: &mut RuntimeState) {
-    let rng = state.env.rng();
-
-    if let Some(airdrop) = state.data.airdrops.execute(participants, rng) {
-        // Add the CHAT transfer actions to the queue. When each transfer has succeeded
-        // the corresponding message action will be added to the queue.
-
-        // Add some suspense to the lottery winning messages by sending them
-        // one at a time, from nth to 1st, spaced by a bunch of main airdrop messages.
-
-        let mut lottery_winners = airdrop.outcome.lottery_winners.clone();
-        let mut actions = Vec::new();
-
-        for (user_id, participant) in airdrop.outcome.participants.iter() {
-            if actions.len() % 500 == 0 {
-                if let Some((user_id, prize)) = lottery_winners.pop() {
-                    actions.push(Action::Transfer(Box::new(AirdropTransfer {
-                        recipient: user_id,
-                        amount: prize.chat_won,
-                        airdrop_type: AirdropType::Lottery(LotteryAirdrop {
-                            position: lottery_winners.len(),
-                        }),
-                    })))
-                }
-            }
+// Builds the transfer actions for the given airdrop outcome. When each transfer has succeeded
+// the corresponding message action will be added to the queue.
+pub(crate) fn airdrop_transfer_actions(outcome: &AirdropOutcome, community_airdrop_id: Option<u32>) -> Vec<Action> {
+    // Add some suspense to the lottery winning messages by sending them
+    // one at a time, from nth to 1st, spaced by a bunch of main airdrop messages.
+
+    let mut lottery_winners = outcome.lottery_winners.clone();
+    let mut actions = Vec::new();
 
-            if let Some(prize) = &participant.prize {
+    for (user_id, participant) in outcome.participants.iter() {
+        if actions.len() % 500 == 0 {
+            if let Some((user_id, prize)) = lottery_winners.pop() {
                 actions.push(Action::Transfer(Box::new(AirdropTransfer {
-                    recipient: *user_id,
+                    recipient: user_id,
                     amount: prize.chat_won,
-                    airdrop_type: AirdropType::Main(MainAirdrop {
-                        chit: participant.chit,
-                        shares: participant.shares,
+                    airdrop_type: AirdropType::Lottery(LotteryAirdrop {
+                        position: lottery_winners.len(),
                     }),
+                    community_airdrop_id,
                 })))
             }
         }
 
-        while let Some((user_id, prize)) = lottery_winners.pop() {
+        if let Some(prize) = &participant.prize {
             actions.push(Action::Transfer(Box::new(AirdropTransfer {
-                recipient: user_id,
+                recipient: *user_id,
                 amount: prize.chat_won,
-                airdrop_type: AirdropType::Lottery(LotteryAirdrop {
-                    position: lottery_winners.len(),
+                airdrop_type: AirdropType::Main(MainAirdrop {
+                    chit: participant.chit,
+                    shares: participant.shares,
                 }),
+                community_airdrop_id,
             })))
         }
+    }
 
-        state.data.pending_actions_queue.push_many(actions.into_iter());
+    while let Some((user_id, prize)) = lottery_winners.pop() {
+        actions.push(Action::Transfer(Box::new(AirdropTransfer {
+            recipient: user_id,
+            amount: prize.chat_won,
+            airdrop_type: AirdropType::Lottery(LotteryAirdrop {
+                position: lottery_winners.len(),
+            }),
+            community_airdrop_id,
+        })))
     }
+
+    actions
 }
@@ -0,0 +1,112 @@
+use crate::actions::{Action, AirdropRefund};
+use crate::jobs::execute_airdrop::{FetchParticipantsError, airdrop_transfer_actions, fetch_participants};
+use crate::{RuntimeState, mutate_state, read_state};
+use airdrop_bot_canister::AirdropConfig;
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+use types::{CanisterId, Chit, UserId};
+use utils::canister::get_random_seed;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() { start_community_airdrop_timer(state) } else { false }
+}
+
+pub(crate) fn start_community_airdrop_timer(state: &RuntimeState) -> bool {
+    clear_community_airdrop_timer();
+
+    if let Some(airdrop) = state.data.community_airdrops.next_due() {
+        // Start the airdrop now if the start date is in the past
+        let delay = airdrop.config.start.saturating_sub(state.env.now());
+        let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(delay), run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+pub(crate) fn clear_community_airdrop_timer() {
+    if let Some(timer_id) = TIMER_ID.take() {
+        ic_cdk_timers::clear_timer(timer_id);
+    }
+}
+
+fn run() {
+    trace!("'execute_community_airdrops' running");
+    TIMER_ID.set(None);
+
+    let (next, user_index_canister_id) = read_state(|state| {
+        (
+            state
+                .data
+                .community_airdrops
+                .next_due()
+                .filter(|a| a.config.start <= state.env.now())
+                .map(|a| (a.id, a.config.clone())),
+            state.data.user_index_canister_id,
+        )
+    });
+
+    if let Some((id, config)) = next {
+        ic_cdk::futures::spawn(prepare_airdrop(id, config, user_index_canister_id));
+    } else {
+        read_state(start_community_airdrop_timer);
+    }
+}
+
+// Unlike the platform airdrops, the channel isn't locked because the AirdropBot isn't necessarily an admin of
+// the channel, so the participants are simply the channel members at the start time
+async fn prepare_airdrop(id: u32, config: AirdropConfig, user_index_canister_id: CanisterId) {
+    let participants = match fetch_participants(&config, user_index_canister_id).await {
+        Ok(participants) => participants,
+        Err(FetchParticipantsError::Retry) => {
+            let timer_id = ic_cdk_timers::set_timer(Duration::from_secs(60), run);
+            TIMER_ID.set(Some(timer_id));
+            return;
+        }
+        // If the participants can't be determined the airdrop is abandoned and the escrowed funds are refunded
+        Err(FetchParticipantsError::Failed) => Vec::new(),
+    };
+
+    // The lottery is drawn from a fresh `raw_rand` seed which is recorded and published so the draw can be verified
+    let seed = get_random_seed().await;
+
+    mutate_state(|state| {
+        execute_airdrop(id, participants, seed, state);
+        start_community_airdrop_timer(state);
+    });
+}
+
+fn execute_airdrop(id: u32, participants: Vec<(UserId, Chit)>, seed: [u8; 32], state: &mut RuntimeState) {
+    let Some(airdrop) = state.data.community_airdrops.execute(id, participants, seed) else {
+        return;
+    };
+
+    let mut actions = airdrop
+        .outcome
+        .as_ref()
+        .map(|o| airdrop_transfer_actions(o, Some(id)))
+        .unwrap_or_default();
+
+    let unallocated = airdrop.unallocated();
+    if unallocated > airdrop.token.fee {
+        actions.push(Action::Refund(Box::new(AirdropRefund {
+            community_airdrop_id: id,
+            token: airdrop.token.clone(),
+            recipient: airdrop.owner,
+            amount: unallocated,
+        })));
+    }
+
+    if airdrop.outcome.is_none() {
+        info!(id, "Community airdrop had no participants, refunding escrowed funds");
+    }
+
+    state.data.pending_actions_queue.push_many(actions.into_iter());
+}
@@ -1,7 +1,9 @@
 use crate::RuntimeState;
 
 pub mod execute_airdrop;
+pub mod execute_community_airdrops;
 
 pub(crate) fn start(state: &RuntimeState) {
     execute_airdrop::start_job_if_required(state);
+    execute_community_airdrops::start_job_if_required(state);
 }
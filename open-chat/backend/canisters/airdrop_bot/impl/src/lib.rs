@@ -3,6 +3,7 @@ use crate::model::user_minutes_online::UserMinutesOnline;
 use candid::Principal;
 use canister_state_macros::canister_state;
 use model::airdrops::{Airdrops, AirdropsMetrics};
+use model::community_airdrops::{CommunityAirdrops, CommunityAirdropsMetrics};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashSet};
@@ -58,6 +59,7 @@ impl RuntimeState {
             wasm_version: WASM_VERSION.with_borrow(|v| **v),
             git_commit_id: utils::git::git_commit_id().to_string(),
             airdrops: self.data.airdrops.metrics(),
+            community_airdrops: self.data.community_airdrops.metrics(),
             pending_actions: self.data.pending_actions_queue.len(),
             channels_joined: self.data.channels_joined.iter().cloned().collect(),
             stable_memory_sizes: memory::memory_sizes(),
@@ -80,6 +82,8 @@ struct Data {
     pub admins: HashSet<Principal>,
     pub avatar: Timestamped<Option<Document>>,
     pub airdrops: Airdrops,
+    #[serde(default)]
+    pub community_airdrops: CommunityAirdrops,
     pub channels_joined: HashSet<(CommunityId, ChannelId)>,
     pub user_minutes_online: UserMinutesOnline,
     pub pending_actions_queue: TimerJobQueue<Action>,
@@ -105,6 +109,7 @@ impl Data {
             admins,
             avatar: Timestamped::default(),
             airdrops: Airdrops::default(),
+            community_airdrops: CommunityAirdrops::default(),
             channels_joined: HashSet::default(),
             user_minutes_online: UserMinutesOnline::default(),
             pending_actions_queue: TimerJobQueue::new(20, true),
@@ -126,6 +131,7 @@ pub struct Metrics {
     pub git_commit_id: String,
     pub canister_ids: CanisterIds,
     pub airdrops: AirdropsMetrics,
+    pub community_airdrops: CommunityAirdropsMetrics,
     pub pending_actions: usize,
     pub channels_joined: Vec<(CommunityId, ChannelId)>,
     pub stable_memory_sizes: BTreeMap<u8, u64>,
@@ -1,8 +1,7 @@
-use airdrop_bot_canister::{AirdropAlgorithm, AirdropConfig, V1Algorithm, V2Algorithm};
-use rand::RngCore;
+use airdrop_bot_canister::{AirdropAlgorithm, AirdropConfig, LotteryDraw, V1Algorithm, V2Algorithm};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use types::{Chit, TimestampMillis, UserId};
+use types::{ChannelId, Chit, CommunityId, TimestampMillis, UserId};
 use utils::time::MonthKey;
 
 #[derive(Serialize, Deserialize, Default)]
@@ -21,6 +20,8 @@ pub struct Airdrop {
 pub struct AirdropOutcome {
     pub participants: HashMap<UserId, Participant>,
     pub lottery_winners: Vec<(UserId, Prize)>,
+    #[serde(default)]
+    pub lottery_draw: Option<LotteryDraw>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -36,6 +37,17 @@ pub struct Prize {
     pub block_index: Option<u64>,
 }
 
+impl AirdropOutcome {
+    pub fn total_allocated(&self) -> u128 {
+        self.participants
+            .values()
+            .filter_map(|p| p.prize.as_ref())
+            .chain(self.lottery_winners.iter().map(|(_, p)| p))
+            .map(|p| p.chat_won)
+            .sum()
+    }
+}
+
 pub enum SetNextResult {
     Success,
     ChannelUsed,
@@ -90,19 +102,42 @@ impl Airdrops {
         self.next.take()
     }
 
-    pub fn execute<R: RngCore>(&mut self, users: Vec<(UserId, Chit)>, rng: &mut R) -> Option<&Airdrop> {
+    pub fn execute(&mut self, users: Vec<(UserId, Chit)>, seed: [u8; 32]) -> Option<&Airdrop> {
         let config = self.next.take()?;
-
-        let outcome = match &config.algorithm {
-            AirdropAlgorithm::V1(c) => Airdrops::execute_v1(c.clone(), users, rng),
-            AirdropAlgorithm::V2(c) => Airdrops::execute_v2(c.clone(), users, rng),
-        }?;
+        let outcome = Airdrops::calculate_outcome(&config.algorithm, users, seed)?;
 
         self.past.push(Airdrop { config, outcome });
 
         Some(self.past.last().as_ref().unwrap())
     }
 
+    pub fn calculate_outcome(
+        algorithm: &AirdropAlgorithm,
+        users: Vec<(UserId, Chit)>,
+        seed: [u8; 32],
+    ) -> Option<AirdropOutcome> {
+        match algorithm {
+            AirdropAlgorithm::V1(c) => Airdrops::execute_v1(c.clone(), users, seed),
+            AirdropAlgorithm::V2(c) => Airdrops::execute_v2(c.clone(), users, seed),
+        }
+    }
+
+    pub fn uses_channel(&self, community_id: CommunityId, channel_id: ChannelId) -> bool {
+        self.past
+            .iter()
+            .map(|a| &a.config)
+            .chain(self.next.iter())
+            .any(|c| c.community_id == community_id && c.channel_id == channel_id)
+    }
+
+    pub fn has_airdrop_in_month(&self, community_id: CommunityId, month: MonthKey) -> bool {
+        self.past
+            .iter()
+            .map(|a| &a.config)
+            .chain(self.next.iter())
+            .any(|c| c.community_id == community_id && MonthKey::from_timestamp(c.start) == month)
+    }
+
     pub fn set_main_transaction(&mut self, user_id: &UserId, block_index: u64) -> bool {
         if let Some(last) = self.past.last_mut() {
             if let Some(participant) = last.outcome.participants.get_mut(user_id) {
@@ -141,6 +176,20 @@ impl Airdrops {
         None
     }
 
+    pub fn current_lottery_draw(&self, now: TimestampMillis) -> Option<&LotteryDraw> {
+        self.current(now)?;
+        self.past.last()?.outcome.lottery_draw.as_ref()
+    }
+
+    // Platform airdrops are identified by their position in the list of past airdrops
+    pub fn lottery_draw(&self, index: usize) -> Option<&LotteryDraw> {
+        self.past.get(index)?.outcome.lottery_draw.as_ref()
+    }
+
+    pub fn latest_index(&self) -> Option<usize> {
+        self.past.len().checked_sub(1)
+    }
+
     pub fn metrics(&self) -> AirdropsMetrics {
         AirdropsMetrics {
             past: self
@@ -162,49 +211,46 @@ impl Airdrops {
         self.next.as_ref()
     }
 
-    fn execute_v1<R: RngCore>(config: V1Algorithm, users: Vec<(UserId, Chit)>, rng: &mut R) -> Option<AirdropOutcome> {
+    fn execute_v1(config: V1Algorithm, users: Vec<(UserId, Chit)>, seed: [u8; 32]) -> Option<AirdropOutcome> {
         let participants = Airdrops::execute_main(config.main_chat_fund, config.main_chit_band, &users);
 
         if participants.is_empty() {
             return None;
         }
 
-        let mut tickets: Vec<UserId> = Vec::new();
-
-        for (user_id, chit) in users {
-            let num_tickets = chit.balance as u32 / config.lottery_chit_band;
-
-            for _n in 0..num_tickets {
-                tickets.push(user_id);
-            }
-        }
+        let tickets: Vec<(UserId, u32)> = users
+            .into_iter()
+            .map(|(user_id, chit)| (user_id, chit.balance as u32 / config.lottery_chit_band))
+            .collect();
 
-        let lottery_winners = Airdrops::execute_lottery(tickets, config.lottery_prizes, rng);
+        let (lottery_winners, lottery_draw) = Airdrops::execute_lottery(tickets, config.lottery_prizes, seed);
 
         Some(AirdropOutcome {
             participants,
             lottery_winners,
+            lottery_draw: Some(lottery_draw),
         })
     }
 
-    fn execute_v2<R: RngCore>(config: V2Algorithm, users: Vec<(UserId, Chit)>, rng: &mut R) -> Option<AirdropOutcome> {
+    fn execute_v2(config: V2Algorithm, users: Vec<(UserId, Chit)>, seed: [u8; 32]) -> Option<AirdropOutcome> {
         let participants = Airdrops::execute_main(config.main_chat_fund, config.main_chit_band, &users);
 
         if participants.is_empty() {
             return None;
         }
 
-        let tickets: Vec<UserId> = users
+        let tickets: Vec<(UserId, u32)> = users
             .iter()
             .filter(|(_, chit)| chit.balance as u32 >= config.lottery_min_chit || chit.streak >= config.lottery_min_streak)
-            .map(|(user_id, _)| *user_id)
+            .map(|(user_id, _)| (*user_id, 1))
             .collect();
 
-        let lottery_winners = Airdrops::execute_lottery(tickets, config.lottery_prizes, rng);
+        let (lottery_winners, lottery_draw) = Airdrops::execute_lottery(tickets, config.lottery_prizes, seed);
 
         Some(AirdropOutcome {
             participants,
             lottery_winners,
+            lottery_draw: Some(lottery_draw),
         })
     }
 
@@ -251,36 +297,32 @@ impl Airdrops {
             .collect()
     }
 
-    fn execute_lottery<R: RngCore>(mut tickets: Vec<UserId>, prizes: Vec<u128>, rng: &mut R) -> Vec<(UserId, Prize)> {
-        let mut lottery_winners = Vec::new();
-
-        for prize in prizes {
-            if tickets.is_empty() {
-                break;
-            }
+    fn execute_lottery(tickets: Vec<(UserId, u32)>, prizes: Vec<u128>, seed: [u8; 32]) -> (Vec<(UserId, Prize)>, LotteryDraw) {
+        let lottery_draw = LotteryDraw::new(seed, tickets, prizes.len() as u32);
 
-            let winning_ticket = (rng.next_u32() % tickets.len() as u32) as usize;
-
-            let winner = tickets.remove(winning_ticket);
-
-            // Ensure the same user can't win multiple times
-            tickets.retain(|u| *u != winner);
-
-            lottery_winners.push((
-                winner,
-                Prize {
-                    chat_won: prize,
-                    block_index: None,
-                },
-            ));
-        }
+        let lottery_winners = lottery_draw
+            .winners
+            .iter()
+            .zip(prizes)
+            .map(|(winner, prize)| {
+                (
+                    *winner,
+                    Prize {
+                        chat_won: prize,
+                        block_index: None,
+                    },
+                )
+            })
+            .collect();
 
-        lottery_winners
+        (lottery_winners, lottery_draw)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use airdrop_bot_canister::{draw_lottery_winners, tickets_hash};
+    use rand::RngCore;
     use testing::rng::random_principal;
     use utils::env::{Environment, test::TestEnv};
 
@@ -292,7 +334,10 @@ mod tests {
         let mut airdrops = setup(env.now);
         let users = generate_random_users();
 
-        let airdrop = airdrops.execute(users, env.rng()).expect("Expected some airdrop");
+        let mut seed = [0; 32];
+        env.rng().fill_bytes(&mut seed);
+
+        let airdrop = airdrops.execute(users, seed).expect("Expected some airdrop");
 
         println!("{:#?}", airdrop.outcome);
 
@@ -305,7 +350,16 @@ mod tests {
                 .map(|(_, p)| p.chat_won)
                 .collect::<Vec<u128>>(),
             vec![12000_u128, 5000_u128, 3000_u128]
-        )
+        );
+
+        // Anyone holding the published draw can recompute the same winners
+        let draw = airdrop.outcome.lottery_draw.as_ref().unwrap();
+        assert_eq!(draw.tickets_hash, tickets_hash(&draw.tickets));
+        assert_eq!(draw_lottery_winners(draw.seed, &draw.tickets, draw.prize_count), draw.winners);
+        assert_eq!(
+            airdrop.outcome.lottery_winners.iter().map(|(u, _)| *u).collect::<Vec<_>>(),
+            draw.winners
+        );
     }
 
     fn setup(now: TimestampMillis) -> Airdrops {
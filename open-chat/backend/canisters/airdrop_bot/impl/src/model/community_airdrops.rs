@@ -0,0 +1,318 @@
+use crate::model::airdrops::{AirdropOutcome, Airdrops, SetNextResult};
+use airdrop_bot_canister::{AirdropConfig, AirdropToken, LotteryDraw};
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{ChannelId, Chit, CommunityId, TimestampMillis, UserId};
+use utils::time::MonthKey;
+
+// Airdrops configured and funded by community owners. Each airdrop's funds are held in escrow in its own subaccount
+// of this canister until the airdrop is executed or cancelled.
+#[derive(Serialize, Deserialize, Default)]
+pub struct CommunityAirdrops {
+    next_id: u32,
+    pending: BTreeMap<u32, CommunityAirdrop>,
+    past: BTreeMap<u32, CommunityAirdrop>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommunityAirdrop {
+    pub id: u32,
+    pub owner: Principal,
+    pub config: AirdropConfig,
+    pub token: AirdropToken,
+    pub escrowed: u128,
+    pub escrow_block_index: u64,
+    pub outcome: Option<AirdropOutcome>,
+    // Prizes which couldn't be paid out and are yet to be refunded to the owner
+    #[serde(default)]
+    pub unpaid: u128,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CommunityAirdropsMetrics {
+    pending: Vec<CommunityAirdropMetrics>,
+    past: Vec<CommunityAirdropMetrics>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CommunityAirdropMetrics {
+    pub id: u32,
+    pub owner: Principal,
+    pub config: AirdropConfig,
+    pub token: AirdropToken,
+    pub escrowed: u128,
+    pub participants: u32,
+}
+
+impl CommunityAirdrop {
+    pub fn escrow_subaccount(&self) -> [u8; 32] {
+        escrow_subaccount(self.id)
+    }
+
+    // The escrowed funds which weren't allocated to any participant and so are returned to the owner
+    pub fn unallocated(&self) -> u128 {
+        self.escrowed
+            .saturating_sub(self.outcome.as_ref().map(|o| o.total_allocated()).unwrap_or_default())
+    }
+
+    pub fn lottery_draw(&self) -> Option<&LotteryDraw> {
+        self.outcome.as_ref()?.lottery_draw.as_ref()
+    }
+}
+
+impl CommunityAirdrops {
+    pub fn validate(&self, config: &AirdropConfig, platform: &Airdrops, now: TimestampMillis) -> SetNextResult {
+        if config.start < now {
+            return SetNextResult::InThePast;
+        }
+
+        if self.uses_channel(config.community_id, config.channel_id)
+            || platform.uses_channel(config.community_id, config.channel_id)
+        {
+            return SetNextResult::ChannelUsed;
+        }
+
+        // Each community can hold at most one airdrop per month
+        let month = MonthKey::from_timestamp(config.start);
+        if self
+            .iter()
+            .any(|a| a.config.community_id == config.community_id && MonthKey::from_timestamp(a.config.start) == month)
+            || platform.has_airdrop_in_month(config.community_id, month)
+        {
+            return SetNextResult::ClashesWithPrevious;
+        }
+
+        SetNextResult::Success
+    }
+
+    // Ids are reserved before the funds are transferred into escrow so that each airdrop gets its own subaccount
+    pub fn reserve_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    pub fn add(&mut self, airdrop: CommunityAirdrop) {
+        self.pending.insert(airdrop.id, airdrop);
+    }
+
+    pub fn cancel(&mut self, id: u32) -> Option<CommunityAirdrop> {
+        self.pending.remove(&id)
+    }
+
+    pub fn get(&self, id: u32) -> Option<&CommunityAirdrop> {
+        self.pending.get(&id).or_else(|| self.past.get(&id))
+    }
+
+    pub fn get_pending(&self, id: u32) -> Option<&CommunityAirdrop> {
+        self.pending.get(&id)
+    }
+
+    // The pending airdrop which is due to start soonest
+    pub fn next_due(&self) -> Option<&CommunityAirdrop> {
+        self.pending.values().min_by_key(|a| a.config.start)
+    }
+
+    // Moves the airdrop into the past. If no users qualified the outcome is `None` and all of the escrowed funds are
+    // returned to the owner.
+    pub fn execute(&mut self, id: u32, users: Vec<(UserId, Chit)>, seed: [u8; 32]) -> Option<&CommunityAirdrop> {
+        let mut airdrop = self.pending.remove(&id)?;
+        airdrop.outcome = Airdrops::calculate_outcome(&airdrop.config.algorithm, users, seed);
+
+        self.past.insert(id, airdrop);
+        self.past.get(&id)
+    }
+
+    pub fn set_main_transaction(&mut self, id: u32, user_id: &UserId, block_index: u64) -> bool {
+        if let Some(outcome) = self.past.get_mut(&id).and_then(|a| a.outcome.as_mut()) {
+            if let Some(prize) = outcome.participants.get_mut(user_id).and_then(|p| p.prize.as_mut()) {
+                if prize.block_index.is_none() {
+                    prize.block_index = Some(block_index);
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    pub fn set_lottery_transaction(&mut self, id: u32, winning_index: usize, block_index: u64) -> bool {
+        if let Some(outcome) = self.past.get_mut(&id).and_then(|a| a.outcome.as_mut()) {
+            if let Some((_, prize)) = outcome.lottery_winners.get_mut(winning_index) {
+                if prize.block_index.is_none() {
+                    prize.block_index = Some(block_index);
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    // Records a prize which couldn't be paid out so that it is returned to the owner. Unpaid prizes are accumulated
+    // until they exceed the transfer fee, at which point the total is taken to be refunded.
+    pub fn add_unpaid(&mut self, id: u32, amount: u128) -> Option<u128> {
+        let airdrop = self.past.get_mut(&id)?;
+        airdrop.unpaid += amount;
+        (airdrop.unpaid > airdrop.token.fee).then(|| std::mem::take(&mut airdrop.unpaid))
+    }
+
+    pub fn metrics(&self) -> CommunityAirdropsMetrics {
+        let to_metrics = |a: &CommunityAirdrop| CommunityAirdropMetrics {
+            id: a.id,
+            owner: a.owner,
+            config: a.config.clone(),
+            token: a.token.clone(),
+            escrowed: a.escrowed,
+            participants: a.outcome.as_ref().map(|o| o.participants.len() as u32).unwrap_or_default(),
+        };
+
+        CommunityAirdropsMetrics {
+            pending: self.pending.values().map(to_metrics).collect(),
+            past: self.past.values().map(to_metrics).collect(),
+        }
+    }
+
+    pub fn uses_channel(&self, community_id: CommunityId, channel_id: ChannelId) -> bool {
+        self.iter()
+            .any(|a| a.config.community_id == community_id && a.config.channel_id == channel_id)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &CommunityAirdrop> {
+        self.pending.values().chain(self.past.values())
+    }
+}
+
+// Subaccount 0 is the default subaccount which holds the funds of the platform airdrops
+pub fn escrow_subaccount(id: u32) -> [u8; 32] {
+    let mut subaccount = [0; 32];
+    subaccount[0] = 1;
+    subaccount[28..].copy_from_slice(&id.to_be_bytes());
+    subaccount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use airdrop_bot_canister::{AirdropAlgorithm, V2Algorithm};
+    use testing::rng::random_principal;
+
+    #[test]
+    fn one_airdrop_per_community_per_month() {
+        let now = 1_700_000_000_000;
+        let mut airdrops = CommunityAirdrops::default();
+        let platform = Airdrops::default();
+        let community_id: CommunityId = random_principal().into();
+
+        let config = test_config(community_id, 1, now + 1_000);
+        assert!(matches!(airdrops.validate(&config, &platform, now), SetNextResult::Success));
+        let id = airdrops.reserve_id();
+        airdrops.add(test_airdrop(id, config));
+
+        assert!(matches!(
+            airdrops.validate(&test_config(community_id, 1, now + 2_000), &platform, now),
+            SetNextResult::ChannelUsed
+        ));
+        assert!(matches!(
+            airdrops.validate(&test_config(community_id, 2, now + 2_000), &platform, now),
+            SetNextResult::ClashesWithPrevious
+        ));
+        assert!(matches!(
+            airdrops.validate(&test_config(random_principal().into(), 1, now + 2_000), &platform, now),
+            SetNextResult::Success
+        ));
+        assert!(matches!(
+            airdrops.validate(&test_config(community_id, 2, now - 1), &platform, now),
+            SetNextResult::InThePast
+        ));
+
+        // Once cancelled the community can schedule another airdrop for the same month
+        assert!(airdrops.cancel(id).is_some());
+        assert!(matches!(
+            airdrops.validate(&test_config(community_id, 2, now + 2_000), &platform, now),
+            SetNextResult::Success
+        ));
+    }
+
+    #[test]
+    fn unallocated_funds_are_refundable() {
+        let now = 1_700_000_000_000;
+        let mut airdrops = CommunityAirdrops::default();
+        airdrops.add(test_airdrop(0, test_config(random_principal().into(), 1, now)));
+
+        let users = (0..10)
+            .map(|_| {
+                (
+                    random_principal().into(),
+                    Chit {
+                        balance: 25_000,
+                        streak: 10,
+                    },
+                )
+            })
+            .collect();
+
+        let airdrop = airdrops.execute(0, users, [7; 32]).unwrap();
+        let outcome = airdrop.outcome.as_ref().unwrap();
+
+        assert_eq!(outcome.total_allocated() + airdrop.unallocated(), airdrop.escrowed);
+        assert!(airdrops.get_pending(0).is_none());
+
+        airdrops.add(test_airdrop(1, test_config(random_principal().into(), 1, now)));
+        let airdrop = airdrops.execute(1, Vec::new(), [7; 32]).unwrap();
+        assert_eq!(airdrop.unallocated(), airdrop.escrowed);
+    }
+
+    #[test]
+    fn unpaid_prizes_are_refunded_once_they_exceed_the_fee() {
+        let now = 1_700_000_000_000;
+        let mut airdrops = CommunityAirdrops::default();
+        airdrops.add(test_airdrop(0, test_config(random_principal().into(), 1, now)));
+        airdrops.execute(0, Vec::new(), [7; 32]).unwrap();
+
+        assert_eq!(airdrops.add_unpaid(0, 6_000), None);
+        assert_eq!(airdrops.add_unpaid(0, 6_000), Some(12_000));
+        assert_eq!(airdrops.add_unpaid(0, 4_000), None);
+        assert_eq!(airdrops.get(0).unwrap().unpaid, 4_000);
+    }
+
+    #[test]
+    fn escrow_subaccounts_are_distinct_from_the_default_subaccount() {
+        assert_ne!(escrow_subaccount(0), [0; 32]);
+        assert_ne!(escrow_subaccount(0), escrow_subaccount(1));
+    }
+
+    fn test_config(community_id: CommunityId, channel_id: u32, start: TimestampMillis) -> AirdropConfig {
+        AirdropConfig {
+            community_id,
+            channel_id: channel_id.into(),
+            start,
+            algorithm: AirdropAlgorithm::V2(V2Algorithm {
+                main_chat_fund: 100_000_000_000,
+                main_chit_band: 10_000,
+                lottery_prizes: vec![5_000_000_000, 2_000_000_000],
+                lottery_min_chit: 20_000,
+                lottery_min_streak: 5,
+                min_minutes_online: 0,
+            }),
+        }
+    }
+
+    fn test_airdrop(id: u32, config: AirdropConfig) -> CommunityAirdrop {
+        CommunityAirdrop {
+            id,
+            owner: random_principal(),
+            escrowed: config.algorithm.total_fund(),
+            config,
+            token: AirdropToken {
+                ledger_canister_id: random_principal(),
+                symbol: "TKN".to_string(),
+                fee: 10_000,
+            },
+            escrow_block_index: 0,
+            outcome: None,
+            unpaid: 0,
+        }
+    }
+}
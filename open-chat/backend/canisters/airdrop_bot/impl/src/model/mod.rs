@@ -1,2 +1,3 @@
 pub mod airdrops;
+pub mod community_airdrops;
 pub mod user_minutes_online;
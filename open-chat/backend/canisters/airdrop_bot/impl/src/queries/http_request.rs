@@ -1,6 +1,8 @@
 use crate::{RuntimeState, read_state};
 use http_request::{AvatarRoute, Route, build_json_response, encode_logs, extract_route, get_document};
 use ic_cdk::query;
+use std::collections::HashMap;
+use std::str::FromStr;
 use types::{HttpRequest, HttpResponse, TimestampMillis};
 
 #[query]
@@ -31,6 +33,28 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         build_json_response(&principals)
     }
 
+    // `/lottery?community_airdrop={id}` serves the draw of a community airdrop and `/lottery?airdrop={index}` serves
+    // the draw of a platform airdrop, defaulting to the latest
+    fn get_lottery_draw(qs: HashMap<String, String>, state: &RuntimeState) -> HttpResponse {
+        let draw = if let Some(id) = qs.get("community_airdrop") {
+            u32::from_str(id)
+                .ok()
+                .and_then(|id| state.data.community_airdrops.get(id))
+                .and_then(|a| a.lottery_draw())
+        } else {
+            match qs.get("airdrop") {
+                Some(index) => usize::from_str(index).ok(),
+                None => state.data.airdrops.latest_index(),
+            }
+            .and_then(|index| state.data.airdrops.lottery_draw(index))
+        };
+
+        match draw {
+            Some(draw) => build_json_response(draw),
+            None => HttpResponse::not_found(),
+        }
+    }
+
     match extract_route(&request.url) {
         Route::Avatar(route) => read_state(|state| get_avatar_impl(route, state)),
         Route::Errors(since) => get_errors_impl(since),
@@ -38,6 +62,7 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         Route::Traces(since) => get_traces_impl(since),
         Route::Metrics => read_state(get_metrics_impl),
         Route::Other(path, _) if path == "admins" => read_state(get_admins),
+        Route::Other(path, qs) if path == "lottery" => read_state(|state| get_lottery_draw(qs, state)),
         _ => HttpResponse::not_found(),
     }
 }
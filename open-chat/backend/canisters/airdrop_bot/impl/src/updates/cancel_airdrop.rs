@@ -1,20 +1,48 @@
-use crate::guards::caller_is_admin;
+use crate::actions::{Action, AirdropRefund};
 use crate::jobs::execute_airdrop::clear_airdrop_timer;
+use crate::jobs::execute_community_airdrops::start_community_airdrop_timer;
 use crate::{RuntimeState, mutate_state};
-use airdrop_bot_canister::cancel_airdrop::*;
+use airdrop_bot_canister::cancel_airdrop::{Response::*, *};
 use canister_tracing_macros::trace;
 use ic_cdk::update;
 
-#[update(guard = "caller_is_admin")]
+#[update]
 #[trace]
-fn cancel_airdrop(_args: Args) -> Response {
-    mutate_state(cancel_airdrop_impl)
+fn cancel_airdrop(args: Args) -> Response {
+    mutate_state(|state| cancel_airdrop_impl(args, state))
 }
 
-fn cancel_airdrop_impl(state: &mut RuntimeState) -> Response {
-    if state.data.airdrops.cancel().is_some() {
-        clear_airdrop_timer();
+fn cancel_airdrop_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let Some(id) = args.community_airdrop_id else {
+        if !state.is_caller_admin() {
+            return NotAuthorized;
+        }
+
+        if state.data.airdrops.cancel().is_some() {
+            clear_airdrop_timer();
+        }
+        return Success;
+    };
+
+    // A community airdrop can be cancelled by the owner who funded it or by a platform admin
+    let Some(airdrop) = state.data.community_airdrops.get_pending(id) else {
+        return NotFound;
+    };
+
+    if airdrop.owner != state.env.caller() && !state.is_caller_admin() {
+        return NotAuthorized;
     }
 
-    Response::Success
+    let airdrop = state.data.community_airdrops.cancel(id).unwrap();
+
+    // Return the escrowed funds to the owner
+    state.data.pending_actions_queue.push(Action::Refund(Box::new(AirdropRefund {
+        community_airdrop_id: id,
+        token: airdrop.token,
+        recipient: airdrop.owner,
+        amount: airdrop.escrowed,
+    })));
+    start_community_airdrop_timer(state);
+
+    Success
 }
@@ -17,6 +17,10 @@ fn set_airdrop_impl(args: Args, state: &mut RuntimeState) -> Response {
     let community_id = args.community_id;
     let channel_id = args.channel_id;
 
+    if state.data.community_airdrops.uses_channel(community_id, channel_id) {
+        return Response::ChannelUsed;
+    }
+
     match state.data.airdrops.set_next(args, state.env.now()) {
         SetNextResult::Success => {
             if state.data.channels_joined.contains(&(community_id, channel_id)) {
@@ -0,0 +1,171 @@
+use crate::actions::{Action, AirdropRefund};
+use crate::jobs::execute_community_airdrops::start_community_airdrop_timer;
+use crate::model::airdrops::SetNextResult;
+use crate::model::community_airdrops::{CommunityAirdrop, escrow_subaccount};
+use crate::{RuntimeState, mutate_state};
+use airdrop_bot_canister::AirdropAlgorithm;
+use airdrop_bot_canister::set_community_airdrop::{Response::*, *};
+use candid::Principal;
+use canister_tracing_macros::trace;
+use ic_cdk::update;
+use icrc_ledger_types::icrc2::transfer_from::TransferFromArgs;
+use types::icrc1::Account;
+use types::{CanisterId, TimestampNanos};
+
+#[update]
+#[trace]
+async fn set_community_airdrop(args: Args) -> Response {
+    let PrepareResult {
+        caller,
+        this_canister_id,
+        airdrop_id,
+        now_nanos,
+    } = match mutate_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    // Only an owner of the community can fund an airdrop for it
+    match community_canister_c2c_client::c2c_member_role(
+        args.config.community_id.into(),
+        &community_canister::c2c_member_role::Args {
+            user_id_or_principal: caller,
+        },
+    )
+    .await
+    {
+        Ok(community_canister::c2c_member_role::Response::Success(result)) if result.role.is_owner() => (),
+        Ok(_) => return NotCommunityOwner,
+        Err(error) => return InternalError(format!("{error:?}")),
+    }
+
+    // Move the funds from the owner's account into this airdrop's escrow subaccount
+    let amount = args.config.algorithm.total_fund();
+    let transfer_args = TransferFromArgs {
+        spender_subaccount: None,
+        from: Account::from(caller).into(),
+        to: Account {
+            owner: this_canister_id,
+            subaccount: Some(escrow_subaccount(airdrop_id)),
+        }
+        .into(),
+        amount: amount.into(),
+        fee: Some(args.token.fee.into()),
+        memo: None,
+        created_at_time: Some(now_nanos),
+    };
+
+    let escrow_block_index =
+        match icrc_ledger_canister_c2c_client::icrc2_transfer_from(args.token.ledger_canister_id, &transfer_args).await {
+            Ok(Ok(block_index)) => block_index.0.try_into().unwrap(),
+            Ok(Err(error)) => return TransferFailed(format!("{error:?}")),
+            Err(error) => return InternalError(format!("{error:?}")),
+        };
+
+    mutate_state(|state| commit(args, caller, airdrop_id, amount, escrow_block_index, state))
+}
+
+struct PrepareResult {
+    caller: Principal,
+    this_canister_id: CanisterId,
+    airdrop_id: u32,
+    now_nanos: TimestampNanos,
+}
+
+fn prepare(args: &Args, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
+    validate_algorithm(&args.config.algorithm, args.token.fee).map_err(InvalidAlgorithm)?;
+
+    if let Some(response) = validate_config(args, state) {
+        return Err(response);
+    }
+
+    Ok(PrepareResult {
+        caller: state.env.caller(),
+        this_canister_id: state.env.canister_id(),
+        airdrop_id: state.data.community_airdrops.reserve_id(),
+        now_nanos: state.env.now_nanos(),
+    })
+}
+
+fn commit(
+    args: Args,
+    owner: Principal,
+    airdrop_id: u32,
+    escrowed: u128,
+    escrow_block_index: u64,
+    state: &mut RuntimeState,
+) -> Response {
+    // Another airdrop may have been configured while the funds were being transferred, in which case the funds are
+    // returned to the owner
+    if let Some(response) = validate_config(&args, state) {
+        state.data.pending_actions_queue.push(Action::Refund(Box::new(AirdropRefund {
+            community_airdrop_id: airdrop_id,
+            token: args.token,
+            recipient: owner,
+            amount: escrowed,
+        })));
+        return response;
+    }
+
+    let community_id = args.config.community_id;
+    let channel_id = args.config.channel_id;
+
+    state.data.community_airdrops.add(CommunityAirdrop {
+        id: airdrop_id,
+        owner,
+        config: args.config,
+        token: args.token,
+        escrowed,
+        escrow_block_index,
+        outcome: None,
+        unpaid: 0,
+    });
+
+    if !state.data.channels_joined.contains(&(community_id, channel_id)) {
+        state
+            .data
+            .pending_actions_queue
+            .push(Action::JoinChannel(community_id, channel_id));
+    }
+    start_community_airdrop_timer(state);
+
+    Success(SuccessResult {
+        airdrop_id,
+        escrow_block_index,
+    })
+}
+
+fn validate_config(args: &Args, state: &RuntimeState) -> Option<Response> {
+    match state
+        .data
+        .community_airdrops
+        .validate(&args.config, &state.data.airdrops, state.env.now())
+    {
+        SetNextResult::Success => None,
+        SetNextResult::ChannelUsed => Some(ChannelUsed),
+        SetNextResult::InThePast => Some(InThePast),
+        SetNextResult::ClashesWithPrevious => Some(ClashesWithPrevious),
+    }
+}
+
+fn validate_algorithm(algorithm: &AirdropAlgorithm, fee: u128) -> Result<(), String> {
+    let (main_fund, main_chit_band, lottery_prizes) = match algorithm {
+        AirdropAlgorithm::V1(v1) => {
+            if v1.lottery_chit_band == 0 && !v1.lottery_prizes.is_empty() {
+                return Err("lottery_chit_band must be greater than zero".to_string());
+            }
+            (v1.main_chat_fund, v1.main_chit_band, &v1.lottery_prizes)
+        }
+        AirdropAlgorithm::V2(v2) => (v2.main_chat_fund, v2.main_chit_band, &v2.lottery_prizes),
+    };
+
+    if main_chit_band == 0 {
+        Err("main_chit_band must be greater than zero".to_string())
+    } else if main_fund == 0 && lottery_prizes.is_empty() {
+        Err("The airdrop must have a main fund or lottery prizes".to_string())
+    } else if lottery_prizes.iter().any(|p| *p <= fee) {
+        Err("Each lottery prize must be greater than the transfer fee".to_string())
+    } else {
+        Ok(())
+    }
+}
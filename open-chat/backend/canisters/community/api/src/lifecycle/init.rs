@@ -24,6 +24,7 @@ pub struct Args {
     pub group_index_canister_id: CanisterId,
     pub proposals_bot_user_id: UserId,
     pub escrow_canister_id: CanisterId,
+    pub airdrop_bot_canister_id: CanisterId,
     pub internet_identity_canister_id: CanisterId,
     pub gate_config: Option<AccessGateConfig>,
     pub channels: Vec<(ChannelId, String)>,
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
-use types::BuildVersion;
+use types::{BuildVersion, CanisterId};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub wasm_version: BuildVersion,
+    pub airdrop_bot_canister_id: CanisterId,
 }
@@ -11,15 +11,22 @@ fn main() {
     generate_ts_method!(community, channel_summary_updates);
     generate_ts_method!(community, channel_summary);
     generate_ts_method!(community, deleted_message);
+    generate_ts_method!(community, deny_bot_permissions_request);
     generate_ts_method!(community, events_by_index);
     generate_ts_method!(community, events_window);
     generate_ts_method!(community, events);
+    generate_ts_method!(community, export_events);
     generate_ts_method!(community, explore_channels);
     generate_ts_method!(community, invite_code);
     generate_ts_method!(community, local_user_index);
     generate_ts_method!(community, lookup_members);
+    generate_ts_method!(community, member_warnings);
+    generate_ts_method!(community, message_edit_history);
     generate_ts_method!(community, messages_by_message_index);
+    generate_ts_method!(community, moderation_log);
     generate_ts_method!(community, search_channel);
+    generate_ts_method!(community, search_community);
+    generate_ts_method!(community, secret_ballot_audit);
     generate_ts_method!(community, selected_channel_initial);
     generate_ts_method!(community, selected_channel_updates_v2);
     generate_ts_method!(community, selected_initial);
@@ -39,6 +46,7 @@ fn main() {
     generate_ts_method!(community, change_channel_role);
     generate_ts_method!(community, change_role);
     generate_ts_method!(community, claim_prize);
+    generate_ts_method!(community, contribute_to_crowdfund);
     generate_ts_method!(community, create_channel);
     generate_ts_method!(community, create_user_group);
     generate_ts_method!(community, decline_invitation);
@@ -53,8 +61,10 @@ fn main() {
     generate_ts_method!(community, import_group);
     generate_ts_method!(community, join_video_call);
     generate_ts_method!(community, leave_channel);
+    generate_ts_method!(community, mute_member);
     generate_ts_method!(community, pin_message);
     generate_ts_method!(community, regenerate_webhook);
+    generate_ts_method!(community, register_delegate_proposal_vote);
     generate_ts_method!(community, register_poll_vote);
     generate_ts_method!(community, register_proposal_vote);
     generate_ts_method!(community, register_proposal_vote_v2);
@@ -64,9 +74,12 @@ fn main() {
     generate_ts_method!(community, remove_reaction);
     generate_ts_method!(community, report_message);
     generate_ts_method!(community, reset_invite_code);
+    generate_ts_method!(community, reveal_poll_ballot);
     generate_ts_method!(community, send_message);
     generate_ts_method!(community, set_member_display_name);
+    generate_ts_method!(community, set_proposal_delegate);
     generate_ts_method!(community, set_video_call_presence);
+    generate_ts_method!(community, set_warning_policy);
     generate_ts_method!(community, toggle_mute_notifications);
     generate_ts_method!(community, unblock_user);
     generate_ts_method!(community, undelete_messages);
@@ -77,4 +90,5 @@ fn main() {
     generate_ts_method!(community, update_community);
     generate_ts_method!(community, update_user_group);
     generate_ts_method!(community, update_webhook);
+    generate_ts_method!(community, warn_member);
 }
@@ -0,0 +1,12 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, ChannelId, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub channel_id: ChannelId,
+}
+
+pub type Response = types::ActiveProposalTalliesResponse;
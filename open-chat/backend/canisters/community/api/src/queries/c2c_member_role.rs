@@ -0,0 +1,21 @@
+use candid::Principal;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use types::{CommunityRole, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id_or_principal: Principal,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub user_id: UserId,
+    pub role: CommunityRole,
+}
@@ -0,0 +1,20 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, ChatExportPage, EventIndex, MessageIndex};
+
+#[ts_export(community, export_events)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub start_index: EventIndex,
+    pub max_events: u32,
+}
+
+#[ts_export(community, export_events)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatExportPage),
+    Error(OCError),
+}
@@ -0,0 +1,18 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, MemberWarnings, UserId};
+
+#[ts_export(community, member_warnings)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+}
+
+#[ts_export(community, member_warnings)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(MemberWarnings),
+    Error(OCError),
+}
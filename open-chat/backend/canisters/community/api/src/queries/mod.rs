@@ -1,5 +1,6 @@
 pub mod active_proposal_tallies;
 pub mod c2c_active_proposal_tallies;
+pub mod c2c_bot_active_proposal_tallies;
 pub mod c2c_bot_channel_details;
 pub mod c2c_bot_community_events;
 pub mod c2c_bot_community_summary;
@@ -8,6 +9,7 @@ pub mod c2c_can_issue_access_token;
 pub mod c2c_events;
 pub mod c2c_events_by_index;
 pub mod c2c_events_window;
+pub mod c2c_member_role;
 pub mod c2c_summary;
 pub mod c2c_summary_updates;
 pub mod channel_summary;
@@ -18,11 +20,17 @@ pub mod events;
 pub mod events_by_index;
 pub mod events_window;
 pub mod explore_channels;
+pub mod export_events;
 pub mod invite_code;
 pub mod local_user_index;
 pub mod lookup_members;
+pub mod member_warnings;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
+pub mod moderation_log;
 pub mod search_channel;
+pub mod search_community;
+pub mod secret_ballot_audit;
 pub mod selected_channel_initial;
 pub mod selected_channel_updates_v2;
 pub mod selected_initial;
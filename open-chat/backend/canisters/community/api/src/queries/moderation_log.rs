@@ -0,0 +1,20 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, ModerationLogPage};
+
+#[ts_export(community, moderation_log)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    // If None, the log of community level actions is returned
+    pub channel_id: Option<ChannelId>,
+    pub start_index: u32,
+    pub max_entries: u32,
+}
+
+#[ts_export(community, moderation_log)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ModerationLogPage),
+    Error(OCError),
+}
@@ -8,6 +8,7 @@ use types::{ChannelId, MessageMatch, UserId};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub channel_id: ChannelId,
+    // Supports "quoted phrases" along with the filters `from:`, `has:`, `in:thread`, `after:` and `before:`
     pub search_term: String,
     pub max_results: u8,
     pub users: Option<HashSet<UserId>>,
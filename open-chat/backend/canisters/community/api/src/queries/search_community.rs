@@ -0,0 +1,39 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use ts_export::ts_export;
+use types::{ChannelId, MessageIndex, TimestampMillis, UserId};
+
+#[ts_export(community, search_community)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    // Supports "quoted phrases" along with the filters `from:`, `has:`, `in:thread`, `after:` and `before:`
+    pub search_term: String,
+    pub max_results: u8,
+    pub users: Option<HashSet<UserId>>,
+    #[serde(default)]
+    pub offset: u32,
+}
+
+#[ts_export(community, search_community)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[ts_export(community, search_community)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub matches: Vec<ChannelMessageMatch>,
+    pub next_offset: Option<u32>,
+}
+
+#[ts_export(community, search_community)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChannelMessageMatch {
+    pub channel_id: ChannelId,
+    pub message_index: MessageIndex,
+    pub timestamp: TimestampMillis,
+    pub score: u32,
+}
@@ -0,0 +1,19 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, MessageIndex, SecretBallotAudit};
+
+#[ts_export(community, secret_ballot_audit)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+}
+
+#[ts_export(community, secret_ballot_audit)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SecretBallotAudit),
+    Error(OCError),
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, ChannelId, Milliseconds, UnitResult, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+    pub duration: Option<Milliseconds>,
+}
+
+pub type Response = UnitResult;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, ChannelId, MessageIndex, UnitResult, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub channel_id: ChannelId,
+    pub message_index: MessageIndex,
+    pub adopt: bool,
+}
+
+pub type Response = UnitResult;
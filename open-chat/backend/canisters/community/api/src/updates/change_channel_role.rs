@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{ChannelId, GroupRole, UnitResult, UserId};
+use types::{ChannelId, GroupRole, TimestampMillis, UnitResult, UserId};
 
 #[ts_export(community, change_channel_role)]
 #[derive(Serialize, Deserialize, Debug)]
@@ -8,6 +8,8 @@ pub struct Args {
     pub channel_id: ChannelId,
     pub user_id: UserId,
     pub new_role: GroupRole,
+    // If set, the user reverts to their previous role at this time
+    pub expires_at: Option<TimestampMillis>,
 }
 
 pub type Response = UnitResult;
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{CommunityRole, UnitResult, UserId};
+use types::{CommunityRole, TimestampMillis, UnitResult, UserId};
 
 #[ts_export(community, change_role)]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub user_id: UserId,
     pub new_role: CommunityRole,
+    // If set, the user reverts to their previous role at this time
+    pub expires_at: Option<TimestampMillis>,
 }
 
 pub type Response = UnitResult;
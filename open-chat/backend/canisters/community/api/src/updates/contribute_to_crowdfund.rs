@@ -0,0 +1,21 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, ContributeToCrowdfundSuccess, MessageId, MessageIndex, PinNumberWrapper};
+
+#[ts_export(community, contribute_to_crowdfund)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub amount: u128,
+    pub pin: Option<PinNumberWrapper>,
+}
+
+#[ts_export(community, contribute_to_crowdfund)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ContributeToCrowdfundSuccess),
+    Error(OCError),
+}
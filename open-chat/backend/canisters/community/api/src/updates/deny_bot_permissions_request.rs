@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{UnitResult, UserId};
+
+#[ts_export(community, deny_bot_permissions_request)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+}
+
+pub type Response = UnitResult;
@@ -1,3 +1,4 @@
+use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use types::{ChannelId, UserId};
 
@@ -11,4 +12,10 @@ pub struct Args {
     pub message: String,
 }
 
-pub type Response = send_message::Response;
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(send_message::SuccessResult),
+    // The webhook is bound to a bot so the payload was forwarded to the bot rather than posted as a message
+    DeliveredToBot,
+    Error(OCError),
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, Milliseconds, UnitResult, UserId};
+
+#[ts_export(community, mute_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+    // If None, any existing mute is lifted
+    pub duration: Option<Milliseconds>,
+    pub reason: Option<String>,
+}
+
+pub type Response = UnitResult;
@@ -1,7 +1,7 @@
 use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{ChannelId, MessageIndex, PollVotes, VoteOperation};
+use types::{ChannelId, MessageIndex, PollBallot, PollVotes, VoteOperation};
 
 #[ts_export(community, register_poll_vote)]
 #[derive(Serialize, Deserialize, Debug)]
@@ -10,6 +10,8 @@ pub struct Args {
     pub thread_root_message_index: Option<MessageIndex>,
     pub message_index: MessageIndex,
     pub poll_option: u32,
+    #[serde(default)]
+    pub ballot: Option<PollBallot>,
     pub operation: VoteOperation,
     pub new_achievement: bool,
 }
@@ -9,6 +9,8 @@ pub struct Args {
     pub channel_id: ChannelId,
     pub name: String,
     pub avatar: Option<String>,
+    #[serde(default)]
+    pub bot_id: Option<UserId>,
 }
 
 #[ts_export(community, register_webhook)]
@@ -7,6 +7,8 @@ use types::{ChannelId, UnitResult, UserId};
 pub struct Args {
     pub channel_id: ChannelId,
     pub user_id: UserId,
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 pub type Response = UnitResult;
@@ -0,0 +1,22 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, MessageIndex, PollVotes};
+
+#[ts_export(community, reveal_poll_ballot)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub options: Vec<u32>,
+    #[serde(with = "serde_bytes")]
+    pub salt: Vec<u8>,
+}
+
+#[ts_export(community, reveal_poll_ballot)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(PollVotes),
+    Error(OCError),
+}
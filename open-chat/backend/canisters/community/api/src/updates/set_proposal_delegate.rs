@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, UnitResult, UserId};
+
+#[ts_export(community, set_proposal_delegate)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub delegate: Option<UserId>,
+}
+
+pub type Response = UnitResult;
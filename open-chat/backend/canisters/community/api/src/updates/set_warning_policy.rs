@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, UnitResult, WarningPolicy};
+
+#[ts_export(community, set_warning_policy)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub policy: Option<WarningPolicy>,
+}
+
+pub type Response = UnitResult;
@@ -2,7 +2,8 @@ use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
 use types::{
-    AccessGateConfig, ChannelId, Document, Milliseconds, OptionUpdate, OptionalGroupPermissions, UpdatedRules, Version,
+    AccessGateConfig, ChannelId, Document, Milliseconds, OptionUpdate, OptionalGroupPermissions, SlowMode, UpdatedRules,
+    Version,
 };
 
 #[ts_export(community, update_channel)]
@@ -21,8 +22,13 @@ pub struct Args {
     pub gate_config: OptionUpdate<AccessGateConfig>,
     pub public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    #[serde(default)]
+    pub retain_edit_history: Option<bool>,
     #[ts(as = "types::OptionUpdateString")]
     pub external_url: OptionUpdate<String>,
+    #[serde(default)]
+    #[ts(as = "types::OptionUpdateSlowMode")]
+    pub slow_mode: OptionUpdate<SlowMode>,
 }
 
 #[ts_export(community, update_channel)]
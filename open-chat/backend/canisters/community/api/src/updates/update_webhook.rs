@@ -10,6 +10,9 @@ pub struct Args {
     pub name: Option<String>,
     #[ts(as = "types::OptionUpdateString")]
     pub avatar: OptionUpdate<String>,
+    #[serde(default)]
+    #[ts(as = "types::OptionUpdateUserId")]
+    pub bot_id: OptionUpdate<UserId>,
 }
 
 pub type Response = UnitResult;
@@ -0,0 +1,26 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChannelId, EscalationAction, UserId};
+
+#[ts_export(community, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+    pub reason: String,
+}
+
+#[ts_export(community, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[ts_export(community, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub active_warnings: u32,
+    pub escalation: Option<EscalationAction>,
+}
@@ -3,6 +3,7 @@ use community_canister::*;
 
 // Queries
 generate_c2c_call!(c2c_active_proposal_tallies);
+generate_c2c_call!(c2c_bot_active_proposal_tallies);
 generate_c2c_call!(c2c_bot_channel_details);
 generate_c2c_call!(c2c_bot_community_events);
 generate_c2c_call!(c2c_bot_community_summary);
@@ -11,6 +12,7 @@ generate_c2c_call!(c2c_can_issue_access_token);
 generate_c2c_call!(c2c_events);
 generate_c2c_call!(c2c_events_by_index);
 generate_c2c_call!(c2c_events_window);
+generate_c2c_call!(c2c_member_role);
 generate_c2c_call!(local_user_index);
 generate_c2c_call!(selected_channel_initial);
 generate_c2c_call!(summary);
@@ -21,7 +23,10 @@ generate_c2c_call!(c2c_bot_add_reaction);
 generate_c2c_call!(c2c_bot_create_channel);
 generate_c2c_call!(c2c_bot_delete_channel);
 generate_c2c_call!(c2c_bot_delete_messages);
+generate_c2c_call!(c2c_bot_mute_user);
+generate_c2c_call!(c2c_bot_register_delegate_proposal_vote);
 generate_c2c_call!(c2c_bot_remove_user);
+generate_c2c_call!(c2c_bot_request_permissions);
 generate_c2c_call!(c2c_bot_invite_users);
 generate_c2c_call!(c2c_bot_send_message);
 generate_c2c_call!(c2c_bot_subscribe_to_events);
@@ -41,6 +46,8 @@ generate_c2c_call!(c2c_set_user_suspended);
 generate_c2c_call!(c2c_tip_message);
 generate_c2c_call!(c2c_unfreeze_community);
 generate_c2c_call!(c2c_uninstall_bot);
+generate_c2c_call!(c2c_set_bot_version);
+generate_c2c_call!(c2c_update_bot_config);
 generate_c2c_call!(c2c_update_proposals);
 generate_c2c_call!(change_channel_role);
 generate_c2c_call!(delete_channel);
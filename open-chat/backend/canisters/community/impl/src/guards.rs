@@ -48,6 +48,14 @@ pub fn caller_is_escrow_canister() -> Result<(), String> {
     }
 }
 
+pub fn caller_is_airdrop_bot_or_local_user_index() -> Result<(), String> {
+    if read_state(|state| state.is_caller_airdrop_bot() || state.is_caller_local_user_index()) {
+        Ok(())
+    } else {
+        Err("Caller is not the airdrop_bot or the local_user_index".to_string())
+    }
+}
+
 pub fn caller_is_video_call_operator() -> Result<(), String> {
     if read_state(|state| state.is_caller_video_call_operator()) {
         Ok(())
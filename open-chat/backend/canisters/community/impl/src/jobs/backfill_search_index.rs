@@ -0,0 +1,50 @@
+use crate::{RuntimeState, mutate_state};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+
+const BATCH_SIZE: usize = 100;
+const MAX_INSTRUCTIONS: u64 = 2_000_000_000;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none()
+        && state
+            .data
+            .channels
+            .iter()
+            .any(|c| c.chat.events.search_index_requires_backfill())
+    {
+        let timer_id = ic_cdk_timers::set_timer(Duration::ZERO, run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    trace!("'backfill_search_index' job running");
+    TIMER_ID.set(None);
+
+    mutate_state(|state| {
+        for channel in state
+            .data
+            .channels
+            .iter_mut()
+            .filter(|c| c.chat.events.search_index_requires_backfill())
+        {
+            while ic_cdk::api::instruction_counter() < MAX_INSTRUCTIONS {
+                if channel.chat.events.backfill_search_index(BATCH_SIZE) {
+                    info!(channel_id = %channel.id, "Search index backfill complete");
+                    break;
+                }
+            }
+        }
+        start_job_if_required(state);
+    });
+}
@@ -0,0 +1,106 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::jobs::expire_members;
+use crate::model::events::CommunityEventInternal;
+use crate::{RuntimeState, mutate_state};
+use constants::OPENCHAT_BOT_USER_ID;
+use group_community_common::{DEFERRED_ROLE_EXPIRY_RETRY_INTERVAL, ExpiringMember, ExpiringRole};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+use types::{CommunityRoleChanged, TimestampMillis};
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() {
+        if let Some(next_expiry) = state.data.expiring_roles.next_expiry() {
+            let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(next_expiry.saturating_sub(state.env.now())), run);
+            TIMER_ID.set(Some(timer_id));
+            return true;
+        }
+    }
+
+    false
+}
+
+pub(crate) fn restart_job(state: &RuntimeState) {
+    if let Some(timer_id) = TIMER_ID.get() {
+        ic_cdk_timers::clear_timer(timer_id);
+        TIMER_ID.set(None);
+    }
+
+    start_job_if_required(state);
+}
+
+fn run() {
+    trace!("'expire_roles' job running");
+    TIMER_ID.set(None);
+    mutate_state(|state| {
+        let now = state.env.now();
+        let mut any_expired = false;
+
+        while let Some(role) = state.data.expiring_roles.pop_if_expires_before(now + 1) {
+            // The role may since have been changed, in which case there is nothing to expire
+            let (was_owner, is_owner) = if let Some(channel_id) = role.channel_id {
+                let Some(channel) = state.data.channels.get_mut(&channel_id) else {
+                    continue;
+                };
+                let Some(result) = channel.chat.expire_role(role.user_id, now) else {
+                    if channel.chat.members.is_role_expiry_deferred(role.user_id, now) {
+                        defer_role_expiry(role, now, state);
+                    }
+                    continue;
+                };
+                state.push_bot_notification(result.bot_notification);
+                (result.prev_role.is_owner(), result.new_role.is_owner())
+            } else {
+                let Some((prev_role, new_role)) = state.data.members.expire_role(role.user_id, now) else {
+                    if state.data.members.is_role_expiry_deferred(role.user_id, now) {
+                        defer_role_expiry(role, now, state);
+                    }
+                    continue;
+                };
+                state.push_community_event(CommunityEventInternal::RoleChanged(Box::new(CommunityRoleChanged {
+                    user_ids: vec![role.user_id],
+                    old_role: prev_role,
+                    new_role,
+                    changed_by: OPENCHAT_BOT_USER_ID,
+                })));
+                (prev_role.is_owner(), new_role.is_owner())
+            };
+
+            // Owners can't "lapse" so either add or remove user from expiry list if they lose or gain owner status
+            if let Some(gate_expiry) = state.data.get_access_gate_config(role.channel_id).and_then(|gc| gc.expiry()) {
+                if is_owner {
+                    state.data.expiring_members.remove_member(role.user_id, role.channel_id);
+                } else if was_owner {
+                    state.data.expiring_members.push(ExpiringMember {
+                        expires: now + gate_expiry,
+                        channel_id: role.channel_id,
+                        user_id: role.user_id,
+                    });
+                }
+            }
+            any_expired = true;
+        }
+
+        expire_members::start_job_if_required(state);
+        start_job_if_required(state);
+
+        if any_expired {
+            handle_activity_notification(state);
+        }
+    });
+}
+
+// The member is the last owner so their term can't end until there is another owner
+fn defer_role_expiry(role: ExpiringRole, now: TimestampMillis, state: &mut RuntimeState) {
+    info!(user_id = %role.user_id, channel_id = ?role.channel_id, "Role expiry deferred because the member is the last owner");
+    state.data.expiring_roles.push(ExpiringRole {
+        expires: now + DEFERRED_ROLE_EXPIRY_RETRY_INTERVAL,
+        ..role
+    });
+}
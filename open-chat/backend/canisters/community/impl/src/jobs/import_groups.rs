@@ -12,6 +12,7 @@ use chat_events::ChatEvents;
 use constants::OPENCHAT_BOT_USER_ID;
 use group_canister::c2c_export_group::{Args, Response};
 use group_chat_core::{GroupChatCore, GroupMembers};
+use group_community_common::{ExpiringMute, ExpiringRole};
 use ic_cdk_timers::TimerId;
 use std::cell::Cell;
 use std::collections::HashMap;
@@ -215,12 +216,33 @@ pub(crate) fn finalize_group_import(group_id: ChatId) {
                 }
             }
 
+            for (user_id, expires) in chat.members.role_expiries() {
+                state.data.expiring_roles.push(ExpiringRole {
+                    expires,
+                    channel_id: Some(channel_id),
+                    user_id,
+                });
+            }
+            crate::jobs::expire_roles::restart_job(state);
+
+            for (user_id, expires) in chat.members.mute_expiries() {
+                state.data.expiring_mutes.push(ExpiringMute {
+                    expires,
+                    channel_id: Some(channel_id),
+                    user_id,
+                });
+            }
+            crate::jobs::expire_mutes::restart_job(state);
+
             state.data.channels.add(Channel {
                 id: channel_id,
                 chat,
                 date_imported: None, // This is only set once everything is complete
             });
 
+            // Changing the chat resets the channel's search index so it needs to be rebuilt
+            crate::jobs::backfill_search_index::start_job_if_required(state);
+
             state.data.timer_jobs.enqueue_job(
                 TimerJob::ProcessGroupImportChannelMembers(ProcessGroupImportChannelMembersJob {
                     group_id,
@@ -316,7 +338,7 @@ pub(crate) async fn process_channel_members(group_id: ChatId, channel_id: Channe
                             let channel = state.data.channels.get_mut(&channel_id).unwrap();
                             let _ = channel
                                 .chat
-                                .remove_member(Caller::OCBot(OPENCHAT_BOT_USER_ID), user_id, false, now);
+                                .remove_member(Caller::OCBot(OPENCHAT_BOT_USER_ID), user_id, false, None, now);
                         }
                     }
                 }
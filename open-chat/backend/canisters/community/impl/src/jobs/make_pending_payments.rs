@@ -1,6 +1,7 @@
 use crate::{RuntimeState, mutate_state, read_state};
 use constants::{
-    MEMO_GROUP_IMPORT_INTO_COMMUNITY, MEMO_JOINING_FEE, OPENCHAT_TREASURY_CANISTER_ID, SNS_GOVERNANCE_CANISTER_ID,
+    MEMO_GROUP_IMPORT_INTO_COMMUNITY, MEMO_JOINING_FEE, MEMO_PRIZE_REFUND, OPENCHAT_TREASURY_CANISTER_ID,
+    SNS_GOVERNANCE_CANISTER_ID,
 };
 use group_community_common::{PaymentRecipient, PendingPayment, PendingPaymentReason};
 use ic_cdk_timers::TimerId;
@@ -83,5 +84,6 @@ fn memo(reason: PendingPaymentReason) -> Memo {
     match reason {
         PendingPaymentReason::AccessGate => MEMO_JOINING_FEE.to_vec().into(),
         PendingPaymentReason::TransferToCommunityBeingImportedInto => MEMO_GROUP_IMPORT_INTO_COMMUNITY.to_vec().into(),
+        PendingPaymentReason::BotPrizeRefund => MEMO_PRIZE_REFUND.to_vec().into(),
     }
 }
@@ -13,10 +13,10 @@ use constants::{ICP_LEDGER_CANISTER_ID, OPENCHAT_BOT_USER_ID};
 use event_store_types::Event;
 use fire_and_forget_handler::FireAndForgetHandler;
 use gated_groups::{GatePayment, calculate_gate_payments};
-use group_chat_core::{AccessRulesInternal, AddResult};
+use group_chat_core::{AccessRulesInternal, AddResult, ModerationLog};
 use group_community_common::{
-    Achievements, ExpiringMember, ExpiringMemberActions, ExpiringMembers, Members, PaymentReceipts, PendingPaymentsQueue,
-    UserCache,
+    Achievements, ExpiringMember, ExpiringMemberActions, ExpiringMembers, ExpiringMutes, ExpiringRoles, Members,
+    PaymentReceipts, PendingPaymentsQueue, PollWeightSnapshots, UnsentBotTransfers, UserCache,
 };
 use ic_principal::Principal;
 use installed_bots::InstalledBots;
@@ -43,7 +43,7 @@ use types::{
     IdempotentEnvelope, MembersAdded, Milliseconds, Notification, Rules, TimestampMillis, Timestamped, UserId,
     UserNotification, UserNotificationPayload, UserType,
 };
-use types::{BotSubscriptions, CommunityId};
+use types::{BotCommandArg, BotSubscriptions, CommunityId, OCResult};
 use user_canister::CommunityCanisterEvent;
 use utils::env::Environment;
 use utils::idempotency_checker::IdempotencyChecker;
@@ -97,6 +97,10 @@ impl RuntimeState {
         self.env.caller() == self.data.escrow_canister_id
     }
 
+    pub fn is_caller_airdrop_bot(&self) -> bool {
+        self.env.caller() == self.data.airdrop_bot_canister_id
+    }
+
     pub fn is_caller_video_call_operator(&self) -> bool {
         let caller = self.env.caller();
         self.data.video_call_operators.contains(&caller)
@@ -449,6 +453,8 @@ struct Data {
     group_index_canister_id: CanisterId,
     proposals_bot_user_id: UserId,
     escrow_canister_id: CanisterId,
+    #[serde(default = "CanisterId::anonymous")]
+    airdrop_bot_canister_id: CanisterId,
     internet_identity_canister_id: CanisterId,
     date_created: TimestampMillis,
     members: CommunityMembers,
@@ -476,6 +482,16 @@ struct Data {
     achievements: Achievements,
     expiring_members: ExpiringMembers,
     expiring_member_actions: ExpiringMemberActions,
+    #[serde(default)]
+    expiring_roles: ExpiringRoles,
+    #[serde(default)]
+    expiring_mutes: ExpiringMutes,
+    #[serde(default)]
+    poll_weight_snapshots: PollWeightSnapshots,
+    #[serde(default)]
+    unsent_bot_transfers: UnsentBotTransfers,
+    #[serde(default)]
+    moderation_log: ModerationLog,
     user_cache: UserCache,
     user_event_sync_queue: GroupedTimerJobQueue<UserEventBatch>,
     local_user_index_event_sync_queue: BatchedTimerJobQueue<LocalUserIndexEventBatch>,
@@ -506,6 +522,7 @@ impl Data {
         group_index_canister_id: CanisterId,
         proposals_bot_user_id: UserId,
         escrow_canister_id: CanisterId,
+        airdrop_bot_canister_id: CanisterId,
         internet_identity_canister_id: CanisterId,
         gate: Option<AccessGateConfigInternal>,
         channels: Vec<(ChannelId, String)>,
@@ -563,6 +580,7 @@ impl Data {
             group_index_canister_id,
             proposals_bot_user_id,
             escrow_canister_id,
+            airdrop_bot_canister_id,
             internet_identity_canister_id,
             date_created: now,
             members,
@@ -588,6 +606,11 @@ impl Data {
             achievements: Achievements::default(),
             expiring_members: ExpiringMembers::default(),
             expiring_member_actions: ExpiringMemberActions::default(),
+            expiring_roles: ExpiringRoles::default(),
+            expiring_mutes: ExpiringMutes::default(),
+            poll_weight_snapshots: PollWeightSnapshots::default(),
+            unsent_bot_transfers: UnsentBotTransfers::default(),
+            moderation_log: ModerationLog::default(),
             user_cache: UserCache::default(),
             user_event_sync_queue: GroupedTimerJobQueue::new(5, true),
             local_user_index_event_sync_queue: BatchedTimerJobQueue::new(local_user_index_canister_id, true),
@@ -922,6 +945,7 @@ impl Data {
         }
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn install_bot(
         &mut self,
         owner_id: UserId,
@@ -929,6 +953,7 @@ impl Data {
         command_permissions: BotPermissions,
         autonomous_permissions: Option<BotPermissions>,
         default_subscriptions: Option<BotSubscriptions>,
+        config: Vec<BotCommandArg>,
         now: TimestampMillis,
     ) -> bool {
         if !self.bots.add(
@@ -937,6 +962,7 @@ impl Data {
             command_permissions,
             autonomous_permissions.clone(),
             default_subscriptions.clone(),
+            config,
             now,
         ) {
             return false;
@@ -1013,6 +1039,7 @@ impl Data {
 
         for channel in self.channels.iter_mut() {
             channel.chat.events.unsubscribe_bot_from_events(bot_id);
+            channel.chat.webhooks.unbind_bot(&bot_id, now);
         }
 
         // TODO: Notify UserIndex
@@ -1034,7 +1061,10 @@ impl Data {
             let channel = self.channels.get(&channel_id)?;
             let channel_member = channel.chat.members.get_verified_member(*user_id).ok()?;
 
-            let channel_permissions = channel_member.role().chat_permissions(&channel.chat.permissions);
+            let mut channel_permissions = channel_member.role().chat_permissions(&channel.chat.permissions);
+            if channel.chat.is_proposal_delegate(*user_id) {
+                channel_permissions.insert(ChatPermission::CastDelegateVote);
+            }
             let message_permissions = channel_member
                 .role()
                 .message_permissions(&channel.chat.permissions.message_permissions);
@@ -1047,15 +1077,30 @@ impl Data {
         Some(bot_permissions)
     }
 
-    pub fn is_bot_permitted(
+    pub fn verify_bot_permitted(
         &self,
         bot_id: &UserId,
         channel_id: Option<ChannelId>,
         initiator: &BotInitiator,
         required: &BotPermissions,
-    ) -> bool {
-        self.granted_bot_permissions(bot_id, initiator, channel_id)
+    ) -> OCResult {
+        if self
+            .granted_bot_permissions(bot_id, initiator, channel_id)
             .is_some_and(|granted| required.is_subset(&granted))
+        {
+            return Ok(());
+        }
+
+        // If the bot was never granted some of the required permissions, name them so that the bot can request them
+        match self
+            .bots
+            .get(bot_id)
+            .map(|bot| bot.missing_permissions(initiator, required))
+            .filter(|missing| !missing.is_empty())
+        {
+            Some(missing) => Err(OCErrorCode::BotPermissionsMissing.with_json(&missing)),
+            None => Err(OCErrorCode::InitiatorNotAuthorized.into()),
+        }
     }
 
     fn granted_bot_permissions(
@@ -38,6 +38,7 @@ fn init(args: Args) {
         args.group_index_canister_id,
         args.proposals_bot_user_id,
         args.escrow_canister_id,
+        args.airdrop_bot_canister_id,
         args.internet_identity_canister_id,
         args.gate_config.map(|g| g.into()),
         args.channels,
@@ -313,6 +313,9 @@ impl Channel {
             is_public: chat.is_public.value,
             history_visible_to_new_joiners: chat.history_visible_to_new_joiners,
             messages_visible_to_non_members: chat.messages_visible_to_non_members.value,
+            retain_edit_history: chat.events.retain_edit_history().value,
+            proposal_delegate: chat.proposal_delegate.value,
+            slow_mode: chat.slow_mode.value,
             min_visible_event_index,
             min_visible_message_index,
             latest_message,
@@ -403,6 +406,9 @@ impl Channel {
             avatar_id: updates.avatar_id,
             is_public: updates.is_public,
             messages_visible_to_non_members: updates.messages_visible_to_non_members,
+            retain_edit_history: updates.retain_edit_history,
+            proposal_delegate: updates.proposal_delegate,
+            slow_mode: updates.slow_mode,
             latest_message,
             latest_message_sender_display_name,
             latest_event_index: updates.latest_event_index,
@@ -1,7 +1,7 @@
 use crate::model::members::stable_memory::MembersStableStorage;
 use crate::model::user_groups::{UserGroup, UserGroups};
 use constants::calculate_summary_updates_data_removal_cutoff;
-use group_community_common::{Member, MemberUpdate, Members};
+use group_community_common::{Member, MemberUpdate, Members, RoleExpiry};
 use ic_principal::Principal;
 use oc_error_codes::OCErrorCode;
 use principal_to_user_id_map::PrincipalToUserIdMap;
@@ -39,6 +39,8 @@ pub struct CommunityMembers {
     members_with_referrals: BTreeSet<UserId>,
     updates: BTreeSet<(TimestampMillis, UserId, MemberUpdate)>,
     latest_update_removed: TimestampMillis,
+    #[serde(default)]
+    role_expiries: BTreeMap<UserId, RoleExpiry<CommunityRole>>,
 }
 
 impl CommunityMembers {
@@ -86,6 +88,7 @@ impl CommunityMembers {
             members_with_referrals: BTreeSet::new(),
             updates: BTreeSet::new(),
             latest_update_removed: 0,
+            role_expiries: BTreeMap::new(),
         }
     }
 
@@ -195,6 +198,7 @@ impl CommunityMembers {
                 self.members_with_referrals.remove(&referrer);
             }
         }
+        self.role_expiries.remove(&user_id);
         self.members_and_channels.remove(&user_id);
         let channels_removed: Vec<_> = self.channels_removed_for_member(user_id).map(|(c, _)| c).collect();
         for channel_id in channels_removed {
@@ -212,11 +216,16 @@ impl CommunityMembers {
         user_id: UserId,
         target_user_id: UserId,
         new_role: CommunityRole,
+        expires: Option<TimestampMillis>,
         permissions: &CommunityPermissions,
         is_caller_platform_moderator: bool,
         is_user_platform_moderator: bool,
         now: TimestampMillis,
     ) -> OCResult<ChangeRoleSuccess> {
+        if expires.is_some_and(|e| e <= now) {
+            return Err(OCErrorCode::DateInThePast.into());
+        }
+
         // Is the caller authorized to change the user to this role
         let initiator = self.get_verified_member(user_id.into())?;
 
@@ -225,7 +234,7 @@ impl CommunityMembers {
             return Err(OCErrorCode::InitiatorNotAuthorized.into());
         }
 
-        let mut member = self
+        let member = self
             .members_map
             .get(&target_user_id)
             .ok_or(OCErrorCode::TargetUserNotInCommunity)?;
@@ -246,14 +255,72 @@ impl CommunityMembers {
         }
 
         let prev_role = member.role;
+        let prev_expiry = self.role_expiries.get(&target_user_id).copied();
 
-        if prev_role == new_role {
+        if prev_role == new_role && prev_expiry.map(|e| e.expires) == expires {
             return Err(OCErrorCode::NoChange.into());
         }
 
-        match prev_role {
-            CommunityRole::Owner => self.owners.remove(&target_user_id),
-            CommunityRole::Admin => self.admins.remove(&target_user_id),
+        match expires {
+            Some(expires) => {
+                // If the current role is itself term-limited, the member still reverts to their original role
+                let revert_to = prev_expiry.map_or(prev_role, |e| e.revert_to);
+                if revert_to == new_role {
+                    return Err(OCErrorCode::InvalidRoleChange.into());
+                }
+                self.role_expiries.insert(target_user_id, RoleExpiry { expires, revert_to });
+            }
+            None => {
+                self.role_expiries.remove(&target_user_id);
+            }
+        }
+
+        if prev_role != new_role {
+            self.set_role(member, new_role, now);
+        }
+
+        Ok(ChangeRoleSuccess { prev_role })
+    }
+
+    // Reverts the member's role if their term has ended, returning the previous and new roles
+    pub fn expire_role(&mut self, user_id: UserId, now: TimestampMillis) -> Option<(CommunityRole, CommunityRole)> {
+        let expiry = self.role_expiries.get(&user_id).copied()?;
+        if expiry.expires > now {
+            return None;
+        }
+
+        let Some(member) = self.members_map.get(&user_id) else {
+            self.role_expiries.remove(&user_id);
+            return None;
+        };
+        let prev_role = member.role;
+
+        // It is not possible to change the role of the last owner, so the expiry is kept until there is another owner
+        if prev_role != expiry.revert_to && prev_role.is_owner() && self.owners.len() <= 1 {
+            return None;
+        }
+
+        self.role_expiries.remove(&user_id);
+
+        if prev_role == expiry.revert_to {
+            return None;
+        }
+
+        self.set_role(member, expiry.revert_to, now);
+        Some((prev_role, expiry.revert_to))
+    }
+
+    // True if the member's term has ended but their role couldn't be reverted because they are the last owner
+    pub fn is_role_expiry_deferred(&self, user_id: UserId, now: TimestampMillis) -> bool {
+        self.role_expiries.get(&user_id).is_some_and(|e| e.expires <= now)
+    }
+
+    fn set_role(&mut self, mut member: CommunityMemberInternal, new_role: CommunityRole, now: TimestampMillis) {
+        let user_id = member.user_id;
+
+        match member.role {
+            CommunityRole::Owner => self.owners.remove(&user_id),
+            CommunityRole::Admin => self.admins.remove(&user_id),
             _ => false,
         };
 
@@ -263,18 +330,16 @@ impl CommunityMembers {
             CommunityRole::Owner => {
                 if member.lapsed.value {
                     member.lapsed = Timestamped::new(false, now);
-                    self.lapsed.remove(&target_user_id);
+                    self.lapsed.remove(&user_id);
                 }
-                self.owners.insert(target_user_id)
+                self.owners.insert(user_id)
             }
-            CommunityRole::Admin => self.admins.insert(target_user_id),
+            CommunityRole::Admin => self.admins.insert(user_id),
             _ => false,
         };
 
-        self.members_map.insert(target_user_id, member);
-        self.prune_then_insert_member_update(target_user_id, MemberUpdate::RoleChanged, now);
-
-        Ok(ChangeRoleSuccess { prev_role })
+        self.members_map.insert(user_id, member);
+        self.prune_then_insert_member_update(user_id, MemberUpdate::RoleChanged, now);
     }
 
     pub fn set_suspended(&mut self, user_id: UserId, suspended: bool, now: TimestampMillis) -> Option<bool> {
@@ -107,6 +107,7 @@ fn execute_operation(members: &mut CommunityMembers, op: Operation, timestamp: T
                 owner,
                 user_id,
                 role,
+                None,
                 &CommunityPermissions::default(),
                 false,
                 false,
@@ -3,7 +3,6 @@ use crate::guards::caller_is_local_user_index;
 use crate::read_state;
 use canister_api_macros::query;
 use community_canister::c2c_bot_channel_details::{Response::*, *};
-use oc_error_codes::OCErrorCode;
 use std::cmp::max;
 use types::ChatPermission;
 use types::ChatSummaryGroup;
@@ -19,14 +18,12 @@ fn c2c_bot_channel_details(args: Args) -> Response {
 }
 
 fn c2c_bot_channel_details_impl(args: Args, state: &RuntimeState) -> OCResult<ChatSummaryGroup> {
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         Some(args.channel_id),
         &args.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::ReadSummary),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     let channel = state.data.channels.get_or_err(&args.channel_id)?;
     let chat = &channel.chat;
@@ -3,7 +3,6 @@ use crate::guards::caller_is_local_user_index;
 use crate::read_state;
 use canister_api_macros::query;
 use community_canister::c2c_bot_community_summary::{Response::*, *};
-use oc_error_codes::OCErrorCode;
 use types::{BotPermissions, CommunityPermission, Document, OCResult};
 
 #[query(guard = "caller_is_local_user_index", msgpack = true)]
@@ -15,14 +14,12 @@ fn c2c_bot_community_summary(args: Args) -> Response {
 }
 
 fn c2c_bot_community_summary_impl(args: Args, state: &RuntimeState) -> OCResult<CommunitySummary> {
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         None,
         &args.initiator,
         &BotPermissions::from_community_permission(CommunityPermission::ReadSummary),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     let data = &state.data;
 
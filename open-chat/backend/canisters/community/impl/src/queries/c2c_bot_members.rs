@@ -32,12 +32,9 @@ fn c2c_bot_members_impl(args: Args, state: &RuntimeState) -> OCResult<MembersRes
         BotPermissions::from_community_permission(CommunityPermission::ReadMembership)
     };
 
-    if !state
+    state
         .data
-        .is_bot_permitted(&args.bot_id, args.channel_id, &args.initiator, &required_permissions)
-    {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+        .verify_bot_permitted(&args.bot_id, args.channel_id, &args.initiator, &required_permissions)?;
 
     let mut members_map = HashMap::new();
 
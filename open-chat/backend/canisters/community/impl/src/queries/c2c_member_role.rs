@@ -0,0 +1,27 @@
+use crate::guards::caller_is_airdrop_bot_or_local_user_index;
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::c2c_member_role::{Response::*, *};
+use oc_error_codes::OCErrorCode;
+use types::OCResult;
+
+#[query(guard = "caller_is_airdrop_bot_or_local_user_index", msgpack = true)]
+fn c2c_member_role(args: Args) -> Response {
+    match read_state(|state| c2c_member_role_impl(args, state)) {
+        Ok(result) => Success(result),
+        Err(error) => Error(error),
+    }
+}
+
+fn c2c_member_role_impl(args: Args, state: &RuntimeState) -> OCResult<SuccessResult> {
+    let member = state
+        .data
+        .members
+        .get(args.user_id_or_principal)
+        .ok_or(OCErrorCode::InitiatorNotInCommunity)?;
+
+    Ok(SuccessResult {
+        user_id: member.user_id,
+        role: member.role(),
+    })
+}
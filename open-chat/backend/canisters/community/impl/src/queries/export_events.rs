@@ -0,0 +1,21 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::export_events::{Response::*, *};
+use types::{ChatExportPage, OCResult};
+
+#[query(msgpack = true)]
+fn export_events(args: Args) -> Response {
+    match read_state(|state| export_events_impl(args, state)) {
+        Ok(page) => Success(page),
+        Err(error) => Error(error),
+    }
+}
+
+fn export_events_impl(args: Args, state: &RuntimeState) -> OCResult<ChatExportPage> {
+    let user_id = state.get_caller_user_id()?;
+    let channel = state.data.channels.get_or_err(&args.channel_id)?;
+
+    channel
+        .chat
+        .export_events(user_id, args.thread_root_message_index, args.start_index, args.max_events)
+}
@@ -0,0 +1,20 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::member_warnings::{Response::*, *};
+use types::{MemberWarnings, OCResult};
+
+#[query(msgpack = true)]
+fn member_warnings(args: Args) -> Response {
+    match read_state(|state| member_warnings_impl(args, state)) {
+        Ok(warnings) => Success(warnings),
+        Err(error) => Error(error),
+    }
+}
+
+fn member_warnings_impl(args: Args, state: &RuntimeState) -> OCResult<MemberWarnings> {
+    let user_id = state.get_caller_user_id()?;
+    let channel = state.data.channels.get_or_err(&args.channel_id)?;
+    let now = state.env.now();
+
+    channel.chat.member_warnings(user_id, args.user_id, now)
+}
@@ -2,11 +2,13 @@ use crate::RuntimeState;
 use types::TimestampMillis;
 
 mod active_proposal_tallies;
+mod c2c_bot_active_proposal_tallies;
 mod c2c_bot_channel_details;
 mod c2c_bot_community_events;
 mod c2c_bot_community_summary;
 mod c2c_bot_members;
 mod c2c_can_issue_access_token;
+mod c2c_member_role;
 mod channel_summary;
 mod channel_summary_updates;
 mod community_events;
@@ -15,12 +17,18 @@ mod events;
 mod events_by_index;
 mod events_window;
 mod explore_channels;
+mod export_events;
 mod http_request;
 mod invite_code;
 mod local_user_index;
 mod lookup_members;
+mod member_warnings;
+mod message_edit_history;
 mod messages_by_message_index;
+mod moderation_log;
 mod search_channel;
+mod search_community;
+mod secret_ballot_audit;
 mod selected_channel_initial;
 mod selected_channel_updates;
 mod selected_initial;
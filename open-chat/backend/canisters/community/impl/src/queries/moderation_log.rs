@@ -0,0 +1,29 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::moderation_log::{Response::*, *};
+use oc_error_codes::OCErrorCode;
+use types::{ModerationLogPage, OCResult};
+
+#[query(msgpack = true)]
+fn moderation_log(args: Args) -> Response {
+    match read_state(|state| moderation_log_impl(args, state)) {
+        Ok(page) => Success(page),
+        Err(error) => Error(error),
+    }
+}
+
+fn moderation_log_impl(args: Args, state: &RuntimeState) -> OCResult<ModerationLogPage> {
+    if let Some(channel_id) = args.channel_id {
+        let user_id = state.get_caller_user_id()?;
+        let channel = state.data.channels.get_or_err(&channel_id)?;
+
+        channel.chat.moderation_log_page(user_id, args.start_index, args.max_entries)
+    } else {
+        let member = state.get_calling_member(true)?;
+        if !member.role().can_view_moderation_log() {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        Ok(state.data.moderation_log.page(args.start_index, args.max_entries))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::search_community::{Response::*, *};
+use group_chat_core::GroupChatCore;
+use oc_error_codes::OCErrorCode;
+use std::cmp::Reverse;
+use types::OCResult;
+
+// Bounds how deep into the results a client can page, since each page requires every channel to be searched again
+const MAX_RESULTS_WINDOW: u32 = 500;
+
+#[query(msgpack = true)]
+fn search_community(args: Args) -> Response {
+    match read_state(|state| search_community_impl(args, state)) {
+        Ok(result) => Success(result),
+        Err(error) => Error(error),
+    }
+}
+
+fn search_community_impl(args: Args, state: &RuntimeState) -> OCResult<SuccessResult> {
+    let user_id = state.get_caller_user_id()?;
+    let (query, users) = GroupChatCore::parse_search_query(&args.search_term, args.users)?;
+
+    let window = args.offset.saturating_add(args.max_results as u32);
+    if window > MAX_RESULTS_WINDOW {
+        return Err(OCErrorCode::InvalidRequest.with_message(format!("Cannot page beyond {MAX_RESULTS_WINDOW} results")));
+    }
+
+    // Fetch one extra result so that we know whether there is another page
+    let per_channel_limit = window as usize + 1;
+
+    // Channels which the user can't see are skipped
+    let mut matches: Vec<_> = state
+        .data
+        .channels
+        .iter()
+        .filter_map(|c| {
+            c.chat
+                .search_visible_messages(user_id, &query, &users, per_channel_limit)
+                .ok()
+                .map(|matches| (c.id, matches))
+        })
+        .flat_map(|(channel_id, matches)| {
+            matches.into_iter().map(move |(m, timestamp)| ChannelMessageMatch {
+                channel_id,
+                message_index: m.message_index,
+                timestamp,
+                score: m.score,
+            })
+        })
+        .collect();
+
+    matches.sort_unstable_by_key(|m| (Reverse(m.score), Reverse(m.timestamp)));
+
+    let has_more = matches.len() > window as usize;
+    let matches: Vec<_> = matches
+        .into_iter()
+        .skip(args.offset as usize)
+        .take(args.max_results as usize)
+        .collect();
+
+    Ok(SuccessResult {
+        next_offset: has_more.then_some(window),
+        matches,
+    })
+}
@@ -0,0 +1,21 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use community_canister::secret_ballot_audit::{Response::*, *};
+use types::{OCResult, SecretBallotAudit};
+
+#[query(msgpack = true)]
+fn secret_ballot_audit(args: Args) -> Response {
+    match read_state(|state| secret_ballot_audit_impl(args, state)) {
+        Ok(audit) => Success(audit),
+        Err(error) => Error(error),
+    }
+}
+
+fn secret_ballot_audit_impl(args: Args, state: &RuntimeState) -> OCResult<SecretBallotAudit> {
+    let user_id = state.get_caller_user_id()?;
+    let channel = state.data.channels.get_or_err(&args.channel_id)?;
+
+    channel
+        .chat
+        .secret_ballot_audit(user_id, args.thread_root_message_index, args.message_index)
+}
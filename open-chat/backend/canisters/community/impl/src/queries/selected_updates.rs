@@ -104,6 +104,9 @@ fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
                             added_by: bot.added_by,
                             permissions: bot.permissions.clone(),
                             autonomous_permissions: bot.autonomous_permissions.clone(),
+                            config: bot.config.clone(),
+                            permissions_request: bot.permissions_request.clone(),
+                            pinned_version: bot.pinned_version,
                         });
                     }
                 }
@@ -4,7 +4,6 @@ use crate::{CommunityEventPusher, RuntimeState, execute_update};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::{add_reaction::*, c2c_bot_add_reaction};
-use oc_error_codes::OCErrorCode;
 use types::{
     Achievement, BotCaller, BotPermissions, Caller, ChannelReactionAddedNotification, Chat, ChatPermission, CommunityId,
     EventIndex, FcmData, OCResult, UserNotificationPayload,
@@ -39,14 +38,12 @@ fn c2c_bot_add_reaction_impl(args: c2c_bot_add_reaction::Args, state: &mut Runti
         new_achievement: false,
     };
 
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &bot_caller.bot,
         Some(args.channel_id),
         &bot_caller.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::ReactToMessages),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     add_reaction_impl(args, Some(Caller::BotV2(bot_caller)), state)
 }
@@ -0,0 +1,55 @@
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::c2c_bot_request_permissions::*;
+use oc_error_codes::OCErrorCode;
+use types::{BotPermissionsRequest, FieldTooLongResult, OCResult};
+
+const MAX_JUSTIFICATION_LENGTH: usize = 1000;
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_bot_request_permissions(args: Args) -> Response {
+    execute_update(|state| c2c_bot_request_permissions_impl(args, state)).into()
+}
+
+fn c2c_bot_request_permissions_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let justification_len = args.justification.chars().count();
+    if justification_len > MAX_JUSTIFICATION_LENGTH {
+        return Err(OCErrorCode::TextTooLong.with_json(&FieldTooLongResult {
+            length_provided: justification_len as u32,
+            max_length: MAX_JUSTIFICATION_LENGTH as u32,
+        }));
+    }
+
+    let bot = state.data.bots.get(&args.bot_id).ok_or(OCErrorCode::BotNotFound)?;
+
+    // Only the permissions which haven't already been granted are put to the owners
+    let command_permissions = args.command_permissions.difference(&bot.permissions);
+    let autonomous_permissions = args
+        .autonomous_permissions
+        .difference(&bot.autonomous_permissions.clone().unwrap_or_default());
+
+    if command_permissions.is_empty() && autonomous_permissions.is_empty() {
+        return Err(OCErrorCode::NoChange.into());
+    }
+
+    let now = state.env.now();
+
+    state.data.bots.set_permissions_request(
+        args.bot_id,
+        BotPermissionsRequest {
+            command_permissions,
+            autonomous_permissions,
+            justification: args.justification,
+            requested_at: now,
+        },
+        now,
+    );
+
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -3,7 +3,6 @@ use crate::{RuntimeState, execute_update};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::c2c_bot_subscribe_to_events::*;
-use oc_error_codes::OCErrorCode;
 use types::{BotInitiator, BotPermissions, BotSubscriptions, OCResult};
 
 #[update(guard = "caller_is_local_user_index", msgpack = true)]
@@ -19,14 +18,12 @@ fn c2c_bot_subscribe_to_events_impl(args: Args, state: &mut RuntimeState) -> OCR
     };
 
     // Note: Currently if _any_ of the events is not permitted then we don't update _any_ subscriptions
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         args.channel_id,
         &BotInitiator::Autonomous,
         &BotPermissions::from(&subscriptions),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     // TODO: Subscribe to permitted community events
 
@@ -30,14 +30,12 @@ pub(crate) fn invite_users_to_community_impl(
     state.data.verify_not_frozen()?;
 
     if let Caller::BotV2(bot_caller) = &caller {
-        if !state.data.is_bot_permitted(
+        state.data.verify_bot_permitted(
             &bot_caller.bot,
             None,
             &bot_caller.initiator,
             &BotPermissions::from_community_permission(CommunityPermission::InviteUsers),
-        ) {
-            return Err(OCErrorCode::InitiatorNotAuthorized.into());
-        }
+        )?;
     }
 
     let invited_by = if let Some(initiator) = caller.initiator() {
@@ -7,7 +7,6 @@ use canister_tracing_macros::trace;
 use community_canister::c2c_bot_invite_users;
 use community_canister::c2c_invite_users_to_channel::{Response::*, *};
 use ic_principal::Principal;
-use oc_error_codes::OCErrorCode;
 use types::{BotCaller, BotPermissions, Caller, ChannelId, ChatPermission, OCResult, UserId};
 
 #[update(guard = "caller_is_local_user_index", msgpack = true)]
@@ -43,14 +42,12 @@ fn c2c_invite_users_to_channel_impl(
     state.data.verify_not_frozen()?;
 
     if let Caller::BotV2(bot_caller) = &ext_caller {
-        if !state.data.is_bot_permitted(
+        state.data.verify_bot_permitted(
             &bot_caller.bot,
             Some(channel_id),
             &bot_caller.initiator,
             &BotPermissions::from_chat_permission(ChatPermission::InviteUsers),
-        ) {
-            return Err(OCErrorCode::InitiatorNotAuthorized.into());
-        }
+        )?;
     }
 
     let mut users_to_invite_to_channel = Vec::new();
@@ -0,0 +1,39 @@
+use crate::guards::caller_is_local_user_index;
+use crate::model::events::CommunityEventInternal;
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use oc_error_codes::OCErrorCode;
+use types::c2c_set_bot_version::*;
+use types::{BotUpdated, OCResult};
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_set_bot_version(args: Args) -> Response {
+    execute_update(|state| c2c_set_bot_version_impl(args, state)).into()
+}
+
+fn c2c_set_bot_version_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let member = state.data.members.get_verified_member(args.caller.into())?;
+
+    if !member.role().is_owner() {
+        return Err(OCErrorCode::InitiatorNotAuthorized.into());
+    }
+
+    let updated_by = member.user_id;
+    let now = state.env.now();
+
+    if !state.data.bots.set_pinned_version(args.bot_id, args.pinned_version, now) {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    state.push_community_event(CommunityEventInternal::BotUpdated(Box::new(BotUpdated {
+        user_id: args.bot_id,
+        updated_by,
+    })));
+
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::guards::caller_is_local_user_index;
+use crate::model::events::CommunityEventInternal;
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use oc_error_codes::OCErrorCode;
+use types::c2c_update_bot_config::*;
+use types::{BotEvent, BotInstallationLocation, BotInstalledEvent, BotLifecycleEvent, BotNotification, BotUpdated, OCResult};
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_update_bot_config(args: Args) -> Response {
+    execute_update(|state| c2c_update_bot_config_impl(args, state)).into()
+}
+
+fn c2c_update_bot_config_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let member = state.data.members.get_verified_member(args.caller.into())?;
+
+    if !member.role().is_owner() {
+        return Err(OCErrorCode::InitiatorNotAuthorized.into());
+    }
+
+    let updated_by = member.user_id;
+    let now = state.env.now();
+
+    if !state.data.bots.update_config(args.bot_id, args.config.clone(), now) {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    state.push_community_event(CommunityEventInternal::BotUpdated(Box::new(BotUpdated {
+        user_id: args.bot_id,
+        updated_by,
+    })));
+
+    let bot = state.data.bots.get(&args.bot_id).unwrap();
+    let granted_command_permissions = bot.permissions.clone();
+    let granted_autonomous_permissions = bot.autonomous_permissions.clone().unwrap_or_default();
+
+    state.push_bot_notification(Some(BotNotification {
+        event: BotEvent::Lifecycle(BotLifecycleEvent::Installed(BotInstalledEvent {
+            installed_by: updated_by,
+            location: BotInstallationLocation::Community(state.env.canister_id().into()),
+            api_gateway: state.data.local_user_index_canister_id,
+            granted_command_permissions,
+            granted_autonomous_permissions,
+            config: args.config,
+        })),
+        recipients: vec![args.bot_id],
+        timestamp: now,
+    }));
+
+    handle_activity_notification(state);
+    Ok(())
+}
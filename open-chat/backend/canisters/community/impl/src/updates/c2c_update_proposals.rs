@@ -23,12 +23,13 @@ fn c2c_update_proposals_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         return Err(OCErrorCode::InitiatorNotInChat.into());
     }
 
-    if channel
+    let result = channel
         .chat
         .events
-        .update_proposals(member.user_id, args.proposals, state.env.now())
-    {
+        .update_proposals(member.user_id, args.proposals, state.env.now());
+    if result.chat_updated {
         handle_activity_notification(state);
     }
+    state.push_bot_notifications(result.bot_notifications);
     Ok(())
 }
@@ -1,9 +1,9 @@
-use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update, jobs};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::change_channel_role::*;
 use group_chat_core::GroupRoleInternal;
-use group_community_common::ExpiringMember;
+use group_community_common::{ExpiringMember, ExpiringRole};
 use types::{GroupRole, OCResult};
 
 #[update(msgpack = true)]
@@ -19,9 +19,15 @@ fn change_channel_role_impl(args: Args, state: &mut RuntimeState) -> OCResult {
     let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
     let now = state.env.now();
 
-    let result = channel
-        .chat
-        .change_role(member.user_id, args.user_id, args.new_role, false, false, now)?;
+    let result = channel.chat.change_role(
+        member.user_id,
+        args.user_id,
+        args.new_role,
+        args.expires_at,
+        false,
+        false,
+        now,
+    )?;
 
     // Owners can't "lapse" so either add or remove user from expiry list if they lose or gain owner status
     if let Some(gate_expiry) = channel.chat.gate_config.value.as_ref().and_then(|gc| gc.expiry()) {
@@ -36,6 +42,15 @@ fn change_channel_role_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         }
     }
 
+    if let Some(expires) = args.expires_at {
+        state.data.expiring_roles.push(ExpiringRole {
+            expires,
+            channel_id: Some(args.channel_id),
+            user_id: args.user_id,
+        });
+        jobs::expire_roles::restart_job(state);
+    }
+
     state.push_bot_notification(result.bot_notification);
     handle_activity_notification(state);
     Ok(())
@@ -5,7 +5,7 @@ use crate::{
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::change_role::*;
-use group_community_common::ExpiringMember;
+use group_community_common::{ExpiringMember, ExpiringRole};
 use oc_error_codes::OCErrorCode;
 use types::{CanisterId, CommunityRole, CommunityRoleChanged, OCResult, UserId};
 use user_index_canister_c2c_client::lookup_user;
@@ -99,6 +99,7 @@ fn commit(
         caller_id,
         args.user_id,
         args.new_role,
+        args.expires_at,
         &state.data.permissions,
         is_caller_platform_moderator,
         is_user_platform_moderator,
@@ -118,13 +119,25 @@ fn commit(
         }
     }
 
-    let event = CommunityRoleChanged {
-        user_ids: vec![args.user_id],
-        old_role: result.prev_role,
-        new_role: args.new_role,
-        changed_by: caller_id,
-    };
-    state.push_community_event(CommunityEventInternal::RoleChanged(Box::new(event)));
+    // If only the term of the role has changed then there is no event to push
+    if result.prev_role != args.new_role {
+        let event = CommunityRoleChanged {
+            user_ids: vec![args.user_id],
+            old_role: result.prev_role,
+            new_role: args.new_role,
+            changed_by: caller_id,
+        };
+        state.push_community_event(CommunityEventInternal::RoleChanged(Box::new(event)));
+    }
+
+    if let Some(expires) = args.expires_at {
+        state.data.expiring_roles.push(ExpiringRole {
+            expires,
+            channel_id: None,
+            user_id: args.user_id,
+        });
+        jobs::expire_roles::restart_job(state);
+    }
 
     jobs::expire_members::start_job_if_required(state);
 
@@ -113,9 +113,9 @@ fn create_channel_impl(
     let channel_id = state.generate_channel_id();
     let subtype = is_proposals_channel.then_some(args.subtype).flatten();
 
-    if !is_proposals_channel
-        && !match &caller {
-            Caller::BotV2(bot_caller) => state.data.is_bot_permitted(
+    if !is_proposals_channel {
+        match &caller {
+            Caller::BotV2(bot_caller) => state.data.verify_bot_permitted(
                 &bot_caller.bot,
                 None,
                 &bot_caller.initiator,
@@ -124,9 +124,9 @@ fn create_channel_impl(
                 } else {
                     CommunityPermission::CreatePrivateChannel
                 }),
-            ),
+            )?,
             _ => {
-                if let Some(member) = state.data.members.get_by_user_id(&caller.agent()) {
+                let permitted = if let Some(member) = state.data.members.get_by_user_id(&caller.agent()) {
                     if args.is_public {
                         member.role().can_create_public_channel(&state.data.permissions)
                     } else {
@@ -134,11 +134,13 @@ fn create_channel_impl(
                     }
                 } else {
                     false
+                };
+
+                if !permitted {
+                    return Err(OCErrorCode::InitiatorNotAuthorized.into());
                 }
             }
         }
-    {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
     }
 
     if let Err(error) = validate_channel_name(&args.name) {
@@ -8,7 +8,7 @@ use community_canister::c2c_bot_delete_channel;
 use community_canister::delete_channel::*;
 use group_community_common::Member;
 use oc_error_codes::OCErrorCode;
-use stable_memory_map::{BaseKeyPrefix, ChatEventKeyPrefix, UserIdKeyPrefix};
+use stable_memory_map::{BaseKeyPrefix, ChatEventKeyPrefix, MessageRevisionKeyPrefix, SearchIndexKeyPrefix, UserIdKeyPrefix};
 use types::{BotCaller, Caller, ChannelDeleted, ChannelId, OCResult};
 
 #[update(msgpack = true)]
@@ -89,6 +89,16 @@ fn delete_channel_impl(channel_id: ChannelId, ext_caller: Option<Caller>, state:
         .stable_memory_keys_to_garbage_collect
         .push(BaseKeyPrefix::from(UserIdKeyPrefix::new_from_channel(channel_id)));
 
+    state
+        .data
+        .stable_memory_keys_to_garbage_collect
+        .push(BaseKeyPrefix::from(SearchIndexKeyPrefix::new_from_channel(channel_id)));
+
+    state
+        .data
+        .stable_memory_keys_to_garbage_collect
+        .push(BaseKeyPrefix::from(MessageRevisionKeyPrefix::new_from_channel(channel_id)));
+
     crate::jobs::garbage_collect_stable_memory::start_job_if_required(state);
 
     state.push_community_event(CommunityEventInternal::ChannelDeleted(Box::new(ChannelDeleted {
@@ -57,14 +57,12 @@ fn c2c_bot_delete_messages_impl(args: c2c_bot_delete_messages::Args, state: &mut
         new_achievement: false,
     };
 
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &bot_caller.bot,
         Some(args.channel_id),
         &bot_caller.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::DeleteMessages),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     commit(args, Some(Caller::BotV2(bot_caller)), state)
 }
@@ -0,0 +1,43 @@
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::deny_bot_permissions_request::*;
+use oc_error_codes::OCErrorCode;
+use types::{BotEvent, BotInstallationLocation, BotLifecycleEvent, BotNotification, BotPermissionsDeniedEvent, OCResult};
+
+#[update(msgpack = true)]
+#[trace]
+fn deny_bot_permissions_request(args: Args) -> Response {
+    execute_update(|state| deny_bot_permissions_request_impl(args, state)).into()
+}
+
+fn deny_bot_permissions_request_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let member = state.get_calling_member(true)?;
+
+    if !member.role().is_owner() {
+        return Err(OCErrorCode::InitiatorNotAuthorized.into());
+    }
+
+    let denied_by = member.user_id;
+    let now = state.env.now();
+
+    let Some(request) = state.data.bots.take_permissions_request(args.bot_id, now) else {
+        return Err(OCErrorCode::InvalidRequest.with_message("No pending permissions request"));
+    };
+
+    state.push_bot_notification(Some(BotNotification {
+        event: BotEvent::Lifecycle(BotLifecycleEvent::PermissionsDenied(BotPermissionsDeniedEvent {
+            denied_by,
+            location: BotInstallationLocation::Community(state.env.canister_id().into()),
+            requested_command_permissions: request.command_permissions,
+            requested_autonomous_permissions: request.autonomous_permissions,
+        })),
+        recipients: vec![args.bot_id],
+        timestamp: now,
+    }));
+
+    handle_activity_notification(state);
+    Ok(())
+}
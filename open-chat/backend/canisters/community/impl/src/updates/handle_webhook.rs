@@ -1,20 +1,22 @@
 use crate::{RuntimeState, execute_update};
 use community_canister::handle_webhook::*;
-use community_canister::send_message::SuccessResult;
 use oc_error_codes::OCErrorCode;
 use rand::Rng;
-use types::{Caller, MessageContentInitial, OCResult, TextContent};
+use types::{
+    BotEvent, BotNotification, BotWebhookEvent, Caller, ChannelId, Chat, MAX_TEXT_LENGTH_USIZE, MessageContentInitial,
+    OCResult, TextContent, UserId,
+};
 
 use super::send_message::send_message_impl;
 
 pub(crate) fn handle_webhook(args: Args) -> Response {
     match execute_update(|state| handle_webhook_impl(args, state)) {
-        Ok(result) => Response::Success(result),
+        Ok(response) => response,
         Err(error) => Response::Error(error),
     }
 }
 
-fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<SuccessResult> {
+fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Response> {
     let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
 
     let Some(webhook) = channel.chat.webhooks.get(&args.id) else {
@@ -25,6 +27,11 @@ fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Success
         return Err(OCErrorCode::InvalidWebhook.into());
     }
 
+    if let Some(bot_id) = webhook.bot_id {
+        deliver_to_bot(args.id, bot_id, args.channel_id, args.message, state)?;
+        return Ok(Response::DeliveredToBot);
+    }
+
     let send_message_args = community_canister::send_message::Args {
         thread_root_message_index: None,
         message_id: state.env.rng().r#gen::<u64>().into(),
@@ -42,5 +49,39 @@ fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Success
         channel_rules_accepted: None,
     };
 
-    send_message_impl(send_message_args, Some(Caller::Webhook(args.id)), true, state)
+    send_message_impl(send_message_args, Some(Caller::Webhook(args.id)), None, true, state).map(Response::Success)
+}
+
+fn deliver_to_bot(
+    webhook_id: UserId,
+    bot_id: UserId,
+    channel_id: ChannelId,
+    payload: String,
+    state: &mut RuntimeState,
+) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    if state.data.bots.get(&bot_id).is_none() {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    if payload.len() > MAX_TEXT_LENGTH_USIZE {
+        return Err(OCErrorCode::TextTooLong.into());
+    }
+
+    if serde_json::from_str::<serde_json::Value>(&payload).is_err() {
+        return Err(OCErrorCode::InvalidRequest.with_message("payload must be valid JSON"));
+    }
+
+    state.push_bot_notification(Some(BotNotification {
+        event: BotEvent::Webhook(BotWebhookEvent {
+            webhook_id,
+            chat: Chat::Channel(state.env.canister_id().into(), channel_id),
+            payload,
+        }),
+        recipients: vec![bot_id],
+        timestamp: state.env.now(),
+    }));
+
+    Ok(())
 }
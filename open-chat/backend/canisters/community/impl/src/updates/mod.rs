@@ -1,6 +1,7 @@
 pub mod accept_p2p_swap;
 pub mod add_members_to_channel;
 pub mod add_reaction;
+pub mod c2c_bot_request_permissions;
 pub mod c2c_bot_subscribe_to_events;
 pub mod c2c_delete_community;
 pub mod c2c_freeze_community;
@@ -11,11 +12,14 @@ pub mod c2c_join_channel;
 pub mod c2c_join_community;
 pub mod c2c_leave_community;
 pub mod c2c_local_index;
+pub mod c2c_notify_escrow_payout_status_change;
 pub mod c2c_notify_p2p_swap_status_change;
+pub mod c2c_set_bot_version;
 pub mod c2c_set_user_suspended;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_community;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_bot_config;
 pub mod c2c_update_proposals;
 pub mod c2c_update_user_principal;
 pub mod cancel_invites;
@@ -23,12 +27,14 @@ pub mod cancel_p2p_swap;
 pub mod change_channel_role;
 pub mod change_role;
 pub mod claim_prize;
+pub mod contribute_to_crowdfund;
 pub mod create_channel;
 pub mod create_user_group;
 pub mod decline_invitation;
 pub mod delete_channel;
 pub mod delete_messages;
 pub mod delete_user_groups;
+pub mod deny_bot_permissions_request;
 pub mod disable_invite_code;
 pub mod edit_message;
 pub mod enable_invite_code;
@@ -37,9 +43,12 @@ pub mod follow_thread;
 pub mod import_group;
 pub mod join_video_call;
 pub mod leave_channel;
+pub mod mute_member;
 pub mod pin_message;
+pub mod register_delegate_proposal_vote;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
+pub mod reveal_poll_ballot;
 //pub mod register_proposal_vote_v2;
 pub mod delete_webhook;
 pub mod handle_webhook;
@@ -52,7 +61,9 @@ pub mod remove_reaction;
 pub mod report_message;
 pub mod send_message;
 pub mod set_member_display_name;
+pub mod set_proposal_delegate;
 pub mod set_video_call_presence;
+pub mod set_warning_policy;
 pub mod start_video_call;
 pub mod toggle_mute_notifications;
 pub mod unblock_user;
@@ -64,3 +75,4 @@ pub mod update_community;
 pub mod update_user_group;
 pub mod update_webhook;
 pub mod wallet_receive;
+pub mod warn_member;
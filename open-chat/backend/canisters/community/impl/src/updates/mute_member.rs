@@ -0,0 +1,66 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, execute_update, jobs};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::mute_member::*;
+use group_community_common::ExpiringMute;
+use types::{BotCaller, BotPermissions, Caller, ChannelId, ChatPermission, Milliseconds, OCResult, UnitResult, UserId};
+
+#[update(msgpack = true)]
+#[trace]
+fn mute_member(args: Args) -> Response {
+    execute_update(|state| mute_member_impl(args.channel_id, args.user_id, args.duration, args.reason, None, state)).into()
+}
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_bot_mute_user(args: community_canister::c2c_bot_mute_user::Args) -> UnitResult {
+    execute_update(|state| {
+        let caller = Caller::BotV2(BotCaller {
+            bot: args.bot_id,
+            initiator: args.initiator,
+        });
+        mute_member_impl(args.channel_id, args.user_id, args.duration, None, Some(caller), state)
+    })
+    .into()
+}
+
+fn mute_member_impl(
+    channel_id: ChannelId,
+    user_id: UserId,
+    duration: Option<Milliseconds>,
+    reason: Option<String>,
+    ext_caller: Option<Caller>,
+    state: &mut RuntimeState,
+) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let caller = state.verified_caller(ext_caller)?;
+
+    if let Caller::BotV2(bot_caller) = &caller {
+        state.data.verify_bot_permitted(
+            &bot_caller.bot,
+            Some(channel_id),
+            &bot_caller.initiator,
+            &BotPermissions::from_chat_permission(ChatPermission::MuteMembers),
+        )?;
+    }
+
+    let channel = state.data.channels.get_mut_or_err(&channel_id)?;
+    let now = state.env.now();
+    let result = channel.chat.mute_member(caller, user_id, duration, reason, now)?;
+
+    if let Some(expires) = result.muted_until {
+        state.data.expiring_mutes.push(ExpiringMute {
+            expires,
+            channel_id: Some(channel_id),
+            user_id,
+        });
+        jobs::expire_mutes::restart_job(state);
+    }
+
+    state.push_bot_notification(result.bot_notification);
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::{c2c_bot_register_delegate_proposal_vote, register_delegate_proposal_vote::*};
+use types::{BotCaller, BotPermissions, Caller, ChatPermission, OCResult};
+
+#[update(msgpack = true)]
+#[trace]
+fn register_delegate_proposal_vote(args: Args) -> Response {
+    execute_update(|state| register_delegate_proposal_vote_impl(args, None, state)).into()
+}
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_bot_register_delegate_proposal_vote(
+    args: c2c_bot_register_delegate_proposal_vote::Args,
+) -> c2c_bot_register_delegate_proposal_vote::Response {
+    execute_update(|state| c2c_bot_register_delegate_proposal_vote_impl(args, state)).into()
+}
+
+fn c2c_bot_register_delegate_proposal_vote_impl(
+    args: c2c_bot_register_delegate_proposal_vote::Args,
+    state: &mut RuntimeState,
+) -> OCResult {
+    let bot_caller = BotCaller {
+        bot: args.bot_id,
+        initiator: args.initiator,
+    };
+
+    state.data.verify_bot_permitted(
+        &bot_caller.bot,
+        Some(args.channel_id),
+        &bot_caller.initiator,
+        &BotPermissions::from_chat_permission(ChatPermission::CastDelegateVote),
+    )?;
+
+    let args = Args {
+        channel_id: args.channel_id,
+        message_index: args.message_index,
+        adopt: args.adopt,
+    };
+
+    register_delegate_proposal_vote_impl(args, Some(Caller::BotV2(bot_caller)), state)
+}
+
+fn register_delegate_proposal_vote_impl(args: Args, ext_caller: Option<Caller>, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let caller = state.verified_caller(ext_caller)?;
+    let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
+    let now = state.env.now();
+
+    let result = channel
+        .chat
+        .register_delegate_proposal_vote(caller, args.message_index, args.adopt, now)?;
+
+    state.push_bot_notification(result.bot_notification);
+    handle_activity_notification(state);
+    Ok(())
+}
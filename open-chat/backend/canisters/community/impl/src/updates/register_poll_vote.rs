@@ -1,13 +1,11 @@
 use crate::activity_notifications::handle_activity_notification;
 use crate::{RuntimeState, execute_update};
 use canister_api_macros::update;
-use canister_tracing_macros::trace;
 use community_canister::register_poll_vote::{Response::*, *};
 use types::{Achievement, Chat, EventIndex, OCResult, PollVotes, TotalVotes};
 use user_canister::{CommunityCanisterEvent, MessageActivity, MessageActivityEvent};
 
 #[update(msgpack = true)]
-#[trace]
 fn register_poll_vote(args: Args) -> Response {
     match execute_update(|state| register_poll_vote_impl(args, state)) {
         Ok(votes) => Success(votes),
@@ -28,6 +26,7 @@ fn register_poll_vote_impl(args: Args, state: &mut RuntimeState) -> OCResult<Pol
         args.thread_root_message_index,
         args.message_index,
         args.poll_option,
+        args.ballot,
         args.operation,
         now,
     )?;
@@ -19,6 +19,13 @@ fn register_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Succe
     state.data.verify_not_frozen()?;
 
     let user_id = state.get_calling_member(true)?.user_id;
+
+    if let Some(bot_id) = args.bot_id {
+        if state.data.bots.get(&bot_id).is_none() {
+            return Err(OCErrorCode::BotNotFound.into());
+        }
+    }
+
     let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
     let member = channel.chat.members.get_verified_member(user_id)?;
 
@@ -34,7 +41,11 @@ fn register_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Succe
 
     let now = state.env.now();
 
-    let Some(webhook_id) = channel.chat.webhooks.register(args.name, avatar, state.env.rng(), now) else {
+    let Some(webhook_id) = channel
+        .chat
+        .webhooks
+        .register(args.name, avatar, args.bot_id, state.env.rng(), now)
+    else {
         return Err(OCErrorCode::NameTaken.into());
     };
 
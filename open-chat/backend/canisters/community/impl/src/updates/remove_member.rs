@@ -7,26 +7,27 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::remove_member::*;
 use fire_and_forget_handler::FireAndForgetHandler;
+use group_chat_core::{ModerationLogEntryInternal, validate_moderation_reason};
 use local_user_index_canister_c2c_client::lookup_user;
 use msgpack::serialize_then_unwrap;
 use oc_error_codes::OCErrorCode;
 use std::collections::HashMap;
 use types::{
     BotCaller, BotPermissions, Caller, CanisterId, ChatPermission, CommunityMembersRemoved, CommunityPermission, CommunityRole,
-    CommunityUsersBlocked, OCResult, UnitResult, UserId,
+    CommunityUsersBlocked, ModerationAction, OCResult, UnitResult, UserId,
 };
 use user_canister::c2c_remove_from_community;
 
 #[update(msgpack = true)]
 #[trace]
 async fn block_user(args: community_canister::block_user::Args) -> UnitResult {
-    execute_update_async(|| remove_member_impl(args.user_id, true, None)).await
+    execute_update_async(|| remove_member_impl(args.user_id, true, args.reason.clone(), None)).await
 }
 
 #[update(msgpack = true)]
 #[trace]
 async fn remove_member(args: Args) -> UnitResult {
-    execute_update_async(|| remove_member_impl(args.user_id, false, None)).await
+    execute_update_async(|| remove_member_impl(args.user_id, false, args.reason.clone(), None)).await
 }
 
 #[update(guard = "caller_is_local_user_index", msgpack = true)]
@@ -41,7 +42,7 @@ async fn c2c_bot_remove_user_impl(args: community_canister::c2c_bot_remove_user:
         initiator: args.initiator.clone(),
     };
 
-    if !read_state(|state| {
+    if let Err(error) = read_state(|state| {
         let required_permissions = if args.channel_id.is_some() {
             BotPermissions::from_chat_permission(ChatPermission::RemoveMembers)
         } else {
@@ -50,20 +51,26 @@ async fn c2c_bot_remove_user_impl(args: community_canister::c2c_bot_remove_user:
 
         state
             .data
-            .is_bot_permitted(&bot_caller.bot, args.channel_id, &bot_caller.initiator, &required_permissions)
+            .verify_bot_permitted(&bot_caller.bot, args.channel_id, &bot_caller.initiator, &required_permissions)
     }) {
-        return OCErrorCode::InitiatorNotAuthorized.into();
+        return error.into();
     }
 
     if let Some(channel_id) = args.channel_id {
-        mutate_state(|state| remove_member_from_channel_impl(channel_id, args.user_id, Some(Caller::BotV2(bot_caller)), state))
-            .into()
+        mutate_state(|state| {
+            remove_member_from_channel_impl(channel_id, args.user_id, None, Some(Caller::BotV2(bot_caller)), state)
+        })
+        .into()
     } else {
-        remove_member_impl(args.user_id, args.block, Some(Caller::BotV2(bot_caller))).await
+        remove_member_impl(args.user_id, args.block, None, Some(Caller::BotV2(bot_caller))).await
     }
 }
 
-async fn remove_member_impl(user_id: UserId, block: bool, ext_caller: Option<Caller>) -> UnitResult {
+async fn remove_member_impl(user_id: UserId, block: bool, reason: Option<String>, ext_caller: Option<Caller>) -> UnitResult {
+    if let Err(error) = validate_moderation_reason(reason.as_ref()) {
+        return Response::Error(error);
+    }
+
     // Check the caller can remove the user
     let prepare_result = match read_state(|state| prepare(user_id, block, ext_caller, state)) {
         Ok(ok) => ok,
@@ -82,7 +89,7 @@ async fn remove_member_impl(user_id: UserId, block: bool, ext_caller: Option<Cal
     }
 
     // Remove the user from the community
-    mutate_state(|state| commit(user_id, block, prepare_result.removed_by, state));
+    mutate_state(|state| commit(user_id, block, reason, prepare_result.removed_by, state));
 
     Response::Success
 }
@@ -135,7 +142,7 @@ fn prepare(user_id: UserId, block: bool, ext_caller: Option<Caller>, state: &Run
     }
 }
 
-fn commit(user_id: UserId, block: bool, removed_by: UserId, state: &mut RuntimeState) {
+fn commit(user_id: UserId, block: bool, reason: Option<String>, removed_by: UserId, state: &mut RuntimeState) {
     let now = state.env.now();
 
     // Remove the user from the community
@@ -151,22 +158,28 @@ fn commit(user_id: UserId, block: bool, removed_by: UserId, state: &mut RuntimeS
     let invite_removed = state.data.invited_users.remove(&user_id, now).is_some();
 
     // Push relevant event
-    let event = if blocked {
+    let (event, action) = if blocked {
         let event = CommunityUsersBlocked {
             user_ids: vec![user_id],
             blocked_by: removed_by,
             referred_by,
         };
-        Some(CommunityEventInternal::UsersBlocked(Box::new(event)))
+        (
+            Some(CommunityEventInternal::UsersBlocked(Box::new(event))),
+            Some(ModerationAction::UserBlocked),
+        )
     } else if removed {
         let event = CommunityMembersRemoved {
             user_ids: vec![user_id],
             removed_by,
             referred_by,
         };
-        Some(CommunityEventInternal::MembersRemoved(Box::new(event)))
+        (
+            Some(CommunityEventInternal::MembersRemoved(Box::new(event))),
+            Some(ModerationAction::MemberRemoved),
+        )
     } else if invite_removed {
-        None
+        (None, None)
     } else {
         return;
     };
@@ -175,6 +188,13 @@ fn commit(user_id: UserId, block: bool, removed_by: UserId, state: &mut RuntimeS
         state.push_community_event(event);
     }
 
+    if let Some(action) = action {
+        state
+            .data
+            .moderation_log
+            .push(ModerationLogEntryInternal::new(removed_by, user_id, action, reason, now));
+    }
+
     handle_activity_notification(state);
 
     if removed {
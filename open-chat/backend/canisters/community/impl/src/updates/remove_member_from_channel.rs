@@ -7,12 +7,13 @@ use types::{Caller, ChannelId, OCResult, UserId};
 #[update(msgpack = true)]
 #[trace]
 fn remove_member_from_channel(args: Args) -> Response {
-    execute_update(|state| remove_member_from_channel_impl(args.channel_id, args.user_id, None, state)).into()
+    execute_update(|state| remove_member_from_channel_impl(args.channel_id, args.user_id, args.reason, None, state)).into()
 }
 
 pub(crate) fn remove_member_from_channel_impl(
     channel_id: ChannelId,
     user_id: UserId,
+    reason: Option<String>,
     ext_caller: Option<Caller>,
     state: &mut RuntimeState,
 ) -> OCResult {
@@ -23,7 +24,7 @@ pub(crate) fn remove_member_from_channel_impl(
     let channel = state.data.channels.get_mut_or_err(&channel_id)?;
     let now = state.env.now();
 
-    let bot_notification = channel.chat.remove_member(caller, user_id, false, now)?;
+    let bot_notification = channel.chat.remove_member(caller, user_id, false, reason, now)?;
     state.data.remove_user_from_channel(user_id, channel_id, now);
     state.push_bot_notification(bot_notification);
     handle_activity_notification(state);
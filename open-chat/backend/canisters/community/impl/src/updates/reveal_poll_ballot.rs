@@ -0,0 +1,34 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use community_canister::reveal_poll_ballot::{Response::*, *};
+use types::{OCResult, PollVotes};
+
+#[update(msgpack = true)]
+fn reveal_poll_ballot(args: Args) -> Response {
+    match execute_update(|state| reveal_poll_ballot_impl(args, state)) {
+        Ok(votes) => Success(votes),
+        Err(error) => Error(error),
+    }
+}
+
+fn reveal_poll_ballot_impl(args: Args, state: &mut RuntimeState) -> OCResult<PollVotes> {
+    state.data.verify_not_frozen()?;
+
+    let member = state.get_calling_member(true)?;
+    let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
+    let now = state.env.now();
+
+    let result = channel.chat.reveal_poll_ballot(
+        member.user_id,
+        args.thread_root_message_index,
+        args.message_index,
+        args.options,
+        args.salt,
+        now,
+    )?;
+
+    handle_activity_notification(state);
+    state.push_bot_notification(result.bot_notification);
+    Ok(result.value)
+}
@@ -0,0 +1,25 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::set_warning_policy::*;
+use types::OCResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_warning_policy(args: Args) -> Response {
+    execute_update(|state| set_warning_policy_impl(args, state)).into()
+}
+
+fn set_warning_policy_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let user_id = state.get_calling_member(true)?.user_id;
+    let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
+    let now = state.env.now();
+
+    channel.chat.set_warning_policy(user_id, args.policy, now)?;
+
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -56,8 +56,10 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> OCResult<Suc
         args.gate_config.map(|gc| gc.into()),
         args.public,
         args.messages_visible_to_non_members,
+        args.retain_edit_history,
         args.events_ttl,
         args.external_url,
+        args.slow_mode,
         now,
     )?;
 
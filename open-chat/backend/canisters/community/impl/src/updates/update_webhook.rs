@@ -3,7 +3,7 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use community_canister::update_webhook::*;
 use oc_error_codes::OCErrorCode;
-use types::OCResult;
+use types::{OCResult, OptionUpdate};
 use utils::document::try_parse_data_url;
 
 #[update(msgpack = true)]
@@ -16,6 +16,13 @@ fn update_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult {
     state.data.verify_not_frozen()?;
 
     let user_id = state.get_calling_member(true)?.user_id;
+
+    if let OptionUpdate::SetToSome(bot_id) = args.bot_id {
+        if state.data.bots.get(&bot_id).is_none() {
+            return Err(OCErrorCode::BotNotFound.into());
+        }
+    }
+
     let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
     let member = channel.chat.members.get_verified_member(user_id)?;
 
@@ -31,7 +38,7 @@ fn update_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult {
 
     let now = state.env.now();
 
-    if !channel.chat.webhooks.update(args.id, args.name, avatar, now) {
+    if !channel.chat.webhooks.update(args.id, args.name, avatar, args.bot_id, now) {
         return Err(OCErrorCode::WebhookNotFound.into());
     }
 
@@ -0,0 +1,47 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, execute_update, jobs};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use community_canister::warn_member::{Response::*, *};
+use group_community_common::ExpiringMute;
+use types::OCResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn warn_member(args: Args) -> Response {
+    match execute_update(|state| warn_member_impl(args, state)) {
+        Ok(result) => Success(result),
+        Err(error) => Error(error),
+    }
+}
+
+fn warn_member_impl(args: Args, state: &mut RuntimeState) -> OCResult<SuccessResult> {
+    state.data.verify_not_frozen()?;
+
+    let user_id = state.get_calling_member(true)?.user_id;
+    let channel = state.data.channels.get_mut_or_err(&args.channel_id)?;
+    let now = state.env.now();
+
+    let result = channel.chat.warn_member(user_id, args.user_id, args.reason, now)?;
+
+    if let Some(expires) = result.muted_until {
+        state.data.expiring_mutes.push(ExpiringMute {
+            expires,
+            channel_id: Some(args.channel_id),
+            user_id: args.user_id,
+        });
+        jobs::expire_mutes::restart_job(state);
+    }
+
+    if result.removed {
+        state.data.remove_user_from_channel(args.user_id, args.channel_id, now);
+    }
+
+    state.push_bot_notifications(result.bot_notifications);
+    handle_activity_notification(state);
+
+    Ok(SuccessResult {
+        active_warnings: result.active_warnings,
+        escalation: result.escalation,
+    })
+}
@@ -1,9 +1,10 @@
 use candid::{Deserialize, Principal};
+use constants::{DAY_IN_MS, SECOND_IN_MS};
 use icrc_ledger_types::icrc1::account::Subaccount;
 use serde::Serialize;
 use sha256::sha256;
 use types::icrc1::CompletedCryptoTransaction;
-use types::{P2PSwapLocation, TimestampMillis, UserId};
+use types::{Milliseconds, P2PSwapLocation, TimestampMillis, UserId};
 
 mod lifecycle;
 mod updates;
@@ -54,9 +55,93 @@ pub struct SwapStatusChange {
     pub status: SwapStatus,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PayoutStatus {
+    Open,
+    Cancelled(Box<PayoutStatusCancelled>),
+    Expired(Box<PayoutStatusExpired>),
+    Settled(Box<PayoutStatusSettled>),
+    Released(Box<PayoutStatusReleased>),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PayoutStatusCancelled {
+    pub cancelled_at: TimestampMillis,
+    pub refunds: Vec<CompletedCryptoTransaction>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PayoutStatusExpired {
+    pub refunds: Vec<CompletedCryptoTransaction>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PayoutStatusSettled {
+    pub settled_at: TimestampMillis,
+    pub recipients: Vec<PayoutRecipient>,
+    pub release_at: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PayoutStatusReleased {
+    pub settled_at: TimestampMillis,
+    pub transfers_out: Vec<CompletedCryptoTransaction>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PayoutRecipient {
+    pub user_id: UserId,
+    pub amount: u128,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PayoutStatusChange {
+    pub payout_id: u32,
+    pub created_by: UserId,
+    pub location: P2PSwapLocation,
+    pub status: PayoutStatus,
+}
+
 pub fn deposit_subaccount(user_id: UserId, swap_id: u32) -> Subaccount {
     let mut bytes = Vec::new();
     bytes.extend_from_slice(Principal::from(user_id).as_slice());
     bytes.extend_from_slice(&swap_id.to_be_bytes());
     sha256(&bytes)
 }
+
+// Each contributor to a payout deposits into their own subaccount, which is then swept into the payout's pool
+pub fn payout_deposit_subaccount(user_id: UserId, payout_id: u32) -> Subaccount {
+    let mut bytes = b"payout_deposit".to_vec();
+    bytes.extend_from_slice(Principal::from(user_id).as_slice());
+    bytes.extend_from_slice(&payout_id.to_be_bytes());
+    sha256(&bytes)
+}
+
+pub fn payout_pool_subaccount(payout_id: u32) -> Subaccount {
+    let mut bytes = b"payout_pool".to_vec();
+    bytes.extend_from_slice(&payout_id.to_be_bytes());
+    sha256(&bytes)
+}
+
+// Crowdfunds are settled once their deadline has passed, so their payouts stay open for this long afterwards to give the
+// chat time to settle them, after which the contributors are refunded
+pub const CROWDFUND_SETTLEMENT_WINDOW: Milliseconds = DAY_IN_MS;
+
+const PAYOUT_DEPOSIT_NOTIFICATION_ATTEMPTS: u32 = 20;
+
+// Failed attempts to notify the escrow canister of a payout deposit are retried every 10 seconds. If those all fail, a
+// final attempt is made after the deadline, by which point the payout will have closed, so the escrow canister will
+// refund the deposit.
+pub fn payout_deposit_notification_retry_at(
+    attempt: u32,
+    deadline: TimestampMillis,
+    now: TimestampMillis,
+) -> Option<TimestampMillis> {
+    if attempt < PAYOUT_DEPOSIT_NOTIFICATION_ATTEMPTS {
+        Some(now + 10 * SECOND_IN_MS)
+    } else if attempt == PAYOUT_DEPOSIT_NOTIFICATION_ATTEMPTS {
+        Some(deadline.max(now))
+    } else {
+        None
+    }
+}
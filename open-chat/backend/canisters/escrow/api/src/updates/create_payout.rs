@@ -0,0 +1,28 @@
+use candid::Principal;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use types::{CanisterId, P2PSwapLocation, TimestampMillis, TokenInfo};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub location: P2PSwapLocation,
+    pub token: TokenInfo,
+    // Once settled, the payout isn't released to the recipients until this time
+    pub release_at: Option<TimestampMillis>,
+    // If the payout hasn't been settled by this time, all contributors are refunded
+    pub deadline: TimestampMillis,
+    pub additional_admins: Vec<Principal>,
+    pub canister_to_notify: Option<CanisterId>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    InvalidPayout(String),
+    Error(OCError),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub id: u32,
+}
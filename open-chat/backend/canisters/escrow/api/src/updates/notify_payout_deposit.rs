@@ -0,0 +1,33 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use types::UserId;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub payout_id: u32,
+    pub user_id: Option<UserId>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    BalanceTooLow(BalanceTooLowResult),
+    PayoutSettled,
+    PayoutCancelled,
+    PayoutExpired,
+    PayoutNotFound,
+    InternalError(String),
+    Error(OCError),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub contributed: u128,
+    pub total: u128,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BalanceTooLowResult {
+    pub balance: u128,
+    pub balance_required: u128,
+}
@@ -0,0 +1,17 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub payout_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    PayoutAlreadySettled,
+    PayoutExpired,
+    PayoutNotFound,
+    NotAuthorized,
+    Error(OCError),
+}
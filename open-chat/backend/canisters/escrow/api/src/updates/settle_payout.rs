@@ -0,0 +1,33 @@
+use crate::PayoutRecipient;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use types::UserId;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub payout_id: u32,
+    pub recipients: Vec<PayoutRecipient>,
+    // Any funds not allocated to a recipient are paid to this user, or returned to the creator of the payout if not set.
+    // The remainder is calculated when the payout is settled, so it includes any contributions made up until then.
+    #[serde(default)]
+    pub remainder_recipient: Option<UserId>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    InsufficientFunds(InsufficientFundsResult),
+    InvalidRecipients(String),
+    PayoutAlreadySettled,
+    PayoutCancelled,
+    PayoutExpired,
+    PayoutNotFound,
+    NotAuthorized,
+    Error(OCError),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InsufficientFundsResult {
+    pub available: u128,
+    pub required: u128,
+}
@@ -5,6 +5,10 @@ use escrow_canister::*;
 
 // Updates
 generate_c2c_call!(c2c_set_token_enabled);
+generate_c2c_call!(create_payout);
 generate_c2c_call!(create_swap);
 generate_c2c_call!(cancel_swap);
 generate_c2c_call!(notify_deposit);
+generate_c2c_call!(notify_payout_deposit);
+generate_c2c_call!(refund_payout);
+generate_c2c_call!(settle_payout);
@@ -2,7 +2,7 @@ use crate::model::pending_payments_queue::{PendingPayment, PendingPaymentReason}
 use crate::{RuntimeState, mutate_state, read_state};
 use candid::Principal;
 use constants::NANOS_PER_MILLISECOND;
-use escrow_canister::{SwapStatus, deposit_subaccount};
+use escrow_canister::{SwapStatus, deposit_subaccount, payout_pool_subaccount};
 use ic_cdk_timers::TimerId;
 use icrc_ledger_types::icrc1::transfer::TransferArg;
 use ledger_utils::icrc1::make_transfer;
@@ -36,14 +36,15 @@ pub fn run() {
 }
 
 async fn process_payment(pending_payment: PendingPayment) {
-    let from_user = match pending_payment.reason {
-        PendingPaymentReason::Swap(other_user_id) => other_user_id,
-        PendingPaymentReason::Refund => pending_payment.user_id,
+    let from_subaccount = match pending_payment.reason {
+        PendingPaymentReason::Swap(other_user_id) => deposit_subaccount(other_user_id, pending_payment.id),
+        PendingPaymentReason::Refund => deposit_subaccount(pending_payment.user_id, pending_payment.id),
+        PendingPaymentReason::PayoutRelease | PendingPaymentReason::PayoutRefund => payout_pool_subaccount(pending_payment.id),
     };
     let created_at_time = pending_payment.timestamp * NANOS_PER_MILLISECOND;
 
     let args = TransferArg {
-        from_subaccount: Some(deposit_subaccount(from_user, pending_payment.swap_id)),
+        from_subaccount: Some(from_subaccount),
         to: Principal::from(pending_payment.user_id).into(),
         fee: Some(pending_payment.token_info.fee.into()),
         created_at_time: Some(created_at_time),
@@ -55,58 +56,98 @@ async fn process_payment(pending_payment: PendingPayment) {
 
     mutate_state(|state| match response {
         Ok(Ok(block_index)) => {
-            if let Some(swap) = state.data.swaps.get_mut(pending_payment.swap_id) {
-                let transfer = CompletedCryptoTransaction {
-                    ledger: pending_payment.token_info.ledger,
-                    token: pending_payment.token_info.symbol.into(),
-                    amount: pending_payment.amount,
-                    from: Account {
-                        owner: state.env.canister_id(),
-                        subaccount: args.from_subaccount,
-                    }
-                    .into(),
-                    to: Account::from(pending_payment.user_id).into(),
-                    fee: pending_payment.token_info.fee,
-                    memo: None,
-                    created: created_at_time,
-                    block_index,
-                };
-                let notify_status_change = match pending_payment.reason {
-                    PendingPaymentReason::Swap(_) => {
-                        if pending_payment.token_info.ledger == swap.token0.ledger {
-                            swap.token0_transfer_out = Some(transfer);
-                        } else {
-                            swap.token1_transfer_out = Some(transfer);
-                        }
-                        swap.is_complete()
-                    }
-                    PendingPaymentReason::Refund => {
-                        swap.refunds.push(transfer);
-                        matches!(
-                            swap.status(state.env.now()),
-                            SwapStatus::Expired(_) | SwapStatus::Cancelled(_)
-                        )
-                    }
-                };
+            let transfer = CompletedCryptoTransaction {
+                ledger: pending_payment.token_info.ledger,
+                token: pending_payment.token_info.symbol.clone().into(),
+                amount: pending_payment.amount,
+                from: Account {
+                    owner: state.env.canister_id(),
+                    subaccount: args.from_subaccount,
+                }
+                .into(),
+                to: Account::from(pending_payment.user_id).into(),
+                fee: pending_payment.token_info.fee,
+                memo: None,
+                created: created_at_time,
+                block_index,
+            };
 
-                if notify_status_change {
-                    state.data.notify_status_change_queue.push(swap.id);
-                    crate::jobs::notify_status_change::start_job_if_required(state);
+            match pending_payment.reason {
+                PendingPaymentReason::Swap(_) | PendingPaymentReason::Refund => {
+                    record_swap_payment(&pending_payment, transfer, state)
+                }
+                PendingPaymentReason::PayoutRelease | PendingPaymentReason::PayoutRefund => {
+                    record_payout_payment(&pending_payment, transfer, state)
                 }
             }
         }
         Ok(Err(error)) => {
             error!(?error, ?args, "Failed to process payment");
-            if let Some(swap) = state.data.swaps.get_mut(pending_payment.swap_id) {
-                swap.errors.push(format!("Ledger returned an error: {error:?}"));
-            }
+            push_error(&pending_payment, format!("Ledger returned an error: {error:?}"), state);
         }
         Err(error) => {
-            if let Some(swap) = state.data.swaps.get_mut(pending_payment.swap_id) {
-                swap.errors.push(format!("Failed to call into ledger: {error:?}"));
-            }
+            push_error(&pending_payment, format!("Failed to call into ledger: {error:?}"), state);
             state.data.pending_payments_queue.push(pending_payment);
             start_job_if_required(state);
         }
     });
 }
+
+fn record_swap_payment(pending_payment: &PendingPayment, transfer: CompletedCryptoTransaction, state: &mut RuntimeState) {
+    if let Some(swap) = state.data.swaps.get_mut(pending_payment.id) {
+        let notify_status_change = match pending_payment.reason {
+            PendingPaymentReason::Swap(_) => {
+                if pending_payment.token_info.ledger == swap.token0.ledger {
+                    swap.token0_transfer_out = Some(transfer);
+                } else {
+                    swap.token1_transfer_out = Some(transfer);
+                }
+                swap.is_complete()
+            }
+            _ => {
+                swap.refunds.push(transfer);
+                matches!(
+                    swap.status(state.env.now()),
+                    SwapStatus::Expired(_) | SwapStatus::Cancelled(_)
+                )
+            }
+        };
+
+        if notify_status_change {
+            state.data.notify_status_change_queue.push(swap.id);
+            crate::jobs::notify_status_change::start_job_if_required(state);
+        }
+    }
+}
+
+fn record_payout_payment(pending_payment: &PendingPayment, transfer: CompletedCryptoTransaction, state: &mut RuntimeState) {
+    if let Some(payout) = state.data.payouts.get_mut(pending_payment.id) {
+        let notify_status_change = if matches!(pending_payment.reason, PendingPaymentReason::PayoutRelease) {
+            payout.transfers_out.push(transfer);
+            payout.is_released()
+        } else {
+            payout.refunds.push(transfer);
+            payout.is_refunded()
+        };
+
+        if notify_status_change {
+            state.data.notify_status_change_queue.push_payout(payout.id);
+            crate::jobs::notify_status_change::start_job_if_required(state);
+        }
+    }
+}
+
+fn push_error(pending_payment: &PendingPayment, error: String, state: &mut RuntimeState) {
+    let errors = match pending_payment.reason {
+        PendingPaymentReason::Swap(_) | PendingPaymentReason::Refund => {
+            state.data.swaps.get_mut(pending_payment.id).map(|s| &mut s.errors)
+        }
+        PendingPaymentReason::PayoutRelease | PendingPaymentReason::PayoutRefund => {
+            state.data.payouts.get_mut(pending_payment.id).map(|p| &mut p.errors)
+        }
+    };
+
+    if let Some(errors) = errors {
+        errors.push(error);
+    }
+}
@@ -1,5 +1,6 @@
+use crate::model::notify_status_change_queue::StatusChange;
 use crate::{RuntimeState, mutate_state, read_state};
-use escrow_canister::SwapStatusChange;
+use escrow_canister::{PayoutStatusChange, SwapStatusChange};
 use ic_cdk_timers::TimerId;
 use std::cell::Cell;
 use std::time::Duration;
@@ -26,27 +27,54 @@ pub fn run() {
     TIMER_ID.set(None);
 
     if let Some((canister_id, notification)) = mutate_state(get_next) {
-        ic_cdk::futures::spawn(notify_swap_status(canister_id, notification));
+        match notification {
+            Notification::Swap(notification) => ic_cdk::futures::spawn(notify_swap_status(canister_id, notification)),
+            Notification::Payout(notification) => ic_cdk::futures::spawn(notify_payout_status(canister_id, notification)),
+        }
         read_state(start_job_if_required);
     }
 }
 
-fn get_next(state: &mut RuntimeState) -> Option<(CanisterId, SwapStatusChange)> {
-    while let Some(id) = state.data.notify_status_change_queue.pop() {
-        if let Some(notification) = state.data.swaps.get(id).and_then(|swap| {
-            swap.canister_to_notify.map(|canister_id| {
-                (
-                    canister_id,
-                    SwapStatusChange {
-                        swap_id: swap.id,
-                        created_by: swap.created_by,
-                        location: swap.location.clone(),
-                        status: swap.status(state.env.now()),
-                    },
-                )
-            })
-        }) {
-            return Some(notification);
+enum Notification {
+    Swap(SwapStatusChange),
+    Payout(PayoutStatusChange),
+}
+
+fn get_next(state: &mut RuntimeState) -> Option<(CanisterId, Notification)> {
+    let now = state.env.now();
+
+    while let Some(next) = state.data.notify_status_change_queue.pop() {
+        let notification = match next {
+            StatusChange::Swap(id) => state.data.swaps.get(id).and_then(|swap| {
+                swap.canister_to_notify.map(|canister_id| {
+                    (
+                        canister_id,
+                        Notification::Swap(SwapStatusChange {
+                            swap_id: swap.id,
+                            created_by: swap.created_by,
+                            location: swap.location.clone(),
+                            status: swap.status(now),
+                        }),
+                    )
+                })
+            }),
+            StatusChange::Payout(id) => state.data.payouts.get(id).and_then(|payout| {
+                payout.canister_to_notify.map(|canister_id| {
+                    (
+                        canister_id,
+                        Notification::Payout(PayoutStatusChange {
+                            payout_id: payout.id,
+                            created_by: payout.created_by,
+                            location: payout.location.clone(),
+                            status: payout.status(now),
+                        }),
+                    )
+                })
+            }),
+        };
+
+        if notification.is_some() {
+            return notification;
         }
     }
     None
@@ -65,10 +93,30 @@ async fn notify_swap_status(canister_id: CanisterId, notification: SwapStatusCha
     }
 }
 
+async fn notify_payout_status(canister_id: CanisterId, notification: PayoutStatusChange) {
+    let payout_id = notification.payout_id;
+
+    if let Err(error) = c2c_notify_escrow_payout_status_change(canister_id, &notification).await {
+        if delay_if_should_retry_failed_c2c_call(error.reject_code(), error.message()).is_some() {
+            mutate_state(|state| {
+                state.data.notify_status_change_queue.push_payout(payout_id);
+                start_job_if_required(state);
+            });
+        }
+    }
+}
+
 canister_client::generate_c2c_call_ignore_response!(c2c_notify_p2p_swap_status_change);
+canister_client::generate_c2c_call_ignore_response!(c2c_notify_escrow_payout_status_change);
 
 mod c2c_notify_p2p_swap_status_change {
     use super::*;
 
     pub type Args = SwapStatusChange;
 }
+
+mod c2c_notify_escrow_payout_status_change {
+    use super::*;
+
+    pub type Args = PayoutStatusChange;
+}
@@ -1,4 +1,5 @@
 use crate::model::notify_status_change_queue::NotifyStatusChangeQueue;
+use crate::model::payouts::Payouts;
 use crate::model::pending_payments_queue::PendingPaymentsQueue;
 use crate::model::swaps::Swaps;
 use crate::timer_job_types::TimerJob;
@@ -41,6 +42,8 @@ impl RuntimeState {
 
     pub fn metrics(&self) -> Metrics {
         let now = self.env.now();
+        let mut swaps = self.data.swaps.metrics(now);
+        self.data.payouts.populate_metrics(now, &mut swaps);
 
         Metrics {
             heap_memory_used: utils::memory::heap(),
@@ -50,7 +53,7 @@ impl RuntimeState {
             liquid_cycles_balance: self.env.liquid_cycles_balance(),
             wasm_version: WASM_VERSION.with_borrow(|v| **v),
             git_commit_id: utils::git::git_commit_id().to_string(),
-            swaps: self.data.swaps.metrics(now),
+            swaps,
             notify_status_change_queue_len: self.data.notify_status_change_queue.len() as u32,
             stable_memory_sizes: memory::memory_sizes(),
             disabled_tokens: self.data.disabled_tokens.iter().copied().collect(),
@@ -65,6 +68,8 @@ impl RuntimeState {
 #[derive(Serialize, Deserialize)]
 struct Data {
     pub swaps: Swaps,
+    #[serde(default)]
+    pub payouts: Payouts,
     pub pending_payments_queue: PendingPaymentsQueue,
     pub notify_status_change_queue: NotifyStatusChangeQueue,
     timer_jobs: TimerJobs<TimerJob>,
@@ -79,6 +84,7 @@ impl Data {
     pub fn new(registry_canister_id: CanisterId, cycles_dispenser_canister_id: CanisterId, test_mode: bool) -> Data {
         Data {
             swaps: Swaps::default(),
+            payouts: Payouts::default(),
             pending_payments_queue: PendingPaymentsQueue::default(),
             notify_status_change_queue: NotifyStatusChangeQueue::default(),
             timer_jobs: TimerJobs::default(),
@@ -115,6 +121,12 @@ pub struct SwapMetrics {
     pub expired: u32,
     pub accepted: u32,
     pub completed: u32,
+    pub payouts_total: u32,
+    pub payouts_open: u32,
+    pub payouts_cancelled: u32,
+    pub payouts_expired: u32,
+    pub payouts_settled: u32,
+    pub payouts_released: u32,
 }
 
 #[derive(Serialize, Debug)]
@@ -1,3 +1,4 @@
 pub mod notify_status_change_queue;
+pub mod payouts;
 pub mod pending_payments_queue;
 pub mod swaps;
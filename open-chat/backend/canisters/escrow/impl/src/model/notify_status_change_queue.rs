@@ -4,6 +4,13 @@ use std::collections::VecDeque;
 #[derive(Serialize, Deserialize, Default)]
 pub struct NotifyStatusChangeQueue {
     swaps: VecDeque<u32>,
+    #[serde(default)]
+    payouts: VecDeque<u32>,
+}
+
+pub enum StatusChange {
+    Swap(u32),
+    Payout(u32),
 }
 
 impl NotifyStatusChangeQueue {
@@ -11,15 +18,22 @@ impl NotifyStatusChangeQueue {
         self.swaps.push_back(swap_id);
     }
 
-    pub fn pop(&mut self) -> Option<u32> {
-        self.swaps.pop_front()
+    pub fn push_payout(&mut self, payout_id: u32) {
+        self.payouts.push_back(payout_id);
+    }
+
+    pub fn pop(&mut self) -> Option<StatusChange> {
+        self.swaps
+            .pop_front()
+            .map(StatusChange::Swap)
+            .or_else(|| self.payouts.pop_front().map(StatusChange::Payout))
     }
 
     pub fn len(&self) -> usize {
-        self.swaps.len()
+        self.swaps.len() + self.payouts.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.swaps.is_empty()
+        self.swaps.is_empty() && self.payouts.is_empty()
     }
 }
@@ -0,0 +1,177 @@
+use crate::SwapMetrics;
+use candid::Principal;
+use escrow_canister::{
+    PayoutRecipient, PayoutStatus, PayoutStatusCancelled, PayoutStatusExpired, PayoutStatusReleased, PayoutStatusSettled,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{CanisterId, P2PSwapLocation, TimestampMillis, TokenInfo, UserId, icrc1::CompletedCryptoTransaction};
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Payouts {
+    map: BTreeMap<u32, Payout>,
+}
+
+impl Payouts {
+    pub fn push(&mut self, caller: UserId, args: escrow_canister::create_payout::Args, now: TimestampMillis) -> u32 {
+        let id = self.map.last_key_value().map(|(k, _)| *k + 1).unwrap_or_default();
+        self.map.insert(id, Payout::new(id, caller, args, now));
+        id
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Payout> {
+        self.map.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<&mut Payout> {
+        self.map.get_mut(&id)
+    }
+
+    pub fn populate_metrics(&self, now: TimestampMillis, metrics: &mut SwapMetrics) {
+        metrics.payouts_total = self.map.len() as u32;
+
+        for payout in self.map.values() {
+            match payout.status(now) {
+                PayoutStatus::Open => metrics.payouts_open += 1,
+                PayoutStatus::Cancelled(_) => metrics.payouts_cancelled += 1,
+                PayoutStatus::Expired(_) => metrics.payouts_expired += 1,
+                PayoutStatus::Settled(_) => metrics.payouts_settled += 1,
+                PayoutStatus::Released(_) => metrics.payouts_released += 1,
+            }
+        }
+    }
+}
+
+// Funds contributed by any number of users which are later released to whichever recipients the payout's admins
+// choose, or refunded to the contributors if the payout is cancelled or isn't settled by the deadline
+#[derive(Serialize, Deserialize)]
+pub struct Payout {
+    pub id: u32,
+    pub location: P2PSwapLocation,
+    pub created_at: TimestampMillis,
+    pub created_by: UserId,
+    pub token: TokenInfo,
+    pub release_at: Option<TimestampMillis>,
+    pub deadline: TimestampMillis,
+    // The amounts held in the payout's pool for each contributor
+    pub contributions: BTreeMap<UserId, u128>,
+    pub settled_at: Option<TimestampMillis>,
+    pub recipients: Vec<PayoutRecipient>,
+    pub cancelled_at: Option<TimestampMillis>,
+    pub transfers_out: Vec<CompletedCryptoTransaction>,
+    pub refunds: Vec<CompletedCryptoTransaction>,
+    pub additional_admins: Vec<Principal>,
+    pub canister_to_notify: Option<CanisterId>,
+    pub errors: Vec<String>,
+}
+
+impl Payout {
+    pub fn new(id: u32, caller: UserId, args: escrow_canister::create_payout::Args, now: TimestampMillis) -> Payout {
+        Payout {
+            id,
+            location: args.location,
+            created_at: now,
+            created_by: caller,
+            token: args.token,
+            release_at: args.release_at,
+            deadline: args.deadline,
+            contributions: BTreeMap::new(),
+            settled_at: None,
+            recipients: Vec::new(),
+            cancelled_at: None,
+            transfers_out: Vec::new(),
+            refunds: Vec::new(),
+            additional_admins: args.additional_admins,
+            canister_to_notify: args.canister_to_notify,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn is_admin(&self, principal: Principal) -> bool {
+        self.created_by == principal.into() || self.additional_admins.contains(&principal)
+    }
+
+    pub fn total(&self) -> u128 {
+        self.contributions.values().sum()
+    }
+
+    pub fn release_at(&self) -> TimestampMillis {
+        self.release_at.unwrap_or_default().max(self.settled_at.unwrap_or_default())
+    }
+
+    // Every recipient is paid from the pool, with a ledger fee for each transfer. Anything left over is paid to the
+    // remainder recipient, or returned to the creator of the payout if there isn't one.
+    pub fn settle(
+        &mut self,
+        mut recipients: Vec<PayoutRecipient>,
+        remainder_recipient: Option<UserId>,
+        now: TimestampMillis,
+    ) -> Result<(), (u128, u128)> {
+        let fee = self.token.fee;
+        let available = self.total();
+        let required: u128 = recipients.iter().map(|r| r.amount + fee).sum();
+        if required > available {
+            return Err((available, required));
+        }
+
+        let remainder = available - required;
+        if remainder > fee {
+            recipients.push(PayoutRecipient {
+                user_id: remainder_recipient.unwrap_or(self.created_by),
+                amount: remainder - fee,
+            });
+        }
+
+        self.settled_at = Some(now);
+        self.recipients = recipients;
+        Ok(())
+    }
+
+    // The refunds owed to contributors, net of the ledger fee
+    pub fn refunds_due(&self) -> Vec<PayoutRecipient> {
+        self.contributions
+            .iter()
+            .filter(|(_, amount)| **amount > self.token.fee)
+            .map(|(user_id, amount)| PayoutRecipient {
+                user_id: *user_id,
+                amount: amount - self.token.fee,
+            })
+            .collect()
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.settled_at.is_some() && self.transfers_out.len() == self.recipients.len()
+    }
+
+    pub fn is_refunded(&self) -> bool {
+        self.refunds.len() >= self.refunds_due().len()
+    }
+
+    pub fn status(&self, now: TimestampMillis) -> PayoutStatus {
+        if let Some(settled_at) = self.settled_at {
+            if self.is_released() {
+                PayoutStatus::Released(Box::new(PayoutStatusReleased {
+                    settled_at,
+                    transfers_out: self.transfers_out.clone(),
+                }))
+            } else {
+                PayoutStatus::Settled(Box::new(PayoutStatusSettled {
+                    settled_at,
+                    recipients: self.recipients.clone(),
+                    release_at: self.release_at(),
+                }))
+            }
+        } else if let Some(cancelled_at) = self.cancelled_at {
+            PayoutStatus::Cancelled(Box::new(PayoutStatusCancelled {
+                cancelled_at,
+                refunds: self.refunds.clone(),
+            }))
+        } else if self.deadline <= now {
+            PayoutStatus::Expired(Box::new(PayoutStatusExpired {
+                refunds: self.refunds.clone(),
+            }))
+        } else {
+            PayoutStatus::Open
+        }
+    }
+}
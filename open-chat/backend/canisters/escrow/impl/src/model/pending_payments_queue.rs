@@ -1,3 +1,4 @@
+use crate::model::payouts::Payout;
 use crate::model::swaps::Swap;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -20,7 +21,7 @@ impl PendingPaymentsQueue {
                 timestamp: now,
                 token_info: swap.token0.clone(),
                 amount: swap.amount0,
-                swap_id: swap.id,
+                id: swap.id,
                 reason: PendingPaymentReason::Refund,
             });
         }
@@ -31,13 +32,39 @@ impl PendingPaymentsQueue {
                     timestamp: now,
                     token_info: swap.token1.clone(),
                     amount: swap.amount1,
-                    swap_id: swap.id,
+                    id: swap.id,
                     reason: PendingPaymentReason::Refund,
                 });
             }
         }
     }
 
+    pub fn push_payout_releases(&mut self, payout: &Payout, now: TimestampMillis) {
+        for recipient in payout.recipients.iter() {
+            self.push(PendingPayment {
+                user_id: recipient.user_id,
+                timestamp: now,
+                token_info: payout.token.clone(),
+                amount: recipient.amount,
+                id: payout.id,
+                reason: PendingPaymentReason::PayoutRelease,
+            });
+        }
+    }
+
+    pub fn push_payout_refunds(&mut self, payout: &Payout, now: TimestampMillis) {
+        for refund in payout.refunds_due() {
+            self.push(PendingPayment {
+                user_id: refund.user_id,
+                timestamp: now,
+                token_info: payout.token.clone(),
+                amount: refund.amount,
+                id: payout.id,
+                reason: PendingPaymentReason::PayoutRefund,
+            });
+        }
+    }
+
     pub fn pop(&mut self) -> Option<PendingPayment> {
         self.pending_payments.pop_front()
     }
@@ -53,7 +80,9 @@ pub struct PendingPayment {
     pub timestamp: TimestampMillis,
     pub token_info: TokenInfo,
     pub amount: u128,
-    pub swap_id: u32,
+    // The id of the swap or payout which the payment belongs to
+    #[serde(alias = "swap_id")]
+    pub id: u32,
     pub reason: PendingPaymentReason,
 }
 
@@ -61,4 +90,6 @@ pub struct PendingPayment {
 pub enum PendingPaymentReason {
     Swap(UserId), // The other user in the swap
     Refund,
+    PayoutRelease,
+    PayoutRefund,
 }
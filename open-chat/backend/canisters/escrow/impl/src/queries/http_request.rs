@@ -33,12 +33,23 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         }
     }
 
+    fn get_payout_logs(qs: HashMap<String, String>, state: &RuntimeState) -> HttpResponse {
+        let payout_id = u32::from_str(qs.get("payout_id").unwrap()).unwrap();
+
+        if let Some(payout) = state.data.payouts.get(payout_id) {
+            build_json_response(payout)
+        } else {
+            HttpResponse::not_found()
+        }
+    }
+
     match extract_route(&request.url) {
         Route::Errors(since) => get_errors_impl(since),
         Route::Logs(since) => get_logs_impl(since),
         Route::Traces(since) => get_traces_impl(since),
         Route::Metrics => read_state(get_metrics_impl),
         Route::Other(p, qs) if p == "swap_logs" => read_state(|state| get_swap_logs(qs, state)),
+        Route::Other(p, qs) if p == "payout_logs" => read_state(|state| get_payout_logs(qs, state)),
         _ => HttpResponse::not_found(),
     }
 }
@@ -1,11 +1,13 @@
 use crate::mutate_state;
 use canister_timer_jobs::Job;
-use escrow_canister::SwapStatus;
+use escrow_canister::{PayoutStatus, SwapStatus};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub enum TimerJob {
     ExpireSwap(Box<ExpireSwapJob>),
+    ExpirePayout(Box<ExpirePayoutJob>),
+    ReleasePayout(Box<ReleasePayoutJob>),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -13,10 +15,22 @@ pub struct ExpireSwapJob {
     pub swap_id: u32,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ExpirePayoutJob {
+    pub payout_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReleasePayoutJob {
+    pub payout_id: u32,
+}
+
 impl Job for TimerJob {
     fn execute(self) {
         match self {
             TimerJob::ExpireSwap(job) => job.execute(),
+            TimerJob::ExpirePayout(job) => job.execute(),
+            TimerJob::ReleasePayout(job) => job.execute(),
         }
     }
 }
@@ -33,3 +47,32 @@ impl Job for ExpireSwapJob {
         });
     }
 }
+
+impl Job for ExpirePayoutJob {
+    fn execute(self) {
+        mutate_state(|state| {
+            if let Some(payout) = state.data.payouts.get(self.payout_id) {
+                if matches!(payout.status(state.env.now()), PayoutStatus::Expired(_)) {
+                    state.data.pending_payments_queue.push_payout_refunds(payout, state.env.now());
+                    crate::jobs::make_pending_payments::start_job_if_required(state);
+                }
+            }
+        });
+    }
+}
+
+impl Job for ReleasePayoutJob {
+    fn execute(self) {
+        mutate_state(|state| {
+            if let Some(payout) = state.data.payouts.get(self.payout_id) {
+                if payout.transfers_out.is_empty() {
+                    state
+                        .data
+                        .pending_payments_queue
+                        .push_payout_releases(payout, state.env.now());
+                    crate::jobs::make_pending_payments::start_job_if_required(state);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,42 @@
+use crate::timer_job_types::{ExpirePayoutJob, TimerJob};
+use crate::{Data, RuntimeState, mutate_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use escrow_canister::create_payout::{Response::*, *};
+use types::TimestampMillis;
+
+#[update(msgpack = true)]
+#[trace]
+fn create_payout(args: Args) -> Response {
+    mutate_state(|state| create_payout_impl(args, state))
+}
+
+fn create_payout_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let now = state.env.now();
+    if let Err(error) = validate_payout(&args, now, &state.data) {
+        InvalidPayout(error)
+    } else {
+        let caller = state.env.caller().into();
+        let deadline = args.deadline;
+        let id = state.data.payouts.push(caller, args, now);
+        state.data.timer_jobs.enqueue_job(
+            TimerJob::ExpirePayout(Box::new(ExpirePayoutJob { payout_id: id })),
+            deadline,
+            now,
+        );
+
+        Success(SuccessResult { id })
+    }
+}
+
+fn validate_payout(args: &Args, now: TimestampMillis, data: &Data) -> Result<(), String> {
+    if args.deadline <= now {
+        Err("Deadline must be in the future".to_string())
+    } else if args.release_at.is_some_and(|ts| ts < now) {
+        Err("Release time cannot be in the past".to_string())
+    } else if data.disabled_tokens.contains(&args.token.ledger) {
+        Err("Token is disabled".to_string())
+    } else {
+        Ok(())
+    }
+}
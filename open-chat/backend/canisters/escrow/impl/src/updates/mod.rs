@@ -1,5 +1,9 @@
 pub mod c2c_set_token_enabled;
 pub mod cancel_swap;
+pub mod create_payout;
 pub mod create_swap;
 pub mod notify_deposit;
+pub mod notify_payout_deposit;
+pub mod refund_payout;
+pub mod settle_payout;
 pub mod wallet_receive;
@@ -47,7 +47,7 @@ async fn notify_deposit(args: Args) -> Response {
                         timestamp: now,
                         token_info: swap.token1.clone(),
                         amount: swap.amount1,
-                        swap_id: swap.id,
+                        id: swap.id,
                         reason: PendingPaymentReason::Swap(accepted_by),
                     });
                     state.data.pending_payments_queue.push(PendingPayment {
@@ -55,7 +55,7 @@ async fn notify_deposit(args: Args) -> Response {
                         timestamp: now,
                         token_info: swap.token0.clone(),
                         amount: swap.amount0,
-                        swap_id: swap.id,
+                        id: swap.id,
                         reason: PendingPaymentReason::Swap(swap.created_by),
                     });
                     crate::jobs::make_pending_payments::start_job_if_required(state);
@@ -0,0 +1,157 @@
+use crate::model::pending_payments_queue::{PendingPayment, PendingPaymentReason};
+use crate::{RuntimeState, mutate_state};
+use candid::Principal;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use escrow_canister::notify_payout_deposit::{Response::*, *};
+use escrow_canister::{PayoutStatus, payout_deposit_subaccount, payout_pool_subaccount};
+use icrc_ledger_types::icrc1::account::Account;
+use icrc_ledger_types::icrc1::transfer::TransferArg;
+use ledger_utils::icrc1::make_transfer;
+use types::{TokenInfo, UserId};
+
+#[update(msgpack = true)]
+#[trace]
+async fn notify_payout_deposit(args: Args) -> Response {
+    let PrepareResult {
+        user_id,
+        token,
+        deposit_account,
+        pool_account,
+        closed,
+    } = match mutate_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let balance = match icrc_ledger_canister_c2c_client::icrc1_balance_of(token.ledger, &deposit_account)
+        .await
+        .map(|b| u128::try_from(b.0).unwrap())
+    {
+        Ok(balance) => balance,
+        Err(error) => return InternalError(format!("{error:?}")),
+    };
+
+    if let Some(response) = closed {
+        return refund_deposit(user_id, &token, deposit_account, balance)
+            .await
+            .unwrap_or(response);
+    }
+
+    if balance <= token.fee {
+        return BalanceTooLow(BalanceTooLowResult {
+            balance,
+            balance_required: token.fee + 1,
+        });
+    }
+
+    // Sweep the deposit into the payout's pool so that the payout can later be made from a single subaccount
+    let contributed = balance - token.fee;
+    let transfer_args = TransferArg {
+        from_subaccount: deposit_account.subaccount,
+        to: pool_account,
+        fee: Some(token.fee.into()),
+        created_at_time: None,
+        memo: None,
+        amount: contributed.into(),
+    };
+
+    match make_transfer(token.ledger, &transfer_args, false).await {
+        Ok(Ok(_)) => mutate_state(|state| {
+            let now = state.env.now();
+            let payout = state.data.payouts.get_mut(args.payout_id).unwrap();
+            match payout.status(now) {
+                PayoutStatus::Open => {
+                    *payout.contributions.entry(user_id).or_default() += contributed;
+                    Success(SuccessResult {
+                        contributed,
+                        total: payout.total(),
+                    })
+                }
+                // The payout closed while the deposit was being swept, so the deposit is returned to the user
+                status => {
+                    if contributed > token.fee {
+                        state.data.pending_payments_queue.push(PendingPayment {
+                            user_id,
+                            timestamp: now,
+                            token_info: token.clone(),
+                            amount: contributed - token.fee,
+                            id: args.payout_id,
+                            reason: PendingPaymentReason::PayoutRefund,
+                        });
+                        crate::jobs::make_pending_payments::start_job_if_required(state);
+                    }
+                    match status {
+                        PayoutStatus::Cancelled(_) => PayoutCancelled,
+                        PayoutStatus::Expired(_) => PayoutExpired,
+                        _ => PayoutSettled,
+                    }
+                }
+            }
+        }),
+        Ok(Err(error)) => InternalError(error),
+        Err(error) => InternalError(format!("{error:?}")),
+    }
+}
+
+// Deposits made to a payout which has since closed, eg. those which the payout wasn't notified of in time, are
+// returned to the user directly from their deposit subaccount
+async fn refund_deposit(user_id: UserId, token: &TokenInfo, deposit_account: Account, balance: u128) -> Option<Response> {
+    if balance <= token.fee {
+        return None;
+    }
+
+    let transfer_args = TransferArg {
+        from_subaccount: deposit_account.subaccount,
+        to: Principal::from(user_id).into(),
+        fee: Some(token.fee.into()),
+        created_at_time: None,
+        memo: None,
+        amount: (balance - token.fee).into(),
+    };
+
+    match make_transfer(token.ledger, &transfer_args, false).await {
+        Ok(Ok(_)) => None,
+        Ok(Err(error)) => Some(InternalError(error)),
+        Err(error) => Some(InternalError(format!("{error:?}"))),
+    }
+}
+
+struct PrepareResult {
+    user_id: UserId,
+    token: TokenInfo,
+    deposit_account: Account,
+    pool_account: Account,
+    // The response to return once any deposit has been refunded if the payout is no longer open
+    closed: Option<Response>,
+}
+
+fn prepare(args: &Args, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
+    let Some(payout) = state.data.payouts.get(args.payout_id) else {
+        return Err(PayoutNotFound);
+    };
+
+    let closed = match payout.status(state.env.now()) {
+        PayoutStatus::Open => None,
+        PayoutStatus::Cancelled(_) => Some(PayoutCancelled),
+        PayoutStatus::Expired(_) => Some(PayoutExpired),
+        PayoutStatus::Settled(_) | PayoutStatus::Released(_) => Some(PayoutSettled),
+    };
+
+    let user_id = args.user_id.unwrap_or_else(|| state.env.caller().into());
+    let this_canister_id = state.env.canister_id();
+
+    Ok(PrepareResult {
+        user_id,
+        token: payout.token.clone(),
+        deposit_account: Account {
+            owner: this_canister_id,
+            subaccount: Some(payout_deposit_subaccount(user_id, payout.id)),
+        },
+        pool_account: Account {
+            owner: this_canister_id,
+            subaccount: Some(payout_pool_subaccount(payout.id)),
+        },
+        closed,
+    })
+}
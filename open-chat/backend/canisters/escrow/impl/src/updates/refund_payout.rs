@@ -0,0 +1,34 @@
+use crate::{RuntimeState, mutate_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use escrow_canister::refund_payout::{Response::*, *};
+
+#[update(msgpack = true)]
+#[trace]
+fn refund_payout(args: Args) -> Response {
+    mutate_state(|state| refund_payout_impl(args, state))
+}
+
+fn refund_payout_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if let Some(payout) = state.data.payouts.get_mut(args.payout_id) {
+        let caller = state.env.caller();
+        let now = state.env.now();
+
+        if !payout.is_admin(caller) {
+            NotAuthorized
+        } else if payout.settled_at.is_some() {
+            PayoutAlreadySettled
+        } else if payout.deadline <= now {
+            PayoutExpired
+        } else {
+            if payout.cancelled_at.is_none() {
+                payout.cancelled_at = Some(now);
+                state.data.pending_payments_queue.push_payout_refunds(payout, now);
+                crate::jobs::make_pending_payments::start_job_if_required(state);
+            }
+            Success
+        }
+    } else {
+        PayoutNotFound
+    }
+}
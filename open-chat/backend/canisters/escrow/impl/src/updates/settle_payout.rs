@@ -0,0 +1,56 @@
+use crate::timer_job_types::{ReleasePayoutJob, TimerJob};
+use crate::{RuntimeState, mutate_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use escrow_canister::settle_payout::{Response::*, *};
+use std::collections::HashSet;
+
+#[update(msgpack = true)]
+#[trace]
+fn settle_payout(args: Args) -> Response {
+    mutate_state(|state| settle_payout_impl(args, state))
+}
+
+fn settle_payout_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if let Some(payout) = state.data.payouts.get_mut(args.payout_id) {
+        let caller = state.env.caller();
+        let now = state.env.now();
+
+        if !payout.is_admin(caller) {
+            NotAuthorized
+        } else if payout.settled_at.is_some() {
+            PayoutAlreadySettled
+        } else if payout.cancelled_at.is_some() {
+            PayoutCancelled
+        } else if payout.deadline <= now {
+            PayoutExpired
+        } else if args.recipients.is_empty() && args.remainder_recipient.is_none() {
+            InvalidRecipients("At least one recipient is required".to_string())
+        } else if args.recipients.iter().any(|r| r.amount == 0) {
+            InvalidRecipients("Amounts cannot be 0".to_string())
+        } else if args.recipients.iter().map(|r| r.user_id).collect::<HashSet<_>>().len() != args.recipients.len() {
+            InvalidRecipients("Recipients must be unique".to_string())
+        } else if let Err((available, required)) = payout.settle(args.recipients, args.remainder_recipient, now) {
+            InsufficientFunds(InsufficientFundsResult { available, required })
+        } else {
+            let release_at = payout.release_at();
+            if release_at <= now {
+                state.data.pending_payments_queue.push_payout_releases(payout, now);
+                crate::jobs::make_pending_payments::start_job_if_required(state);
+            } else {
+                state.data.timer_jobs.enqueue_job(
+                    TimerJob::ReleasePayout(Box::new(ReleasePayoutJob {
+                        payout_id: args.payout_id,
+                    })),
+                    release_at,
+                    now,
+                );
+            }
+            state.data.notify_status_change_queue.push_payout(args.payout_id);
+            crate::jobs::notify_status_change::start_job_if_required(state);
+            Success
+        }
+    } else {
+        PayoutNotFound
+    }
+}
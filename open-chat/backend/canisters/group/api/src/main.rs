@@ -9,12 +9,18 @@ fn main() {
 
     generate_ts_method!(group, active_proposal_tallies);
     generate_ts_method!(group, deleted_message);
+    generate_ts_method!(group, deny_bot_permissions_request);
     generate_ts_method!(group, events);
+    generate_ts_method!(group, export_events);
     generate_ts_method!(group, events_by_index);
     generate_ts_method!(group, events_window);
     generate_ts_method!(group, invite_code);
     generate_ts_method!(group, local_user_index);
+    generate_ts_method!(group, member_warnings);
+    generate_ts_method!(group, message_edit_history);
     generate_ts_method!(group, messages_by_message_index);
+    generate_ts_method!(group, moderation_log);
+    generate_ts_method!(group, secret_ballot_audit);
     generate_ts_method!(group, thread_previews);
     generate_ts_method!(group, public_summary);
     generate_ts_method!(group, rules);
@@ -33,6 +39,7 @@ fn main() {
     generate_ts_method!(group, cancel_p2p_swap);
     generate_ts_method!(group, change_role);
     generate_ts_method!(group, claim_prize);
+    generate_ts_method!(group, contribute_to_crowdfund);
     generate_ts_method!(group, convert_into_community);
     generate_ts_method!(group, decline_invitation);
     generate_ts_method!(group, delete_messages);
@@ -42,8 +49,10 @@ fn main() {
     generate_ts_method!(group, enable_invite_code);
     generate_ts_method!(group, follow_thread);
     generate_ts_method!(group, join_video_call);
+    generate_ts_method!(group, mute_member);
     generate_ts_method!(group, pin_message_v2);
     generate_ts_method!(group, regenerate_webhook);
+    generate_ts_method!(group, register_delegate_proposal_vote);
     generate_ts_method!(group, register_poll_vote);
     generate_ts_method!(group, register_proposal_vote);
     generate_ts_method!(group, register_proposal_vote_v2);
@@ -52,8 +61,11 @@ fn main() {
     generate_ts_method!(group, remove_reaction);
     generate_ts_method!(group, report_message);
     generate_ts_method!(group, reset_invite_code);
+    generate_ts_method!(group, reveal_poll_ballot);
     generate_ts_method!(group, send_message_v2);
+    generate_ts_method!(group, set_proposal_delegate);
     generate_ts_method!(group, set_video_call_presence);
+    generate_ts_method!(group, set_warning_policy);
     generate_ts_method!(group, toggle_mute_notifications);
     generate_ts_method!(group, unblock_user);
     generate_ts_method!(group, undelete_messages);
@@ -62,4 +74,5 @@ fn main() {
     generate_ts_method!(group, update_bot);
     generate_ts_method!(group, update_group_v2);
     generate_ts_method!(group, update_webhook);
+    generate_ts_method!(group, warn_member);
 }
@@ -0,0 +1,19 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ChatExportPage, EventIndex, MessageIndex};
+
+#[ts_export(group, export_events)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub start_index: EventIndex,
+    pub max_events: u32,
+}
+
+#[ts_export(group, export_events)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatExportPage),
+    Error(OCError),
+}
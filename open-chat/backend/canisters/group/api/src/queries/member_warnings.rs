@@ -0,0 +1,17 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MemberWarnings, UserId};
+
+#[ts_export(group, member_warnings)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+}
+
+#[ts_export(group, member_warnings)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(MemberWarnings),
+    Error(OCError),
+}
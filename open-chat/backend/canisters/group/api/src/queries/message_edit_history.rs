@@ -0,0 +1,24 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageId, MessageIndex, MessageRevision};
+
+#[ts_export(group, message_edit_history)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+}
+
+#[ts_export(group, message_edit_history)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[ts_export(group, message_edit_history)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub revisions: Vec<MessageRevision>,
+}
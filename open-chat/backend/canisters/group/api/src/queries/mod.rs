@@ -1,5 +1,6 @@
 pub mod active_proposal_tallies;
 pub mod c2c_active_proposal_tallies;
+pub mod c2c_bot_active_proposal_tallies;
 pub mod c2c_bot_group_details;
 pub mod c2c_bot_members;
 pub mod c2c_can_issue_access_token_v2;
@@ -13,12 +14,17 @@ pub mod deleted_message;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
+pub mod export_events;
 pub mod invite_code;
 pub mod local_user_index;
+pub mod member_warnings;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
+pub mod moderation_log;
 pub mod public_summary;
 pub mod rules;
 pub mod search_messages;
+pub mod secret_ballot_audit;
 pub mod selected_initial;
 pub mod selected_updates_v2;
 pub mod summary;
@@ -0,0 +1,18 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::ModerationLogPage;
+
+#[ts_export(group, moderation_log)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub start_index: u32,
+    pub max_entries: u32,
+}
+
+#[ts_export(group, moderation_log)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ModerationLogPage),
+    Error(OCError),
+}
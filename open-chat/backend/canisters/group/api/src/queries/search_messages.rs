@@ -7,6 +7,7 @@ use types::{MessageMatch, UserId};
 #[ts_export(group, search_messages)]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
+    // Supports "quoted phrases" along with the filters `from:`, `has:`, `in:thread`, `after:` and `before:`
     pub search_term: String,
     pub max_results: u8,
     pub users: Option<HashSet<UserId>>,
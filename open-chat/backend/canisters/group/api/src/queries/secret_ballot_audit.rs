@@ -0,0 +1,18 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, SecretBallotAudit};
+
+#[ts_export(group, secret_ballot_audit)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+}
+
+#[ts_export(group, secret_ballot_audit)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SecretBallotAudit),
+    Error(OCError),
+}
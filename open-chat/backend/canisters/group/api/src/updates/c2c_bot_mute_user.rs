@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, Milliseconds, UnitResult, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub user_id: UserId,
+    pub duration: Option<Milliseconds>,
+}
+
+pub type Response = UnitResult;
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use types::{BotInitiator, MessageIndex, UnitResult, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub initiator: BotInitiator,
+    pub message_index: MessageIndex,
+    pub adopt: bool,
+}
+
+pub type Response = UnitResult;
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use types::{BotPermissions, UnitResult, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub command_permissions: BotPermissions,
+    pub autonomous_permissions: BotPermissions,
+    pub justification: String,
+}
+
+pub type Response = UnitResult;
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{GroupRole, UnitResult, UserId};
+use types::{GroupRole, TimestampMillis, UnitResult, UserId};
 
 #[ts_export(group, change_role)]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Args {
     pub user_id: UserId,
     pub new_role: GroupRole,
+    // If set, the user reverts to their previous role at this time
+    pub expires_at: Option<TimestampMillis>,
 }
 
 pub type Response = UnitResult;
@@ -1,3 +1,4 @@
+use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use types::UserId;
 
@@ -10,4 +11,10 @@ pub struct Args {
     pub message: String,
 }
 
-pub type Response = send_message_v2::Response;
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(send_message_v2::SuccessResult),
+    // The webhook is bound to a bot so the payload was forwarded to the bot rather than posted as a message
+    DeliveredToBot,
+    Error(OCError),
+}
@@ -4,7 +4,10 @@ pub mod block_user;
 pub mod c2c_bot_add_reaction;
 pub mod c2c_bot_delete_messages;
 pub mod c2c_bot_invite_users;
+pub mod c2c_bot_mute_user;
+pub mod c2c_bot_register_delegate_proposal_vote;
 pub mod c2c_bot_remove_user;
+pub mod c2c_bot_request_permissions;
 pub mod c2c_bot_send_message;
 pub mod c2c_bot_subscribe_to_events;
 pub mod c2c_delete_group;
@@ -18,21 +21,25 @@ pub mod c2c_join_group;
 pub mod c2c_leave_group;
 pub mod c2c_local_index;
 pub mod c2c_send_message;
+pub mod c2c_set_bot_version;
 pub mod c2c_set_user_suspended;
 pub mod c2c_start_import_into_community;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_group;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_bot_config;
 pub mod c2c_update_proposals;
 pub mod c2c_update_user_principal;
 pub mod cancel_invites;
 pub mod cancel_p2p_swap;
 pub mod change_role;
 pub mod claim_prize;
+pub mod contribute_to_crowdfund;
 pub mod convert_into_community;
 pub mod decline_invitation;
 pub mod delete_messages;
 pub mod delete_webhook;
+pub mod deny_bot_permissions_request;
 pub mod disable_invite_code;
 pub mod edit_message_v2;
 pub mod enable_invite_code;
@@ -40,8 +47,10 @@ pub mod end_video_call_v2;
 pub mod follow_thread;
 pub mod handle_webhook;
 pub mod join_video_call;
+pub mod mute_member;
 pub mod pin_message_v2;
 pub mod regenerate_webhook;
+pub mod register_delegate_proposal_vote;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
@@ -50,8 +59,11 @@ pub mod remove_participant;
 pub mod remove_reaction;
 pub mod report_message;
 pub mod reset_invite_code;
+pub mod reveal_poll_ballot;
 pub mod send_message_v2;
+pub mod set_proposal_delegate;
 pub mod set_video_call_presence;
+pub mod set_warning_policy;
 pub mod start_video_call_v2;
 pub mod toggle_mute_notifications;
 pub mod unblock_user;
@@ -61,3 +73,4 @@ pub mod unpin_message;
 pub mod update_bot;
 pub mod update_group_v2;
 pub mod update_webhook;
+pub mod warn_member;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{Milliseconds, UnitResult, UserId};
+
+#[ts_export(group, mute_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    // If None, any existing mute is lifted
+    pub duration: Option<Milliseconds>,
+    pub reason: Option<String>,
+}
+
+pub type Response = UnitResult;
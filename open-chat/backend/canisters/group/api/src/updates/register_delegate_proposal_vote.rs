@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{MessageIndex, UnitResult};
+
+#[ts_export(group, register_delegate_proposal_vote)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub message_index: MessageIndex,
+    pub adopt: bool,
+}
+
+pub type Response = UnitResult;
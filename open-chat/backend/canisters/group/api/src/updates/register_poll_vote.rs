@@ -2,7 +2,7 @@ use candid::CandidType;
 use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{MessageIndex, PollVotes, VoteOperation};
+use types::{MessageIndex, PollBallot, PollVotes, VoteOperation};
 
 #[ts_export(group, register_poll_vote)]
 #[derive(Serialize, Deserialize, Debug)]
@@ -10,6 +10,8 @@ pub struct Args {
     pub thread_root_message_index: Option<MessageIndex>,
     pub message_index: MessageIndex,
     pub poll_option: u32,
+    #[serde(default)]
+    pub ballot: Option<PollBallot>,
     pub operation: VoteOperation,
     pub new_achievement: bool,
 }
@@ -9,6 +9,8 @@ use types::UserId;
 pub struct Args {
     pub name: String,
     pub avatar: Option<String>,
+    #[serde(default)]
+    pub bot_id: Option<UserId>,
 }
 
 #[ts_export(group, register_webhook)]
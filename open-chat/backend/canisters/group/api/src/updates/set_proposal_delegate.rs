@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{UnitResult, UserId};
+
+#[ts_export(group, set_proposal_delegate)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub delegate: Option<UserId>,
+}
+
+pub type Response = UnitResult;
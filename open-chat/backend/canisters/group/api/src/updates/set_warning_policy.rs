@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{UnitResult, WarningPolicy};
+
+#[ts_export(group, set_warning_policy)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub policy: Option<WarningPolicy>,
+}
+
+pub type Response = UnitResult;
@@ -2,7 +2,9 @@ use candid::CandidType;
 use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{AccessGateConfig, Document, Milliseconds, OptionUpdate, OptionalGroupPermissions, UpdatedRules, Version};
+use types::{
+    AccessGateConfig, Document, Milliseconds, OptionUpdate, OptionalGroupPermissions, SlowMode, UpdatedRules, Version,
+};
 
 #[ts_export(group, update_group)]
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -19,6 +21,11 @@ pub struct Args {
     pub gate_config: OptionUpdate<AccessGateConfig>,
     pub public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    #[serde(default)]
+    pub retain_edit_history: Option<bool>,
+    #[serde(default)]
+    #[ts(as = "types::OptionUpdateSlowMode")]
+    pub slow_mode: OptionUpdate<SlowMode>,
 }
 
 #[ts_export(group, update_group)]
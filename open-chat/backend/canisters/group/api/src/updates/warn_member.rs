@@ -0,0 +1,25 @@
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{EscalationAction, UserId};
+
+#[ts_export(group, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub reason: String,
+}
+
+#[ts_export(group, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    Error(OCError),
+}
+
+#[ts_export(group, warn_member)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub active_warnings: u32,
+    pub escalation: Option<EscalationAction>,
+}
@@ -3,6 +3,7 @@ use group_canister::*;
 
 // Queries
 generate_c2c_call!(c2c_active_proposal_tallies);
+generate_c2c_call!(c2c_bot_active_proposal_tallies);
 generate_c2c_call!(c2c_bot_group_details);
 generate_c2c_call!(c2c_bot_members);
 generate_c2c_call!(c2c_can_issue_access_token_v2);
@@ -18,7 +19,10 @@ generate_c2c_call!(summary_updates);
 generate_c2c_call!(c2c_bot_add_reaction);
 generate_c2c_call!(c2c_bot_delete_messages);
 generate_c2c_call!(c2c_bot_invite_users);
+generate_c2c_call!(c2c_bot_mute_user);
+generate_c2c_call!(c2c_bot_register_delegate_proposal_vote);
 generate_c2c_call!(c2c_bot_remove_user);
+generate_c2c_call!(c2c_bot_request_permissions);
 generate_c2c_call!(c2c_bot_send_message);
 generate_c2c_call!(c2c_bot_subscribe_to_events);
 generate_c2c_call!(c2c_delete_group);
@@ -37,6 +41,8 @@ generate_c2c_call!(c2c_start_import_into_community);
 generate_c2c_call!(c2c_tip_message);
 generate_c2c_call!(c2c_unfreeze_group);
 generate_c2c_call!(c2c_uninstall_bot);
+generate_c2c_call!(c2c_set_bot_version);
+generate_c2c_call!(c2c_update_bot_config);
 generate_c2c_call!(c2c_update_proposals);
 generate_c2c_call!(c2c_update_user_principal);
 generate_c2c_call!(change_role);
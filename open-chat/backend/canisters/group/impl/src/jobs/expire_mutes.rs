@@ -0,0 +1,54 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, mutate_state};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::trace;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() {
+        if let Some(next_expiry) = state.data.expiring_mutes.next_expiry() {
+            let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(next_expiry.saturating_sub(state.env.now())), run);
+            TIMER_ID.set(Some(timer_id));
+            return true;
+        }
+    }
+
+    false
+}
+
+pub(crate) fn restart_job(state: &RuntimeState) {
+    if let Some(timer_id) = TIMER_ID.get() {
+        ic_cdk_timers::clear_timer(timer_id);
+        TIMER_ID.set(None);
+    }
+
+    start_job_if_required(state);
+}
+
+fn run() {
+    trace!("'expire_mutes' job running");
+    TIMER_ID.set(None);
+    mutate_state(|state| {
+        let now = state.env.now();
+        let mut any_expired = false;
+
+        while let Some(mute) = state.data.expiring_mutes.pop_if_expires_before(now + 1) {
+            // The mute may since have been lifted or extended, in which case there is nothing to expire
+            if let Some(bot_notification) = state.data.chat.expire_mute(mute.user_id, now) {
+                state.push_bot_notification(bot_notification);
+                any_expired = true;
+            }
+        }
+
+        start_job_if_required(state);
+
+        if any_expired {
+            handle_activity_notification(state);
+        }
+    });
+}
@@ -0,0 +1,76 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::jobs::expire_members;
+use crate::{RuntimeState, mutate_state};
+use group_community_common::{DEFERRED_ROLE_EXPIRY_RETRY_INTERVAL, ExpiringMember, ExpiringRole};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() {
+        if let Some(next_expiry) = state.data.expiring_roles.next_expiry() {
+            let timer_id = ic_cdk_timers::set_timer(Duration::from_millis(next_expiry.saturating_sub(state.env.now())), run);
+            TIMER_ID.set(Some(timer_id));
+            return true;
+        }
+    }
+
+    false
+}
+
+pub(crate) fn restart_job(state: &RuntimeState) {
+    if let Some(timer_id) = TIMER_ID.get() {
+        ic_cdk_timers::clear_timer(timer_id);
+        TIMER_ID.set(None);
+    }
+
+    start_job_if_required(state);
+}
+
+fn run() {
+    trace!("'expire_roles' job running");
+    TIMER_ID.set(None);
+    mutate_state(|state| {
+        let now = state.env.now();
+        let mut any_expired = false;
+
+        while let Some(role) = state.data.expiring_roles.pop_if_expires_before(now + 1) {
+            // The role may since have been changed, in which case there is nothing to expire
+            if let Some(result) = state.data.chat.expire_role(role.user_id, now) {
+                // Owners can't "lapse" so either add or remove user from expiry list if they lose or gain owner status
+                if let Some(gate_expiry) = state.data.chat.gate_config.value.as_ref().and_then(|gc| gc.expiry()) {
+                    if result.new_role.is_owner() {
+                        state.data.expiring_members.remove_member(role.user_id, None);
+                    } else if result.prev_role.is_owner() {
+                        state.data.expiring_members.push(ExpiringMember {
+                            expires: now + gate_expiry,
+                            channel_id: None,
+                            user_id: role.user_id,
+                        });
+                    }
+                }
+                state.push_bot_notification(result.bot_notification);
+                any_expired = true;
+            } else if state.data.chat.members.is_role_expiry_deferred(role.user_id, now) {
+                info!(user_id = %role.user_id, "Role expiry deferred because the member is the last owner");
+                state.data.expiring_roles.push(ExpiringRole {
+                    expires: now + DEFERRED_ROLE_EXPIRY_RETRY_INTERVAL,
+                    channel_id: None,
+                    user_id: role.user_id,
+                });
+            }
+        }
+
+        expire_members::start_job_if_required(state);
+        start_job_if_required(state);
+
+        if any_expired {
+            handle_activity_notification(state);
+        }
+    });
+}
@@ -1,13 +1,21 @@
 use crate::RuntimeState;
 
+pub mod backfill_search_index;
 pub mod expire_members;
+pub mod expire_mutes;
+pub mod expire_roles;
 pub mod garbage_collect_stable_memory;
 pub mod make_pending_payments;
 pub mod process_expire_member_actions;
+pub mod snapshot_poll_weights;
 
 pub(crate) fn start(state: &RuntimeState) {
+    backfill_search_index::start_job_if_required(state);
     expire_members::start_job_if_required(state);
+    expire_mutes::start_job_if_required(state);
+    expire_roles::start_job_if_required(state);
     garbage_collect_stable_memory::start_job_if_required(state);
     make_pending_payments::start_job_if_required(state);
     process_expire_member_actions::start_job_if_required(state);
+    snapshot_poll_weights::start_job_if_required(state);
 }
@@ -0,0 +1,55 @@
+use crate::{RuntimeState, mutate_state, read_state};
+use gated_groups::lookup_poll_weights;
+use group_community_common::PollWeightsBatch;
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::trace;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+    static IN_PROGRESS: Cell<bool> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() && !IN_PROGRESS.get() && !state.data.poll_weight_snapshots.is_empty() {
+        let timer_id = ic_cdk_timers::set_timer(Duration::ZERO, run);
+        TIMER_ID.set(Some(timer_id));
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    trace!("'snapshot_poll_weights' job running");
+    TIMER_ID.set(None);
+
+    // The batch stays in state until its results are recorded, so if it is interrupted it will be looked up again
+    if let Some(batch) = read_state(|state| state.data.poll_weight_snapshots.next_batch()) {
+        IN_PROGRESS.set(true);
+        ic_cdk::futures::spawn(process_batch(batch));
+    }
+}
+
+async fn process_batch(batch: PollWeightsBatch) {
+    let (weights, failed) = lookup_poll_weights(&batch.weighting, &batch.users).await;
+
+    IN_PROGRESS.set(false);
+
+    mutate_state(|state| {
+        let now = state.env.now();
+        let snapshot_complete = state.data.poll_weight_snapshots.complete_batch(&batch, failed);
+        state.data.chat.events.record_poll_weights(
+            batch.thread_root_message_index,
+            batch.message_index,
+            weights,
+            0,
+            snapshot_complete,
+            now,
+        );
+
+        // Only look up the next batch once this one has completed to limit the number of concurrent calls
+        start_job_if_required(state);
+    });
+}
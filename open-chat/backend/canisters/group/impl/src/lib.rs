@@ -12,8 +12,8 @@ use fire_and_forget_handler::FireAndForgetHandler;
 use gated_groups::{GatePayment, calculate_gate_payments};
 use group_chat_core::{AddResult as AddMemberResult, GroupChatCore, GroupMemberInternal, InvitedUsersSuccess, UserInvitation};
 use group_community_common::{
-    Achievements, ExpiringMemberActions, ExpiringMembers, PaymentReceipts, PaymentRecipient, PendingPayment,
-    PendingPaymentReason, PendingPaymentsQueue, UserCache,
+    Achievements, ExpiringMemberActions, ExpiringMembers, ExpiringMutes, ExpiringRoles, PaymentReceipts, PaymentRecipient,
+    PendingPayment, PendingPaymentReason, PendingPaymentsQueue, PollWeightSnapshots, UnsentBotTransfers, UserCache,
 };
 use ic_principal::Principal;
 use installed_bots::InstalledBots;
@@ -33,12 +33,12 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Deref;
 use timer_job_queues::{BatchedTimerJobQueue, GroupedTimerJobQueue};
 use types::{
-    AccessGateConfigInternal, Achievement, BotAdded, BotEventsCaller, BotInitiator, BotNotification, BotPermissions,
-    BotRemoved, BotSubscriptions, BotUpdated, BuildVersion, Caller, CanisterId, ChatEventCategory, ChatId, ChatMetrics,
-    CommunityId, Cycles, Document, EventIndex, EventsCaller, FcmData, FrozenGroupInfo, GroupCanisterGroupChatSummary,
-    GroupMembership, GroupPermissions, GroupSubtype, IdempotentEnvelope, MAX_THREADS_IN_SUMMARY, MessageIndex, Milliseconds,
-    MultiUserChat, Notification, OCResult, Rules, TimestampMillis, Timestamped, UserId, UserNotification,
-    UserNotificationPayload, UserType,
+    AccessGateConfigInternal, Achievement, BotAdded, BotCommandArg, BotEventsCaller, BotInitiator, BotNotification,
+    BotPermissions, BotRemoved, BotSubscriptions, BotUpdated, BuildVersion, Caller, CanisterId, ChatEventCategory, ChatId,
+    ChatMetrics, ChatPermission, CommunityId, Cycles, Document, EventIndex, EventsCaller, FcmData, FrozenGroupInfo,
+    GroupCanisterGroupChatSummary, GroupMembership, GroupPermissions, GroupSubtype, IdempotentEnvelope, MAX_THREADS_IN_SUMMARY,
+    MessageIndex, Milliseconds, MultiUserChat, Notification, OCResult, Rules, TimestampMillis, Timestamped, UserId,
+    UserNotification, UserNotificationPayload, UserType,
 };
 use user_canister::GroupCanisterEvent;
 use utils::env::Environment;
@@ -214,6 +214,9 @@ impl RuntimeState {
             is_public: chat.is_public.value,
             history_visible_to_new_joiners: chat.history_visible_to_new_joiners,
             messages_visible_to_non_members: chat.messages_visible_to_non_members.value,
+            retain_edit_history: chat.events.retain_edit_history().value,
+            proposal_delegate: chat.proposal_delegate.value,
+            slow_mode: chat.slow_mode.value,
             min_visible_event_index,
             min_visible_message_index,
             latest_message: main_events_reader.latest_message_event(Some(member.user_id())),
@@ -526,6 +529,14 @@ struct Data {
     achievements: Achievements,
     expiring_members: ExpiringMembers,
     expiring_member_actions: ExpiringMemberActions,
+    #[serde(default)]
+    expiring_roles: ExpiringRoles,
+    #[serde(default)]
+    expiring_mutes: ExpiringMutes,
+    #[serde(default)]
+    poll_weight_snapshots: PollWeightSnapshots,
+    #[serde(default)]
+    unsent_bot_transfers: UnsentBotTransfers,
     user_cache: UserCache,
     user_event_sync_queue: GroupedTimerJobQueue<UserEventBatch>,
     local_user_index_event_sync_queue: BatchedTimerJobQueue<LocalUserIndexEventBatch>,
@@ -621,6 +632,10 @@ impl Data {
             achievements: Achievements::default(),
             expiring_members: ExpiringMembers::default(),
             expiring_member_actions: ExpiringMemberActions::default(),
+            expiring_roles: ExpiringRoles::default(),
+            expiring_mutes: ExpiringMutes::default(),
+            poll_weight_snapshots: PollWeightSnapshots::default(),
+            unsent_bot_transfers: UnsentBotTransfers::default(),
             user_cache: UserCache::default(),
             user_event_sync_queue: GroupedTimerJobQueue::new(5, true),
             local_user_index_event_sync_queue: BatchedTimerJobQueue::new(local_user_index_canister_id, true),
@@ -768,7 +783,10 @@ impl Data {
     pub fn get_user_permissions(&self, user_id: &UserId) -> Option<BotPermissions> {
         let member = self.chat.members.get_verified_member(*user_id).ok()?;
 
-        let group_permissions = member.role().chat_permissions(&self.chat.permissions);
+        let mut group_permissions = member.role().chat_permissions(&self.chat.permissions);
+        if self.chat.is_proposal_delegate(*user_id) {
+            group_permissions.insert(ChatPermission::CastDelegateVote);
+        }
         let message_permissions = member.role().message_permissions(&self.chat.permissions.message_permissions);
 
         Some(
@@ -778,9 +796,24 @@ impl Data {
         )
     }
 
-    pub fn is_bot_permitted(&self, bot_id: &UserId, initiator: &BotInitiator, required: &BotPermissions) -> bool {
-        self.granted_bot_permissions(bot_id, initiator)
+    pub fn verify_bot_permitted(&self, bot_id: &UserId, initiator: &BotInitiator, required: &BotPermissions) -> OCResult {
+        if self
+            .granted_bot_permissions(bot_id, initiator)
             .is_some_and(|granted| required.is_subset(&granted))
+        {
+            return Ok(());
+        }
+
+        // If the bot was never granted some of the required permissions, name them so that the bot can request them
+        match self
+            .bots
+            .get(bot_id)
+            .map(|bot| bot.missing_permissions(initiator, required))
+            .filter(|missing| !missing.is_empty())
+        {
+            Some(missing) => Err(OCErrorCode::BotPermissionsMissing.with_json(&missing)),
+            None => Err(OCErrorCode::InitiatorNotAuthorized.into()),
+        }
     }
 
     fn granted_bot_permissions(&self, bot_id: &UserId, initiator: &BotInitiator) -> Option<BotPermissions> {
@@ -796,6 +829,7 @@ impl Data {
         }
     }
 
+    #[expect(clippy::too_many_arguments)]
     pub fn install_bot(
         &mut self,
         owner_id: UserId,
@@ -803,6 +837,7 @@ impl Data {
         permissions: BotPermissions,
         autonomous_permissions: Option<BotPermissions>,
         default_subscriptions: Option<BotSubscriptions>,
+        config: Vec<BotCommandArg>,
         now: TimestampMillis,
     ) -> bool {
         if !self.bots.add(
@@ -811,6 +846,7 @@ impl Data {
             permissions,
             autonomous_permissions.clone(),
             default_subscriptions.clone(),
+            config,
             now,
         ) {
             return false;
@@ -879,12 +915,57 @@ impl Data {
         true
     }
 
+    pub fn update_bot_config(
+        &mut self,
+        updated_by: UserId,
+        bot_id: UserId,
+        config: Vec<BotCommandArg>,
+        now: TimestampMillis,
+    ) -> bool {
+        if !self.bots.update_config(bot_id, config, now) {
+            return false;
+        }
+
+        self.chat.events.push_main_event(
+            ChatEventInternal::BotUpdated(Box::new(BotUpdated {
+                user_id: bot_id,
+                updated_by,
+            })),
+            now,
+        );
+
+        true
+    }
+
+    pub fn set_bot_pinned_version(
+        &mut self,
+        updated_by: UserId,
+        bot_id: UserId,
+        pinned_version: Option<u32>,
+        now: TimestampMillis,
+    ) -> bool {
+        if !self.bots.set_pinned_version(bot_id, pinned_version, now) {
+            return false;
+        }
+
+        self.chat.events.push_main_event(
+            ChatEventInternal::BotUpdated(Box::new(BotUpdated {
+                user_id: bot_id,
+                updated_by,
+            })),
+            now,
+        );
+
+        true
+    }
+
     pub fn uninstall_bot(&mut self, owner_id: UserId, bot_id: UserId, now: TimestampMillis) -> bool {
         if !self.bots.remove(bot_id, now) {
             return false;
         }
 
         self.chat.events.unsubscribe_bot_from_events(bot_id);
+        self.chat.webhooks.unbind_bot(&bot_id, now);
 
         self.chat.events.push_main_event(
             ChatEventInternal::BotRemoved(Box::new(BotRemoved {
@@ -0,0 +1,25 @@
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use group_canister::c2c_bot_active_proposal_tallies::*;
+use types::{ActiveProposalTalliesSuccessResult, BotPermissions, ChatPermission, OCResult};
+
+#[query(guard = "caller_is_local_user_index", msgpack = true)]
+fn c2c_bot_active_proposal_tallies(args: Args) -> Response {
+    match read_state(|state| c2c_bot_active_proposal_tallies_impl(args, state)) {
+        Ok(result) => Response::Success(result),
+        Err(error) => Response::Error(error),
+    }
+}
+
+fn c2c_bot_active_proposal_tallies_impl(args: Args, state: &RuntimeState) -> OCResult<ActiveProposalTalliesSuccessResult> {
+    state.data.verify_bot_permitted(
+        &args.bot_id,
+        &args.initiator,
+        &BotPermissions::from_chat_permission(ChatPermission::ReadMessages),
+    )?;
+
+    Ok(ActiveProposalTalliesSuccessResult {
+        tallies: state.data.chat.events.active_proposal_tallies(),
+    })
+}
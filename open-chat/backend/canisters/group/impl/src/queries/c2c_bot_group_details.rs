@@ -3,7 +3,6 @@ use crate::guards::caller_is_local_user_index;
 use crate::read_state;
 use canister_api_macros::query;
 use group_canister::c2c_bot_group_details::{Response::*, *};
-use oc_error_codes::OCErrorCode;
 use types::ChatPermission;
 use types::ChatSummaryGroup;
 use types::EventIndex;
@@ -18,13 +17,11 @@ fn c2c_bot_group_details(args: Args) -> Response {
 }
 
 fn c2c_bot_group_details_impl(args: Args, state: &RuntimeState) -> OCResult<ChatSummaryGroup> {
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         &args.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::ReadSummary),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     let chat = &state.data.chat;
     let events_ttl = chat.events.get_events_time_to_live();
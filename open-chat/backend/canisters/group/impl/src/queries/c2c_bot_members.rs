@@ -4,7 +4,6 @@ use crate::guards::caller_is_local_user_index;
 use crate::read_state;
 use canister_api_macros::query;
 use group_canister::c2c_bot_members::*;
-use oc_error_codes::OCErrorCode;
 use std::collections::HashMap;
 use types::ChatPermission;
 use types::GroupRole;
@@ -23,13 +22,11 @@ fn c2c_bot_members(args: Args) -> MembersResponse {
 }
 
 fn c2c_bot_members_impl(args: Args, state: &RuntimeState) -> OCResult<MembersResult> {
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         &args.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::ReadMembership),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     let mut members_map = HashMap::new();
 
@@ -2,6 +2,7 @@ use crate::RuntimeState;
 use types::TimestampMillis;
 
 mod active_proposal_tallies;
+mod c2c_bot_active_proposal_tallies;
 mod c2c_bot_group_details;
 mod c2c_bot_members;
 mod c2c_can_issue_access_token_v2;
@@ -10,13 +11,18 @@ mod deleted_message;
 mod events;
 mod events_by_index;
 mod events_window;
+mod export_events;
 mod http_request;
 mod invite_code;
 mod local_user_index;
+mod member_warnings;
+mod message_edit_history;
 mod messages_by_message_index;
+mod moderation_log;
 mod public_summary;
 mod rules;
 mod search_messages;
+mod secret_ballot_audit;
 mod selected_initial;
 mod selected_updates;
 mod summary;
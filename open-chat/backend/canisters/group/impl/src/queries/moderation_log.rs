@@ -0,0 +1,20 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use group_canister::moderation_log::{Response::*, *};
+use types::{ModerationLogPage, OCResult};
+
+#[query(msgpack = true)]
+fn moderation_log(args: Args) -> Response {
+    match read_state(|state| moderation_log_impl(args, state)) {
+        Ok(page) => Success(page),
+        Err(error) => Error(error),
+    }
+}
+
+fn moderation_log_impl(args: Args, state: &RuntimeState) -> OCResult<ModerationLogPage> {
+    let user_id = state.get_caller_user_id()?;
+    state
+        .data
+        .chat
+        .moderation_log_page(user_id, args.start_index, args.max_entries)
+}
@@ -46,6 +46,9 @@ fn selected_initial_impl(state: &RuntimeState) -> OCResult<SuccessResult> {
             added_by: bot.added_by,
             permissions: bot.permissions.clone(),
             autonomous_permissions: bot.autonomous_permissions.clone(),
+            config: bot.config.clone(),
+            permissions_request: bot.permissions_request.clone(),
+            pinned_version: bot.pinned_version,
         })
         .collect();
 
@@ -91,6 +91,9 @@ fn summary_updates_impl(updates_since: TimestampMillis, on_behalf_of: Option<Pri
             my_metrics: membership.my_metrics.clone(),
             is_public: updates.is_public,
             messages_visible_to_non_members: updates.messages_visible_to_non_members,
+            retain_edit_history: updates.retain_edit_history,
+            proposal_delegate: updates.proposal_delegate,
+            slow_mode: updates.slow_mode,
             latest_threads: membership.latest_threads.clone(),
             unfollowed_threads: membership.unfollowed_threads.clone(),
             notifications_muted: membership.notifications_muted,
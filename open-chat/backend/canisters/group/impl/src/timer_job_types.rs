@@ -9,7 +9,10 @@ use constants::{DAY_IN_MS, MINUTE_IN_MS, NANOS_PER_MILLISECOND, SECOND_IN_MS};
 use ledger_utils::process_transaction;
 use serde::{Deserialize, Serialize};
 use tracing::error;
-use types::{BlobReference, CanisterId, MessageId, MessageIndex, P2PSwapStatus, PendingCryptoTransaction, UserId};
+use types::{
+    BlobReference, CanisterId, CrowdfundFunded, CrowdfundStatus, EventIndex, MessageId, MessageIndex, P2PSwapStatus,
+    PendingCryptoTransaction, UserId,
+};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub enum TimerJob {
@@ -22,6 +25,8 @@ pub enum TimerJob {
     NotifyEscrowCanisterOfDeposit(NotifyEscrowCanisterOfDepositJob),
     CancelP2PSwapInEscrowCanister(CancelP2PSwapInEscrowCanisterJob),
     MarkP2PSwapExpired(MarkP2PSwapExpiredJob),
+    NotifyEscrowCanisterOfPayoutDeposit(NotifyEscrowCanisterOfPayoutDepositJob),
+    SettleCrowdfund(SettleCrowdfundJob),
     MarkVideoCallEnded(MarkVideoCallEndedJob),
 }
 
@@ -105,6 +110,36 @@ pub struct MarkP2PSwapExpiredJob {
     pub message_id: MessageId,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NotifyEscrowCanisterOfPayoutDepositJob {
+    pub user_id: UserId,
+    pub payout_id: u32,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub attempt: u32,
+}
+
+impl NotifyEscrowCanisterOfPayoutDepositJob {
+    pub fn run(user_id: UserId, payout_id: u32, thread_root_message_index: Option<MessageIndex>, message_id: MessageId) {
+        let job = NotifyEscrowCanisterOfPayoutDepositJob {
+            user_id,
+            payout_id,
+            thread_root_message_index,
+            message_id,
+            attempt: 0,
+        };
+        job.execute();
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SettleCrowdfundJob {
+    pub payout_id: u32,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub attempt: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MarkVideoCallEndedJob(pub group_canister::end_video_call_v2::Args);
 
@@ -125,6 +160,8 @@ impl Job for TimerJob {
             TimerJob::NotifyEscrowCanisterOfDeposit(job) => job.execute(),
             TimerJob::CancelP2PSwapInEscrowCanister(job) => job.execute(),
             TimerJob::MarkP2PSwapExpired(job) => job.execute(),
+            TimerJob::NotifyEscrowCanisterOfPayoutDeposit(job) => job.execute(),
+            TimerJob::SettleCrowdfund(job) => job.execute(),
             TimerJob::MarkVideoCallEnded(job) => job.execute(),
         }
 
@@ -396,6 +433,147 @@ impl Job for MarkP2PSwapExpiredJob {
     }
 }
 
+impl Job for NotifyEscrowCanisterOfPayoutDepositJob {
+    fn execute(self) {
+        let escrow_canister_id = read_state(|state| state.data.escrow_canister_id);
+
+        ic_cdk::futures::spawn(async move {
+            match escrow_canister_c2c_client::notify_payout_deposit(
+                escrow_canister_id,
+                &escrow_canister::notify_payout_deposit::Args {
+                    payout_id: self.payout_id,
+                    user_id: Some(self.user_id),
+                },
+            )
+            .await
+            {
+                Ok(escrow_canister::notify_payout_deposit::Response::Success(result)) => mutate_state(|state| {
+                    if let Ok(success) = state.data.chat.events.record_crowdfund_contribution(
+                        self.user_id,
+                        self.thread_root_message_index,
+                        self.message_id,
+                        result.contributed,
+                        state.env.now(),
+                    ) {
+                        state.push_bot_notification(success.bot_notification);
+                        handle_activity_notification(state);
+                    }
+                }),
+                response @ (Ok(escrow_canister::notify_payout_deposit::Response::InternalError(_)) | Err(_)) => {
+                    mutate_state(|state| {
+                        let now = state.env.now();
+                        let retry_at = state
+                            .data
+                            .chat
+                            .events
+                            .get_crowdfund(self.thread_root_message_index, self.message_id, EventIndex::default())
+                            .and_then(|c| escrow_canister::payout_deposit_notification_retry_at(self.attempt, c.deadline, now));
+
+                        if let Some(retry_at) = retry_at {
+                            state.data.timer_jobs.enqueue_job(
+                                TimerJob::NotifyEscrowCanisterOfPayoutDeposit(NotifyEscrowCanisterOfPayoutDepositJob {
+                                    user_id: self.user_id,
+                                    payout_id: self.payout_id,
+                                    thread_root_message_index: self.thread_root_message_index,
+                                    message_id: self.message_id,
+                                    attempt: self.attempt + 1,
+                                }),
+                                retry_at,
+                                now,
+                            );
+                        } else {
+                            error!(?response, "Failed to notify escrow canister of payout deposit");
+                        }
+                    });
+                }
+                response => error!(?response, "Failed to notify escrow canister of payout deposit"),
+            };
+        })
+    }
+}
+
+impl Job for SettleCrowdfundJob {
+    fn execute(self) {
+        let Some((escrow_canister_id, content)) = read_state(|state| {
+            state
+                .data
+                .chat
+                .events
+                .get_crowdfund(self.thread_root_message_index, self.message_id, EventIndex::default())
+                .filter(|c| c.status.is_open())
+                .map(|c| (state.data.escrow_canister_id, c))
+        }) else {
+            return;
+        };
+
+        ic_cdk::futures::spawn(async move {
+            // Contributions are accepted right up until the deadline, so the whole pool goes to the beneficiary if the
+            // target was reached, otherwise the contributors are refunded
+            let response = if content.raised >= content.target {
+                escrow_canister_c2c_client::settle_payout(
+                    escrow_canister_id,
+                    &escrow_canister::settle_payout::Args {
+                        payout_id: self.payout_id,
+                        recipients: Vec::new(),
+                        remainder_recipient: Some(content.beneficiary),
+                    },
+                )
+                .await
+                .map(|response| match response {
+                    escrow_canister::settle_payout::Response::Success => Ok(true),
+                    escrow_canister::settle_payout::Response::PayoutAlreadySettled => Ok(false),
+                    response => Err(format!("{response:?}")),
+                })
+            } else {
+                escrow_canister_c2c_client::refund_payout(
+                    escrow_canister_id,
+                    &escrow_canister::refund_payout::Args {
+                        payout_id: self.payout_id,
+                    },
+                )
+                .await
+                .map(|response| match response {
+                    // The escrow canister notifies this canister once the refunds have been made
+                    escrow_canister::refund_payout::Response::Success => Ok(false),
+                    response => Err(format!("{response:?}")),
+                })
+            };
+
+            match response {
+                Ok(Ok(true)) => mutate_state(|state| {
+                    let now = state.env.now();
+                    if let Ok(result) = state.data.chat.events.set_crowdfund_status(
+                        self.thread_root_message_index,
+                        self.message_id,
+                        CrowdfundStatus::Funded(CrowdfundFunded { funded_at: now }),
+                        now,
+                    ) {
+                        state.push_bot_notification(result.bot_notification);
+                        handle_activity_notification(state);
+                    }
+                }),
+                Ok(Ok(false)) => {}
+                Err(_) if self.attempt < 20 => {
+                    mutate_state(|state| {
+                        let now = state.env.now();
+                        state.data.timer_jobs.enqueue_job(
+                            TimerJob::SettleCrowdfund(SettleCrowdfundJob {
+                                payout_id: self.payout_id,
+                                thread_root_message_index: self.thread_root_message_index,
+                                message_id: self.message_id,
+                                attempt: self.attempt + 1,
+                            }),
+                            now + 10 * SECOND_IN_MS,
+                            now,
+                        );
+                    });
+                }
+                response => error!(?response, "Failed to settle crowdfund"),
+            };
+        })
+    }
+}
+
 impl Job for MarkVideoCallEndedJob {
     fn execute(self) {
         if let Err(error) = mutate_state(|state| end_video_call_impl(self.0.clone(), state)) {
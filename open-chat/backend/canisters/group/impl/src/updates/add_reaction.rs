@@ -4,7 +4,6 @@ use crate::{GroupEventPusher, RuntimeState, execute_update};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_canister::{add_reaction::*, c2c_bot_add_reaction};
-use oc_error_codes::{OCError, OCErrorCode};
 use types::{
     Achievement, BotCaller, BotPermissions, Caller, Chat, ChatId, ChatPermission, EventIndex, FcmData,
     GroupReactionAddedNotification, OCResult, UserNotificationPayload,
@@ -38,12 +37,12 @@ fn c2c_bot_add_reaction_impl(args: c2c_bot_add_reaction::Args, state: &mut Runti
         new_achievement: false,
     };
 
-    if !state.data.is_bot_permitted(
+    if let Err(error) = state.data.verify_bot_permitted(
         &bot_caller.bot,
         &bot_caller.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::ReactToMessages),
     ) {
-        return OCError::from(OCErrorCode::InitiatorNotAuthorized).into();
+        return error.into();
     }
 
     add_reaction_impl(args, Some(Caller::BotV2(bot_caller)), state).into()
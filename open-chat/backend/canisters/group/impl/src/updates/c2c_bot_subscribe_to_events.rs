@@ -3,7 +3,6 @@ use crate::{RuntimeState, execute_update};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_canister::c2c_bot_subscribe_to_events::*;
-use oc_error_codes::OCErrorCode;
 use std::collections::HashSet;
 use types::{BotInitiator, BotPermissions, BotSubscriptions, OCResult};
 
@@ -19,12 +18,9 @@ fn c2c_bot_subscribe_to_events_impl(args: Args, state: &mut RuntimeState) -> OCR
         chat: args.event_types,
     };
 
-    if !state
+    state
         .data
-        .is_bot_permitted(&args.bot_id, &BotInitiator::Autonomous, &BotPermissions::from(&subscriptions))
-    {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+        .verify_bot_permitted(&args.bot_id, &BotInitiator::Autonomous, &BotPermissions::from(&subscriptions))?;
 
     state
         .data
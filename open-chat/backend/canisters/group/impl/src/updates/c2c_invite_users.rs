@@ -6,7 +6,6 @@ use canister_tracing_macros::trace;
 use group_canister::c2c_bot_invite_users;
 use group_canister::c2c_invite_users::{Response::*, *};
 use ic_principal::Principal;
-use oc_error_codes::OCErrorCode;
 use types::{BotCaller, BotPermissions, Caller, ChatPermission, OCResult, UserId};
 
 #[update(guard = "caller_is_user_index_or_local_user_index", msgpack = true)]
@@ -33,13 +32,11 @@ fn c2c_bot_invite_users_impl(args: c2c_bot_invite_users::Args, state: &mut Runti
         initiator: args.initiator.clone(),
     };
 
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &bot_caller.bot,
         &bot_caller.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::InviteUsers),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     c2c_invite_users_impl(args.users, Caller::BotV2(bot_caller), state)
 }
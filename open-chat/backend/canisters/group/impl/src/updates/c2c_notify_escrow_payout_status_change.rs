@@ -0,0 +1,60 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::guards::caller_is_escrow_canister;
+use crate::{RuntimeState, execute_update};
+use candid::Principal;
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use escrow_canister::{PayoutStatus, PayoutStatusChange as Args};
+use types::icrc1::CryptoAccount;
+use types::{CrowdfundFunded, CrowdfundPaidOut, CrowdfundStatus, EventIndex, P2PSwapLocation};
+
+#[update(guard = "caller_is_escrow_canister", msgpack = true)]
+#[trace]
+fn c2c_notify_escrow_payout_status_change(args: Args) {
+    execute_update(|state| c2c_notify_escrow_payout_status_change_impl(args, state))
+}
+
+fn c2c_notify_escrow_payout_status_change_impl(args: Args, state: &mut RuntimeState) {
+    let P2PSwapLocation::Message(m) = args.location;
+
+    let status = match args.status {
+        PayoutStatus::Settled(s) => CrowdfundStatus::Funded(CrowdfundFunded { funded_at: s.settled_at }),
+        PayoutStatus::Released(r) => {
+            let Some(content) =
+                state
+                    .data
+                    .chat
+                    .events
+                    .get_crowdfund(m.thread_root_message_index, m.message_id, EventIndex::default())
+            else {
+                return;
+            };
+
+            let beneficiary = Principal::from(content.beneficiary);
+            let Some(txn_out) = r
+                .transfers_out
+                .iter()
+                .find(|t| matches!(&t.to, CryptoAccount::Account(a) if a.owner == beneficiary))
+                .or(r.transfers_out.first())
+                .map(|t| t.block_index)
+            else {
+                return;
+            };
+
+            CrowdfundStatus::PaidOut(CrowdfundPaidOut { txn_out })
+        }
+        PayoutStatus::Expired(_) | PayoutStatus::Cancelled(_) => CrowdfundStatus::Refunded,
+        PayoutStatus::Open => return,
+    };
+
+    if let Ok(result) =
+        state
+            .data
+            .chat
+            .events
+            .set_crowdfund_status(m.thread_root_message_index, m.message_id, status, state.env.now())
+    {
+        state.push_bot_notification(result.bot_notification);
+        handle_activity_notification(state);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, activity_notifications::handle_activity_notification, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use oc_error_codes::OCErrorCode;
+use types::OCResult;
+use types::c2c_set_bot_version::*;
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_set_bot_version(args: Args) -> Response {
+    execute_update(|state| c2c_set_bot_version_impl(args, state)).into()
+}
+
+fn c2c_set_bot_version_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let member = state.data.chat.members.get_verified_member(args.caller)?;
+
+    if !member.role().is_owner() {
+        return Err(OCErrorCode::InitiatorNotAuthorized.into());
+    }
+
+    let updated_by = member.user_id();
+    let now = state.env.now();
+
+    if !state
+        .data
+        .set_bot_pinned_version(updated_by, args.bot_id, args.pinned_version, now)
+    {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    handle_activity_notification(state);
+    Ok(())
+}
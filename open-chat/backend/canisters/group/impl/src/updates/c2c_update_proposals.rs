@@ -15,8 +15,10 @@ fn c2c_update_proposals_impl(args: Args, state: &mut RuntimeState) -> OCResult {
     let user_id = state.get_caller_user_id()?;
     let now = state.env.now();
 
-    if state.data.chat.events.update_proposals(user_id, args.proposals, now) {
+    let result = state.data.chat.events.update_proposals(user_id, args.proposals, now);
+    if result.chat_updated {
         handle_activity_notification(state);
     }
+    state.push_bot_notifications(result.bot_notifications);
     Ok(())
 }
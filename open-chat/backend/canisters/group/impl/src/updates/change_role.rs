@@ -4,7 +4,7 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_canister::change_role::*;
 use group_chat_core::GroupRoleInternal;
-use group_community_common::ExpiringMember;
+use group_community_common::{ExpiringMember, ExpiringRole};
 use oc_error_codes::OCErrorCode;
 use types::{CanisterId, GroupRole, OCResult, UserId};
 use user_index_canister_c2c_client::lookup_user;
@@ -94,6 +94,7 @@ fn commit(
         caller_id,
         args.user_id,
         args.new_role,
+        args.expires_at,
         is_caller_platform_moderator,
         is_user_platform_moderator,
         now,
@@ -112,6 +113,15 @@ fn commit(
         }
     }
 
+    if let Some(expires) = args.expires_at {
+        state.data.expiring_roles.push(ExpiringRole {
+            expires,
+            channel_id: None,
+            user_id: args.user_id,
+        });
+        jobs::expire_roles::restart_job(state);
+    }
+
     jobs::expire_members::start_job_if_required(state);
 
     state.push_bot_notification(result.bot_notification);
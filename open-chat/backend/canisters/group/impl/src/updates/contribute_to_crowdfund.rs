@@ -0,0 +1,64 @@
+use crate::timer_job_types::NotifyEscrowCanisterOfPayoutDepositJob;
+use crate::{RuntimeState, execute_update_async, read_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::contribute_to_crowdfund::{Response::*, *};
+use oc_error_codes::OCErrorCode;
+use types::{ContributeToCrowdfundSuccess, OCResult, UserId};
+
+#[update(msgpack = true)]
+#[trace]
+async fn contribute_to_crowdfund(args: Args) -> Response {
+    execute_update_async(|| contribute_to_crowdfund_impl(args)).await
+}
+
+async fn contribute_to_crowdfund_impl(args: Args) -> Response {
+    let PrepareResult { user_id, c2c_args } = match read_state(|state| prepare(&args, state)) {
+        Ok(result) => result,
+        Err(error) => return Error(error),
+    };
+
+    match user_canister_c2c_client::c2c_contribute_to_crowdfund(user_id.into(), &c2c_args).await {
+        Ok(user_canister::c2c_contribute_to_crowdfund::Response::Success(txn_in)) => {
+            NotifyEscrowCanisterOfPayoutDepositJob::run(
+                user_id,
+                c2c_args.payout_id,
+                args.thread_root_message_index,
+                args.message_id,
+            );
+
+            Success(ContributeToCrowdfundSuccess { txn_in })
+        }
+        Ok(user_canister::c2c_contribute_to_crowdfund::Response::Error(error)) => Error(error),
+        Err(error) => Error(error.into()),
+    }
+}
+
+struct PrepareResult {
+    user_id: UserId,
+    c2c_args: user_canister::c2c_contribute_to_crowdfund::Args,
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> OCResult<PrepareResult> {
+    state.data.verify_not_frozen()?;
+
+    if args.amount == 0 {
+        return Err(OCErrorCode::InvalidRequest.with_message("Amount must be greater than 0"));
+    }
+
+    let user_id = state.get_caller_user_id()?;
+    let content = state
+        .data
+        .chat
+        .open_crowdfund(user_id, args.thread_root_message_index, args.message_id, state.env.now())?;
+
+    Ok(PrepareResult {
+        user_id,
+        c2c_args: user_canister::c2c_contribute_to_crowdfund::Args {
+            payout_id: content.payout_id,
+            token: content.token,
+            amount: args.amount,
+            pin: args.pin.clone(),
+        },
+    })
+}
@@ -56,13 +56,11 @@ fn c2c_bot_delete_messages_impl(args: c2c_bot_delete_messages::Args, state: &mut
         new_achievement: false,
     };
 
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &bot_caller.bot,
         &bot_caller.initiator,
         &BotPermissions::from_chat_permission(ChatPermission::DeleteMessages),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     commit(Some(Caller::BotV2(bot_caller)), args, state)
 }
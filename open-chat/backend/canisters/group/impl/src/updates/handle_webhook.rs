@@ -1,20 +1,22 @@
 use crate::{RuntimeState, execute_update};
 use group_canister::handle_webhook::*;
-use group_canister::send_message_v2::SuccessResult;
 use oc_error_codes::OCErrorCode;
 use rand::Rng;
-use types::{Caller, MessageContentInitial, OCResult, TextContent};
+use types::{
+    BotEvent, BotNotification, BotWebhookEvent, Caller, Chat, MAX_TEXT_LENGTH_USIZE, MessageContentInitial, OCResult,
+    TextContent, UserId,
+};
 
 use super::send_message::send_message_impl;
 
 pub(crate) fn handle_webhook(args: Args) -> Response {
     match execute_update(|state| handle_webhook_impl(args, state)) {
-        Ok(result) => Response::Success(result),
+        Ok(response) => response,
         Err(error) => Response::Error(error),
     }
 }
 
-fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<SuccessResult> {
+fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Response> {
     let Some(webhook) = state.data.chat.webhooks.get(&args.id) else {
         return Err(OCErrorCode::WebhookNotFound.into());
     };
@@ -23,6 +25,11 @@ fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Success
         return Err(OCErrorCode::InvalidWebhook.into());
     }
 
+    if let Some(bot_id) = webhook.bot_id {
+        deliver_to_bot(args.id, bot_id, args.message, state)?;
+        return Ok(Response::DeliveredToBot);
+    }
+
     let send_message_args = group_canister::send_message_v2::Args {
         thread_root_message_index: None,
         message_id: state.env.rng().r#gen::<u64>().into(),
@@ -38,5 +45,33 @@ fn handle_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Success
         new_achievement: false,
     };
 
-    send_message_impl(send_message_args, Some(Caller::Webhook(args.id)), true, state)
+    send_message_impl(send_message_args, Some(Caller::Webhook(args.id)), None, true, state).map(Response::Success)
+}
+
+fn deliver_to_bot(webhook_id: UserId, bot_id: UserId, payload: String, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    if state.data.bots.get(&bot_id).is_none() {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    if payload.len() > MAX_TEXT_LENGTH_USIZE {
+        return Err(OCErrorCode::TextTooLong.into());
+    }
+
+    if serde_json::from_str::<serde_json::Value>(&payload).is_err() {
+        return Err(OCErrorCode::InvalidRequest.with_message("payload must be valid JSON"));
+    }
+
+    state.push_bot_notification(Some(BotNotification {
+        event: BotEvent::Webhook(BotWebhookEvent {
+            webhook_id,
+            chat: Chat::Group(state.env.canister_id().into()),
+            payload,
+        }),
+        recipients: vec![bot_id],
+        timestamp: state.env.now(),
+    }));
+
+    Ok(())
 }
@@ -1,5 +1,6 @@
 pub mod accept_p2p_swap;
 pub mod add_reaction;
+pub mod c2c_bot_request_permissions;
 pub mod c2c_bot_subscribe_to_events;
 pub mod c2c_delete_group;
 pub mod c2c_export_group;
@@ -11,22 +12,27 @@ pub mod c2c_invite_users;
 pub mod c2c_join_group;
 pub mod c2c_leave_group;
 pub mod c2c_local_index;
+pub mod c2c_notify_escrow_payout_status_change;
 pub mod c2c_notify_p2p_swap_status_change;
+pub mod c2c_set_bot_version;
 pub mod c2c_set_user_suspended;
 pub mod c2c_start_import_into_community;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_group;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_bot_config;
 pub mod c2c_update_proposals;
 pub mod c2c_update_user_principal;
 pub mod cancel_invites;
 pub mod cancel_p2p_swap;
 pub mod change_role;
 pub mod claim_prize;
+pub mod contribute_to_crowdfund;
 pub mod convert_into_community;
 pub mod decline_invitation;
 pub mod delete_messages;
 pub mod delete_webhook;
+pub mod deny_bot_permissions_request;
 pub mod disable_invite_code;
 pub mod edit_message;
 pub mod enable_invite_code;
@@ -35,8 +41,10 @@ pub mod follow_thread;
 pub mod handle_webhook;
 pub mod http_request_update;
 pub mod join_video_call;
+pub mod mute_member;
 pub mod pin_message;
 pub mod regenerate_webhook;
+pub mod register_delegate_proposal_vote;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
@@ -44,8 +52,11 @@ pub mod register_webhook;
 pub mod remove_participant;
 pub mod remove_reaction;
 pub mod report_message;
+pub mod reveal_poll_ballot;
 pub mod send_message;
+pub mod set_proposal_delegate;
 pub mod set_video_call_presence;
+pub mod set_warning_policy;
 pub mod start_video_call;
 pub mod toggle_mute_notifications;
 pub mod unblock_user;
@@ -56,3 +67,4 @@ pub mod update_bot;
 pub mod update_group_v2;
 pub mod update_webhook;
 pub mod wallet_receive;
+pub mod warn_member;
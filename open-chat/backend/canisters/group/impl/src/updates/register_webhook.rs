@@ -34,6 +34,12 @@ fn register_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Succe
         Err(UsernameValidationError::Invalid) => return Err(OCErrorCode::InvalidRequest.with_message("name invalid")),
     };
 
+    if let Some(bot_id) = args.bot_id {
+        if state.data.bots.get(&bot_id).is_none() {
+            return Err(OCErrorCode::BotNotFound.into());
+        }
+    }
+
     let avatar = args
         .avatar
         .map(|avatar_url| try_parse_data_url(&avatar_url))
@@ -42,7 +48,12 @@ fn register_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult<Succe
 
     let now = state.env.now();
 
-    let Some(webhook_id) = state.data.chat.webhooks.register(args.name, avatar, state.env.rng(), now) else {
+    let Some(webhook_id) = state
+        .data
+        .chat
+        .webhooks
+        .register(args.name, avatar, args.bot_id, state.env.rng(), now)
+    else {
         return Err(OCErrorCode::NameTaken.into());
     };
 
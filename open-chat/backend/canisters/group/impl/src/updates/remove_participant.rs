@@ -5,7 +5,7 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use fire_and_forget_handler::FireAndForgetHandler;
 use group_canister::remove_participant::*;
-use group_chat_core::GroupRoleInternal;
+use group_chat_core::{GroupRoleInternal, validate_moderation_reason};
 use local_user_index_canister_c2c_client::lookup_user;
 use msgpack::serialize_then_unwrap;
 use oc_error_codes::OCErrorCode;
@@ -15,7 +15,7 @@ use user_canister::c2c_remove_from_group;
 #[update(msgpack = true)]
 #[trace]
 async fn block_user(args: group_canister::block_user::Args) -> UnitResult {
-    execute_update_async(|| remove_participant_impl(args.user_id, true, None))
+    execute_update_async(|| remove_participant_impl(args.user_id, true, args.reason.clone(), None))
         .await
         .into()
 }
@@ -23,7 +23,7 @@ async fn block_user(args: group_canister::block_user::Args) -> UnitResult {
 #[update(msgpack = true)]
 #[trace]
 async fn remove_participant(args: Args) -> UnitResult {
-    execute_update_async(|| remove_participant_impl(args.user_id, false, None))
+    execute_update_async(|| remove_participant_impl(args.user_id, false, args.reason.clone(), None))
         .await
         .into()
 }
@@ -35,6 +35,7 @@ async fn c2c_bot_remove_user(args: group_canister::c2c_bot_remove_user::Args) ->
         remove_participant_impl(
             args.user_id,
             args.block,
+            None,
             Some(Caller::BotV2(BotCaller {
                 bot: args.bot_id,
                 initiator: args.initiator.clone(),
@@ -45,7 +46,14 @@ async fn c2c_bot_remove_user(args: group_canister::c2c_bot_remove_user::Args) ->
     .into()
 }
 
-async fn remove_participant_impl(user_to_remove: UserId, block: bool, ext_caller: Option<Caller>) -> OCResult {
+async fn remove_participant_impl(
+    user_to_remove: UserId,
+    block: bool,
+    reason: Option<String>,
+    ext_caller: Option<Caller>,
+) -> OCResult {
+    validate_moderation_reason(reason.as_ref())?;
+
     // Check the caller can remove the user
     let prepare_result = match read_state(|state| prepare(user_to_remove, block, ext_caller, state))? {
         Some(ok) => ok,
@@ -63,7 +71,16 @@ async fn remove_participant_impl(user_to_remove: UserId, block: bool, ext_caller
     }
 
     // Remove the user from the group
-    mutate_state(|state| commit(user_to_remove, block, prepare_result.remove, prepare_result.removed_by, state))
+    mutate_state(|state| {
+        commit(
+            user_to_remove,
+            block,
+            reason,
+            prepare_result.remove,
+            prepare_result.removed_by,
+            state,
+        )
+    })
 }
 
 struct PrepareResult {
@@ -103,13 +120,11 @@ fn prepare(
         };
 
         if let Caller::BotV2(bot_caller) = &caller {
-            if !state.data.is_bot_permitted(
+            state.data.verify_bot_permitted(
                 &bot_caller.bot,
                 &bot_caller.initiator,
                 &BotPermissions::from_chat_permission(ChatPermission::RemoveMembers),
-            ) {
-                return Err(OCErrorCode::InitiatorNotAuthorized.into());
-            }
+            )?;
         }
 
         if let Some(initiator) = caller.initiator() {
@@ -131,13 +146,20 @@ fn prepare(
     }
 }
 
-fn commit(user_to_remove: UserId, block: bool, remove: bool, caller: Caller, state: &mut RuntimeState) -> OCResult {
+fn commit(
+    user_to_remove: UserId,
+    block: bool,
+    reason: Option<String>,
+    remove: bool,
+    caller: Caller,
+    state: &mut RuntimeState,
+) -> OCResult {
     let agent = caller.agent();
 
     let bot_notification = state
         .data
         .chat
-        .remove_member(caller, user_to_remove, block, state.env.now())?;
+        .remove_member(caller, user_to_remove, block, reason, state.env.now())?;
 
     if remove {
         state.data.remove_user(user_to_remove, None);
@@ -161,7 +183,7 @@ fn commit(user_to_remove: UserId, block: bool, remove: bool, caller: Caller, sta
     Ok(())
 }
 
-fn remove_membership_from_user_canister(
+pub(crate) fn remove_membership_from_user_canister(
     user_to_remove: UserId,
     removed_by: UserId,
     blocked: bool,
@@ -1,25 +1,35 @@
 use crate::activity_notifications::handle_activity_notification;
 use crate::guards::caller_is_local_user_index;
-use crate::timer_job_types::{DeleteFileReferencesJob, EndPollJob, FinalPrizePaymentsJob, MarkP2PSwapExpiredJob};
-use crate::{Data, GroupEventPusher, RuntimeState, TimerJob, execute_update};
+use crate::timer_job_types::{
+    DeleteFileReferencesJob, EndPollJob, FinalPrizePaymentsJob, MarkP2PSwapExpiredJob, SettleCrowdfundJob,
+};
+use crate::{
+    Data, GroupEventPusher, RuntimeState, TimerJob, execute_update, execute_update_async, jobs, mutate_state, read_state,
+};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use chat_events::{MessageContentInternal, ValidateNewMessageContentResult};
+use constants::{MEMO_MESSAGE, MEMO_PRIZE, PRIZE_FEE_PERCENT};
 use group_canister::c2c_bot_send_message;
 use group_canister::c2c_send_message::{Args as C2CArgs, Response as C2CResponse};
 use group_canister::send_message_v2::{Response::*, *};
 use group_chat_core::SendMessageSuccess;
-use oc_error_codes::OCErrorCode;
+use group_community_common::{
+    PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPollWeights, ReserveBotTransferResult,
+};
+use oc_error_codes::{OCError, OCErrorCode};
+use types::icrc1::{Account, CryptoAccount};
 use types::{
-    Achievement, BotCaller, BotPermissions, Caller, Chat, ChatId, EventIndex, EventWrapper, FcmData, GroupMessageNotification,
-    Message, MessageContent, MessageIndex, OCResult, TimestampMillis, User, UserNotificationPayload,
+    Achievement, BotCaller, BotPermissions, Caller, CanisterId, Chat, ChatId, CompletedCryptoTransaction, CryptoTransaction,
+    EventIndex, EventWrapper, FcmData, GroupMessageNotification, Message, MessageContent, MessageContentInitial, MessageId,
+    MessageIndex, OCResult, PendingCryptoTransaction, PollWeighting, TimestampMillis, User, UserNotificationPayload, icrc2,
 };
 use user_canister::{GroupCanisterEvent, MessageActivity, MessageActivityEvent};
 
 #[update(msgpack = true)]
 #[trace]
 fn send_message_v2(args: Args) -> Response {
-    match execute_update(|state| send_message_impl(args, None, true, state)) {
+    match execute_update(|state| send_message_impl(args, None, None, true, state)) {
         Ok(result) => Success(result),
         Err(error) => Error(error),
     }
@@ -36,35 +46,201 @@ fn c2c_send_message(args: C2CArgs) -> C2CResponse {
 
 #[update(guard = "caller_is_local_user_index", msgpack = true)]
 #[trace]
-fn c2c_bot_send_message(args: c2c_bot_send_message::Args) -> c2c_bot_send_message::Response {
-    execute_update(|state| c2c_bot_send_message_impl(args, state))
+async fn c2c_bot_send_message(args: c2c_bot_send_message::Args) -> c2c_bot_send_message::Response {
+    execute_update_async(|| c2c_bot_send_message_impl(args)).await
 }
 
-fn c2c_bot_send_message_impl(args: c2c_bot_send_message::Args, state: &mut RuntimeState) -> c2c_bot_send_message::Response {
+async fn c2c_bot_send_message_impl(args: c2c_bot_send_message::Args) -> c2c_bot_send_message::Response {
     let finalised = args.finalised;
     let bot_caller = BotCaller {
         bot: args.bot_id,
         initiator: args.initiator.clone(),
     };
     let args: Args = args.into();
+    let is_prize = matches!(args.content, MessageContentInitial::Prize(_));
+    let message_id = args.message_id;
+
+    let (pending_transaction, this_canister_id) = match read_state(|state| prepare_bot_message(&bot_caller, &args, state)) {
+        Ok(result) => result,
+        Err(error) => return Error(error),
+    };
+
+    let transfer = match pending_transaction {
+        Some(pending) => {
+            // The message id is reserved until the message has been sent, so that concurrent attempts to send the same
+            // message can't each make a transfer. If a previous attempt to send this message made the transfer but then
+            // failed, that transfer is reused.
+            match mutate_state(|state| state.data.unsent_bot_transfers.reserve(message_id, &pending, state.env.now())) {
+                ReserveBotTransferResult::AlreadyInProgress => return Error(OCErrorCode::AlreadyInProgress.into()),
+                ReserveBotTransferResult::Reuse(completed) => Some(completed),
+                ReserveBotTransferResult::Reserved => {
+                    match ledger_utils::icrc2::process_transaction(pending, this_canister_id).await {
+                        Ok(Ok(completed)) => Some(completed),
+                        Ok(Err(failed)) => {
+                            return release_bot_transfer(
+                                message_id,
+                                OCErrorCode::TransferFailed.with_message(failed.error_message),
+                            );
+                        }
+                        Err(error) => return release_bot_transfer(message_id, error.into()),
+                    }
+                }
+            }
+        }
+        None => None,
+    };
+
+    send_bot_message(args, bot_caller, transfer, finalised, is_prize)
+}
 
-    if !state.data.is_bot_permitted(
+fn release_bot_transfer(message_id: MessageId, error: OCError) -> c2c_bot_send_message::Response {
+    mutate_state(|state| state.data.unsent_bot_transfers.release(message_id, None, state.env.now()));
+    Error(error)
+}
+
+fn send_bot_message(
+    args: Args,
+    bot_caller: BotCaller,
+    transfer: Option<icrc2::CompletedCryptoTransaction>,
+    finalised: bool,
+    is_prize: bool,
+) -> c2c_bot_send_message::Response {
+    let message_id = args.message_id;
+
+    mutate_state(|state| {
+        let result = send_message_impl(
+            args,
+            Some(Caller::BotV2(bot_caller)),
+            transfer.clone().map(CompletedCryptoTransaction::ICRC2),
+            finalised,
+            state,
+        );
+        let now = state.env.now();
+
+        match (result, transfer) {
+            (Ok(result), transfer) => {
+                if transfer.is_some() {
+                    state.data.unsent_bot_transfers.release(message_id, None, now);
+                }
+                Success(result)
+            }
+            (Err(error), Some(t)) if is_prize => {
+                state.data.unsent_bot_transfers.release(message_id, None, now);
+                refund_bot_prize(t, state);
+                Error(error)
+            }
+            (Err(error), Some(t)) => {
+                // The funds have already reached the recipient, so tell the bot about the transfer and keep it to be
+                // reused if the bot retries the message using the same message id
+                let block_index = t.block_index;
+                state.data.unsent_bot_transfers.release(message_id, Some(t), now);
+                Error(OCErrorCode::TransferMadeButMessageNotSent.with_message(format!(
+                    "Transfer made at block index {block_index} but message not sent (error code {}: {}). Retry using the same message id within a day to reuse the transfer",
+                    error.code(),
+                    error.message().unwrap_or_default()
+                )))
+            }
+            (Err(error), None) => Error(error),
+        }
+    })
+}
+
+fn prepare_bot_message(
+    bot_caller: &BotCaller,
+    args: &Args,
+    state: &RuntimeState,
+) -> OCResult<(Option<icrc2::PendingCryptoTransaction>, CanisterId)> {
+    state.data.verify_not_frozen()?;
+    state.data.verify_bot_permitted(
         &bot_caller.bot,
         &bot_caller.initiator,
         &BotPermissions::from_message_permission((&args.content).into()),
-    ) {
-        return Error(OCErrorCode::InitiatorNotAuthorized.into());
+    )?;
+
+    let this_canister_id = state.env.canister_id();
+    let now = state.env.now();
+
+    let pending_transaction = match &args.content {
+        MessageContentInitial::Crypto(c) => match &c.transfer {
+            CryptoTransaction::Pending(PendingCryptoTransaction::ICRC2(t)) => {
+                if state.data.chat.members.get(&c.recipient).is_none() {
+                    return Err(OCErrorCode::TargetUserNotInChat.into());
+                }
+                if !is_default_account_of(&t.to, c.recipient.into()) {
+                    return Err(OCErrorCode::InvalidRequest.with_message("Transfer must be to the recipient's account"));
+                }
+                Some(t.clone())
+            }
+            _ => return Err(OCErrorCode::InvalidRequest.with_message("Transfer must be a pending ICRC-2 transfer")),
+        },
+        MessageContentInitial::Prize(c) => {
+            if args.thread_root_message_index.is_some() {
+                return Err(OCErrorCode::InvalidRequest.with_message("Prize messages cannot be sent within threads"));
+            }
+            if c.end_date <= now {
+                return Err(OCErrorCode::DateInThePast.into());
+            }
+            match &c.transfer {
+                CryptoTransaction::Pending(PendingCryptoTransaction::ICRC2(t)) => {
+                    if !is_default_account_of(&t.to, this_canister_id) {
+                        return Err(OCErrorCode::InvalidRequest.with_message("Prize funds must be transferred to the chat"));
+                    }
+                    let total_prizes = c.prizes_v2.iter().sum::<u128>();
+                    let total_amount =
+                        total_prizes + c.prizes_v2.len() as u128 * t.fee + (total_prizes * PRIZE_FEE_PERCENT as u128) / 100;
+                    if t.amount != total_amount {
+                        return Err(
+                            OCErrorCode::InvalidRequest.with_message("Transaction amount must equal total prizes + total fees")
+                        );
+                    }
+                    Some(t.clone())
+                }
+                _ => return Err(OCErrorCode::InvalidRequest.with_message("Transfer must be a pending ICRC-2 transfer")),
+            }
+        }
+        _ => None,
+    };
+
+    match pending_transaction {
+        Some(t) if t.amount == 0 => Err(OCErrorCode::TransferCannotBeZero.into()),
+        Some(t) => {
+            let memo = if matches!(args.content, MessageContentInitial::Prize(_)) { MEMO_PRIZE } else { MEMO_MESSAGE };
+            Ok((
+                Some(icrc2::PendingCryptoTransaction {
+                    memo: Some(memo.to_vec().into()),
+                    ..t
+                }),
+                this_canister_id,
+            ))
+        }
+        None => Ok((None, this_canister_id)),
     }
+}
 
-    match send_message_impl(args, Some(Caller::BotV2(bot_caller)), finalised, state) {
-        Ok(result) => Success(result),
-        Err(error) => Error(error),
+fn is_default_account_of(account: &Account, owner: CanisterId) -> bool {
+    account.owner == owner && account.subaccount.is_none_or(|s| s == [0; 32])
+}
+
+// If the message couldn't be sent then the prize funds are held by this canister, so return them to the bot
+fn refund_bot_prize(transfer: icrc2::CompletedCryptoTransaction, state: &mut RuntimeState) {
+    if let CryptoAccount::Account(from) = transfer.from {
+        if transfer.amount > transfer.fee {
+            state.data.pending_payments_queue.push(PendingPayment {
+                amount: transfer.amount - transfer.fee,
+                fee: transfer.fee,
+                ledger_canister: transfer.ledger,
+                recipient: PaymentRecipient::Account(from.into()),
+                reason: PendingPaymentReason::BotPrizeRefund,
+            });
+            jobs::make_pending_payments::start_job_if_required(state);
+        }
     }
 }
 
 pub(crate) fn send_message_impl(
     args: Args,
     ext_caller: Option<Caller>,
+    transfer: Option<CompletedCryptoTransaction>,
     finalised: bool,
     state: &mut RuntimeState,
 ) -> OCResult<SuccessResult> {
@@ -79,12 +255,20 @@ pub(crate) fn send_message_impl(
     let now = state.env.now();
     let mentioned: Vec<_> = args.mentioned.iter().map(|u| u.user_id).collect();
 
-    let content =
-        match MessageContentInternal::validate_new_message(args.content, false, (&caller).into(), args.forwarding, now) {
-            ValidateNewMessageContentResult::Success(content) => content,
-            ValidateNewMessageContentResult::Error(error) => return Err(error.into()),
-            _ => return Err(OCErrorCode::InvalidRequest.with_message("Message type not supported")),
-        };
+    let content = match (
+        MessageContentInternal::validate_new_message(args.content, false, (&caller).into(), args.forwarding, now),
+        transfer,
+    ) {
+        (ValidateNewMessageContentResult::Success(content), None) => content,
+        (ValidateNewMessageContentResult::SuccessCrypto(c), Some(t)) => {
+            MessageContentInternal::new_with_transfer(MessageContentInitial::Crypto(c), t.into(), None, now)
+        }
+        (ValidateNewMessageContentResult::SuccessPrize(c), Some(t)) => {
+            MessageContentInternal::new_with_transfer(MessageContentInitial::Prize(c), t.into(), None, now)
+        }
+        (ValidateNewMessageContentResult::Error(error), _) => return Err(error.into()),
+        _ => return Err(OCErrorCode::InvalidRequest.with_message("Message type not supported")),
+    };
 
     let result = state.data.chat.send_message(
         &caller,
@@ -185,6 +369,7 @@ fn process_send_message_result(
     let expires_at = message_event.expires_at;
 
     register_timer_jobs(thread_root_message_index, message_event, now, &mut state.data);
+    jobs::snapshot_poll_weights::start_job_if_required(state);
 
     if !result.unfinalised_bot_message {
         let sender = caller.agent();
@@ -336,6 +521,17 @@ fn register_timer_jobs(
                     now,
                 );
             }
+            if let Some(weighting @ (PollWeighting::SnsNeuronStake(_) | PollWeighting::TokenBalance(_))) = &p.config.weighting {
+                data.poll_weight_snapshots.push(PendingPollWeights {
+                    channel_id: None,
+                    thread_root_message_index,
+                    message_index: message_event.event.message_index,
+                    weighting: weighting.clone(),
+                    users: data.chat.members.member_ids().iter().copied().collect(),
+                    failed: Vec::new(),
+                    attempts: 0,
+                });
+            }
         }
         MessageContent::Prize(p) => {
             data.timer_jobs.enqueue_job(
@@ -356,6 +552,18 @@ fn register_timer_jobs(
                 now,
             );
         }
+        MessageContent::Crowdfund(c) => {
+            data.timer_jobs.enqueue_job(
+                TimerJob::SettleCrowdfund(SettleCrowdfundJob {
+                    payout_id: c.payout_id,
+                    thread_root_message_index,
+                    message_id: message_event.event.message_id,
+                    attempt: 0,
+                }),
+                c.deadline,
+                now,
+            );
+        }
         _ => {}
     }
 }
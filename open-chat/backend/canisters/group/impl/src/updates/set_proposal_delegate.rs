@@ -0,0 +1,24 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::set_proposal_delegate::*;
+use types::OCResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_proposal_delegate(args: Args) -> Response {
+    execute_update(|state| set_proposal_delegate_impl(args, state)).into()
+}
+
+fn set_proposal_delegate_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let user_id = state.get_caller_user_id()?;
+    let now = state.env.now();
+
+    state.data.chat.set_proposal_delegate(user_id, args.delegate, now)?;
+
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use group_canister::set_warning_policy::*;
+use types::OCResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn set_warning_policy(args: Args) -> Response {
+    execute_update(|state| set_warning_policy_impl(args, state)).into()
+}
+
+fn set_warning_policy_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    state.data.verify_not_frozen()?;
+
+    let user_id = state.get_caller_user_id()?;
+    let now = state.env.now();
+
+    state.data.chat.set_warning_policy(user_id, args.policy, now)?;
+
+    handle_activity_notification(state);
+    Ok(())
+}
@@ -4,8 +4,9 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use chat_events::ChatEventInternal;
 use group_canister::unblock_user::*;
+use group_chat_core::ModerationLogEntryInternal;
 use oc_error_codes::OCErrorCode;
-use types::{OCResult, UsersUnblocked};
+use types::{ModerationAction, OCResult, UsersUnblocked};
 
 #[update(msgpack = true)]
 #[trace]
@@ -25,7 +26,15 @@ fn unblock_user_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         } else if caller_member.role().can_unblock_users(&state.data.chat.permissions) {
             let now = state.env.now();
 
-            state.data.chat.members.unblock(args.user_id, now);
+            if state.data.chat.members.unblock(args.user_id, now) {
+                state.data.chat.moderation_log.push(ModerationLogEntryInternal::new(
+                    caller_member.user_id(),
+                    args.user_id,
+                    ModerationAction::UserUnblocked,
+                    None,
+                    now,
+                ));
+            }
 
             let event = UsersUnblocked {
                 user_ids: vec![args.user_id],
@@ -33,6 +33,13 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         return Err(OCErrorCode::BotNotFound.into());
     }
 
+    let config = state
+        .data
+        .bots
+        .get(&args.bot_id)
+        .map(|b| b.config.clone())
+        .unwrap_or_default();
+
     state.push_bot_notification(Some(BotNotification {
         event: BotEvent::Lifecycle(BotLifecycleEvent::Installed(BotInstalledEvent {
             installed_by,
@@ -40,6 +47,7 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
             api_gateway: state.data.local_user_index_canister_id,
             granted_command_permissions: args.granted_permissions,
             granted_autonomous_permissions: args.granted_autonomous_permissions.unwrap_or_default(),
+            config,
         })),
         recipients: vec![args.bot_id],
         timestamp: now,
@@ -121,6 +121,7 @@ fn prepare(args: &Args, state: &RuntimeState) -> OCResult<PrepareResult> {
         &args.avatar,
         permissions,
         &args.public,
+        &args.slow_mode,
     )?;
 
     let avatar_update = args.avatar.as_ref().expand();
@@ -172,8 +173,10 @@ fn commit(my_user_id: UserId, args: Args, state: &mut RuntimeState) -> SuccessRe
         args.gate_config.map(|g| g.into()),
         args.public,
         args.messages_visible_to_non_members,
+        args.retain_edit_history,
         args.events_ttl,
         OptionUpdate::NoChange,
+        args.slow_mode,
         now,
     );
 
@@ -3,7 +3,7 @@ use canister_api_macros::update;
 use canister_tracing_macros::trace;
 use group_canister::update_webhook::*;
 use oc_error_codes::OCErrorCode;
-use types::OCResult;
+use types::{OCResult, OptionUpdate};
 use utils::document::try_parse_data_url;
 
 #[update(msgpack = true)]
@@ -21,6 +21,12 @@ fn update_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         return Err(OCErrorCode::InitiatorNotAuthorized.into());
     }
 
+    if let OptionUpdate::SetToSome(bot_id) = args.bot_id {
+        if state.data.bots.get(&bot_id).is_none() {
+            return Err(OCErrorCode::BotNotFound.into());
+        }
+    }
+
     let avatar = args
         .avatar
         .map(|avatar_url| try_parse_data_url(&avatar_url))
@@ -29,7 +35,7 @@ fn update_webhook_impl(args: Args, state: &mut RuntimeState) -> OCResult {
 
     let now = state.env.now();
 
-    if !state.data.chat.webhooks.update(args.id, args.name, avatar, now) {
+    if !state.data.chat.webhooks.update(args.id, args.name, avatar, args.bot_id, now) {
         return Err(OCErrorCode::WebhookNotFound.into());
     }
 
@@ -0,0 +1,57 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::updates::remove_participant::remove_membership_from_user_canister;
+use crate::{RuntimeState, execute_update, jobs};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use constants::OPENCHAT_BOT_USER_ID;
+use group_canister::warn_member::{Response::*, *};
+use group_community_common::ExpiringMute;
+use types::OCResult;
+
+#[update(msgpack = true)]
+#[trace]
+fn warn_member(args: Args) -> Response {
+    match execute_update(|state| warn_member_impl(args, state)) {
+        Ok(result) => Success(result),
+        Err(error) => Error(error),
+    }
+}
+
+fn warn_member_impl(args: Args, state: &mut RuntimeState) -> OCResult<SuccessResult> {
+    state.data.verify_not_frozen()?;
+
+    let user_id = state.get_caller_user_id()?;
+    let now = state.env.now();
+
+    let result = state.data.chat.warn_member(user_id, args.user_id, args.reason, now)?;
+
+    if let Some(expires) = result.muted_until {
+        state.data.expiring_mutes.push(ExpiringMute {
+            expires,
+            channel_id: None,
+            user_id: args.user_id,
+        });
+        jobs::expire_mutes::restart_job(state);
+    }
+
+    if result.removed {
+        state.data.remove_user(args.user_id, None);
+
+        remove_membership_from_user_canister(
+            args.user_id,
+            OPENCHAT_BOT_USER_ID,
+            false,
+            state.data.chat.name.value.clone(),
+            state.data.chat.is_public.value,
+            &mut state.data.fire_and_forget_handler,
+        );
+    }
+
+    state.push_bot_notifications(result.bot_notifications);
+    handle_activity_notification(state);
+
+    Ok(SuccessResult {
+        active_warnings: result.active_warnings,
+        escalation: result.escalation,
+    })
+}
@@ -5,11 +5,12 @@ use std::cmp::max;
 use std::collections::HashMap;
 use types::nns::CryptoAmount;
 use types::{
-    AutonomousConfig, BotCommandDefinition, BotDataEncoding, BotDefinition, BotInstallationLocation, BotSubscriptions,
-    BuildVersion, CanisterId, ChannelLatestMessageIndex, ChatId, ChitEarnedReason, CommunityId, CyclesTopUp,
-    DiamondMembershipPlanDuration, MessageContent, MessageContentInitial, MessageId, MessageIndex, Notification, NotifyChit,
-    PhoneNumber, ReferralType, SuspensionDuration, TimestampMillis, UniquePersonProof, UpdateUserPrincipalArgs, User,
-    UserCanisterStreakInsuranceClaim, UserCanisterStreakInsurancePayment, UserId, UserType, is_default,
+    AutonomousConfig, BotCommandDefinition, BotDataEncoding, BotDefinition, BotDefinitionVersion, BotInstallationConfig,
+    BotInstallationLocation, BotPermissions, BotStagedRollout, BotSubscriptions, BuildVersion, CanisterId,
+    ChannelLatestMessageIndex, ChatId, ChitEarnedReason, CommunityId, CyclesTopUp, DiamondMembershipPlanDuration,
+    MessageContent, MessageContentInitial, MessageId, MessageIndex, Notification, NotifyChit, PhoneNumber, ReferralType,
+    SuspensionDuration, TimestampMillis, UniquePersonProof, UpdateUserPrincipalArgs, User, UserCanisterStreakInsuranceClaim,
+    UserCanisterStreakInsurancePayment, UserId, UserType, is_default,
 };
 
 mod lifecycle;
@@ -52,6 +53,9 @@ pub enum UserIndexEvent {
     SyncExistingUser(UserDetailsFull),
     UserBlocked(UserId, UserId),
     UserUnblocked(UserId, UserId),
+    BotRolloutUpdated(BotRolloutUpdated),
+    BotVersionPinned(BotVersionPinned),
+    BotVersionRequiresPermissions(BotVersionRequiresPermissions),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -135,6 +139,8 @@ pub struct BotRegistered {
     pub default_subscriptions: Option<BotSubscriptions>,
     pub data_encoding: BotDataEncoding,
     pub notification_canister: CanisterId,
+    #[serde(default)]
+    pub installation_config: Option<BotInstallationConfig>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -148,6 +154,34 @@ pub struct BotUpdated {
     pub owner_id: UserId,
     pub endpoint: String,
     pub definition: BotDefinition,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub previous_versions: Vec<BotDefinitionVersion>,
+    #[serde(default)]
+    pub staged_rollout: Option<BotStagedRollout>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BotRolloutUpdated {
+    pub bot_id: UserId,
+    pub staged_rollout: Option<BotStagedRollout>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BotVersionPinned {
+    pub bot_id: UserId,
+    pub location: BotInstallationLocation,
+    pub version: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BotVersionRequiresPermissions {
+    pub bot_id: UserId,
+    pub location: BotInstallationLocation,
+    pub version: u32,
+    pub command_permissions: BotPermissions,
+    pub autonomous_permissions: BotPermissions,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -13,6 +13,7 @@ pub struct Args {
     pub proposals_bot_canister_id: CanisterId,
     pub cycles_dispenser_canister_id: CanisterId,
     pub escrow_canister_id: CanisterId,
+    pub airdrop_bot_canister_id: CanisterId,
     pub event_relay_canister_id: CanisterId,
     pub online_users_canister_id: CanisterId,
     pub internet_identity_canister_id: CanisterId,
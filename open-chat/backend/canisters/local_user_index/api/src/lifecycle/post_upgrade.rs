@@ -1,8 +1,9 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::BuildVersion;
+use types::{BuildVersion, CanisterId};
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
     pub wasm_version: BuildVersion,
+    pub airdrop_bot_canister_id: CanisterId,
 }
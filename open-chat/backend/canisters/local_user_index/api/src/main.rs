@@ -12,7 +12,10 @@ fn main() {
     generate_ts_method!(local_user_index, chat_events);
     generate_ts_method!(local_user_index, group_and_community_summary_updates_v2);
 
+    generate_ts_method!(local_user_index, bot_active_proposal_tallies);
     generate_ts_method!(local_user_index, bot_add_reaction);
+    generate_ts_method!(local_user_index, bot_mute_user);
+    generate_ts_method!(local_user_index, bot_register_delegate_proposal_vote);
     generate_ts_method!(local_user_index, bot_remove_user);
     generate_ts_method!(local_user_index, bot_chat_events);
     generate_ts_method!(local_user_index, bot_chat_summary);
@@ -23,6 +26,7 @@ fn main() {
     generate_ts_method!(local_user_index, bot_delete_messages);
     generate_ts_method!(local_user_index, bot_invite_users);
     generate_ts_method!(local_user_index, bot_members);
+    generate_ts_method!(local_user_index, bot_request_permissions);
     generate_ts_method!(local_user_index, bot_send_message);
     generate_ts_method!(local_user_index, bot_subscribe_to_events);
     generate_ts_method!(local_user_index, install_bot);
@@ -32,7 +36,9 @@ fn main() {
     generate_ts_method!(local_user_index, join_channel);
     generate_ts_method!(local_user_index, join_community);
     generate_ts_method!(local_user_index, join_group);
+    generate_ts_method!(local_user_index, pin_bot_version);
     generate_ts_method!(local_user_index, register_user);
     generate_ts_method!(local_user_index, uninstall_bot);
+    generate_ts_method!(local_user_index, update_bot_config);
     generate_ts_method!(local_user_index, withdraw_from_icpswap);
 }
@@ -0,0 +1,12 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{ActiveProposalTalliesResponse, BotChatContext};
+
+#[ts_export(local_user_index, bot_active_proposal_tallies)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chat_context: BotChatContext,
+}
+
+pub type Response = ActiveProposalTalliesResponse;
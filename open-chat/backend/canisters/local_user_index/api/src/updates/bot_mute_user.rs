@@ -0,0 +1,16 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{BotCommunityOrGroupContext, ChannelId, Milliseconds, UnitResult, UserId};
+
+#[ts_export(local_user_index, bot_mute_user)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub community_or_group_context: BotCommunityOrGroupContext,
+    pub channel_id: Option<ChannelId>,
+    pub user_id: UserId,
+    // If None, any existing mute is lifted
+    pub duration: Option<Milliseconds>,
+}
+
+pub type Response = UnitResult;
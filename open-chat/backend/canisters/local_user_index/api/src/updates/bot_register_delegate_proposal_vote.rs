@@ -0,0 +1,14 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{BotChatContext, MessageIndex, UnitResult};
+
+#[ts_export(local_user_index, bot_register_delegate_proposal_vote)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub chat_context: BotChatContext,
+    pub message_index: MessageIndex,
+    pub adopt: bool,
+}
+
+pub type Response = UnitResult;
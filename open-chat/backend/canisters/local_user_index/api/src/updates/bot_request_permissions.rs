@@ -0,0 +1,15 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{BotCommunityOrGroupContext, BotPermissions, UnitResult};
+
+#[ts_export(local_user_index, bot_request_permissions)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct Args {
+    pub community_or_group_context: BotCommunityOrGroupContext,
+    pub command_permissions: BotPermissions,
+    pub autonomous_permissions: BotPermissions,
+    pub justification: String,
+}
+
+pub type Response = UnitResult;
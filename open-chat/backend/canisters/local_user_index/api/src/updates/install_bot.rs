@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{BotInstallationLocation, BotPermissions, UnitResult, UserId};
+use types::{BotCommandArg, BotInstallationLocation, BotPermissions, UnitResult, UserId};
 
 #[ts_export(local_user_index, install_bot)]
 #[derive(Serialize, Deserialize, Debug)]
@@ -9,6 +9,7 @@ pub struct Args {
     pub bot_id: UserId,
     pub granted_permissions: BotPermissions,
     pub granted_autonomous_permissions: Option<BotPermissions>,
+    pub config: Option<Vec<BotCommandArg>>,
 }
 
 pub type Response = UnitResult;
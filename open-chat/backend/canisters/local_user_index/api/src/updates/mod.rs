@@ -3,7 +3,10 @@ pub mod bot_create_channel;
 pub mod bot_delete_channel;
 pub mod bot_delete_messages;
 pub mod bot_invite_users;
+pub mod bot_mute_user;
+pub mod bot_register_delegate_proposal_vote;
 pub mod bot_remove_user;
+pub mod bot_request_permissions;
 pub mod bot_send_message;
 pub mod bot_subscribe_to_events;
 pub mod c2c_community_canister;
@@ -36,8 +39,10 @@ pub mod invite_users_to_group;
 pub mod join_channel;
 pub mod join_community;
 pub mod join_group;
+pub mod pin_bot_version;
 pub mod push_events;
 pub mod register_user;
 pub mod remove_notifications;
 pub mod uninstall_bot;
+pub mod update_bot_config;
 pub mod withdraw_from_icpswap;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{BotInstallationLocation, UnitResult, UserId};
+
+#[ts_export(local_user_index, pin_bot_version)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub location: BotInstallationLocation,
+    pub bot_id: UserId,
+    pub version: Option<u32>,
+}
+
+pub type Response = UnitResult;
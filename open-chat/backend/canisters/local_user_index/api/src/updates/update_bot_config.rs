@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{BotCommandArg, BotInstallationLocation, UnitResult, UserId};
+
+#[ts_export(local_user_index, update_bot_config)]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub location: BotInstallationLocation,
+    pub bot_id: UserId,
+    pub config: Vec<BotCommandArg>,
+}
+
+pub type Response = UnitResult;
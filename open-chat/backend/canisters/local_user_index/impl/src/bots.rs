@@ -122,3 +122,21 @@ fn extract_access_context_from_jwt(jwt: &str, bot: &User, state: &mut RuntimeSta
         scope,
     })
 }
+
+// Only call this from update endpoints, since any changes made to state within queries are discarded
+pub fn record_bot_usage(context: &BotAccessContext, state: &mut RuntimeState) {
+    match &context.initiator {
+        BotInitiator::Command(command) => {
+            let message_id = match &context.scope {
+                BotActionScope::Chat(details) => Some(details.message_id),
+                BotActionScope::Community(_) => None,
+            };
+            let now = state.env.now();
+            state
+                .data
+                .bot_usage
+                .record_command(context.bot_id, &command.name, message_id, now);
+        }
+        BotInitiator::Autonomous => state.data.bot_usage.record_autonomous_action(context.bot_id),
+    }
+}
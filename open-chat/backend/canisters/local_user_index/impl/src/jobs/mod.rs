@@ -3,6 +3,7 @@ use ic_cdk::management_canister::ClearChunkStoreArgs;
 use tracing::info;
 
 pub mod delete_users;
+pub mod sync_bot_usage;
 pub mod topup_canister_pool;
 pub mod topup_canisters;
 pub mod upgrade_communities;
@@ -11,6 +12,7 @@ pub mod upgrade_users;
 
 pub(crate) fn start(state: &RuntimeState) {
     delete_users::start_job_if_required(state, None);
+    sync_bot_usage::start_job();
     topup_canister_pool::start_job_if_required(state, None);
     topup_canisters::start_job();
     upgrade_communities::start_job_if_required(state);
@@ -0,0 +1,26 @@
+use crate::{UserIndexEvent, mutate_state};
+use constants::MINUTE_IN_MS;
+use std::time::Duration;
+use tracing::trace;
+use types::Milliseconds;
+
+const SYNC_BOT_USAGE_INTERVAL: Milliseconds = 5 * MINUTE_IN_MS;
+
+pub fn start_job() {
+    ic_cdk_timers::set_timer_interval(Duration::from_millis(SYNC_BOT_USAGE_INTERVAL), run);
+}
+
+fn run() {
+    mutate_state(|state| {
+        if state.data.bot_usage.is_empty() {
+            return;
+        }
+
+        let now = state.env.now();
+        for bot_usage in state.data.bot_usage.take(now) {
+            state.push_event_to_user_index(UserIndexEvent::BotUsageRecorded(Box::new(bot_usage)), now);
+        }
+
+        trace!("Bot usage pushed to UserIndex");
+    });
+}
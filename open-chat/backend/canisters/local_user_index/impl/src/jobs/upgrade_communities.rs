@@ -104,6 +104,7 @@ fn initialize_upgrade(canister_id: CanisterId, force: bool, state: &mut RuntimeS
         deposit_cycles_if_needed,
         args: msgpack::serialize_then_unwrap(&community_canister::post_upgrade::Args {
             wasm_version: new_wasm_version,
+            airdrop_bot_canister_id: state.data.airdrop_bot_canister_id,
         }),
         mode: CanisterInstallMode::Upgrade(None),
         stop_start_canister: true,
@@ -1,3 +1,4 @@
+use crate::model::bot_usage_tracker::BotUsageTracker;
 use crate::model::community_event_batch::CommunityEventBatch;
 use crate::model::group_event_batch::GroupEventBatch;
 use crate::model::local_community_map::LocalCommunityMap;
@@ -439,6 +440,8 @@ struct Data {
     pub proposals_bot_canister_id: CanisterId,
     pub cycles_dispenser_canister_id: CanisterId,
     pub escrow_canister_id: CanisterId,
+    #[serde(default = "CanisterId::anonymous")]
+    pub airdrop_bot_canister_id: CanisterId,
     pub online_users_canister_id: CanisterId,
     pub internet_identity_canister_id: CanisterId,
     pub website_canister_id: CanisterId,
@@ -477,6 +480,8 @@ struct Data {
     pub notifications: EventStream<NotificationEnvelope>,
     pub blocked_users: UserIdsSet,
     pub fcm_token_store: FcmTokenStore,
+    #[serde(default)]
+    pub bot_usage: BotUsageTracker,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -502,6 +507,7 @@ impl Data {
         proposals_bot_canister_id: CanisterId,
         cycles_dispenser_canister_id: CanisterId,
         escrow_canister_id: CanisterId,
+        airdrop_bot_canister_id: CanisterId,
         event_relay_canister_id: CanisterId,
         online_users_canister_id: CanisterId,
         internet_identity_canister_id: CanisterId,
@@ -525,6 +531,7 @@ impl Data {
             proposals_bot_canister_id,
             cycles_dispenser_canister_id,
             escrow_canister_id,
+            airdrop_bot_canister_id,
             online_users_canister_id,
             internet_identity_canister_id,
             website_canister_id,
@@ -567,6 +574,7 @@ impl Data {
             notifications: EventStream::default(),
             blocked_users: UserIdsSet::new(UserIdsKeyPrefix::new_for_blocked_users()),
             fcm_token_store: FcmTokenStore::default(),
+            bot_usage: BotUsageTracker::default(),
         }
     }
 
@@ -616,6 +624,10 @@ impl Data {
             return;
         }
 
+        for bot_id in recipients.keys() {
+            self.bot_usage.record_event_delivered(*bot_id);
+        }
+
         let encodings: HashSet<BotDataEncoding> = recipients.values().cloned().collect();
 
         let event_wrapper = BotEventWrapper {
@@ -27,6 +27,7 @@ fn init(args: Args) {
         args.proposals_bot_canister_id,
         args.cycles_dispenser_canister_id,
         args.escrow_canister_id,
+        args.airdrop_bot_canister_id,
         args.event_relay_canister_id,
         args.online_users_canister_id,
         args.internet_identity_canister_id,
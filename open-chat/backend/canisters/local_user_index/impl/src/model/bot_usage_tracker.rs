@@ -0,0 +1,64 @@
+use constants::MINUTE_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use types::{MessageId, Milliseconds, TimestampMillis, UserId};
+use user_index_canister::BotUsageRecorded;
+
+// Longer than the lifetime of a command's access token, after which the bot can no longer act on that command
+const COMMAND_DEDUPE_WINDOW: Milliseconds = 10 * MINUTE_IN_MS;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct BotUsageTracker {
+    pending: HashMap<UserId, BotUsageRecorded>,
+    // A single command can result in several bot actions, so we track when each command message id was first seen
+    // to avoid counting the same command more than once, even if its actions span a flush
+    commands_seen: HashMap<(UserId, MessageId), TimestampMillis>,
+}
+
+impl BotUsageTracker {
+    pub fn record_command(&mut self, bot_id: UserId, command_name: &str, message_id: Option<MessageId>, now: TimestampMillis) {
+        if let Some(message_id) = message_id {
+            match self.commands_seen.entry((bot_id, message_id)) {
+                Occupied(_) => return,
+                Vacant(e) => {
+                    e.insert(now);
+                }
+            }
+        }
+
+        *self.entry(bot_id).commands.entry(command_name.to_string()).or_default() += 1;
+    }
+
+    pub fn record_failed_command_response(&mut self, bot_id: UserId) {
+        self.entry(bot_id).failed_command_responses += 1;
+    }
+
+    pub fn record_autonomous_action(&mut self, bot_id: UserId) {
+        self.entry(bot_id).autonomous_actions += 1;
+    }
+
+    pub fn record_event_delivered(&mut self, bot_id: UserId) {
+        self.entry(bot_id).events_delivered += 1;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn take(&mut self, now: TimestampMillis) -> Vec<BotUsageRecorded> {
+        self.commands_seen
+            .retain(|_, seen| now.saturating_sub(*seen) < COMMAND_DEDUPE_WINDOW);
+        std::mem::take(&mut self.pending).into_values().collect()
+    }
+
+    fn entry(&mut self, bot_id: UserId) -> &mut BotUsageRecorded {
+        self.pending.entry(bot_id).or_insert_with(|| BotUsageRecorded {
+            bot_id,
+            commands: Default::default(),
+            failed_command_responses: 0,
+            autonomous_actions: 0,
+            events_delivered: 0,
+        })
+    }
+}
@@ -1,9 +1,10 @@
 use candid::Principal;
+use local_user_index_canister::BotUpdated;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use types::{
-    AutonomousConfig, BotCommandDefinition, BotDataEncoding, BotDefinition, BotInstallationLocation, BotRegistrationStatus,
-    BotSubscriptions, UserId,
+    AutonomousConfig, BotCommandDefinition, BotDataEncoding, BotDefinitionVersion, BotInstallationConfig,
+    BotInstallationLocation, BotRegistrationStatus, BotStagedRollout, BotSubscriptions, UserId, resolve_bot_version,
 };
 
 #[derive(Serialize, Deserialize, Default)]
@@ -24,6 +25,39 @@ pub struct Bot {
     pub principal: Principal,
     pub registration_status: BotRegistrationStatus,
     pub data_encoding: BotDataEncoding,
+    #[serde(default)]
+    pub installation_config: Option<BotInstallationConfig>,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub previous_versions: Vec<BotDefinitionVersion>,
+    #[serde(default)]
+    pub staged_rollout: Option<BotStagedRollout>,
+    #[serde(default)]
+    pub pinned_versions: HashMap<BotInstallationLocation, u32>,
+}
+
+impl Bot {
+    pub fn has_version(&self, version: u32) -> bool {
+        version == self.version || self.previous_versions.iter().any(|v| v.version == version)
+    }
+
+    // Returns the commands of the version of the bot which applies to the given location. The UserIndex never evicts
+    // a version which is pinned or is the stable version of a staged rollout, so the fallback to the latest commands
+    // should never be hit
+    pub fn commands_for_location(&self, location: &BotInstallationLocation) -> &[BotCommandDefinition] {
+        let version = resolve_bot_version(
+            self.version,
+            self.staged_rollout.as_ref(),
+            self.pinned_versions.get(location).copied(),
+            location,
+        );
+
+        self.previous_versions
+            .iter()
+            .find(|v| v.version == version && version != self.version)
+            .map_or(&self.commands, |v| &v.commands)
+    }
 }
 
 impl BotsMap {
@@ -50,6 +84,7 @@ impl BotsMap {
         default_subscriptions: Option<BotSubscriptions>,
         permitted_install_location: Option<BotInstallationLocation>,
         data_encoding: BotDataEncoding,
+        installation_config: Option<BotInstallationConfig>,
     ) {
         self.bots.insert(
             bot_id,
@@ -64,6 +99,11 @@ impl BotsMap {
                 principal: user_principal,
                 registration_status: BotRegistrationStatus::Private(permitted_install_location),
                 data_encoding,
+                installation_config,
+                version: 0,
+                previous_versions: Vec::new(),
+                staged_rollout: None,
+                pinned_versions: HashMap::new(),
             },
         );
         self.principal_to_user_id.insert(user_principal, bot_id);
@@ -75,16 +115,36 @@ impl BotsMap {
         });
     }
 
-    pub fn update(&mut self, bot_id: UserId, owner_id: UserId, endpoint: String, definition: BotDefinition) {
-        self.bots.entry(bot_id).and_modify(|bot| {
-            bot.owner_id = owner_id;
-            bot.commands = definition.commands;
-            bot.endpoint = endpoint;
-            bot.autonomous_config = definition.autonomous_config;
-            bot.default_subscriptions = definition.default_subscriptions;
+    pub fn update(&mut self, ev: BotUpdated) {
+        self.bots.entry(ev.bot_id).and_modify(|bot| {
+            bot.owner_id = ev.owner_id;
+            bot.commands = ev.definition.commands;
+            bot.endpoint = ev.endpoint;
+            bot.autonomous_config = ev.definition.autonomous_config;
+            bot.default_subscriptions = ev.definition.default_subscriptions;
+            bot.installation_config = ev.definition.installation_config;
+            bot.version = ev.version;
+            bot.previous_versions = ev.previous_versions;
+            bot.staged_rollout = ev.staged_rollout;
         });
     }
 
+    pub fn set_staged_rollout(&mut self, bot_id: UserId, staged_rollout: Option<BotStagedRollout>) {
+        if let Some(bot) = self.bots.get_mut(&bot_id) {
+            bot.staged_rollout = staged_rollout;
+        }
+    }
+
+    pub fn set_pinned_version(&mut self, bot_id: UserId, location: BotInstallationLocation, version: Option<u32>) {
+        if let Some(bot) = self.bots.get_mut(&bot_id) {
+            if let Some(version) = version {
+                bot.pinned_versions.insert(location, version);
+            } else {
+                bot.pinned_versions.remove(&location);
+            }
+        }
+    }
+
     pub fn remove(&mut self, bot_id: &UserId) -> Option<Bot> {
         let bot = self.bots.remove(bot_id)?;
         self.principal_to_user_id.remove(&bot.principal);
@@ -1,3 +1,4 @@
+pub mod bot_usage_tracker;
 pub mod bots_map;
 pub mod community_event_batch;
 pub mod global_user_map;
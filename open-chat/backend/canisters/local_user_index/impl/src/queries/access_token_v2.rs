@@ -10,7 +10,10 @@ use serde::Serialize;
 use types::c2c_can_issue_access_token::{
     AccessTypeArgs, BotActionByCommandArgs, JoinVideoCallArgs, MarkVideoCallAsEndedArgs, StartVideoCallArgs,
 };
-use types::{AutonomousBotScope, BotActionByCommandClaims, BotCommand, Chat, JoinOrEndVideoCallClaims, StartVideoCallClaims};
+use types::{
+    AutonomousBotScope, BotActionByCommandClaims, BotActionScope, BotCommand, BotInstallationLocation, Chat,
+    JoinOrEndVideoCallClaims, StartVideoCallClaims,
+};
 
 #[query(composite = true, candid = true, msgpack = true)]
 #[trace]
@@ -98,8 +101,13 @@ fn prepare(args_outer: &ArgsInternal, state: &RuntimeState) -> Result<PrepareRes
     if let ArgsInternal::BotActionByCommand(args) = args_outer {
         let bot = state.data.bots.get(&args.bot_id).ok_or(Response::NotAuthorized)?;
 
+        let location = match &args.scope {
+            BotActionScope::Chat(details) => details.chat.into(),
+            BotActionScope::Community(details) => BotInstallationLocation::Community(details.community_id),
+        };
+
         let command = bot
-            .commands
+            .commands_for_location(&location)
             .iter()
             .find(|c| c.name == args.command.name)
             .ok_or(Response::NotAuthorized)?;
@@ -0,0 +1,54 @@
+use crate::bots::extract_access_context_from_chat_context;
+use crate::mutate_state;
+use canister_api_macros::{query, update};
+use canister_tracing_macros::trace;
+use local_user_index_canister::bot_active_proposal_tallies::*;
+use oc_error_codes::OCErrorCode;
+use types::Chat;
+
+#[update(candid = true, msgpack = true)]
+#[trace]
+async fn bot_active_proposal_tallies_c2c(args: Args) -> Response {
+    bot_active_proposal_tallies(args).await
+}
+
+#[query(composite = true, candid = true, msgpack = true)]
+#[trace]
+async fn bot_active_proposal_tallies(args: Args) -> Response {
+    let context = match mutate_state(|state| extract_access_context_from_chat_context(args.chat_context, state)) {
+        Ok(context) => context,
+        Err(_) => return Response::Error(OCErrorCode::BotNotAuthenticated.into()),
+    };
+
+    let response = match context.scope.chat(None).unwrap() {
+        Chat::Group(chat_id) => {
+            group_canister_c2c_client::c2c_bot_active_proposal_tallies(
+                chat_id.into(),
+                &group_canister::c2c_bot_active_proposal_tallies::Args {
+                    bot_id: context.bot_id,
+                    initiator: context.initiator,
+                },
+            )
+            .await
+        }
+        Chat::Channel(community_id, channel_id) => {
+            community_canister_c2c_client::c2c_bot_active_proposal_tallies(
+                community_id.into(),
+                &community_canister::c2c_bot_active_proposal_tallies::Args {
+                    bot_id: context.bot_id,
+                    initiator: context.initiator,
+                    channel_id,
+                },
+            )
+            .await
+        }
+        Chat::Direct(_) => {
+            return Response::Error(OCErrorCode::InvalidBotActionScope.with_message("Direct chats not supported"));
+        }
+    };
+
+    match response {
+        Ok(response) => response,
+        Err(error) => Response::Error(error.into()),
+    }
+}
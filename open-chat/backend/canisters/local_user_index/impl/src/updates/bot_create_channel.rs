@@ -3,11 +3,15 @@ use local_user_index_canister::bot_create_channel::{Response::*, *};
 use oc_error_codes::OCErrorCode;
 use types::{BotInitiator, UserId};
 
-use crate::read_state;
+use crate::mutate_state;
 
 #[update(candid = true, json = true, msgpack = true)]
 async fn bot_create_channel(args: Args) -> Response {
-    let Some(bot_id) = read_state(|state| state.data.bots.get_by_caller(&state.env.caller()).map(|bot| bot.bot_id)) else {
+    let Some(bot_id) = mutate_state(|state| {
+        let bot_id = state.data.bots.get_by_caller(&state.env.caller()).map(|bot| bot.bot_id)?;
+        state.data.bot_usage.record_autonomous_action(bot_id);
+        Some(bot_id)
+    }) else {
         return Response::Error(OCErrorCode::BotNotAuthenticated.into());
     };
 
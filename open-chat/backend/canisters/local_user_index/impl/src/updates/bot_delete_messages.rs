@@ -1,5 +1,5 @@
 use crate::{
-    bots::{BotAccessContext, extract_access_context_from_chat_context},
+    bots::{BotAccessContext, extract_access_context_from_chat_context, record_bot_usage},
     mutate_state,
 };
 use canister_api_macros::update;
@@ -9,7 +9,9 @@ use types::{ChannelId, Chat, MessageId, MessageIndex};
 
 #[update(candid = true, json = true, msgpack = true)]
 async fn bot_delete_messages(args: Args) -> Response {
-    let context = match mutate_state(|state| extract_access_context_from_chat_context(args.chat_context, state)) {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_chat_context(args.chat_context, state).inspect(|context| record_bot_usage(context, state))
+    }) {
         Ok(context) => context,
         Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
     };
@@ -1,5 +1,5 @@
 use crate::{
-    bots::{BotAccessContext, extract_access_context_from_chat_context},
+    bots::{BotAccessContext, extract_access_context_from_chat_context, record_bot_usage},
     mutate_state, read_state,
     updates::{invite_users_to_channel::send_channel_invitation, invite_users_to_group::send_group_invitation},
 };
@@ -10,7 +10,9 @@ use types::{Chat, UserId};
 
 #[update(candid = true, json = true, msgpack = true)]
 async fn bot_invite_users(args: Args) -> Response {
-    let context = match mutate_state(|state| extract_access_context_from_chat_context(args.chat_context, state)) {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_chat_context(args.chat_context, state).inspect(|context| record_bot_usage(context, state))
+    }) {
         Ok(context) => context,
         Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
     };
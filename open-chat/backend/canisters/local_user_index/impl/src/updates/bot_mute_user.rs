@@ -0,0 +1,69 @@
+use crate::{
+    bots::{BotAccessContext, extract_access_context_from_community_or_group_context, record_bot_usage},
+    mutate_state,
+};
+use canister_api_macros::update;
+use local_user_index_canister::bot_mute_user::*;
+use oc_error_codes::OCErrorCode;
+use types::{BotActionScope, ChannelId, Chat, Milliseconds, UserId};
+
+#[update(candid = true, json = true, msgpack = true)]
+async fn bot_mute_user(args: Args) -> Response {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_community_or_group_context(args.community_or_group_context, state)
+            .inspect(|context| record_bot_usage(context, state))
+    }) {
+        Ok(context) => context,
+        Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
+    };
+
+    call_chat_canister(context, args.channel_id, args.user_id, args.duration).await
+}
+
+async fn call_chat_canister(
+    context: BotAccessContext,
+    channel_id: Option<ChannelId>,
+    user_id: UserId,
+    duration: Option<Milliseconds>,
+) -> Response {
+    let (community_id, channel_id) = match context.scope {
+        BotActionScope::Chat(details) => match details.chat {
+            Chat::Channel(community_id, channel_id) => (community_id, channel_id),
+            Chat::Group(chat_id) => {
+                return group_canister_c2c_client::c2c_bot_mute_user(
+                    chat_id.into(),
+                    &group_canister::c2c_bot_mute_user::Args {
+                        bot_id: context.bot_id,
+                        initiator: context.initiator,
+                        user_id,
+                        duration,
+                    },
+                )
+                .await
+                .into();
+            }
+            Chat::Direct(_) => {
+                return OCErrorCode::InvalidBotActionScope
+                    .with_message("Direct chats not supported")
+                    .into();
+            }
+        },
+        BotActionScope::Community(details) => match channel_id {
+            Some(channel_id) => (details.community_id, channel_id),
+            None => return OCErrorCode::InvalidRequest.with_message("Channel required").into(),
+        },
+    };
+
+    community_canister_c2c_client::c2c_bot_mute_user(
+        community_id.into(),
+        &community_canister::c2c_bot_mute_user::Args {
+            bot_id: context.bot_id,
+            initiator: context.initiator,
+            channel_id,
+            user_id,
+            duration,
+        },
+    )
+    .await
+    .into()
+}
@@ -0,0 +1,57 @@
+use crate::{
+    bots::{BotAccessContext, extract_access_context_from_chat_context, record_bot_usage},
+    mutate_state,
+};
+use canister_api_macros::update;
+use local_user_index_canister::bot_register_delegate_proposal_vote::*;
+use oc_error_codes::OCErrorCode;
+use types::{Chat, MessageIndex};
+
+#[update(candid = true, json = true, msgpack = true)]
+async fn bot_register_delegate_proposal_vote(args: Args) -> Response {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_chat_context(args.chat_context, state).inspect(|context| record_bot_usage(context, state))
+    }) {
+        Ok(context) => context,
+        Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
+    };
+
+    call_chat_canister(context, args.message_index, args.adopt).await
+}
+
+async fn call_chat_canister(context: BotAccessContext, message_index: MessageIndex, adopt: bool) -> Response {
+    let Some(chat) = context.scope.chat(None) else {
+        return OCErrorCode::InvalidBotActionScope
+            .with_message("Channel not specified")
+            .into();
+    };
+
+    match chat {
+        Chat::Direct(_) => OCErrorCode::InvalidBotActionScope
+            .with_message("Direct chats not supported")
+            .into(),
+        Chat::Channel(community_id, channel_id) => community_canister_c2c_client::c2c_bot_register_delegate_proposal_vote(
+            community_id.into(),
+            &community_canister::c2c_bot_register_delegate_proposal_vote::Args {
+                bot_id: context.bot_id,
+                initiator: context.initiator,
+                channel_id,
+                message_index,
+                adopt,
+            },
+        )
+        .await
+        .into(),
+        Chat::Group(chat_id) => group_canister_c2c_client::c2c_bot_register_delegate_proposal_vote(
+            chat_id.into(),
+            &group_canister::c2c_bot_register_delegate_proposal_vote::Args {
+                bot_id: context.bot_id,
+                initiator: context.initiator,
+                message_index,
+                adopt,
+            },
+        )
+        .await
+        .into(),
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    bots::{BotAccessContext, extract_access_context_from_community_or_group_context},
+    bots::{BotAccessContext, extract_access_context_from_community_or_group_context, record_bot_usage},
     mutate_state,
 };
 use canister_api_macros::update;
@@ -11,6 +11,7 @@ use types::{BotActionScope, ChannelId, Chat, UserId};
 async fn bot_remove_user(args: Args) -> Response {
     let context = match mutate_state(|state| {
         extract_access_context_from_community_or_group_context(args.community_or_group_context, state)
+            .inspect(|context| record_bot_usage(context, state))
     }) {
         Ok(context) => context,
         Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
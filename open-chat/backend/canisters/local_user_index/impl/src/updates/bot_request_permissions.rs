@@ -0,0 +1,75 @@
+use crate::{
+    bots::{BotAccessContext, extract_access_context_from_community_or_group_context, record_bot_usage},
+    mutate_state,
+};
+use canister_api_macros::update;
+use local_user_index_canister::bot_request_permissions::*;
+use oc_error_codes::OCErrorCode;
+use types::{BotActionScope, BotPermissions, Chat};
+
+#[update(candid = true, json = true, msgpack = true)]
+async fn bot_request_permissions(args: Args) -> Response {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_community_or_group_context(args.community_or_group_context, state)
+            .inspect(|context| record_bot_usage(context, state))
+    }) {
+        Ok(context) => context,
+        Err(_) => return OCErrorCode::BotNotAuthenticated.into(),
+    };
+
+    call_chat_canister(
+        context,
+        args.command_permissions,
+        args.autonomous_permissions,
+        args.justification,
+    )
+    .await
+}
+
+async fn call_chat_canister(
+    context: BotAccessContext,
+    command_permissions: BotPermissions,
+    autonomous_permissions: BotPermissions,
+    justification: String,
+) -> Response {
+    match context.scope {
+        BotActionScope::Chat(details) => match details.chat {
+            Chat::Channel(community_id, _) => community_canister_c2c_client::c2c_bot_request_permissions(
+                community_id.into(),
+                &community_canister::c2c_bot_request_permissions::Args {
+                    bot_id: context.bot_id,
+                    command_permissions,
+                    autonomous_permissions,
+                    justification,
+                },
+            )
+            .await
+            .into(),
+            Chat::Group(chat_id) => group_canister_c2c_client::c2c_bot_request_permissions(
+                chat_id.into(),
+                &group_canister::c2c_bot_request_permissions::Args {
+                    bot_id: context.bot_id,
+                    command_permissions,
+                    autonomous_permissions,
+                    justification,
+                },
+            )
+            .await
+            .into(),
+            Chat::Direct(_) => OCErrorCode::InvalidBotActionScope
+                .with_message("Direct chats not supported")
+                .into(),
+        },
+        BotActionScope::Community(details) => community_canister_c2c_client::c2c_bot_request_permissions(
+            details.community_id.into(),
+            &community_canister::c2c_bot_request_permissions::Args {
+                bot_id: context.bot_id,
+                command_permissions,
+                autonomous_permissions,
+                justification,
+            },
+        )
+        .await
+        .into(),
+    }
+}
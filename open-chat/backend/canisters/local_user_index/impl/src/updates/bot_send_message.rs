@@ -1,20 +1,23 @@
 use crate::{
     RuntimeState,
-    bots::{BotAccessContext, extract_access_context_from_chat_context},
+    bots::{BotAccessContext, extract_access_context_from_chat_context, record_bot_usage},
     mutate_state,
 };
+use candid::Principal;
 use canister_api_macros::update;
 use local_user_index_canister::bot_send_message::*;
 use oc_error_codes::{OCError, OCErrorCode};
 use rand::Rng;
 use types::{
-    BotActionScope, BotInitiator, BotMessageContent, ChannelId, Chat, ChatId, CommunityId, EventIndex, MessageId, MessageIndex,
-    UserId,
+    BotActionScope, BotInitiator, BotMessageContent, ChannelId, Chat, ChatId, CommunityId, CryptoTransaction, EventIndex,
+    MessageId, MessageIndex, PendingCryptoTransaction, UserId,
 };
 
 #[update(candid = true, json = true, msgpack = true)]
 async fn bot_send_message(args: Args) -> Response {
-    let context = match mutate_state(|state| extract_access_context_from_chat_context(args.chat_context, state)) {
+    let context = match mutate_state(|state| {
+        extract_access_context_from_chat_context(args.chat_context, state).inspect(|context| record_bot_usage(context, state))
+    }) {
         Ok(context) => context,
         Err(_) => return Response::Error(OCErrorCode::BotNotAuthenticated.into()),
     };
@@ -53,12 +56,19 @@ async fn bot_send_message_impl(
     block_level_markdown: bool,
     finalised: bool,
 ) -> Response {
-    let context = match mutate_state(|state| extract_message_access_context(context, channel_id, thread, message_id, state)) {
+    let context = match mutate_state(|state| {
+        let context = extract_message_access_context(context, channel_id, thread, message_id, state)?;
+        verify_transfer_funded_by_bot(&content, context.chat, state.env.caller())?;
+        Ok(context)
+    }) {
         Ok(context) => context,
         Err(error) => return Response::Error(error),
     };
 
-    match context.chat {
+    let bot_id = context.bot_id;
+    let is_command = matches!(context.initiator, BotInitiator::Command(_));
+
+    let response = match context.chat {
         Chat::Direct(chat_id) => {
             send_message_to_user(
                 context.bot_id,
@@ -106,7 +116,13 @@ async fn bot_send_message_impl(
             )
             .await
         }
+    };
+
+    if is_command && matches!(response, Response::Error(_)) {
+        mutate_state(|state| state.data.bot_usage.record_failed_command_response(bot_id));
     }
+
+    response
 }
 
 fn extract_message_access_context(
@@ -149,6 +165,25 @@ fn extract_message_access_context(
     })
 }
 
+// Bots fund crypto and prize messages from their own account by approving the chat canister to make an ICRC-2
+// transfer on their behalf
+fn verify_transfer_funded_by_bot(content: &BotMessageContent, chat: Chat, caller: Principal) -> Result<(), OCError> {
+    let transfer = match content {
+        BotMessageContent::Crypto(c) => &c.transfer,
+        BotMessageContent::Prize(c) => &c.transfer,
+        _ => return Ok(()),
+    };
+
+    if matches!(chat, Chat::Direct(_)) {
+        return Err(OCErrorCode::InvalidRequest.with_message("Bots cannot send crypto transfers in direct chats"));
+    }
+
+    match transfer {
+        CryptoTransaction::Pending(PendingCryptoTransaction::ICRC2(t)) if t.from.owner == caller => Ok(()),
+        _ => Err(OCErrorCode::InvalidRequest.with_message("Transfer must be a pending ICRC-2 transfer from the bot's account")),
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 async fn send_message_to_channel(
     bot_id: UserId,
@@ -114,6 +114,7 @@ fn prepare(args: Args, state: &mut RuntimeState) -> Result<PrepareOk, Response>
         local_user_index_canister_id,
         proposals_bot_user_id: state.data.proposals_bot_canister_id.into(),
         escrow_canister_id: state.data.escrow_canister_id,
+        airdrop_bot_canister_id: state.data.airdrop_bot_canister_id,
         internet_identity_canister_id: state.data.internet_identity_canister_id,
         avatar: args.avatar,
         banner: args.banner,
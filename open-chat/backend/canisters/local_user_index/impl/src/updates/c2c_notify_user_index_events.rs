@@ -12,7 +12,10 @@ use stable_memory_map::StableMemoryMap;
 use std::cell::LazyCell;
 use std::cmp::min;
 use tracing::info;
-use types::{BotEvent, BotLifecycleEvent, BotNotification, BotRegisteredEvent, TimestampMillis, c2c_uninstall_bot};
+use types::{
+    BotEvent, BotInstallationLocation, BotLifecycleEvent, BotNotification, BotRegisteredEvent, TimestampMillis,
+    c2c_uninstall_bot,
+};
 use user_canister::{
     DiamondMembershipPaymentReceived, DisplayNameChanged, ExternalAchievementAwarded, OpenChatBotMessageV2,
     PhoneNumberConfirmed, ReferredUserRegistered, StorageUpgraded, UserJoinedCommunityOrChannel, UserJoinedGroup,
@@ -102,6 +105,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                 ev.default_subscriptions,
                 ev.permitted_install_location,
                 ev.data_encoding,
+                ev.installation_config,
             );
 
             let this_canister_id = state.env.canister_id();
@@ -125,7 +129,7 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
             state.data.bots.publish(ev.bot_id);
         }
         UserIndexEvent::BotUpdated(ev) => {
-            state.data.bots.update(ev.bot_id, ev.owner_id, ev.endpoint, ev.definition);
+            state.data.bots.update(ev);
         }
         UserIndexEvent::PlatformOperatorStatusChanged(ev) => {
             state
@@ -308,6 +312,41 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
         UserIndexEvent::UserUnblocked(user_id, unblocked) => {
             state.data.blocked_users.remove(&(user_id, unblocked));
         }
+        UserIndexEvent::BotRolloutUpdated(ev) => {
+            state.data.bots.set_staged_rollout(ev.bot_id, ev.staged_rollout);
+        }
+        UserIndexEvent::BotVersionPinned(ev) => {
+            state.data.bots.set_pinned_version(ev.bot_id, ev.location, ev.version);
+        }
+        UserIndexEvent::BotVersionRequiresPermissions(ev) => {
+            // Raise a permissions request with the installation so that its owners are prompted to grant the
+            // additional permissions required by the new version
+            let justification = format!("Version {} of this bot requires additional permissions", ev.version);
+            let args = match ev.location {
+                BotInstallationLocation::Group(_) => {
+                    serialize_then_unwrap(&group_canister::c2c_bot_request_permissions::Args {
+                        bot_id: ev.bot_id,
+                        command_permissions: ev.command_permissions,
+                        autonomous_permissions: ev.autonomous_permissions,
+                        justification,
+                    })
+                }
+                BotInstallationLocation::Community(_) => {
+                    serialize_then_unwrap(&community_canister::c2c_bot_request_permissions::Args {
+                        bot_id: ev.bot_id,
+                        command_permissions: ev.command_permissions,
+                        autonomous_permissions: ev.autonomous_permissions,
+                        justification,
+                    })
+                }
+                BotInstallationLocation::User(_) => return,
+            };
+            state.data.fire_and_forget_handler.send(
+                ev.location.canister_id(),
+                "c2c_bot_request_permissions_msgpack".to_string(),
+                args,
+            );
+        }
     }
 }
 
@@ -1,10 +1,11 @@
+use crate::model::bots_map::Bot;
 use crate::{RuntimeState, UserIndexEvent, guards::caller_is_openchat_user, mutate_state, read_state};
 use canister_api_macros::update;
 use canister_client::generate_c2c_call;
 use canister_tracing_macros::trace;
 use local_user_index_canister::install_bot::*;
 use oc_error_codes::{OCError, OCErrorCode};
-use types::{BotRegistrationStatus, BotSubscriptions, OCResult, UserId, c2c_install_bot};
+use types::{BotCommandArg, BotRegistrationStatus, BotSubscriptions, OCResult, UserId, c2c_install_bot};
 
 #[update(guard = "caller_is_openchat_user", msgpack = true)]
 #[trace]
@@ -16,6 +17,7 @@ async fn install_bot_impl(args: Args) -> OCResult {
     let PrepareResult {
         user_id,
         default_subscriptions,
+        config,
     } = read_state(|state| prepare(&args, state))?;
 
     let response = c2c_install_bot(
@@ -26,6 +28,7 @@ async fn install_bot_impl(args: Args) -> OCResult {
             granted_permissions: args.granted_permissions,
             granted_autonomous_permissions: args.granted_autonomous_permissions,
             default_subscriptions,
+            config,
         },
     )
     .await?;
@@ -51,6 +54,7 @@ async fn install_bot_impl(args: Args) -> OCResult {
 struct PrepareResult {
     user_id: UserId,
     default_subscriptions: Option<BotSubscriptions>,
+    config: Vec<BotCommandArg>,
 }
 
 fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, OCError> {
@@ -67,10 +71,23 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, OCError>
         }
     }
 
+    let config = resolve_config(bot, args.config.clone().unwrap_or_default())?;
+
     Ok(PrepareResult {
         user_id: user.user_id,
         default_subscriptions: bot.default_subscriptions.clone(),
+        config,
     })
 }
 
+pub(crate) fn resolve_config(bot: &Bot, values: Vec<BotCommandArg>) -> Result<Vec<BotCommandArg>, OCError> {
+    match bot.installation_config.as_ref() {
+        Some(schema) => schema
+            .resolve(values)
+            .map_err(|error| OCErrorCode::InvalidBotConfig.with_message(error)),
+        None if values.is_empty() => Ok(Vec::new()),
+        None => Err(OCErrorCode::InvalidBotConfig.with_message("bot does not accept any config")),
+    }
+}
+
 generate_c2c_call!(c2c_install_bot);
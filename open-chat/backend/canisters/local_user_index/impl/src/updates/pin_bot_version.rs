@@ -0,0 +1,62 @@
+use crate::{RuntimeState, UserIndexEvent, guards::caller_is_openchat_user, mutate_state, read_state};
+use canister_api_macros::update;
+use canister_client::generate_c2c_call;
+use canister_tracing_macros::trace;
+use local_user_index_canister::pin_bot_version::*;
+use oc_error_codes::{OCError, OCErrorCode};
+use types::{OCResult, UserId, c2c_set_bot_version};
+
+#[update(guard = "caller_is_openchat_user", msgpack = true)]
+#[trace]
+async fn pin_bot_version(args: Args) -> Response {
+    pin_bot_version_impl(args).await.into()
+}
+
+async fn pin_bot_version_impl(args: Args) -> OCResult {
+    let user_id = read_state(|state| prepare(&args, state))?;
+
+    let response = c2c_set_bot_version(
+        args.location.canister_id(),
+        &c2c_set_bot_version::Args {
+            bot_id: args.bot_id,
+            caller: user_id,
+            pinned_version: args.version,
+        },
+    )
+    .await?;
+
+    if let Response::Error(error) = response {
+        return Err(error);
+    }
+
+    mutate_state(|state| {
+        state.data.bots.set_pinned_version(args.bot_id, args.location, args.version);
+
+        state.push_event_to_user_index(
+            UserIndexEvent::BotVersionPinned(Box::new(user_index_canister::BotVersionPinned {
+                bot_id: args.bot_id,
+                location: args.location,
+                version: args.version,
+            })),
+            state.env.now(),
+        );
+    });
+
+    Ok(())
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<UserId, OCError> {
+    let caller = state.env.caller();
+    let user = state.data.global_users.get(&caller).unwrap();
+    let bot = state.data.bots.get(&args.bot_id).ok_or(OCErrorCode::BotNotFound)?;
+
+    if let Some(version) = args.version {
+        if !bot.has_version(version) {
+            return Err(OCErrorCode::BotVersionNotFound.into());
+        }
+    }
+
+    Ok(user.user_id)
+}
+
+generate_c2c_call!(c2c_set_bot_version);
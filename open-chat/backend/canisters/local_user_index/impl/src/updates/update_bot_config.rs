@@ -0,0 +1,45 @@
+use crate::updates::install_bot::resolve_config;
+use crate::{RuntimeState, guards::caller_is_openchat_user, read_state};
+use canister_api_macros::update;
+use canister_client::generate_c2c_call;
+use canister_tracing_macros::trace;
+use local_user_index_canister::update_bot_config::*;
+use oc_error_codes::{OCError, OCErrorCode};
+use types::{BotCommandArg, OCResult, UserId, c2c_update_bot_config};
+
+#[update(guard = "caller_is_openchat_user", msgpack = true)]
+#[trace]
+async fn update_bot_config(args: Args) -> Response {
+    update_bot_config_impl(args).await.into()
+}
+
+async fn update_bot_config_impl(args: Args) -> OCResult {
+    let (user_id, config) = read_state(|state| prepare(&args, state))?;
+
+    let response = c2c_update_bot_config(
+        args.location.canister_id(),
+        &c2c_update_bot_config::Args {
+            bot_id: args.bot_id,
+            caller: user_id,
+            config,
+        },
+    )
+    .await?;
+
+    if let Response::Error(error) = response {
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<(UserId, Vec<BotCommandArg>), OCError> {
+    let caller = state.env.caller();
+    let user = state.data.global_users.get(&caller).unwrap();
+    let bot = state.data.bots.get(&args.bot_id).ok_or(OCErrorCode::BotNotFound)?;
+    let config = resolve_config(bot, args.config.clone())?;
+
+    Ok((user.user_id, config))
+}
+
+generate_c2c_call!(c2c_update_bot_config);
@@ -94,6 +94,7 @@ async fn push_group_proposal(governance_canister_id: CanisterId, group_id: ChatI
             governance_canister_id,
             proposal: proposal.clone(),
             votes: BTreeMap::new(),
+            delegate_vote: None,
         }),
         sender_name: "ProposalsBot".to_string(),
         sender_display_name: None,
@@ -129,6 +130,7 @@ async fn push_channel_proposal(
             governance_canister_id,
             proposal: proposal.clone(),
             votes: BTreeMap::new(),
+            delegate_vote: None,
         }),
         sender_name: "ProposalsBot".to_string(),
         sender_display_name: None,
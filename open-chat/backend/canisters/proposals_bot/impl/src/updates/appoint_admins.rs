@@ -27,6 +27,7 @@ async fn appoint_group_admin(group_id: ChatId, user_id: UserId) {
     let args = group_canister::change_role::Args {
         user_id,
         new_role: GroupRole::Admin,
+        expires_at: None,
     };
 
     let _ = group_canister_c2c_client::change_role(group_id.into(), &args).await;
@@ -37,6 +38,7 @@ async fn appoint_channel_admin(community_id: CommunityId, channel_id: ChannelId,
         channel_id,
         user_id,
         new_role: GroupRole::Admin,
+        expires_at: None,
     };
 
     let _ = community_canister_c2c_client::change_channel_role(community_id.into(), &args).await;
@@ -359,6 +359,38 @@ pub struct Referral {
     pub status: ReferralStatus,
 }
 
+#[ts_export(user)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MessageRecurrence {
+    pub schedule: RecurrenceSchedule,
+    // The message is not sent again after this time
+    pub until: Option<TimestampMillis>,
+}
+
+#[ts_export(user)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub enum RecurrenceSchedule {
+    // The message is sent at `send_at` and then repeatedly after this interval
+    Interval(Milliseconds),
+    // A 5 field cron expression ("minute hour day-of-month month day-of-week") evaluated in UTC, eg. "0 9 * * 1" for
+    // 09:00 every Monday. The message is first sent at the first matching time at or after `send_at`. Fields can be
+    // `*`, numbers, ranges, lists or steps, but not names such as `MON`. The minute field must be a single value.
+    Cron(String),
+}
+
+#[ts_export(user)]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ScheduledMessage {
+    pub id: u64,
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub content: MessageContent,
+    pub recurrence: Option<MessageRecurrence>,
+    pub next_send_at: TimestampMillis,
+    pub created: TimestampMillis,
+    pub times_sent: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Referrals {
     pub referred_by: Option<UserId>,
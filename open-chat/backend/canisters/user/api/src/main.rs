@@ -22,6 +22,7 @@ fn main() {
     generate_ts_method!(user, public_profile);
     generate_ts_method!(user, search_messages);
     generate_ts_method!(user, saved_crypto_accounts);
+    generate_ts_method!(user, scheduled_messages);
     generate_ts_method!(user, token_swap_status);
     generate_ts_method!(user, token_swaps);
     generate_ts_method!(user, updates);
@@ -34,6 +35,7 @@ fn main() {
     generate_ts_method!(user, block_user);
     generate_ts_method!(user, cancel_message_reminder);
     generate_ts_method!(user, cancel_p2p_swap);
+    generate_ts_method!(user, cancel_scheduled_message);
     generate_ts_method!(user, claim_daily_chit);
     generate_ts_method!(user, configure_wallet);
     generate_ts_method!(user, create_community);
@@ -58,6 +60,7 @@ fn main() {
     generate_ts_method!(user, reclaim_swap_tokens);
     generate_ts_method!(user, report_message);
     generate_ts_method!(user, save_crypto_account);
+    generate_ts_method!(user, schedule_message);
     generate_ts_method!(user, send_message_with_transfer_to_channel);
     generate_ts_method!(user, send_message_with_transfer_to_group);
     generate_ts_method!(user, send_message_v2);
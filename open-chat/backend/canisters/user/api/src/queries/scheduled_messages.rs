@@ -0,0 +1,13 @@
+use crate::ScheduledMessage;
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::Empty;
+
+pub type Args = Empty;
+
+#[ts_export(user, scheduled_messages)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(Vec<ScheduledMessage>),
+}
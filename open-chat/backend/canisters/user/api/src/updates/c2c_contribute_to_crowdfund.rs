@@ -0,0 +1,18 @@
+use candid::CandidType;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use types::{PinNumberWrapper, TokenInfo};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub payout_id: u32,
+    pub token: TokenInfo,
+    pub amount: u128,
+    pub pin: Option<PinNumberWrapper>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(u64),
+    Error(OCError),
+}
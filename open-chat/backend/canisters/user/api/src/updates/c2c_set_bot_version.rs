@@ -0,0 +1,2 @@
+pub type Args = types::c2c_set_bot_version::Args;
+pub type Response = types::c2c_set_bot_version::Response;
@@ -0,0 +1,2 @@
+pub type Args = types::c2c_update_bot_config::Args;
+pub type Response = types::c2c_update_bot_config::Response;
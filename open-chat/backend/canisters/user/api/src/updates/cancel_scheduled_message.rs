@@ -0,0 +1,17 @@
+use candid::CandidType;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export(user, cancel_scheduled_message)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub scheduled_message_id: u64,
+}
+
+#[ts_export(user, cancel_scheduled_message)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    Error(OCError),
+}
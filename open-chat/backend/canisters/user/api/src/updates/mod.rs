@@ -9,6 +9,7 @@ pub mod c2c_bot_add_reaction;
 pub mod c2c_bot_send_message;
 pub mod c2c_charge_user_account;
 pub mod c2c_community_canister;
+pub mod c2c_contribute_to_crowdfund;
 pub mod c2c_grant_super_admin;
 pub mod c2c_group_canister;
 pub mod c2c_handle_bot_messages;
@@ -20,13 +21,16 @@ pub mod c2c_notify_group_deleted;
 pub mod c2c_remove_from_community;
 pub mod c2c_remove_from_group;
 pub mod c2c_revoke_super_admin;
+pub mod c2c_set_bot_version;
 pub mod c2c_set_user_suspended;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_bot_config;
 pub mod c2c_user_canister;
 pub mod c2c_vote_on_proposal;
 pub mod c2c_withdraw_from_icpswap;
 pub mod cancel_message_reminder;
 pub mod cancel_p2p_swap;
+pub mod cancel_scheduled_message;
 pub mod claim_daily_chit;
 pub mod configure_wallet;
 pub mod create_community;
@@ -52,6 +56,7 @@ pub mod reclaim_swap_tokens;
 pub mod remove_reaction;
 pub mod report_message;
 pub mod save_crypto_account;
+pub mod schedule_message;
 pub mod send_message_v2;
 pub mod send_message_with_transfer_to_channel;
 pub mod send_message_with_transfer_to_group;
@@ -0,0 +1,25 @@
+use crate::MessageRecurrence;
+use candid::CandidType;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{Chat, MessageContentInitial, MessageIndex, TimestampMillis, User};
+
+#[ts_export(user, schedule_message)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub content: MessageContentInitial,
+    pub mentioned: Vec<User>,
+    pub block_level_markdown: bool,
+    pub send_at: TimestampMillis,
+    pub recurrence: Option<MessageRecurrence>,
+}
+
+#[ts_export(user, schedule_message)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(u64),
+    Error(OCError),
+}
@@ -13,6 +13,7 @@ generate_c2c_call!(c2c_bot_send_message);
 generate_c2c_call!(c2c_accept_p2p_swap);
 generate_c2c_call!(c2c_charge_user_account);
 generate_c2c_call!(c2c_community_canister, 300);
+generate_c2c_call!(c2c_contribute_to_crowdfund);
 generate_c2c_call!(c2c_grant_super_admin);
 generate_c2c_call!(c2c_group_canister, 300);
 generate_candid_c2c_call!(c2c_handle_bot_messages);
@@ -25,6 +26,8 @@ generate_c2c_call!(c2c_remove_from_group);
 generate_c2c_call!(c2c_revoke_super_admin);
 generate_c2c_call!(c2c_set_user_suspended);
 generate_c2c_call!(c2c_uninstall_bot);
+generate_c2c_call!(c2c_set_bot_version);
+generate_c2c_call!(c2c_update_bot_config);
 generate_c2c_call!(c2c_user_canister, 300);
 generate_c2c_call!(c2c_vote_on_proposal);
 generate_c2c_call!(c2c_withdraw_from_icpswap);
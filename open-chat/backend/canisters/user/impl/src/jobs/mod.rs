@@ -1,7 +1,9 @@
 use crate::RuntimeState;
 
+pub mod backfill_search_index;
 pub mod garbage_collect_stable_memory;
 
 pub(crate) fn start(state: &RuntimeState) {
+    backfill_search_index::start_job_if_required(state);
     garbage_collect_stable_memory::start_job_if_required(state);
 }
@@ -7,6 +7,7 @@ use crate::model::hot_group_exclusions::HotGroupExclusions;
 use crate::model::local_user_index_event_batch::LocalUserIndexEventBatch;
 use crate::model::p2p_swaps::P2PSwaps;
 use crate::model::pin_number::PinNumber;
+use crate::model::scheduled_messages::ScheduledMessages;
 use crate::model::token_swaps::TokenSwaps;
 use crate::model::user_canister_event_batch::UserCanisterEventBatch;
 use crate::timer_job_types::{ClaimOrResetStreakInsuranceJob, DeleteFileReferencesJob, RemoveExpiredEventsJob, TimerJob};
@@ -31,7 +32,7 @@ use rand::RngCore;
 use rand::prelude::StdRng;
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
-use stable_memory_map::{BaseKeyPrefix, ChatEventKeyPrefix};
+use stable_memory_map::{BaseKeyPrefix, ChatEventKeyPrefix, MessageRevisionKeyPrefix, SearchIndexKeyPrefix};
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashSet};
 use std::ops::Deref;
@@ -39,7 +40,7 @@ use timer_job_queues::{BatchedTimerJobQueue, GroupedTimerJobQueue};
 use types::{
     Achievement, BotInitiator, BotNotification, BotPermissions, BuildVersion, CanisterId, Chat, ChatId, ChatMetrics,
     ChitEarned, ChitEarnedReason, CommunityId, Cycles, Document, FcmData, IdempotentEnvelope, Milliseconds, Notification,
-    NotifyChit, TimestampMillis, Timestamped, UniquePersonProof, UserCanisterStreakInsuranceClaim,
+    NotifyChit, OCResult, TimestampMillis, Timestamped, UniquePersonProof, UserCanisterStreakInsuranceClaim,
     UserCanisterStreakInsurancePayment, UserId, UserNotification, UserNotificationPayload,
 };
 use user_canister::{MessageActivityEvent, NamedAccount, UserCanisterEvent, WalletConfig};
@@ -406,6 +407,14 @@ Your streak is now {new_streak} days and you have {days_remaining_text} of strea
             ));
         }
 
+        self.data
+            .stable_memory_keys_to_garbage_collect
+            .push(BaseKeyPrefix::from(SearchIndexKeyPrefix::new_from_direct_chat(user_id)));
+
+        self.data
+            .stable_memory_keys_to_garbage_collect
+            .push(BaseKeyPrefix::from(MessageRevisionKeyPrefix::new_from_direct_chat(user_id)));
+
         jobs::garbage_collect_stable_memory::start_job_if_required(self);
         true
     }
@@ -463,6 +472,8 @@ struct Data {
     pub local_user_index_event_sync_queue: BatchedTimerJobQueue<LocalUserIndexEventBatch>,
     pub idempotency_checker: IdempotencyChecker,
     pub bots: InstalledBots,
+    #[serde(default)]
+    pub scheduled_messages: ScheduledMessages,
 }
 
 impl Data {
@@ -530,6 +541,7 @@ impl Data {
             local_user_index_event_sync_queue: BatchedTimerJobQueue::new(local_user_index_canister_id, true),
             idempotency_checker: IdempotencyChecker::default(),
             bots: InstalledBots::default(),
+            scheduled_messages: ScheduledMessages::default(),
         }
     }
 
@@ -589,23 +601,19 @@ impl Data {
         }
     }
 
-    pub fn is_bot_permitted(&self, bot_id: &UserId, initiator: &BotInitiator, required: BotPermissions) -> bool {
+    pub fn verify_bot_permitted(&self, bot_id: &UserId, initiator: &BotInitiator, required: BotPermissions) -> OCResult {
         // Try to get the installed bot
         let Some(bot) = self.bots.get(bot_id) else {
-            return false;
-        };
-
-        // Get the granted permissions when initiated by command or API key
-        let granted = match initiator {
-            BotInitiator::Command(_) => &bot.permissions,
-            BotInitiator::Autonomous => match bot.autonomous_permissions.as_ref() {
-                Some(permissions) => permissions,
-                None => return false,
-            },
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
         };
 
         // The permissions required must be a subset of the permissions granted to the bot
-        required.is_subset(granted)
+        let missing = bot.missing_permissions(initiator, &required);
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(OCErrorCode::BotPermissionsMissing.with_json(&missing))
+        }
     }
 
     pub fn flush_pending_events(&mut self) {
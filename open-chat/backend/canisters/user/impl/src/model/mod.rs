@@ -13,6 +13,7 @@ pub mod message_activity_events;
 pub mod p2p_swaps;
 pub mod pin_number;
 pub mod referrals;
+pub mod scheduled_messages;
 pub mod streak;
 pub mod token_swaps;
 pub mod unread_message_index_map;
@@ -0,0 +1,90 @@
+use chat_events::MessageContentInternal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use types::{Chat, MessageIndex, TimestampMillis, User};
+use user_canister::{MessageRecurrence, RecurrenceSchedule, ScheduledMessage};
+use utils::cron::CronSchedule;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ScheduledMessages {
+    messages: BTreeMap<u64, ScheduledMessageInternal>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ScheduledMessageInternal {
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub content: MessageContentInternal,
+    pub mentioned: Vec<User>,
+    pub block_level_markdown: bool,
+    pub recurrence: Option<MessageRecurrence>,
+    pub next_send_at: TimestampMillis,
+    pub created: TimestampMillis,
+    pub times_sent: u32,
+}
+
+impl ScheduledMessages {
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn add(&mut self, id: u64, message: ScheduledMessageInternal) {
+        self.messages.insert(id, message);
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<ScheduledMessageInternal> {
+        self.messages.remove(&id)
+    }
+
+    // Returns the message to be sent along with when it is next due, removing it if it won't recur
+    pub fn take_due(&mut self, id: u64, now: TimestampMillis) -> Option<(ScheduledMessageInternal, Option<TimestampMillis>)> {
+        let message = self.messages.get_mut(&id)?;
+        if message.next_send_at > now {
+            return None;
+        }
+
+        message.times_sent += 1;
+
+        let next = message
+            .recurrence
+            .as_ref()
+            .and_then(|r| next_occurrence(r, message.next_send_at, now));
+
+        if let Some(next) = next {
+            message.next_send_at = next;
+            Some((message.clone(), Some(next)))
+        } else {
+            self.messages.remove(&id).map(|m| (m, None))
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ScheduledMessage> + '_ {
+        self.messages.iter().map(|(id, m)| ScheduledMessage {
+            id: *id,
+            chat: m.chat,
+            thread_root_message_index: m.thread_root_message_index,
+            content: m.content.clone().hydrate(None),
+            recurrence: m.recurrence.clone(),
+            next_send_at: m.next_send_at,
+            created: m.created,
+            times_sent: m.times_sent,
+        })
+    }
+}
+
+// Any occurrences which were missed are skipped, rather than sending them all at once
+fn next_occurrence(recurrence: &MessageRecurrence, previous: TimestampMillis, now: TimestampMillis) -> Option<TimestampMillis> {
+    let next = match &recurrence.schedule {
+        RecurrenceSchedule::Interval(interval) => {
+            let mut next = previous + interval;
+            while next <= now {
+                next += interval;
+            }
+            next
+        }
+        RecurrenceSchedule::Cron(expression) => CronSchedule::from_str(expression).ok()?.next_after(now)?,
+    };
+
+    if recurrence.until.is_some_and(|until| next > until) { None } else { Some(next) }
+}
@@ -14,13 +14,11 @@ fn c2c_bot_chat_summary(args: Args) -> Response {
 }
 
 fn c2c_bot_chat_summary_impl(args: Args, state: &RuntimeState) -> OCResult<ChatSummaryDirect> {
-    if !state.data.is_bot_permitted(
+    state.data.verify_bot_permitted(
         &args.bot_id,
         &args.initiator,
         BotPermissions::from_chat_permission(ChatPermission::ReadSummary),
-    ) {
-        return Err(OCErrorCode::InitiatorNotAuthorized.into());
-    }
+    )?;
 
     let chat = &state
         .data
@@ -43,6 +43,9 @@ fn initial_state_impl(state: &RuntimeState) -> Response {
             added_by: bot.added_by,
             permissions: bot.permissions.clone(),
             autonomous_permissions: bot.autonomous_permissions.clone(),
+            config: bot.config.clone(),
+            permissions_request: bot.permissions_request.clone(),
+            pinned_version: bot.pinned_version,
         })
         .collect();
 
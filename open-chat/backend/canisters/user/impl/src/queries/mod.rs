@@ -20,6 +20,7 @@ pub mod message_activity_feed;
 pub mod messages_by_message_index;
 pub mod public_profile;
 pub mod saved_crypto_accounts;
+pub mod scheduled_messages;
 pub mod search_messages;
 pub mod token_swap_status;
 pub mod token_swaps;
@@ -0,0 +1,13 @@
+use crate::guards::caller_is_owner;
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use user_canister::scheduled_messages::{Response::*, *};
+
+#[query(guard = "caller_is_owner", msgpack = true)]
+fn scheduled_messages(_args: Args) -> Response {
+    read_state(scheduled_messages_impl)
+}
+
+fn scheduled_messages_impl(state: &RuntimeState) -> Response {
+    Success(state.data.scheduled_messages.iter().collect())
+}
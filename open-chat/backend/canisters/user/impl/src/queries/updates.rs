@@ -174,6 +174,9 @@ fn updates_impl(updates_since: TimestampMillis, state: &RuntimeState) -> Respons
                             added_by: bot.added_by,
                             permissions: bot.permissions.clone(),
                             autonomous_permissions: bot.autonomous_permissions.clone(),
+                            config: bot.config.clone(),
+                            permissions_request: bot.permissions_request.clone(),
+                            pinned_version: bot.pinned_version,
                         });
                     }
                 }
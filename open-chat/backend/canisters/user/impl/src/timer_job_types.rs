@@ -5,9 +5,12 @@ use crate::{can_borrow_state, flush_pending_events, mutate_state, openchat_bot,
 use canister_timer_jobs::Job;
 use chat_events::{MessageContentInternal, MessageReminderContentInternal};
 use constants::{MINUTE_IN_MS, OPENCHAT_BOT_USER_ID, SECOND_IN_MS};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use tracing::error;
-use types::{BlobReference, Chat, ChatId, CommunityId, EventIndex, MessageId, MessageIndex, P2PSwapStatus, UserId};
+use types::{
+    BlobReference, Chat, ChatId, CommunityId, EventIndex, MessageId, MessageIndex, P2PSwapStatus, TimestampMillis, UserId,
+};
 use user_canister::{C2CReplyContext, UserCanisterEvent};
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -19,12 +22,14 @@ pub enum TimerJob {
     RemoveExpiredEvents(RemoveExpiredEventsJob),
     ProcessTokenSwap(Box<ProcessTokenSwapJob>),
     NotifyEscrowCanisterOfDeposit(Box<NotifyEscrowCanisterOfDepositJob>),
+    NotifyEscrowCanisterOfPayoutDeposit(Box<NotifyEscrowCanisterOfPayoutDepositJob>),
     CancelP2PSwapInEscrowCanister(Box<CancelP2PSwapInEscrowCanisterJob>),
     MarkP2PSwapExpired(Box<MarkP2PSwapExpiredJob>),
     SendMessageToGroup(Box<SendMessageToGroupJob>),
     SendMessageToChannel(Box<SendMessageToChannelJob>),
     MarkVideoCallEnded(MarkVideoCallEndedJob),
     ClaimOrResetStreakInsurance(ClaimOrResetStreakInsuranceJob),
+    SendScheduledMessage(SendScheduledMessageJob),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -78,6 +83,24 @@ impl NotifyEscrowCanisterOfDepositJob {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NotifyEscrowCanisterOfPayoutDepositJob {
+    pub payout_id: u32,
+    pub deadline: TimestampMillis,
+    pub attempt: u32,
+}
+
+impl NotifyEscrowCanisterOfPayoutDepositJob {
+    pub fn run(payout_id: u32, deadline: TimestampMillis) {
+        let job = NotifyEscrowCanisterOfPayoutDepositJob {
+            payout_id,
+            deadline,
+            attempt: 0,
+        };
+        job.execute();
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct CancelP2PSwapInEscrowCanisterJob {
     pub swap_id: u32,
@@ -120,6 +143,11 @@ pub struct MarkVideoCallEndedJob(pub user_canister::end_video_call_v2::Args);
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ClaimOrResetStreakInsuranceJob;
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SendScheduledMessageJob {
+    pub scheduled_message_id: u64,
+}
+
 impl Job for TimerJob {
     fn execute(self) {
         let can_borrow_state = can_borrow_state();
@@ -135,12 +163,14 @@ impl Job for TimerJob {
             TimerJob::RemoveExpiredEvents(job) => job.execute(),
             TimerJob::ProcessTokenSwap(job) => job.execute(),
             TimerJob::NotifyEscrowCanisterOfDeposit(job) => job.execute(),
+            TimerJob::NotifyEscrowCanisterOfPayoutDeposit(job) => job.execute(),
             TimerJob::CancelP2PSwapInEscrowCanister(job) => job.execute(),
             TimerJob::MarkP2PSwapExpired(job) => job.execute(),
             TimerJob::SendMessageToGroup(job) => job.execute(),
             TimerJob::SendMessageToChannel(job) => job.execute(),
             TimerJob::MarkVideoCallEnded(job) => job.execute(),
             TimerJob::ClaimOrResetStreakInsurance(job) => job.execute(),
+            TimerJob::SendScheduledMessage(job) => job.execute(),
         }
 
         if can_borrow_state {
@@ -300,6 +330,49 @@ impl Job for NotifyEscrowCanisterOfDepositJob {
     }
 }
 
+impl Job for NotifyEscrowCanisterOfPayoutDepositJob {
+    fn execute(self) {
+        let escrow_canister_id = read_state(|state| state.data.escrow_canister_id);
+
+        ic_cdk::futures::spawn(async move {
+            match escrow_canister_c2c_client::notify_payout_deposit(
+                escrow_canister_id,
+                &escrow_canister::notify_payout_deposit::Args {
+                    payout_id: self.payout_id,
+                    user_id: None,
+                },
+            )
+            .await
+            {
+                Ok(escrow_canister::notify_payout_deposit::Response::Success(_)) => {}
+                response @ (Ok(escrow_canister::notify_payout_deposit::Response::InternalError(_)) | Err(_)) => {
+                    mutate_state(|state| {
+                        let now = state.env.now();
+                        if let Some(retry_at) =
+                            escrow_canister::payout_deposit_notification_retry_at(self.attempt, self.deadline, now)
+                        {
+                            state.data.timer_jobs.enqueue_job(
+                                TimerJob::NotifyEscrowCanisterOfPayoutDeposit(Box::new(
+                                    NotifyEscrowCanisterOfPayoutDepositJob {
+                                        payout_id: self.payout_id,
+                                        deadline: self.deadline,
+                                        attempt: self.attempt + 1,
+                                    },
+                                )),
+                                retry_at,
+                                now,
+                            );
+                        } else {
+                            error!(?response, "Failed to notify escrow canister of payout deposit");
+                        }
+                    });
+                }
+                response => error!(?response, "Failed to notify escrow canister of payout deposit"),
+            };
+        })
+    }
+}
+
 impl Job for CancelP2PSwapInEscrowCanisterJob {
     fn execute(self) {
         let escrow_canister_id = read_state(|state| state.data.escrow_canister_id);
@@ -419,3 +492,86 @@ impl Job for ClaimOrResetStreakInsuranceJob {
         });
     }
 }
+
+impl Job for SendScheduledMessageJob {
+    fn execute(self) {
+        let send_message_job = mutate_state(|state| {
+            let now = state.env.now();
+            let (message, next_send_at) = state.data.scheduled_messages.take_due(self.scheduled_message_id, now)?;
+
+            // Stop sending the message if the user has since left the chat
+            let is_member = match message.chat {
+                Chat::Group(chat_id) => state.data.group_chats.exists(&chat_id),
+                Chat::Channel(community_id, _) => state.data.communities.exists(&community_id),
+                Chat::Direct(_) => false,
+            };
+            if !is_member {
+                state.data.scheduled_messages.remove(self.scheduled_message_id);
+                return None;
+            }
+
+            if let Some(next_send_at) = next_send_at {
+                state
+                    .data
+                    .timer_jobs
+                    .enqueue_job(TimerJob::SendScheduledMessage(self), next_send_at, now);
+            }
+
+            if state.data.suspended.value {
+                return None;
+            }
+
+            let message_id = state.env.rng().r#gen();
+            let sender_name = state.data.username.value.clone();
+            let sender_display_name = state.data.display_name.value.clone();
+
+            match message.chat {
+                Chat::Group(chat_id) => Some(TimerJob::SendMessageToGroup(Box::new(SendMessageToGroupJob {
+                    chat_id,
+                    args: group_canister::c2c_send_message::Args {
+                        thread_root_message_index: message.thread_root_message_index,
+                        message_id,
+                        content: message.content,
+                        sender_name,
+                        sender_display_name,
+                        replies_to: None,
+                        mentioned: message.mentioned,
+                        forwarding: false,
+                        block_level_markdown: message.block_level_markdown,
+                        rules_accepted: None,
+                        message_filter_failed: None,
+                    },
+                    p2p_swap_id: None,
+                    attempt: 0,
+                }))),
+                Chat::Channel(community_id, channel_id) => {
+                    Some(TimerJob::SendMessageToChannel(Box::new(SendMessageToChannelJob {
+                        community_id,
+                        args: community_canister::c2c_send_message::Args {
+                            channel_id,
+                            thread_root_message_index: message.thread_root_message_index,
+                            message_id,
+                            content: message.content,
+                            sender_name,
+                            sender_display_name,
+                            replies_to: None,
+                            mentioned: message.mentioned,
+                            forwarding: false,
+                            block_level_markdown: message.block_level_markdown,
+                            community_rules_accepted: None,
+                            channel_rules_accepted: None,
+                            message_filter_failed: None,
+                        },
+                        p2p_swap_id: None,
+                        attempt: 0,
+                    })))
+                }
+                Chat::Direct(_) => None,
+            }
+        });
+
+        if let Some(job) = send_message_job {
+            job.execute();
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::guards::caller_is_known_group_or_community_canister;
+use crate::{RuntimeState, execute_update_async, mutate_state};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use constants::{MEMO_CROWDFUND_CONTRIBUTION, NANOS_PER_MILLISECOND};
+use escrow_canister::payout_deposit_subaccount;
+use icrc_ledger_types::icrc1::account::Account;
+use icrc_ledger_types::icrc1::transfer::TransferArg;
+use oc_error_codes::OCErrorCode;
+use types::{CanisterId, OCResult, TimestampMillis, UserId};
+use user_canister::c2c_contribute_to_crowdfund::{Response::*, *};
+
+#[update(guard = "caller_is_known_group_or_community_canister", msgpack = true)]
+#[trace]
+async fn c2c_contribute_to_crowdfund(args: Args) -> Response {
+    execute_update_async(|| c2c_contribute_to_crowdfund_impl(args)).await
+}
+
+async fn c2c_contribute_to_crowdfund_impl(args: Args) -> Response {
+    let PrepareResult {
+        my_user_id,
+        escrow_canister_id,
+        now,
+    } = match mutate_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return Error(response),
+    };
+
+    // The escrow canister pays a fee to sweep the deposit into the payout's pool, so that is added on top
+    match icrc_ledger_canister_c2c_client::icrc1_transfer(
+        args.token.ledger,
+        &TransferArg {
+            from_subaccount: None,
+            to: Account {
+                owner: escrow_canister_id,
+                subaccount: Some(payout_deposit_subaccount(my_user_id, args.payout_id)),
+            },
+            fee: Some(args.token.fee.into()),
+            created_at_time: Some(now * NANOS_PER_MILLISECOND),
+            memo: Some(MEMO_CROWDFUND_CONTRIBUTION.to_vec().into()),
+            amount: (args.amount + args.token.fee).into(),
+        },
+    )
+    .await
+    {
+        Ok(Ok(index_nat)) => Success(index_nat.0.try_into().unwrap()),
+        Ok(Err(error)) => Error(OCErrorCode::TransferFailed.with_json(&error)),
+        Err(error) => Error(error.into()),
+    }
+}
+
+struct PrepareResult {
+    my_user_id: UserId,
+    escrow_canister_id: CanisterId,
+    now: TimestampMillis,
+}
+
+fn prepare(args: &Args, state: &mut RuntimeState) -> OCResult<PrepareResult> {
+    let now = state.env.now();
+    state.data.verify_not_suspended()?;
+    state.data.pin_number.verify(args.pin.as_deref(), now)?;
+
+    Ok(PrepareResult {
+        my_user_id: state.env.canister_id().into(),
+        escrow_canister_id: state.data.escrow_canister_id,
+        now,
+    })
+}
@@ -33,6 +33,7 @@ fn c2c_install_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
         args.granted_permissions.clone(),
         args.granted_autonomous_permissions.clone(),
         args.default_subscriptions.clone(),
+        args.config.clone(),
         now,
     ) {
         return Err(OCErrorCode::AlreadyAdded.into());
@@ -66,6 +67,7 @@ fn c2c_install_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
             api_gateway: state.data.local_user_index_canister_id,
             granted_command_permissions: args.granted_permissions,
             granted_autonomous_permissions: args.granted_autonomous_permissions.unwrap_or_default(),
+            config: args.config,
         })),
         recipients: vec![args.bot_id],
         timestamp: now,
@@ -0,0 +1,29 @@
+use crate::guards::caller_is_local_user_index;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use oc_error_codes::OCErrorCode;
+use types::OCResult;
+use types::c2c_set_bot_version::*;
+
+#[update(guard = "caller_is_local_user_index", msgpack = true)]
+#[trace]
+fn c2c_set_bot_version(args: Args) -> Response {
+    execute_update(|state| c2c_set_bot_version_impl(args, state)).into()
+}
+
+fn c2c_set_bot_version_impl(args: Args, state: &mut RuntimeState) -> OCResult {
+    if args.caller != state.env.canister_id().into() {
+        return Err(OCErrorCode::InitiatorNotAuthorized.into());
+    };
+
+    state.data.verify_not_suspended()?;
+
+    let now = state.env.now();
+
+    if !state.data.bots.set_pinned_version(args.bot_id, args.pinned_version, now) {
+        return Err(OCErrorCode::BotNotFound.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::guards::caller_is_owner;
+use crate::timer_job_types::TimerJob;
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use oc_error_codes::OCErrorCode;
+use types::OCResult;
+use user_canister::cancel_scheduled_message::{Response::*, *};
+
+#[update(guard = "caller_is_owner", msgpack = true)]
+#[trace]
+fn cancel_scheduled_message(args: Args) -> Response {
+    match execute_update(|state| cancel_scheduled_message_impl(args.scheduled_message_id, state)) {
+        Ok(()) => Success,
+        Err(error) => Error(error),
+    }
+}
+
+fn cancel_scheduled_message_impl(scheduled_message_id: u64, state: &mut RuntimeState) -> OCResult {
+    if state.data.scheduled_messages.remove(scheduled_message_id).is_none() {
+        return Err(OCErrorCode::ScheduledMessageNotFound.into());
+    }
+
+    state.data.timer_jobs.cancel_job(|j| {
+        if let TimerJob::SendScheduledMessage(job) = j {
+            job.scheduled_message_id == scheduled_message_id
+        } else {
+            false
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::guards::caller_is_owner;
+use crate::model::scheduled_messages::ScheduledMessageInternal;
+use crate::timer_job_types::{SendScheduledMessageJob, TimerJob};
+use crate::{RuntimeState, execute_update};
+use canister_api_macros::update;
+use canister_tracing_macros::trace;
+use chat_events::{MessageContentInternal, ValidateNewMessageContentResult};
+use constants::{DAY_IN_MS, HOUR_IN_MS};
+use oc_error_codes::OCErrorCode;
+use rand::RngCore;
+use std::str::FromStr;
+use types::{Chat, MessageContentInitial, Milliseconds, OCResult, UserType};
+use user_canister::RecurrenceSchedule;
+use user_canister::schedule_message::{Response::*, *};
+use utils::cron::CronSchedule;
+
+const MAX_SCHEDULED_MESSAGES: usize = 100;
+const MIN_RECURRENCE_INTERVAL: Milliseconds = HOUR_IN_MS;
+const MAX_RECURRENCE_INTERVAL: Milliseconds = 365 * DAY_IN_MS;
+
+#[update(guard = "caller_is_owner", msgpack = true)]
+#[trace]
+fn schedule_message(args: Args) -> Response {
+    match execute_update(|state| schedule_message_impl(args, state)) {
+        Ok(scheduled_message_id) => Success(scheduled_message_id),
+        Err(error) => Error(error),
+    }
+}
+
+fn schedule_message_impl(args: Args, state: &mut RuntimeState) -> OCResult<u64> {
+    state.data.verify_not_suspended()?;
+
+    let now = state.env.now();
+    if args.send_at <= now {
+        return Err(OCErrorCode::DateInThePast.into());
+    }
+
+    match args.chat {
+        Chat::Group(chat_id) if state.data.group_chats.exists(&chat_id) => {}
+        Chat::Channel(community_id, _) if state.data.communities.exists(&community_id) => {}
+        Chat::Direct(_) => {
+            return Err(OCErrorCode::InvalidRequest.with_message("Messages can only be scheduled in groups and channels"));
+        }
+        _ => return Err(OCErrorCode::ChatNotFound.into()),
+    }
+
+    let send_at = match args.recurrence.as_ref().map(|r| &r.schedule) {
+        Some(RecurrenceSchedule::Interval(interval)) if *interval < MIN_RECURRENCE_INTERVAL => {
+            return Err(OCErrorCode::InvalidRequest.with_message("Recurrence interval must be at least 1 hour"));
+        }
+        Some(RecurrenceSchedule::Interval(interval)) if *interval > MAX_RECURRENCE_INTERVAL => {
+            return Err(OCErrorCode::InvalidRequest.with_message("Recurrence interval must be at most 1 year"));
+        }
+        Some(RecurrenceSchedule::Cron(expression)) => {
+            let schedule =
+                CronSchedule::from_str(expression).map_err(|error| OCErrorCode::InvalidRequest.with_message(error))?;
+            if !schedule.is_at_most_hourly() {
+                return Err(OCErrorCode::InvalidRequest.with_message("Cron schedules must specify a single minute"));
+            }
+            schedule
+                .next_after(args.send_at - 1)
+                .ok_or_else(|| OCErrorCode::InvalidRequest.with_message("Cron schedule never matches"))?
+        }
+        _ => args.send_at,
+    };
+
+    if args
+        .recurrence
+        .as_ref()
+        .is_some_and(|r| r.until.is_some_and(|until| until <= send_at))
+    {
+        return Err(OCErrorCode::InvalidRequest.with_message("Recurrence must end after the first message is sent"));
+    }
+
+    if state.data.scheduled_messages.len() >= MAX_SCHEDULED_MESSAGES {
+        return Err(OCErrorCode::TooManyScheduledMessages.with_message(MAX_SCHEDULED_MESSAGES));
+    }
+
+    // Messages which involve a transfer or which have their own deadline can't be scheduled
+    if !matches!(
+        args.content,
+        MessageContentInitial::Text(_)
+            | MessageContentInitial::Image(_)
+            | MessageContentInitial::Video(_)
+            | MessageContentInitial::Audio(_)
+            | MessageContentInitial::File(_)
+            | MessageContentInitial::Giphy(_)
+    ) {
+        return Err(OCErrorCode::InvalidMessageType.into());
+    }
+
+    let content = match MessageContentInternal::validate_new_message(args.content, false, UserType::User, false, now) {
+        ValidateNewMessageContentResult::Success(content) => content,
+        ValidateNewMessageContentResult::Error(error) => {
+            return Err(OCErrorCode::InvalidMessageContent.with_json(&error));
+        }
+        _ => unreachable!(),
+    };
+
+    let scheduled_message_id = state.env.rng().next_u64();
+
+    state.data.scheduled_messages.add(
+        scheduled_message_id,
+        ScheduledMessageInternal {
+            chat: args.chat,
+            thread_root_message_index: args.thread_root_message_index,
+            content,
+            mentioned: args.mentioned,
+            block_level_markdown: args.block_level_markdown,
+            recurrence: args.recurrence,
+            next_send_at: send_at,
+            created: now,
+            times_sent: 0,
+        },
+    );
+
+    state.data.timer_jobs.enqueue_job(
+        TimerJob::SendScheduledMessage(SendScheduledMessageJob { scheduled_message_id }),
+        send_at,
+        now,
+    );
+
+    Ok(scheduled_message_id)
+}
@@ -129,6 +129,9 @@ async fn send_message_v2_impl(args: Args) -> Response {
                     Err(error) => return Error(error.into()),
                 }
             }
+            ValidateNewMessageContentResult::SuccessCrowdfund(_) => {
+                return Error(OCErrorCode::InvalidRequest.with_message("Crowdfunds can only be sent in groups and channels"));
+            }
             ValidateNewMessageContentResult::Error(error) => {
                 return Error(OCErrorCode::InvalidMessageContent.with_json(&error));
             }
@@ -172,12 +175,12 @@ fn c2c_bot_send_message_impl(args: c2c_bot_send_message::Args, state: &mut Runti
     let args: Args = args.into();
     let message_content: MessageContent = args.content.clone().into();
 
-    if !state.data.is_bot_permitted(
+    if let Err(error) = state.data.verify_bot_permitted(
         &bot_id,
         &bot_caller.initiator,
         BotPermissions::from_message_permission((&args.content).into()),
     ) {
-        return c2c_bot_send_message::Response::Error(OCErrorCode::InitiatorNotAuthorized.into());
+        return c2c_bot_send_message::Response::Error(error);
     }
 
     let result = match prepare(&args, true, state) {
@@ -192,7 +195,8 @@ fn c2c_bot_send_message_impl(args: c2c_bot_send_message::Args, state: &mut Runti
         ValidateNewMessageContentResult::Success(content) => content,
         ValidateNewMessageContentResult::SuccessP2PSwap(_)
         | ValidateNewMessageContentResult::SuccessCrypto(_)
-        | ValidateNewMessageContentResult::SuccessPrize(_) => unreachable!(),
+        | ValidateNewMessageContentResult::SuccessPrize(_)
+        | ValidateNewMessageContentResult::SuccessCrowdfund(_) => unreachable!(),
         ValidateNewMessageContentResult::Error(error) => {
             return c2c_bot_send_message::Response::Error(OCErrorCode::InvalidMessageContent.with_json(&error));
         }
@@ -1,12 +1,18 @@
 use crate::guards::caller_is_owner;
 use crate::model::p2p_swaps::P2PSwap;
-use crate::timer_job_types::{NotifyEscrowCanisterOfDepositJob, SendMessageToChannelJob, SendMessageToGroupJob, TimerJob};
+use crate::timer_job_types::{
+    NotifyEscrowCanisterOfDepositJob, NotifyEscrowCanisterOfPayoutDepositJob, SendMessageToChannelJob, SendMessageToGroupJob,
+    TimerJob,
+};
 use crate::{RuntimeState, execute_update_async, mutate_state, read_state};
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
-use chat_events::MessageContentInternal;
-use constants::{MEMO_MESSAGE, MEMO_P2P_SWAP_CREATE, MEMO_PRIZE, NANOS_PER_MILLISECOND, PRIZE_FEE_PERCENT, SECOND_IN_MS};
-use escrow_canister::deposit_subaccount;
+use chat_events::{CrowdfundContentInternal, MessageContentInternal};
+use constants::{
+    MEMO_CROWDFUND_CONTRIBUTION, MEMO_MESSAGE, MEMO_P2P_SWAP_CREATE, MEMO_PRIZE, NANOS_PER_MILLISECOND, PRIZE_FEE_PERCENT,
+    SECOND_IN_MS,
+};
+use escrow_canister::{CROWDFUND_SETTLEMENT_WINDOW, deposit_subaccount, payout_deposit_subaccount};
 use oc_error_codes::{OCError, OCErrorCode};
 use tracing::error;
 use types::icrc1::Account;
@@ -39,7 +45,7 @@ async fn send_message_with_transfer_to_channel_impl(
     let chat = Chat::Channel(args.community_id, args.channel_id);
 
     // Validate the content and extract the PendingCryptoTransaction
-    let (pending_transaction, p2p_swap_id) = match mutate_state(|state| {
+    let (pending_transaction, escrow_deposit) = match mutate_state(|state| {
         prepare(
             chat,
             args.thread_root_message_index,
@@ -53,20 +59,27 @@ async fn send_message_with_transfer_to_channel_impl(
         Ok(PrepareResult::Success(t)) => (t, None),
         Ok(PrepareResult::P2PSwap(escrow_canister_id, create_swap_args)) => {
             match set_up_p2p_swap(escrow_canister_id, create_swap_args).await {
-                Ok((id, t)) => (t, Some(id)),
+                Ok((id, t)) => (t, Some(EscrowDeposit::P2PSwap(id))),
                 Err(error) => return Error(error.into()),
             }
         }
+        Ok(PrepareResult::Crowdfund(escrow_canister_id, create_payout_args, initial_contribution)) => {
+            match set_up_crowdfund(escrow_canister_id, create_payout_args, initial_contribution).await {
+                Ok((id, t)) => (t, Some(EscrowDeposit::Crowdfund(id))),
+                Err(error) => return Error(error),
+            }
+        }
         Err(error) => return Error(error),
     };
 
     // Make the crypto transfer
-    let (content, completed_transaction) = match process_transaction(args.content, pending_transaction, p2p_swap_id, now).await
-    {
-        Ok(Ok((c, t))) => (c, t),
-        Ok(Err(error)) => return Error(OCErrorCode::TransferFailed.with_message(error)),
-        Err(error) => return Error(error.into()),
-    };
+    let p2p_swap_id = escrow_deposit.as_ref().and_then(|d| d.p2p_swap_id());
+    let (content, completed_transaction) =
+        match process_transaction(args.content, pending_transaction, escrow_deposit, now).await {
+            Ok(Ok((c, t))) => (c, t),
+            Ok(Err(error)) => return Error(OCErrorCode::TransferFailed.with_message(error)),
+            Err(error) => return Error(error.into()),
+        };
 
     let achievement = content.content_type().achievement();
     let has_thread = args.thread_root_message_index.is_some();
@@ -147,7 +160,7 @@ async fn send_message_with_transfer_to_group_impl(
     let chat = Chat::Group(args.group_id);
 
     // Validate the content and extract the PendingCryptoTransaction
-    let (pending_transaction, p2p_swap_id) = match mutate_state(|state| {
+    let (pending_transaction, escrow_deposit) = match mutate_state(|state| {
         prepare(
             chat,
             args.thread_root_message_index,
@@ -161,20 +174,27 @@ async fn send_message_with_transfer_to_group_impl(
         Ok(PrepareResult::Success(t)) => (t, None),
         Ok(PrepareResult::P2PSwap(escrow_canister_id, create_swap_args)) => {
             match set_up_p2p_swap(escrow_canister_id, create_swap_args).await {
-                Ok((id, t)) => (t, Some(id)),
+                Ok((id, t)) => (t, Some(EscrowDeposit::P2PSwap(id))),
                 Err(error) => return Error(error.into()),
             }
         }
+        Ok(PrepareResult::Crowdfund(escrow_canister_id, create_payout_args, initial_contribution)) => {
+            match set_up_crowdfund(escrow_canister_id, create_payout_args, initial_contribution).await {
+                Ok((id, t)) => (t, Some(EscrowDeposit::Crowdfund(id))),
+                Err(error) => return Error(error),
+            }
+        }
         Err(error) => return Error(error),
     };
 
     // Make the crypto transfer
-    let (content, completed_transaction) = match process_transaction(args.content, pending_transaction, p2p_swap_id, now).await
-    {
-        Ok(Ok((c, t))) => (c, t),
-        Ok(Err(error)) => return Error(OCErrorCode::TransferFailed.with_message(error)),
-        Err(error) => return Error(error.into()),
-    };
+    let p2p_swap_id = escrow_deposit.as_ref().and_then(|d| d.p2p_swap_id());
+    let (content, completed_transaction) =
+        match process_transaction(args.content, pending_transaction, escrow_deposit, now).await {
+            Ok(Ok((c, t))) => (c, t),
+            Ok(Err(error)) => return Error(OCErrorCode::TransferFailed.with_message(error)),
+            Err(error) => return Error(error.into()),
+        };
 
     let achievement = content.content_type().achievement();
     let has_thread = args.thread_root_message_index.is_some();
@@ -234,6 +254,18 @@ async fn send_message_with_transfer_to_group_impl(
 enum PrepareResult {
     Success(PendingCryptoTransaction),
     P2PSwap(CanisterId, escrow_canister::create_swap::Args),
+    Crowdfund(CanisterId, escrow_canister::create_payout::Args, u128),
+}
+
+enum EscrowDeposit {
+    P2PSwap(u32),
+    Crowdfund(u32),
+}
+
+impl EscrowDeposit {
+    fn p2p_swap_id(&self) -> Option<u32> {
+        if let EscrowDeposit::P2PSwap(id) = self { Some(*id) } else { None }
+    }
 }
 
 fn prepare(
@@ -317,6 +349,31 @@ fn prepare(
             };
             return Ok(P2PSwap(state.data.escrow_canister_id, create_swap_args));
         }
+        MessageContentInitial::Crowdfund(c) => {
+            if c.deadline <= now {
+                return Err(OCErrorCode::DateInThePast.into());
+            }
+            if c.initial_contribution == 0 || c.initial_contribution >= c.target {
+                return Err(OCErrorCode::InvalidRequest
+                    .with_message("Initial contribution must be greater than zero and less than the target"));
+            }
+            let chat_canister_id = chat.canister_id();
+            let create_payout_args = escrow_canister::create_payout::Args {
+                location: P2PSwapLocation::from_message(chat, thread_root_message_index, message_id),
+                token: c.token.clone(),
+                // The chat settles the payout once the crowdfund's deadline has passed, after which the funds are paid
+                // to the beneficiary if the target was reached, otherwise they are refunded to the contributors
+                release_at: Some(c.deadline),
+                deadline: c.deadline + CROWDFUND_SETTLEMENT_WINDOW,
+                additional_admins: vec![chat_canister_id],
+                canister_to_notify: Some(chat_canister_id),
+            };
+            return Ok(Crowdfund(
+                state.data.escrow_canister_id,
+                create_payout_args,
+                c.initial_contribution,
+            ));
+        }
         _ => return Err(OCErrorCode::InvalidRequest.with_message("Message must include a crypto transfer")),
     };
 
@@ -330,18 +387,26 @@ fn prepare(
 async fn process_transaction(
     content: MessageContentInitial,
     pending_transaction: PendingCryptoTransaction,
-    p2p_swap_id: Option<u32>,
+    escrow_deposit: Option<EscrowDeposit>,
     now: TimestampMillis,
 ) -> Result<Result<(MessageContentInternal, CompletedCryptoTransaction), String>, C2CError> {
     match crate::crypto::process_transaction(pending_transaction).await {
         Ok(Ok(completed)) => {
-            if let Some(id) = p2p_swap_id {
-                NotifyEscrowCanisterOfDepositJob::run(id);
-            }
-            Ok(Ok((
-                MessageContentInternal::new_with_transfer(content, completed.clone().into(), p2p_swap_id, now),
-                completed,
-            )))
+            let content = match (content, escrow_deposit) {
+                (MessageContentInitial::Crowdfund(c), Some(EscrowDeposit::Crowdfund(id))) => {
+                    NotifyEscrowCanisterOfPayoutDepositJob::run(id, c.deadline);
+                    let my_user_id = read_state(|state| state.env.canister_id().into());
+                    MessageContentInternal::Crowdfund(CrowdfundContentInternal::new(id, my_user_id, c))
+                }
+                (content, escrow_deposit) => {
+                    let p2p_swap_id = escrow_deposit.and_then(|d| d.p2p_swap_id());
+                    if let Some(id) = p2p_swap_id {
+                        NotifyEscrowCanisterOfDepositJob::run(id);
+                    }
+                    MessageContentInternal::new_with_transfer(content, completed.clone().into(), p2p_swap_id, now)
+                }
+            };
+            Ok(Ok((content, completed)))
         }
         Ok(Err(failed)) => Ok(Err(failed.error_message().to_string())),
         Err(error) => Err(error),
@@ -394,6 +459,42 @@ pub(crate) async fn set_up_p2p_swap(
     })
 }
 
+async fn set_up_crowdfund(
+    escrow_canister_id: CanisterId,
+    args: escrow_canister::create_payout::Args,
+    initial_contribution: u128,
+) -> OCResult<(u32, PendingCryptoTransaction)> {
+    let id = match escrow_canister_c2c_client::create_payout(escrow_canister_id, &args).await {
+        Ok(escrow_canister::create_payout::Response::Success(result)) => result.id,
+        Ok(escrow_canister::create_payout::Response::InvalidPayout(message)) => {
+            return Err(OCErrorCode::InvalidRequest.with_message(message));
+        }
+        Ok(escrow_canister::create_payout::Response::Error(error)) => return Err(error),
+        Err(error) => return Err(error.into()),
+    };
+
+    read_state(|state| {
+        let my_user_id = UserId::from(state.env.canister_id());
+
+        // The escrow canister pays a fee to sweep the deposit into the payout's pool, so that is added on top
+        Ok((
+            id,
+            PendingCryptoTransaction::ICRC1(icrc1::PendingCryptoTransaction {
+                ledger: args.token.ledger,
+                token_symbol: args.token.symbol.clone(),
+                amount: initial_contribution + args.token.fee,
+                to: Account {
+                    owner: state.data.escrow_canister_id,
+                    subaccount: Some(payout_deposit_subaccount(my_user_id, id)),
+                },
+                fee: args.token.fee,
+                memo: Some(MEMO_CROWDFUND_CONTRIBUTION.to_vec().into()),
+                created: state.env.now() * NANOS_PER_MILLISECOND,
+            }),
+        ))
+    })
+}
+
 pub(crate) enum SetUpP2PSwapError {
     InvalidSwap(String),
     InternalError(String),
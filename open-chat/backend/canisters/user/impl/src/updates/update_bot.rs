@@ -34,6 +34,7 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
 
     let bot = state.data.bots.get(&args.bot_id).unwrap();
     let subscriptions = bot.default_subscriptions.clone().unwrap_or_default();
+    let config = bot.config.clone();
     let permissions = args.granted_autonomous_permissions.clone().unwrap_or_default();
     let permitted_categories = permissions.permitted_chat_event_categories_to_read();
     let chat = state.data.direct_chats.get_mut(&args.bot_id.into()).unwrap();
@@ -56,6 +57,7 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> OCResult {
             api_gateway: state.data.local_user_index_canister_id,
             granted_command_permissions: args.granted_permissions,
             granted_autonomous_permissions: args.granted_autonomous_permissions.unwrap_or_default(),
+            config,
         })),
         recipients: vec![args.bot_id],
         timestamp: now,
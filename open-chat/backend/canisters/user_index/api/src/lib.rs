@@ -1,5 +1,6 @@
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use types::{
     BotInstallationLocation, CanisterId, ChannelLatestMessageIndex, ChatId, CommunityId, MessageContent, MessageContentInitial,
     MessageId, MessageIndex, NotifyChit, StreakInsuranceClaim, StreakInsurancePayment, TimestampMillis, UniquePersonProof,
@@ -32,6 +33,8 @@ pub enum LocalUserIndexEvent {
     UserUnblocked(UserId, UserId),
     SetMaxStreak(UserId, u16),
     NotifyOfUserDeleted(CanisterId, UserId),
+    BotUsageRecorded(Box<BotUsageRecorded>),
+    BotVersionPinned(Box<BotVersionPinned>),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -119,3 +122,19 @@ pub struct BotUninstalled {
     pub location: BotInstallationLocation,
     pub uninstalled_by: UserId,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BotVersionPinned {
+    pub bot_id: UserId,
+    pub location: BotInstallationLocation,
+    pub version: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BotUsageRecorded {
+    pub bot_id: UserId,
+    pub commands: BTreeMap<String, u32>,
+    pub failed_command_responses: u32,
+    pub autonomous_actions: u32,
+    pub events_delivered: u32,
+}
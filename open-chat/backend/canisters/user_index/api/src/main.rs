@@ -4,6 +4,7 @@ use ts_export::generate_ts_method;
 
 fn main() {
     generate_candid_method!(user_index, bot_updates, query);
+    generate_candid_method!(user_index, bot_usage_stats, query);
     generate_candid_method!(user_index, check_username, query);
     generate_candid_method!(user_index, chit_leaderboard, query);
     generate_candid_method!(user_index, current_user, query);
@@ -36,6 +37,7 @@ fn main() {
     }
 
     generate_ts_method!(user_index, bot_updates);
+    generate_ts_method!(user_index, bot_usage_stats);
     generate_ts_method!(user_index, check_username);
     generate_ts_method!(user_index, chit_leaderboard);
     generate_ts_method!(user_index, current_user);
@@ -1,7 +1,10 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{AutonomousConfig, BotCommandDefinition, BotRegistrationStatus, TimestampMillis, UserId};
+use types::{
+    AutonomousConfig, BotCommandDefinition, BotDefinitionVersion, BotInstallationConfig, BotRegistrationStatus,
+    BotStagedRollout, TimestampMillis, UserId,
+};
 
 #[ts_export(user_index, bot_updates)]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -37,4 +40,8 @@ pub struct BotDetails {
     pub autonomous_config: Option<AutonomousConfig>,
     pub last_updated: TimestampMillis,
     pub registration_status: BotRegistrationStatus,
+    pub installation_config: Option<BotInstallationConfig>,
+    pub version: u32,
+    pub previous_versions: Vec<BotDefinitionVersion>,
+    pub staged_rollout: Option<BotStagedRollout>,
 }
@@ -0,0 +1,45 @@
+use candid::CandidType;
+use oc_error_codes::OCError;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+use types::{TimestampMillis, UserId};
+
+#[ts_export(user_index, bot_usage_stats)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+}
+
+#[ts_export(user_index, bot_usage_stats)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(BotUsageStats),
+    Error(OCError),
+}
+
+#[ts_export(user_index, bot_usage_stats)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct BotUsageStats {
+    pub commands: Vec<CommandUsage>,
+    pub installations: u32,
+    pub installations_by_day: Vec<InstallationsOnDay>,
+    pub failed_command_responses: u64,
+    pub autonomous_actions: u64,
+    pub events_delivered: u64,
+    pub last_updated: TimestampMillis,
+}
+
+#[ts_export(user_index, bot_usage_stats)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct CommandUsage {
+    pub name: String,
+    pub count: u64,
+}
+
+#[ts_export(user_index, bot_usage_stats)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct InstallationsOnDay {
+    pub day_start: TimestampMillis,
+    pub installed: u32,
+    pub uninstalled: u32,
+}
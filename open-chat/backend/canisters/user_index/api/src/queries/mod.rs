@@ -1,4 +1,5 @@
 pub mod bot_updates;
+pub mod bot_usage_stats;
 pub mod c2c_lookup_user;
 pub mod check_username;
 pub mod chit_leaderboard;
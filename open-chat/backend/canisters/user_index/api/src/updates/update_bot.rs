@@ -2,7 +2,7 @@ use candid::{CandidType, Principal};
 use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
 use ts_export::ts_export;
-use types::{BotDefinition, OptionUpdate, UserId};
+use types::{BotDefinition, BotRollout, OptionUpdate, UserId};
 
 #[ts_export(user_index, update_bot)]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -15,6 +15,8 @@ pub struct Args {
     pub avatar: OptionUpdate<String>, // Image as a data URL
     pub endpoint: Option<String>,
     pub definition: Option<BotDefinition>,
+    #[serde(default)]
+    pub rollout: Option<BotRollout>,
 }
 
 #[ts_export(user_index, update_bot)]
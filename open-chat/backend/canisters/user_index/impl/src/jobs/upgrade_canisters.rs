@@ -49,6 +49,7 @@ struct NextCanisterToUpgrade {
     canister_id: CanisterId,
     new_wasm: CanisterWasm,
     current_wasm_version: BuildVersion,
+    airdrop_bot_canister_id: CanisterId,
 }
 
 fn try_get_next(state: &mut RuntimeState) -> GetNextResult {
@@ -84,6 +85,7 @@ fn try_get_next(state: &mut RuntimeState) -> GetNextResult {
         canister_id,
         new_wasm: new_wasm.clone(),
         current_wasm_version,
+        airdrop_bot_canister_id: state.data.airdrop_bot_canister_id,
     })
 }
 
@@ -105,6 +107,7 @@ async fn perform_upgrade(canister_to_upgrade: NextCanisterToUpgrade) {
             deposit_cycles_if_needed: false,
             args: candid::encode_one(&local_user_index_canister::post_upgrade::Args {
                 wasm_version: to_version,
+                airdrop_bot_canister_id: canister_to_upgrade.airdrop_bot_canister_id,
             })
             .unwrap(),
             mode: CanisterInstallMode::Upgrade(None),
@@ -0,0 +1,82 @@
+use constants::DAY_IN_MS;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::TimestampMillis;
+use user_index_canister::BotUsageRecorded;
+use user_index_canister::bot_usage_stats::{BotUsageStats, CommandUsage, InstallationsOnDay};
+
+const MAX_DAYS_RETAINED: u64 = 365;
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct BotUsageStatsInternal {
+    commands: BTreeMap<String, u64>,
+    installations_by_day: BTreeMap<u64, (u32, u32)>,
+    failed_command_responses: u64,
+    autonomous_actions: u64,
+    events_delivered: u64,
+    last_updated: TimestampMillis,
+}
+
+impl BotUsageStatsInternal {
+    pub fn record_usage(&mut self, usage: BotUsageRecorded, now: TimestampMillis) {
+        for (name, count) in usage.commands {
+            *self.commands.entry(name).or_default() += count as u64;
+        }
+        self.failed_command_responses += usage.failed_command_responses as u64;
+        self.autonomous_actions += usage.autonomous_actions as u64;
+        self.events_delivered += usage.events_delivered as u64;
+        self.last_updated = now;
+    }
+
+    pub fn record_installed(&mut self, now: TimestampMillis) {
+        self.day_entry(now).0 += 1;
+    }
+
+    pub fn record_uninstalled(&mut self, now: TimestampMillis) {
+        self.day_entry(now).1 += 1;
+    }
+
+    pub fn to_schema(&self, installations: u32) -> BotUsageStats {
+        let mut commands: Vec<_> = self
+            .commands
+            .iter()
+            .map(|(name, count)| CommandUsage {
+                name: name.clone(),
+                count: *count,
+            })
+            .collect();
+
+        commands.sort_unstable_by(|c1, c2| c2.count.cmp(&c1.count));
+
+        BotUsageStats {
+            commands,
+            installations,
+            installations_by_day: self
+                .installations_by_day
+                .iter()
+                .map(|(day, (installed, uninstalled))| InstallationsOnDay {
+                    day_start: day * DAY_IN_MS,
+                    installed: *installed,
+                    uninstalled: *uninstalled,
+                })
+                .collect(),
+            failed_command_responses: self.failed_command_responses,
+            autonomous_actions: self.autonomous_actions,
+            events_delivered: self.events_delivered,
+            last_updated: self.last_updated,
+        }
+    }
+
+    fn day_entry(&mut self, now: TimestampMillis) -> &mut (u32, u32) {
+        let day = now / DAY_IN_MS;
+        self.last_updated = now;
+
+        // Only keep the most recent year of daily installation counts
+        let cutoff = day.saturating_sub(MAX_DAYS_RETAINED);
+        while self.installations_by_day.first_key_value().is_some_and(|(d, _)| *d < cutoff) {
+            self.installations_by_day.pop_first();
+        }
+
+        self.installations_by_day.entry(day).or_default()
+    }
+}
@@ -1,4 +1,5 @@
 pub mod account_billing;
+pub mod bot_usage_stats;
 pub mod chit_leaderboard;
 pub mod diamond_membership_details;
 pub mod external_achievements;
@@ -16,3 +17,4 @@ pub mod user_map;
 pub const MAX_AVATAR_SIZE: usize = 250_000;
 pub const MAX_DESCRIPTION_LEN: usize = 10_000;
 pub const MAX_COMMANDS: usize = 100;
+pub const MAX_PREVIOUS_BOT_VERSIONS: usize = 10;
@@ -1,17 +1,20 @@
 use super::user::SuspensionDetails;
 use crate::DiamondMembershipUserMetrics;
+use crate::model::bot_usage_stats::BotUsageStatsInternal;
 use crate::model::diamond_membership_details::DiamondMembershipDetailsInternal;
 use crate::model::user::User;
 use candid::Principal;
 use search::weighted::{Document as SearchDocument, Query};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::ops::RangeFrom;
 use tracing::info;
 use types::{
-    AutonomousConfig, BotCommandDefinition, BotInstallationLocation, BotMatch, BotRegistrationStatus, CanisterId, CyclesTopUp,
-    Document, Milliseconds, SuspensionDuration, TimestampMillis, UniquePersonProof, UserId, UserType,
+    AutonomousConfig, BotCommandDefinition, BotDefinitionVersion, BotInstallationConfig, BotInstallationLocation, BotMatch,
+    BotRegistrationStatus, BotStagedRollout, CanisterId, CyclesTopUp, Document, Milliseconds, SuspensionDuration,
+    TimestampMillis, UniquePersonProof, UserId, UserType, resolve_bot_version,
 };
+use user_index_canister::BotUsageRecorded;
 use user_index_canister::bot_updates::BotDetails;
 use utils::case_insensitive_hash_map::CaseInsensitiveHashMap;
 use utils::time::MonthKey;
@@ -53,6 +56,18 @@ pub struct Bot {
     pub last_updated: TimestampMillis,
     pub installations: HashMap<BotInstallationLocation, InstalledBotDetails>,
     pub registration_status: BotRegistrationStatus,
+    #[serde(default)]
+    pub installation_config: Option<BotInstallationConfig>,
+    #[serde(default)]
+    pub usage_stats: BotUsageStatsInternal,
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub previous_versions: Vec<BotDefinitionVersion>,
+    #[serde(default)]
+    pub staged_rollout: Option<BotStagedRollout>,
+    #[serde(default)]
+    pub version_published_at: TimestampMillis,
 }
 
 impl Bot {
@@ -67,6 +82,7 @@ impl Bot {
             avatar_id: self.avatar.as_ref().map(|a| a.id),
             commands: self.commands.clone(),
             autonomous_config: self.autonomous_config.clone(),
+            installation_config: self.installation_config.clone(),
         }
     }
 
@@ -84,6 +100,7 @@ impl Bot {
                     local_user_index,
                     installed_by,
                     installed_at,
+                    pinned_version: None,
                 },
             )
             .is_none()
@@ -105,8 +122,43 @@ impl Bot {
             autonomous_config: self.autonomous_config.clone(),
             last_updated: self.last_updated,
             registration_status: self.registration_status.clone(),
+            installation_config: self.installation_config.clone(),
+            version: self.version,
+            previous_versions: self.previous_versions.clone(),
+            staged_rollout: self.staged_rollout.clone(),
         }
     }
+
+    pub fn current_version(&self) -> BotDefinitionVersion {
+        BotDefinitionVersion {
+            version: self.version,
+            commands: self.commands.clone(),
+            autonomous_config: self.autonomous_config.clone(),
+            published_at: self.version_published_at,
+        }
+    }
+
+    pub fn definition_version(&self, version: u32) -> Option<BotDefinitionVersion> {
+        if version == self.version {
+            Some(self.current_version())
+        } else {
+            self.previous_versions.iter().find(|v| v.version == version).cloned()
+        }
+    }
+
+    // The versions which must be retained because installations are still using them
+    pub fn versions_in_use(&self) -> HashSet<u32> {
+        self.installations
+            .values()
+            .filter_map(|i| i.pinned_version)
+            .chain(self.staged_rollout.as_ref().map(|r| r.stable_version))
+            .collect()
+    }
+
+    pub fn resolved_version(&self, location: &BotInstallationLocation) -> u32 {
+        let pinned_version = self.installations.get(location).and_then(|i| i.pinned_version);
+        resolve_bot_version(self.version, self.staged_rollout.as_ref(), pinned_version, location)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -114,6 +166,8 @@ pub struct InstalledBotDetails {
     pub local_user_index: CanisterId,
     pub installed_by: UserId,
     pub installed_at: TimestampMillis,
+    #[serde(default)]
+    pub pinned_version: Option<u32>,
 }
 
 impl UserMap {
@@ -316,20 +370,43 @@ impl UserMap {
         now: TimestampMillis,
     ) -> bool {
         if let Some(bot) = self.bots.get_mut(&bot_id) {
-            bot.add_installation(location, local_user_index, installed_by, now)
+            let added = bot.add_installation(location, local_user_index, installed_by, now);
+            if added {
+                bot.usage_stats.record_installed(now);
+            }
+            added
         } else {
             false
         }
     }
 
-    pub fn remove_bot_installation(&mut self, bot_id: UserId, location: &BotInstallationLocation) -> bool {
-        if let Some(bot) = self.bots.get_mut(&bot_id) {
-            bot.remove_installation(location).is_some()
+    pub fn remove_bot_installation(
+        &mut self,
+        bot_id: UserId,
+        location: &BotInstallationLocation,
+        now: TimestampMillis,
+    ) -> Option<InstalledBotDetails> {
+        let bot = self.bots.get_mut(&bot_id)?;
+        let removed = bot.remove_installation(location)?;
+        bot.usage_stats.record_uninstalled(now);
+        Some(removed)
+    }
+
+    pub fn set_bot_pinned_version(&mut self, bot_id: UserId, location: &BotInstallationLocation, version: Option<u32>) -> bool {
+        if let Some(installation) = self.bots.get_mut(&bot_id).and_then(|b| b.installations.get_mut(location)) {
+            installation.pinned_version = version;
+            true
         } else {
             false
         }
     }
 
+    pub fn record_bot_usage(&mut self, usage: BotUsageRecorded, now: TimestampMillis) {
+        if let Some(bot) = self.bots.get_mut(&usage.bot_id) {
+            bot.usage_stats.record_usage(usage, now);
+        }
+    }
+
     pub fn remove_bot(&mut self, bot_id: UserId, now: TimestampMillis) -> Option<Bot> {
         let bot = self.bots.remove(&bot_id)?;
         self.botname_to_user_id.remove(&bot.name);
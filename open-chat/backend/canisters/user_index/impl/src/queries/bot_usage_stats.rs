@@ -0,0 +1,26 @@
+use crate::{RuntimeState, read_state};
+use canister_api_macros::query;
+use oc_error_codes::OCErrorCode;
+use user_index_canister::bot_usage_stats::{Response::*, *};
+
+#[query(candid = true, msgpack = true)]
+fn bot_usage_stats(args: Args) -> Response {
+    read_state(|state| bot_usage_stats_impl(args, state))
+}
+
+fn bot_usage_stats_impl(args: Args, state: &RuntimeState) -> Response {
+    let Some(bot) = state.data.users.get_bot(&args.bot_id) else {
+        return Error(OCErrorCode::BotNotFound.into());
+    };
+
+    let caller = state.env.caller();
+    let Some(owner) = state.data.users.get_by_principal(&caller) else {
+        return Error(OCErrorCode::InitiatorNotFound.into());
+    };
+
+    if owner.user_id != bot.owner {
+        return Error(OCErrorCode::InitiatorNotAuthorized.into());
+    }
+
+    Success(bot.usage_stats.to_schema(bot.installations.len() as u32))
+}
@@ -92,6 +92,7 @@ fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response>
                 proposals_bot_canister_id: state.data.proposals_bot_canister_id,
                 cycles_dispenser_canister_id: state.data.cycles_dispenser_canister_id,
                 escrow_canister_id: state.data.escrow_canister_id,
+                airdrop_bot_canister_id: state.data.airdrop_bot_canister_id,
                 event_relay_canister_id: state.data.event_store_client.info().event_store_canister_id,
                 online_users_canister_id: state.data.online_users_canister_id,
                 internet_identity_canister_id: state.data.internet_identity_canister_id,
@@ -8,8 +8,8 @@ use constants::ONE_MB;
 use event_store_producer::EventBuilder;
 use group_index_canister::UserIndexEvent as GroupIndexEvent;
 use local_user_index_canister::{
-    DeleteUser, OpenChatBotMessage, OpenChatBotMessageV2, UserIndexEvent, UserJoinedCommunityOrChannel, UserJoinedGroup,
-    UserRegistered, UsernameChanged,
+    BotVersionPinned, DeleteUser, OpenChatBotMessage, OpenChatBotMessageV2, UserIndexEvent, UserJoinedCommunityOrChannel,
+    UserJoinedGroup, UserRegistered, UsernameChanged,
 };
 use rand::RngCore;
 use stable_memory_map::StableMemoryMap;
@@ -149,7 +149,33 @@ fn handle_event<F: FnOnce() -> TimestampMillis>(
                 .add_bot_installation(ev.bot_id, ev.location, caller, ev.installed_by, **now);
         }
         LocalUserIndexEvent::BotUninstalled(ev) => {
-            state.data.users.remove_bot_installation(ev.bot_id, &ev.location);
+            if let Some(installation) = state.data.users.remove_bot_installation(ev.bot_id, &ev.location, **now) {
+                if installation.pinned_version.is_some() {
+                    state.push_event_to_all_local_user_indexes(
+                        UserIndexEvent::BotVersionPinned(BotVersionPinned {
+                            bot_id: ev.bot_id,
+                            location: ev.location,
+                            version: None,
+                        }),
+                        Some(caller),
+                    );
+                }
+            }
+        }
+        LocalUserIndexEvent::BotVersionPinned(ev) => {
+            if state.data.users.set_bot_pinned_version(ev.bot_id, &ev.location, ev.version) {
+                state.push_event_to_all_local_user_indexes(
+                    UserIndexEvent::BotVersionPinned(BotVersionPinned {
+                        bot_id: ev.bot_id,
+                        location: ev.location,
+                        version: ev.version,
+                    }),
+                    Some(caller),
+                );
+            }
+        }
+        LocalUserIndexEvent::BotUsageRecorded(usage) => {
+            state.data.users.record_bot_usage(*usage, **now);
         }
         LocalUserIndexEvent::UserBlocked(user_id, blocked) => {
             state.data.blocked_users.insert((user_id, blocked), ());
@@ -1,4 +1,5 @@
 use crate::guards::caller_is_openchat_user;
+use crate::model::bot_usage_stats::BotUsageStatsInternal;
 use crate::model::user_map::Bot;
 use crate::model::{MAX_AVATAR_SIZE, MAX_COMMANDS, MAX_DESCRIPTION_LEN};
 use crate::{RuntimeState, mutate_state};
@@ -76,6 +77,12 @@ fn register_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
             avatar,
             installations: HashMap::new(),
             registration_status: BotRegistrationStatus::Private(args.permitted_install_location),
+            installation_config: args.definition.installation_config.clone(),
+            usage_stats: BotUsageStatsInternal::default(),
+            version: 0,
+            previous_versions: Vec::new(),
+            staged_rollout: None,
+            version_published_at: now,
         }),
     );
 
@@ -95,6 +102,7 @@ fn register_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
             default_subscriptions: args.definition.default_subscriptions.clone(),
             data_encoding: args.definition.data_encoding.unwrap_or_default(),
             notification_canister,
+            installation_config: args.definition.installation_config.clone(),
         }),
         None,
     );
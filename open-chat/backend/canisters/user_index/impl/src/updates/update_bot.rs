@@ -1,13 +1,17 @@
 use crate::{
     RuntimeState,
-    model::{MAX_AVATAR_SIZE, MAX_COMMANDS, MAX_DESCRIPTION_LEN, user_map::UpdateUserResult},
+    model::{
+        MAX_AVATAR_SIZE, MAX_COMMANDS, MAX_DESCRIPTION_LEN, MAX_PREVIOUS_BOT_VERSIONS,
+        user_map::{Bot, UpdateUserResult},
+    },
     mutate_state,
 };
 use candid::Principal;
 use canister_api_macros::update;
 use canister_tracing_macros::trace;
-use local_user_index_canister::{BotUpdated, UserIndexEvent};
-use types::OptionUpdate;
+use local_user_index_canister::{BotRolloutUpdated, BotUpdated, BotVersionRequiresPermissions, UserIndexEvent};
+use oc_error_codes::OCErrorCode;
+use types::{BotInstallationLocation, BotRollout, BotStagedRollout, CanisterId, OptionUpdate, UserId};
 use url::Url;
 use user_index_canister::update_bot::{Response::*, *};
 use utils::document::try_parse_data_url;
@@ -31,6 +35,7 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
         return BotNotFound;
     };
 
+    let previous = bot.clone();
     let mut bot = bot.clone();
     let mut user = user.clone();
     let now = state.env.now();
@@ -59,10 +64,63 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
         }
     };
 
-    if let Some(definition) = args.definition.as_ref() {
-        bot.description = definition.description.clone();
-        bot.commands = definition.commands.clone();
-        bot.autonomous_config = definition.autonomous_config.clone();
+    match args.definition.as_ref() {
+        // Only cut a new version if the commands or permissions have changed
+        Some(definition) if definition.commands != bot.commands || definition.autonomous_config != bot.autonomous_config => {
+            bot.previous_versions.push(previous.current_version());
+            bot.version += 1;
+            bot.version_published_at = now;
+            bot.description = definition.description.clone();
+            bot.commands = definition.commands.clone();
+            bot.autonomous_config = definition.autonomous_config.clone();
+            bot.installation_config = definition.installation_config.clone();
+            bot.staged_rollout = match args.rollout.as_ref() {
+                Some(BotRollout::Locations(locations)) => Some(BotStagedRollout {
+                    stable_version: previous
+                        .staged_rollout
+                        .as_ref()
+                        .map_or(previous.version, |r| r.stable_version),
+                    locations: locations.clone(),
+                }),
+                Some(BotRollout::All) | None => None,
+            };
+
+            if bot.previous_versions.len() > MAX_PREVIOUS_BOT_VERSIONS {
+                // Never evict a version which is pinned or is the stable version of a staged rollout
+                let versions_in_use = bot.versions_in_use();
+                let Some(index) = bot
+                    .previous_versions
+                    .iter()
+                    .position(|v| !versions_in_use.contains(&v.version))
+                else {
+                    return Error(
+                        OCErrorCode::InvalidRequest.with_message("Every previous version of the bot is still in use"),
+                    );
+                };
+                bot.previous_versions.remove(index);
+            }
+        }
+        definition => {
+            if let Some(definition) = definition {
+                bot.description = definition.description.clone();
+                bot.installation_config = definition.installation_config.clone();
+            }
+
+            if let Some(rollout) = args.rollout.as_ref() {
+                match rollout {
+                    BotRollout::Locations(locations) => {
+                        if let Some(staged_rollout) = bot.staged_rollout.as_mut() {
+                            for location in locations {
+                                if !staged_rollout.locations.contains(location) {
+                                    staged_rollout.locations.push(*location);
+                                }
+                            }
+                        }
+                    }
+                    BotRollout::All => bot.staged_rollout = None,
+                }
+            }
+        }
     }
 
     let owner_id = bot.owner;
@@ -70,6 +128,11 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
 
     bot.last_updated = now;
 
+    let permissions_required = permissions_required_by_new_versions(args.bot_id, &previous, &bot);
+    let version = bot.version;
+    let previous_versions = bot.previous_versions.clone();
+    let staged_rollout = bot.staged_rollout.clone();
+
     match state.data.users.update(user, now, false, Some(bot)) {
         UpdateUserResult::Success => (),
         UpdateUserResult::UsernameTaken => unreachable!(),
@@ -84,16 +147,76 @@ fn update_bot_impl(args: Args, state: &mut RuntimeState) -> Response {
                 owner_id,
                 endpoint,
                 definition,
+                version,
+                previous_versions,
+                staged_rollout,
             }),
             None,
         );
+    } else if args.rollout.is_some() {
+        state.push_event_to_all_local_user_indexes(
+            UserIndexEvent::BotRolloutUpdated(BotRolloutUpdated {
+                bot_id: args.bot_id,
+                staged_rollout,
+            }),
+            None,
+        );
+    }
+
+    for (local_user_index, event) in permissions_required {
+        state
+            .data
+            .user_index_event_sync_queue
+            .push(local_user_index, UserIndexEvent::BotVersionRequiresPermissions(event));
     }
 
-    // TODO: If there are any new commands or the required permissions have increased for any existing commands,
-    // then notify all the group/communities that have added this bot
     Success
 }
 
+// For each group/community installation whose resolved version has changed, work out whether the new version
+// requires any permissions beyond those required by the version it was previously using
+fn permissions_required_by_new_versions(
+    bot_id: UserId,
+    previous: &Bot,
+    bot: &Bot,
+) -> Vec<(CanisterId, BotVersionRequiresPermissions)> {
+    let mut results = Vec::new();
+
+    for (location, details) in bot.installations.iter() {
+        if matches!(location, BotInstallationLocation::User(_)) {
+            continue;
+        }
+
+        let old_version = previous.resolved_version(location);
+        let new_version = bot.resolved_version(location);
+        if old_version == new_version {
+            continue;
+        }
+
+        let (Some(old), Some(new)) = (previous.definition_version(old_version), bot.definition_version(new_version)) else {
+            continue;
+        };
+
+        let command_permissions = new.command_permissions().difference(&old.command_permissions());
+        let autonomous_permissions = new.autonomous_permissions().difference(&old.autonomous_permissions());
+
+        if !command_permissions.is_empty() || !autonomous_permissions.is_empty() {
+            results.push((
+                details.local_user_index,
+                BotVersionRequiresPermissions {
+                    bot_id,
+                    location: *location,
+                    version: new_version,
+                    command_permissions,
+                    autonomous_permissions,
+                },
+            ));
+        }
+    }
+
+    results
+}
+
 fn validate(args: &Args, state: &RuntimeState) -> Result<(), Response> {
     if let Some(principal) = args.principal {
         if principal == Principal::anonymous() {
@@ -161,6 +284,10 @@ fn validate(args: &Args, state: &RuntimeState) -> Result<(), Response> {
         if definition.commands.len() > MAX_COMMANDS {
             return Err(TooManyCommands);
         }
+    } else if matches!(args.rollout, Some(BotRollout::Locations(_))) && bot.staged_rollout.is_none() {
+        return Err(Error(
+            OCErrorCode::InvalidRequest.with_message("There is no staged rollout to extend"),
+        ));
     }
 
     Ok(())
@@ -1,7 +1,8 @@
 use crate::env::ENV;
 use crate::utils::{now_millis, tick_many};
 use crate::{TestEnv, client};
-use airdrop_bot_canister::{AirdropAlgorithm, V1Algorithm, V2Algorithm};
+use airdrop_bot_canister::{AirdropAlgorithm, AirdropConfig, AirdropToken, V1Algorithm, V2Algorithm};
+use constants::CHAT_TRANSFER_FEE;
 use itertools::Itertools;
 use std::ops::Deref;
 use std::time::Duration;
@@ -193,3 +194,113 @@ fn airdrop_end_to_end(v2: bool) {
     // Expected CHAT per user = 11_000
     assert_eq!(content.transfer.units(), 1_100_000_000_000);
 }
+
+#[test]
+fn community_owner_can_fund_and_cancel_airdrop() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+
+    let community_id =
+        client::user::happy_path::create_community(env, &owner, &random_string(), true, vec!["General".to_string()]);
+    let channel_id = client::community::happy_path::create_channel(env, owner.principal, community_id, true, random_string());
+    client::community::happy_path::join_community(env, user.principal, community_id);
+
+    let initial_balance = 20_000_000_000;
+    client::ledger::happy_path::transfer(env, *controller, canister_ids.chat_ledger, owner.principal, initial_balance);
+
+    let algorithm = AirdropAlgorithm::V2(V2Algorithm {
+        main_chat_fund: 10_000_000_000,
+        main_chit_band: 500,
+        lottery_prizes: vec![1_000_000_000],
+        lottery_min_chit: 500,
+        lottery_min_streak: 30,
+        min_minutes_online: 0,
+    });
+    let total_fund = algorithm.total_fund();
+
+    client::ledger::happy_path::approve(
+        env,
+        owner.principal,
+        canister_ids.chat_ledger,
+        canister_ids.airdrop_bot,
+        total_fund + CHAT_TRANSFER_FEE,
+    );
+
+    let args = airdrop_bot_canister::set_community_airdrop::Args {
+        config: AirdropConfig {
+            community_id,
+            channel_id,
+            start: now_millis(env) + 1_000_000,
+            algorithm,
+        },
+        token: AirdropToken {
+            ledger_canister_id: canister_ids.chat_ledger,
+            symbol: "CHAT".to_string(),
+            fee: CHAT_TRANSFER_FEE,
+        },
+    };
+
+    // Only an owner of the community can set up an airdrop for it
+    let response = client::airdrop_bot::set_community_airdrop(env, user.principal, canister_ids.airdrop_bot, &args);
+    assert!(matches!(
+        response,
+        airdrop_bot_canister::set_community_airdrop::Response::NotCommunityOwner
+    ));
+
+    let response = client::airdrop_bot::set_community_airdrop(env, owner.principal, canister_ids.airdrop_bot, &args);
+    let airdrop_bot_canister::set_community_airdrop::Response::Success(result) = response else {
+        panic!("'set_community_airdrop' error: {response:?}");
+    };
+
+    // The funds are held in escrow by the airdrop_bot
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, owner.principal),
+        initial_balance - total_fund - 2 * CHAT_TRANSFER_FEE
+    );
+
+    // The channel can't be used for a second airdrop
+    let response = client::airdrop_bot::set_community_airdrop(env, owner.principal, canister_ids.airdrop_bot, &args);
+    assert!(matches!(
+        response,
+        airdrop_bot_canister::set_community_airdrop::Response::ChannelUsed
+    ));
+
+    let response = client::airdrop_bot::cancel_airdrop(
+        env,
+        user.principal,
+        canister_ids.airdrop_bot,
+        &airdrop_bot_canister::cancel_airdrop::Args {
+            community_airdrop_id: Some(result.airdrop_id),
+        },
+    );
+    assert!(matches!(
+        response,
+        airdrop_bot_canister::cancel_airdrop::Response::NotAuthorized
+    ));
+
+    let response = client::airdrop_bot::cancel_airdrop(
+        env,
+        owner.principal,
+        canister_ids.airdrop_bot,
+        &airdrop_bot_canister::cancel_airdrop::Args {
+            community_airdrop_id: Some(result.airdrop_id),
+        },
+    );
+    assert!(matches!(response, airdrop_bot_canister::cancel_airdrop::Response::Success));
+
+    tick_many(env, 5);
+
+    // Cancelling refunds the escrowed funds, less the ledger fees
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, owner.principal),
+        initial_balance - 3 * CHAT_TRANSFER_FEE
+    );
+}
@@ -1,13 +1,15 @@
 use crate::env::ENV;
-use crate::utils::{now_millis, tick_many};
+use crate::utils::{now_millis, now_nanos, tick_many};
 use crate::{TestEnv, User, client};
 use candid::Principal;
 use community_canister::c2c_bot_community_events::{
     EventsByIndexArgs as CommunityEventsByIndexArgs, EventsSelectionCriteria as CommunityEventsSelectionCriteria,
 };
 use community_canister::community_events::EventsPageArgs;
+use constants::{ICP_SYMBOL, ICP_TRANSFER_FEE};
 use local_user_index_canister::access_token_v2::{self, BotActionByCommandArgs, BotCommandInitial};
 use local_user_index_canister::chat_events::{EventsByIndexArgs, EventsSelectionCriteria};
+use oc_error_codes::OCErrorCode;
 use pocket_ic::PocketIc;
 use std::collections::HashSet;
 use std::ops::Deref;
@@ -16,10 +18,12 @@ use test_case::test_case;
 use testing::rng::{random_from_u128, random_string};
 use types::{
     AutonomousBotScope, AutonomousConfig, BotActionChatDetails, BotActionScope, BotChatContext, BotCommandArg,
-    BotCommandArgValue, BotCommandDefinition, BotCommandParam, BotCommandParamType, BotDefinition, BotInstallationLocation,
-    BotMessageContent, BotPermissions, CanisterId, Chat, ChatEvent, ChatEventType, ChatPermission, ChatType,
-    CommunityEventType, CommunityPermission, EventIndex, MessageContent, MessageId, MessagePermission, NotificationEnvelope,
-    OptionUpdate, Rules, StringParam, TextContent, UpdatedRules, UserId,
+    BotCommandArgValue, BotCommandDefinition, BotCommandParam, BotCommandParamType, BotCommunityOrGroupContext, BotDefinition,
+    BotInstallationConfig, BotInstallationConfigField, BotInstallationLocation, BotMessageContent, BotPermissions, BotRollout,
+    CanisterId, Chat, ChatEvent, ChatEventType, ChatId, ChatPermission, ChatType, CommunityEventType, CommunityOrGroup,
+    CommunityPermission, CryptoContent, CryptoTransaction, EventIndex, GiphyContent, GiphyImageVariant, IntegerParam,
+    MessageContent, MessageId, MessagePermission, NotificationEnvelope, OptionUpdate, PendingCryptoTransaction, Rules,
+    StringParam, TextContent, UpdatedRules, UserId, icrc2,
 };
 
 #[test]
@@ -608,6 +612,7 @@ fn read_messages_by_command() {
             }),
             default_subscriptions: None,
             data_encoding: None,
+            installation_config: None,
         },
     );
 
@@ -720,6 +725,7 @@ fn send_direct_message() {
             autonomous_config: None,
             default_subscriptions: None,
             data_encoding: None,
+            installation_config: None,
         },
     );
 
@@ -1135,6 +1141,830 @@ fn read_community_events() {
     assert!(matches!(events[2], CommunityEventType::RulesChanged));
 }
 
+#[test]
+fn install_bot_with_config() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let (bot_id, _) = client::user_index::happy_path::register_bot(
+        env,
+        user.principal,
+        canister_ids.user_index,
+        random_string(),
+        "https://my.bot.xyz/".to_string(),
+        BotDefinition {
+            description: random_string(),
+            commands: Vec::new(),
+            autonomous_config: None,
+            default_subscriptions: None,
+            data_encoding: None,
+            installation_config: Some(BotInstallationConfig {
+                fields: vec![
+                    BotInstallationConfigField {
+                        name: "group_size".to_string(),
+                        description: None,
+                        param_type: BotCommandParamType::IntegerParam(IntegerParam {
+                            min_value: 3,
+                            max_value: 5,
+                            choices: Vec::new(),
+                        }),
+                        default_value: Some(BotCommandArgValue::Integer(5)),
+                    },
+                    BotInstallationConfigField {
+                        name: "round_duration_days".to_string(),
+                        description: None,
+                        param_type: BotCommandParamType::IntegerParam(IntegerParam {
+                            min_value: 1,
+                            max_value: 30,
+                            choices: Vec::new(),
+                        }),
+                        default_value: None,
+                    },
+                ],
+            }),
+        },
+    );
+
+    tick_many(env, 3);
+
+    let location = BotInstallationLocation::Group(group_id);
+
+    // A required field is missing so the install should fail
+    let response = client::local_user_index::install_bot(
+        env,
+        user.principal,
+        local_user_index,
+        &local_user_index_canister::install_bot::Args {
+            location,
+            bot_id,
+            granted_permissions: BotPermissions::text_only(),
+            granted_autonomous_permissions: None,
+            config: None,
+        },
+    );
+    assert!(matches!(response, local_user_index_canister::install_bot::Response::Error(_)));
+
+    let response = client::local_user_index::install_bot(
+        env,
+        user.principal,
+        local_user_index,
+        &local_user_index_canister::install_bot::Args {
+            location,
+            bot_id,
+            granted_permissions: BotPermissions::text_only(),
+            granted_autonomous_permissions: None,
+            config: Some(vec![BotCommandArg {
+                name: "round_duration_days".to_string(),
+                value: BotCommandArgValue::Integer(7),
+            }]),
+        },
+    );
+    assert!(matches!(response, local_user_index_canister::install_bot::Response::Success));
+
+    let initial = client::group::happy_path::selected_initial(env, user.principal, group_id);
+    let config = &initial.bots.iter().find(|b| b.user_id == bot_id).unwrap().config;
+    assert_eq!(config.len(), 2);
+    assert_eq!(config[0].value, BotCommandArgValue::Integer(5));
+    assert_eq!(config[1].value, BotCommandArgValue::Integer(7));
+
+    // Values outside of the permitted range are rejected
+    let response = client::local_user_index::update_bot_config(
+        env,
+        user.principal,
+        local_user_index,
+        &local_user_index_canister::update_bot_config::Args {
+            location,
+            bot_id,
+            config: vec![BotCommandArg {
+                name: "group_size".to_string(),
+                value: BotCommandArgValue::Integer(10),
+            }],
+        },
+    );
+    assert!(matches!(
+        response,
+        local_user_index_canister::update_bot_config::Response::Error(_)
+    ));
+
+    let response = client::local_user_index::update_bot_config(
+        env,
+        user.principal,
+        local_user_index,
+        &local_user_index_canister::update_bot_config::Args {
+            location,
+            bot_id,
+            config: vec![
+                BotCommandArg {
+                    name: "group_size".to_string(),
+                    value: BotCommandArgValue::Integer(3),
+                },
+                BotCommandArg {
+                    name: "round_duration_days".to_string(),
+                    value: BotCommandArgValue::Integer(14),
+                },
+            ],
+        },
+    );
+    assert!(matches!(
+        response,
+        local_user_index_canister::update_bot_config::Response::Success
+    ));
+
+    let initial = client::group::happy_path::selected_initial(env, user.principal, group_id);
+    let config = &initial.bots.iter().find(|b| b.user_id == bot_id).unwrap().config;
+    assert_eq!(config[0].value, BotCommandArgValue::Integer(3));
+    assert_eq!(config[1].value, BotCommandArgValue::Integer(14));
+}
+
+#[test]
+fn bot_requests_additional_permissions() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let group_id = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let (bot_id, bot_principal) = register_bot(env, &owner, canister_ids.user_index, random_string(), random_string());
+
+    // Install the bot without any autonomous permissions
+    client::local_user_index::happy_path::install_bot(
+        env,
+        owner.principal,
+        local_user_index,
+        BotInstallationLocation::Group(group_id),
+        bot_id,
+        BotPermissions::text_only(),
+        None,
+    );
+
+    env.advance_time(Duration::from_millis(1000));
+    env.tick();
+
+    let send_message = |env: &mut PocketIc| {
+        client::local_user_index::bot_send_message(
+            env,
+            bot_principal,
+            local_user_index,
+            &local_user_index_canister::bot_send_message::Args {
+                chat_context: BotChatContext::Autonomous(Chat::Group(group_id)),
+                thread: None,
+                message_id: None,
+                replies_to: None,
+                content: BotMessageContent::Text(TextContent {
+                    text: "Hello".to_string(),
+                }),
+                block_level_markdown: false,
+                finalised: true,
+            },
+        )
+    };
+
+    // The bot's message is rejected and the missing permission is named in the error
+    let local_user_index_canister::bot_send_message::Response::Error(error) = send_message(env) else {
+        panic!("Expected 'bot_send_message' to fail");
+    };
+    assert!(error.matches_code(OCErrorCode::BotPermissionsMissing));
+    let missing: BotPermissions = serde_json::from_str(error.message().unwrap()).unwrap();
+    assert_eq!(missing, BotPermissions::text_only());
+
+    let request_permissions = |env: &mut PocketIc| {
+        let response = client::local_user_index::bot_request_permissions(
+            env,
+            bot_principal,
+            local_user_index,
+            &local_user_index_canister::bot_request_permissions::Args {
+                community_or_group_context: BotCommunityOrGroupContext::Autonomous(CommunityOrGroup::Group(group_id)),
+                command_permissions: BotPermissions::default(),
+                autonomous_permissions: BotPermissions::text_only(),
+                justification: "I need to post the daily summary".to_string(),
+            },
+        );
+        assert!(matches!(
+            response,
+            local_user_index_canister::bot_request_permissions::Response::Success
+        ));
+    };
+
+    // The bot requests the missing permission and the request is shown to the owner
+    request_permissions(env);
+
+    let initial = client::group::happy_path::selected_initial(env, owner.principal, group_id);
+    let request = initial
+        .bots
+        .iter()
+        .find(|b| b.user_id == bot_id)
+        .unwrap()
+        .permissions_request
+        .clone()
+        .expect("Expected a pending permissions request");
+    assert_eq!(request.autonomous_permissions, BotPermissions::text_only());
+    assert!(request.command_permissions.is_empty());
+
+    // The owner denies the request
+    let response = client::group::deny_bot_permissions_request(
+        env,
+        owner.principal,
+        group_id.into(),
+        &group_canister::deny_bot_permissions_request::Args { bot_id },
+    );
+    assert!(matches!(
+        response,
+        group_canister::deny_bot_permissions_request::Response::Success
+    ));
+
+    let initial = client::group::happy_path::selected_initial(env, owner.principal, group_id);
+    assert!(
+        initial
+            .bots
+            .iter()
+            .find(|b| b.user_id == bot_id)
+            .unwrap()
+            .permissions_request
+            .is_none()
+    );
+
+    // The bot asks again and this time the owner approves
+    request_permissions(env);
+
+    let response = client::group::update_bot(
+        env,
+        owner.principal,
+        group_id.into(),
+        &group_canister::update_bot::Args {
+            bot_id,
+            granted_permissions: BotPermissions::text_only(),
+            granted_autonomous_permissions: Some(request.autonomous_permissions),
+        },
+    );
+    assert!(matches!(response, group_canister::update_bot::Response::Success));
+
+    let initial = client::group::happy_path::selected_initial(env, owner.principal, group_id);
+    assert!(
+        initial
+            .bots
+            .iter()
+            .find(|b| b.user_id == bot_id)
+            .unwrap()
+            .permissions_request
+            .is_none()
+    );
+
+    assert!(matches!(
+        send_message(env),
+        local_user_index_canister::bot_send_message::Response::Success(_)
+    ));
+}
+
+#[test]
+fn bot_usage_stats_visible_to_owner() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let other_user = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let (bot_id, bot_principal) = register_bot(env, &owner, canister_ids.user_index, random_string(), random_string());
+
+    client::local_user_index::happy_path::install_bot(
+        env,
+        owner.principal,
+        local_user_index,
+        BotInstallationLocation::Group(group_id),
+        bot_id,
+        BotPermissions::text_only(),
+        Some(BotPermissions::text_only()),
+    );
+
+    env.advance_time(Duration::from_millis(1000));
+    env.tick();
+
+    // The bot sends a couple of messages autonomously
+    for i in 0..2 {
+        let response = client::local_user_index::bot_send_message(
+            env,
+            bot_principal,
+            local_user_index,
+            &local_user_index_canister::bot_send_message::Args {
+                chat_context: BotChatContext::Autonomous(Chat::Group(group_id)),
+                thread: None,
+                message_id: None,
+                replies_to: None,
+                content: BotMessageContent::Text(TextContent {
+                    text: format!("Hello {i}"),
+                }),
+                block_level_markdown: false,
+                finalised: true,
+            },
+        );
+        assert!(matches!(
+            response,
+            local_user_index_canister::bot_send_message::Response::Success(_)
+        ));
+    }
+
+    // Allow the usage to be synced to the UserIndex
+    env.advance_time(Duration::from_secs(6 * 60));
+    tick_many(env, 5);
+
+    let user_index_canister::bot_usage_stats::Response::Success(stats) = client::user_index::bot_usage_stats(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        &user_index_canister::bot_usage_stats::Args { bot_id },
+    ) else {
+        panic!("Expected 'bot_usage_stats' to succeed");
+    };
+
+    assert_eq!(stats.installations, 1);
+    assert_eq!(stats.installations_by_day.len(), 1);
+    assert_eq!(stats.installations_by_day[0].installed, 1);
+    assert_eq!(stats.autonomous_actions, 2);
+    assert_eq!(stats.failed_command_responses, 0);
+    assert!(stats.commands.is_empty());
+
+    // Only the bot owner can see the stats
+    let response = client::user_index::bot_usage_stats(
+        env,
+        other_user.principal,
+        canister_ids.user_index,
+        &user_index_canister::bot_usage_stats::Args { bot_id },
+    );
+    assert!(matches!(
+        response,
+        user_index_canister::bot_usage_stats::Response::Error(error) if error.matches_code(OCErrorCode::InitiatorNotAuthorized)
+    ));
+}
+
+#[test]
+fn bot_usage_counts_each_command_once_and_its_failed_responses() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let group_id = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let command_name = random_string();
+    let (bot_id, bot_principal) = register_bot(env, &owner, canister_ids.user_index, random_string(), command_name.clone());
+
+    client::local_user_index::happy_path::install_bot(
+        env,
+        owner.principal,
+        local_user_index,
+        BotInstallationLocation::Group(group_id),
+        bot_id,
+        BotPermissions::text_only(),
+        None,
+    );
+
+    env.advance_time(Duration::from_millis(1000));
+    env.tick();
+
+    let access_token = match client::local_user_index::access_token_v2(
+        env,
+        owner.principal,
+        local_user_index,
+        &access_token_v2::Args::BotActionByCommand(BotActionByCommandArgs {
+            bot_id,
+            command: BotCommandInitial {
+                name: command_name.clone(),
+                args: Vec::new(),
+                meta: None,
+            },
+            scope: BotActionScope::Chat(BotActionChatDetails {
+                chat: Chat::Group(group_id),
+                thread: None,
+                message_id: random_from_u128(),
+                user_message_id: None,
+            }),
+        }),
+    ) {
+        access_token_v2::Response::Success(access_token) => access_token,
+        response => panic!("'access_token' error: {response:?}"),
+    };
+
+    let send_message = |env: &mut PocketIc, content: BotMessageContent, finalised: bool| {
+        client::local_user_index::bot_send_message(
+            env,
+            bot_principal,
+            local_user_index,
+            &local_user_index_canister::bot_send_message::Args {
+                chat_context: BotChatContext::Command(access_token.clone()),
+                thread: None,
+                message_id: None,
+                replies_to: None,
+                content,
+                block_level_markdown: false,
+                finalised,
+            },
+        )
+    };
+
+    // The command's response is sent in several steps, some of which may happen after usage has been flushed
+    let response = send_message(
+        env,
+        BotMessageContent::Text(TextContent {
+            text: "Working...".to_string(),
+        }),
+        false,
+    );
+    assert!(matches!(
+        response,
+        local_user_index_canister::bot_send_message::Response::Success(_)
+    ));
+
+    // The bot doesn't have permission to send gifs so this response fails
+    let giphy_image = GiphyImageVariant {
+        width: 100,
+        height: 100,
+        url: "https://giphy.com/gif".to_string(),
+        mime_type: "image/gif".to_string(),
+    };
+    let response = send_message(
+        env,
+        BotMessageContent::Giphy(GiphyContent {
+            caption: None,
+            title: "gif".to_string(),
+            desktop: giphy_image.clone(),
+            mobile: giphy_image,
+        }),
+        false,
+    );
+    assert!(matches!(
+        response,
+        local_user_index_canister::bot_send_message::Response::Error(_)
+    ));
+
+    env.advance_time(Duration::from_secs(4 * 60));
+    tick_many(env, 3);
+
+    let response = send_message(
+        env,
+        BotMessageContent::Text(TextContent {
+            text: "Done".to_string(),
+        }),
+        true,
+    );
+    assert!(matches!(
+        response,
+        local_user_index_canister::bot_send_message::Response::Success(_)
+    ));
+
+    // Allow the usage to be synced to the UserIndex
+    env.advance_time(Duration::from_secs(6 * 60));
+    tick_many(env, 5);
+
+    let user_index_canister::bot_usage_stats::Response::Success(stats) = client::user_index::bot_usage_stats(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        &user_index_canister::bot_usage_stats::Args { bot_id },
+    ) else {
+        panic!("Expected 'bot_usage_stats' to succeed");
+    };
+
+    assert_eq!(stats.autonomous_actions, 0);
+    assert_eq!(stats.failed_command_responses, 1);
+    assert_eq!(stats.commands.len(), 1);
+    assert_eq!(stats.commands[0].name, command_name);
+    assert_eq!(stats.commands[0].count, 1);
+}
+
+#[test]
+fn staged_rollout_and_version_pinning() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let group1 = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+    let group2 = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+
+    let (bot_id, _) = register_bot(env, &owner, canister_ids.user_index, random_string(), "greet".to_string());
+
+    for group_id in [group1, group2] {
+        client::local_user_index::happy_path::install_bot(
+            env,
+            owner.principal,
+            canister_ids.local_user_index(env, group_id),
+            BotInstallationLocation::Group(group_id),
+            bot_id,
+            BotPermissions::text_only(),
+            None,
+        );
+    }
+
+    // Publish a new version with an extra command, rolled out to group1 only
+    let command = |name: &str| BotCommandDefinition {
+        name: name.to_string(),
+        description: None,
+        placeholder: None,
+        params: vec![],
+        permissions: BotPermissions::text_only(),
+        default_role: None,
+        direct_messages: None,
+    };
+
+    let response = client::user_index::update_bot(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        &user_index_canister::update_bot::Args {
+            bot_id,
+            owner: None,
+            principal: None,
+            avatar: OptionUpdate::NoChange,
+            endpoint: None,
+            definition: Some(BotDefinition {
+                description: "greet and farewell".to_string(),
+                commands: vec![command("greet"), command("farewell")],
+                autonomous_config: None,
+                default_subscriptions: None,
+                data_encoding: None,
+                installation_config: None,
+            }),
+            rollout: Some(BotRollout::Locations(vec![BotInstallationLocation::Group(group1)])),
+        },
+    );
+    assert!(matches!(response, user_index_canister::update_bot::Response::Success));
+
+    tick_many(env, 3);
+
+    let farewell_token = |env: &mut PocketIc, group_id: ChatId| {
+        client::local_user_index::access_token_v2(
+            env,
+            owner.principal,
+            canister_ids.local_user_index(env, group_id),
+            &access_token_v2::Args::BotActionByCommand(BotActionByCommandArgs {
+                bot_id,
+                command: BotCommandInitial {
+                    name: "farewell".to_string(),
+                    args: Vec::new(),
+                    meta: None,
+                },
+                scope: BotActionScope::Chat(BotActionChatDetails {
+                    chat: Chat::Group(group_id),
+                    thread: None,
+                    message_id: random_from_u128(),
+                    user_message_id: None,
+                }),
+            }),
+        )
+    };
+
+    // Only group1 is on the new version
+    assert!(matches!(farewell_token(env, group1), access_token_v2::Response::Success(_)));
+    assert!(matches!(
+        farewell_token(env, group2),
+        access_token_v2::Response::NotAuthorized
+    ));
+
+    // Pinning to a version which doesn't exist fails
+    let response = client::local_user_index::pin_bot_version(
+        env,
+        owner.principal,
+        canister_ids.local_user_index(env, group1),
+        &local_user_index_canister::pin_bot_version::Args {
+            location: BotInstallationLocation::Group(group1),
+            bot_id,
+            version: Some(5),
+        },
+    );
+    assert!(matches!(
+        response,
+        types::UnitResult::Error(error) if error.matches_code(OCErrorCode::BotVersionNotFound)
+    ));
+
+    // Pin group1 back to the original version
+    let response = client::local_user_index::pin_bot_version(
+        env,
+        owner.principal,
+        canister_ids.local_user_index(env, group1),
+        &local_user_index_canister::pin_bot_version::Args {
+            location: BotInstallationLocation::Group(group1),
+            bot_id,
+            version: Some(0),
+        },
+    );
+    assert!(matches!(response, types::UnitResult::Success));
+
+    tick_many(env, 3);
+
+    assert!(matches!(
+        farewell_token(env, group1),
+        access_token_v2::Response::NotAuthorized
+    ));
+
+    let initial = client::group::happy_path::selected_initial(env, owner.principal, group1);
+    let bot = initial.bots.iter().find(|b| b.user_id == bot_id).unwrap();
+    assert_eq!(bot.pinned_version, Some(0));
+
+    // Completing the rollout moves group2 onto the latest version while group1 remains pinned
+    let response = client::user_index::update_bot(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        &user_index_canister::update_bot::Args {
+            bot_id,
+            owner: None,
+            principal: None,
+            avatar: OptionUpdate::NoChange,
+            endpoint: None,
+            definition: None,
+            rollout: Some(BotRollout::All),
+        },
+    );
+    assert!(matches!(response, user_index_canister::update_bot::Response::Success));
+
+    tick_many(env, 3);
+
+    assert!(matches!(
+        farewell_token(env, group1),
+        access_token_v2::Response::NotAuthorized
+    ));
+    assert!(matches!(farewell_token(env, group2), access_token_v2::Response::Success(_)));
+
+    // Publishing more versions than are retained (10) doesn't evict the version group1 is pinned to
+    for i in 0..11 {
+        let response = client::user_index::update_bot(
+            env,
+            owner.principal,
+            canister_ids.user_index,
+            &user_index_canister::update_bot::Args {
+                bot_id,
+                owner: None,
+                principal: None,
+                avatar: OptionUpdate::NoChange,
+                endpoint: None,
+                definition: Some(BotDefinition {
+                    description: "greet and farewell".to_string(),
+                    commands: vec![command("greet"), command("farewell"), command(&format!("extra{i}"))],
+                    autonomous_config: None,
+                    default_subscriptions: None,
+                    data_encoding: None,
+                    installation_config: None,
+                }),
+                rollout: None,
+            },
+        );
+        assert!(matches!(response, user_index_canister::update_bot::Response::Success));
+    }
+
+    tick_many(env, 3);
+
+    assert!(matches!(
+        farewell_token(env, group1),
+        access_token_v2::Response::NotAuthorized
+    ));
+    assert!(matches!(farewell_token(env, group2), access_token_v2::Response::Success(_)));
+}
+
+#[test]
+fn bot_sends_crypto_funded_from_its_own_account() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+    let group_id = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+    let local_user_index = canister_ids.local_user_index(env, group_id);
+
+    let permissions = BotPermissions::default().with_message(&HashSet::from_iter([MessagePermission::Crypto]));
+    let (bot_id, bot_principal) = client::user_index::happy_path::register_bot(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        random_string(),
+        "https://my.bot.xyz/".to_string(),
+        BotDefinition {
+            description: "pays stipends".to_string(),
+            commands: Vec::new(),
+            autonomous_config: Some(AutonomousConfig {
+                permissions: permissions.clone(),
+            }),
+            default_subscriptions: None,
+            data_encoding: None,
+            installation_config: None,
+        },
+    );
+
+    client::local_user_index::happy_path::install_bot(
+        env,
+        owner.principal,
+        local_user_index,
+        BotInstallationLocation::Group(group_id),
+        bot_id,
+        BotPermissions::default(),
+        Some(permissions),
+    );
+
+    env.advance_time(Duration::from_millis(1000));
+    env.tick();
+
+    // Fund the bot and let the group transfer from its account
+    let amount = 100_000_000;
+    client::ledger::happy_path::transfer(env, *controller, canister_ids.icp_ledger, bot_principal, 1_000_000_000);
+    client::ledger::happy_path::approve(
+        env,
+        bot_principal,
+        canister_ids.icp_ledger,
+        Principal::from(group_id),
+        amount + ICP_TRANSFER_FEE,
+    );
+
+    let send_crypto = |env: &mut PocketIc, from: Principal| {
+        client::local_user_index::bot_send_message(
+            env,
+            bot_principal,
+            local_user_index,
+            &local_user_index_canister::bot_send_message::Args {
+                chat_context: BotChatContext::Autonomous(Chat::Group(group_id)),
+                thread: None,
+                message_id: None,
+                replies_to: None,
+                content: BotMessageContent::Crypto(CryptoContent {
+                    recipient: owner.user_id,
+                    transfer: CryptoTransaction::Pending(PendingCryptoTransaction::ICRC2(icrc2::PendingCryptoTransaction {
+                        ledger: canister_ids.icp_ledger,
+                        token_symbol: ICP_SYMBOL.to_string(),
+                        amount,
+                        from: from.into(),
+                        to: owner.user_id.into(),
+                        fee: ICP_TRANSFER_FEE,
+                        memo: None,
+                        created: now_nanos(env),
+                    })),
+                    caption: None,
+                }),
+                block_level_markdown: false,
+                finalised: true,
+            },
+        )
+    };
+
+    // The transfer must come from the bot's own account
+    let response = send_crypto(env, owner.principal);
+    let local_user_index_canister::bot_send_message::Response::Error(error) = response else {
+        panic!("Expected 'bot_send_message' to fail: {response:?}");
+    };
+    assert!(error.matches_code(OCErrorCode::InvalidRequest));
+
+    let response = send_crypto(env, bot_principal);
+    assert!(
+        matches!(response, local_user_index_canister::bot_send_message::Response::Success(_)),
+        "'bot_send_message' error: {response:?}"
+    );
+
+    let owner_balance = client::ledger::happy_path::balance_of(env, canister_ids.icp_ledger, owner.user_id);
+    assert_eq!(owner_balance, amount);
+
+    let response = client::group::happy_path::events(env, &owner, group_id, 0.into(), true, 5, 10);
+    let latest_event = response.events.last().expect("Expected some group events");
+    let ChatEvent::Message(message) = &latest_event.event else {
+        panic!("Expected latest event to be a message: {latest_event:?}");
+    };
+    let MessageContent::Crypto(content) = &message.content else {
+        panic!("Expected message to be a crypto transfer: {message:?}");
+    };
+    assert_eq!(content.recipient, owner.user_id);
+    assert_eq!(message.sender, bot_id);
+}
+
 fn register_bot(
     env: &mut PocketIc,
     owner: &User,
@@ -1170,6 +2000,7 @@ fn register_bot(
             }),
             default_subscriptions: None,
             data_encoding: None,
+            installation_config: None,
         },
     )
 }
@@ -1,10 +1,13 @@
 use crate::env::ENV;
+use crate::utils::{now_millis, tick_many};
 use crate::{CanisterIds, TestEnv, User, client};
 use candid::Principal;
+use constants::{HOUR_IN_MS, OPENCHAT_BOT_USER_ID};
 use pocket_ic::PocketIc;
 use std::ops::Deref;
+use std::time::Duration;
 use testing::rng::random_string;
-use types::{ChatId, GroupRole};
+use types::{ChatEvent, ChatId, GroupRole};
 
 #[test]
 fn owner_can_promote_to_and_demote_from_owner() {
@@ -29,6 +32,53 @@ fn owner_can_promote_to_and_demote_from_owner() {
     assert!(matches!(summary1.role, GroupRole::Admin));
 }
 
+#[test]
+fn term_limited_role_reverts_when_it_expires() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller);
+
+    let expires_at = now_millis(env) + HOUR_IN_MS;
+    let response = client::group::change_role(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::change_role::Args {
+            user_id: user2.user_id,
+            new_role: GroupRole::Moderator,
+            expires_at: Some(expires_at),
+        },
+    );
+    assert!(matches!(response, group_canister::change_role::Response::Success));
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert!(matches!(summary.role, GroupRole::Moderator));
+
+    env.advance_time(Duration::from_millis(HOUR_IN_MS - 1000));
+    tick_many(env, 3);
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert!(matches!(summary.role, GroupRole::Moderator));
+
+    env.advance_time(Duration::from_secs(2));
+    tick_many(env, 3);
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert!(matches!(summary.role, GroupRole::Participant));
+
+    let events = client::group::happy_path::events(env, &user1, group_id, summary.latest_event_index, false, 1, 1).events;
+    assert!(matches!(
+        &events[0].event,
+        ChatEvent::RoleChanged(r) if r.changed_by == OPENCHAT_BOT_USER_ID && matches!(r.new_role, GroupRole::Participant)
+    ));
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
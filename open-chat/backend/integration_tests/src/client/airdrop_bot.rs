@@ -2,4 +2,6 @@ use crate::generate_update_call;
 use airdrop_bot_canister::*;
 
 // Updates
+generate_update_call!(cancel_airdrop);
 generate_update_call!(set_airdrop);
+generate_update_call!(set_community_airdrop);
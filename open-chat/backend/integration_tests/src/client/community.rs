@@ -8,8 +8,11 @@ pub const STABLE_MEMORY_MAP_MEMORY_ID: MemoryId = MemoryId::new(3);
 generate_msgpack_query_call!(channel_summary);
 generate_msgpack_query_call!(events);
 generate_msgpack_query_call!(events_by_index);
+generate_msgpack_query_call!(export_events);
 generate_msgpack_query_call!(local_user_index);
+generate_msgpack_query_call!(message_edit_history);
 generate_msgpack_query_call!(search_channel);
+generate_msgpack_query_call!(search_community);
 generate_msgpack_query_call!(selected_channel_initial);
 generate_msgpack_query_call!(selected_channel_updates_v2);
 generate_msgpack_query_call!(selected_initial);
@@ -27,6 +30,7 @@ generate_msgpack_update_call!(cancel_p2p_swap);
 generate_msgpack_update_call!(change_channel_role);
 generate_msgpack_update_call!(change_role);
 generate_msgpack_update_call!(claim_prize);
+generate_msgpack_update_call!(contribute_to_crowdfund);
 generate_msgpack_update_call!(create_channel);
 generate_msgpack_update_call!(create_user_group);
 generate_msgpack_update_call!(delete_channel);
@@ -36,12 +40,14 @@ generate_msgpack_update_call!(edit_message);
 generate_msgpack_update_call!(enable_invite_code);
 generate_msgpack_update_call!(import_group);
 generate_msgpack_update_call!(leave_channel);
+generate_msgpack_update_call!(register_delegate_proposal_vote);
 generate_msgpack_update_call!(register_poll_vote);
 generate_msgpack_update_call!(register_webhook);
 generate_msgpack_update_call!(remove_member);
 generate_msgpack_update_call!(remove_member_from_channel);
 generate_msgpack_update_call!(remove_reaction);
 generate_msgpack_update_call!(send_message);
+generate_msgpack_update_call!(set_proposal_delegate);
 generate_msgpack_update_call!(unblock_user);
 generate_msgpack_update_call!(undelete_messages);
 generate_msgpack_update_call!(update_bot);
@@ -319,7 +325,11 @@ pub mod happy_path {
             env,
             sender,
             community_id.into(),
-            &community_canister::change_role::Args { user_id, new_role },
+            &community_canister::change_role::Args {
+                user_id,
+                new_role,
+                expires_at: None,
+            },
         );
 
         match response {
@@ -344,6 +354,7 @@ pub mod happy_path {
                 user_id,
                 new_role,
                 channel_id,
+                expires_at: None,
             },
         );
 
@@ -676,6 +687,7 @@ pub mod happy_path {
                 thread_root_message_index: None,
                 message_index,
                 poll_option,
+                ballot: None,
                 operation: VoteOperation::RegisterVote,
                 new_achievement: false,
             },
@@ -751,6 +763,7 @@ pub mod happy_path {
         channel_id: ChannelId,
         name: String,
         avatar: Option<String>,
+        bot_id: Option<UserId>,
     ) {
         let response = super::register_webhook(
             env,
@@ -760,6 +773,7 @@ pub mod happy_path {
                 channel_id,
                 name,
                 avatar,
+                bot_id,
             },
         );
 
@@ -4,8 +4,12 @@ use escrow_canister::*;
 // Queries
 
 // Updates
+generate_msgpack_update_call!(create_payout);
 generate_msgpack_update_call!(create_swap);
 generate_msgpack_update_call!(notify_deposit);
+generate_msgpack_update_call!(notify_payout_deposit);
+generate_msgpack_update_call!(refund_payout);
+generate_msgpack_update_call!(settle_payout);
 
 pub mod happy_path {
     use candid::Principal;
@@ -64,4 +68,55 @@ pub mod happy_path {
             response => panic!("'notify_deposit' error: {response:?}"),
         }
     }
+
+    pub fn create_payout(
+        env: &mut PocketIc,
+        sender: Principal,
+        escrow_canister_id: CanisterId,
+        location: P2PSwapLocation,
+        token: TokenInfo,
+        release_at: Option<TimestampMillis>,
+        deadline: TimestampMillis,
+    ) -> u32 {
+        let response = super::create_payout(
+            env,
+            sender,
+            escrow_canister_id,
+            &escrow_canister::create_payout::Args {
+                location,
+                token,
+                release_at,
+                deadline,
+                additional_admins: Vec::new(),
+                canister_to_notify: None,
+            },
+        );
+
+        match response {
+            escrow_canister::create_payout::Response::Success(result) => result.id,
+            response => panic!("'create_payout' error: {response:?}"),
+        }
+    }
+
+    pub fn notify_payout_deposit(
+        env: &mut PocketIc,
+        user_id: UserId,
+        escrow_canister_id: CanisterId,
+        payout_id: u32,
+    ) -> escrow_canister::notify_payout_deposit::SuccessResult {
+        let response = super::notify_payout_deposit(
+            env,
+            user_id.into(),
+            escrow_canister_id,
+            &escrow_canister::notify_payout_deposit::Args {
+                payout_id,
+                user_id: None,
+            },
+        );
+
+        match response {
+            escrow_canister::notify_payout_deposit::Response::Success(result) => result,
+            response => panic!("'notify_payout_deposit' error: {response:?}"),
+        }
+    }
 }
@@ -1,6 +1,10 @@
-use crate::{generate_msgpack_query_call, generate_msgpack_update_call, generate_update_call};
+use crate::{User, generate_msgpack_query_call, generate_msgpack_update_call, generate_update_call};
 use group_canister::*;
 use ic_stable_structures::memory_manager::MemoryId;
+use oc_error_codes::OCError;
+use pocket_ic::PocketIc;
+use testing::rng::{random_from_u128, random_string};
+use types::{ChatId, MessageContentInitial, TextContent};
 
 pub const CHAT_EVENTS_MEMORY_ID: MemoryId = MemoryId::new(3);
 
@@ -8,8 +12,13 @@ pub const CHAT_EVENTS_MEMORY_ID: MemoryId = MemoryId::new(3);
 generate_msgpack_query_call!(events);
 generate_msgpack_query_call!(events_by_index);
 generate_msgpack_query_call!(events_window);
+generate_msgpack_query_call!(export_events);
 generate_msgpack_query_call!(local_user_index);
+generate_msgpack_query_call!(member_warnings);
+generate_msgpack_query_call!(message_edit_history);
+generate_msgpack_query_call!(moderation_log);
 generate_msgpack_query_call!(public_summary);
+generate_msgpack_query_call!(secret_ballot_audit);
 generate_msgpack_query_call!(selected_initial);
 generate_msgpack_query_call!(selected_updates_v2);
 generate_msgpack_query_call!(summary);
@@ -23,18 +32,25 @@ generate_msgpack_update_call!(block_user);
 generate_msgpack_update_call!(cancel_p2p_swap);
 generate_msgpack_update_call!(change_role);
 generate_msgpack_update_call!(claim_prize);
+generate_msgpack_update_call!(contribute_to_crowdfund);
 generate_msgpack_update_call!(convert_into_community);
 generate_msgpack_update_call!(delete_messages);
+generate_msgpack_update_call!(deny_bot_permissions_request);
 generate_msgpack_update_call!(edit_message_v2);
 generate_msgpack_update_call!(enable_invite_code);
 generate_update_call!(end_video_call_v2);
 generate_msgpack_update_call!(join_video_call);
+generate_msgpack_update_call!(mute_member);
 generate_msgpack_update_call!(pin_message_v2);
+generate_msgpack_update_call!(register_delegate_proposal_vote);
 generate_msgpack_update_call!(register_poll_vote);
 generate_msgpack_update_call!(register_webhook);
 generate_msgpack_update_call!(remove_participant);
 generate_msgpack_update_call!(remove_reaction);
+generate_msgpack_update_call!(reveal_poll_ballot);
 generate_msgpack_update_call!(send_message_v2);
+generate_msgpack_update_call!(set_proposal_delegate);
+generate_msgpack_update_call!(set_warning_policy);
 generate_update_call!(start_video_call_v2);
 generate_msgpack_update_call!(toggle_mute_notifications);
 generate_msgpack_update_call!(unblock_user);
@@ -42,6 +58,35 @@ generate_msgpack_update_call!(undelete_messages);
 generate_msgpack_update_call!(unpin_message);
 generate_msgpack_update_call!(update_bot);
 generate_msgpack_update_call!(update_group_v2);
+generate_msgpack_update_call!(warn_member);
+
+// Sends a random text message, returning the error for tests which expect the message to be rejected
+pub fn try_send_text_message(env: &mut PocketIc, sender: &User, group_id: ChatId) -> Result<(), OCError> {
+    let response = send_message_v2(
+        env,
+        sender.principal,
+        group_id.into(),
+        &send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id: random_from_u128(),
+            content: MessageContentInitial::Text(TextContent { text: random_string() }),
+            sender_name: sender.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            block_level_markdown: false,
+            rules_accepted: None,
+            message_filter_failed: None,
+            new_achievement: false,
+        },
+    );
+
+    match response {
+        send_message_v2::Response::Success(_) => Ok(()),
+        send_message_v2::Response::Error(error) => Err(error),
+    }
+}
 
 pub mod happy_path {
     use crate::User;
@@ -183,7 +228,11 @@ pub mod happy_path {
             env,
             sender,
             group_chat_id.into(),
-            &group_canister::change_role::Args { user_id, new_role },
+            &group_canister::change_role::Args {
+                user_id,
+                new_role,
+                expires_at: None,
+            },
         );
 
         match response {
@@ -192,6 +241,20 @@ pub mod happy_path {
         }
     }
 
+    pub fn set_proposal_delegate(env: &mut PocketIc, sender: Principal, group_chat_id: ChatId, delegate: Option<UserId>) {
+        let response = super::set_proposal_delegate(
+            env,
+            sender,
+            group_chat_id.into(),
+            &group_canister::set_proposal_delegate::Args { delegate },
+        );
+
+        match response {
+            group_canister::set_proposal_delegate::Response::Success => {}
+            response => panic!("'set_proposal_delegate' error: {response:?}"),
+        }
+    }
+
     pub fn register_poll_vote(
         env: &mut PocketIc,
         sender: &User,
@@ -207,6 +270,7 @@ pub mod happy_path {
                 thread_root_message_index: None,
                 message_index,
                 poll_option,
+                ballot: None,
                 operation: VoteOperation::RegisterVote,
                 new_achievement: false,
             },
@@ -496,7 +560,7 @@ pub mod happy_path {
             env,
             sender,
             group_chat_id.into(),
-            &group_canister::block_user::Args { user_id },
+            &group_canister::block_user::Args { user_id, reason: None },
         );
 
         match response {
@@ -579,12 +643,19 @@ pub mod happy_path {
         local_user_index
     }
 
-    pub fn register_webhook(env: &mut PocketIc, caller: Principal, group_id: ChatId, name: String, avatar: Option<String>) {
+    pub fn register_webhook(
+        env: &mut PocketIc,
+        caller: Principal,
+        group_id: ChatId,
+        name: String,
+        avatar: Option<String>,
+        bot_id: Option<UserId>,
+    ) {
         let response = super::register_webhook(
             env,
             caller,
             group_id.into(),
-            &group_canister::register_webhook::Args { name, avatar },
+            &group_canister::register_webhook::Args { name, avatar, bot_id },
         );
 
         match response {
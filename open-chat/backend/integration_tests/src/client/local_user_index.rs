@@ -13,6 +13,7 @@ generate_query_call!(notifications);
 // Updates
 generate_update_call!(bot_create_channel);
 generate_update_call!(bot_delete_channel);
+generate_update_call!(bot_request_permissions);
 generate_update_call!(bot_send_message);
 generate_update_call!(bot_subscribe_to_events);
 generate_msgpack_update_call!(install_bot);
@@ -22,8 +23,10 @@ generate_msgpack_update_call!(invite_users_to_group);
 generate_msgpack_update_call!(join_channel);
 generate_msgpack_update_call!(join_community);
 generate_msgpack_update_call!(join_group);
+generate_msgpack_update_call!(pin_bot_version);
 generate_msgpack_update_call!(register_user);
 generate_msgpack_update_call!(uninstall_bot);
+generate_msgpack_update_call!(update_bot_config);
 
 pub mod happy_path {
     use crate::User;
@@ -283,6 +286,7 @@ pub mod happy_path {
                 granted_permissions,
                 location,
                 granted_autonomous_permissions,
+                config: None,
             },
         );
 
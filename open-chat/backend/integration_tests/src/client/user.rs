@@ -9,6 +9,7 @@ generate_msgpack_query_call!(events_window);
 generate_msgpack_query_call!(initial_state);
 generate_msgpack_query_call!(message_activity_feed);
 generate_msgpack_query_call!(saved_crypto_accounts);
+generate_msgpack_query_call!(scheduled_messages);
 generate_msgpack_query_call!(updates);
 
 // Updates
@@ -18,6 +19,7 @@ generate_msgpack_update_call!(add_reaction);
 generate_msgpack_update_call!(block_user);
 generate_msgpack_update_call!(cancel_message_reminder);
 generate_msgpack_update_call!(cancel_p2p_swap);
+generate_msgpack_update_call!(cancel_scheduled_message);
 generate_msgpack_update_call!(claim_daily_chit);
 generate_msgpack_update_call!(create_community);
 generate_msgpack_update_call!(create_group);
@@ -36,6 +38,7 @@ generate_msgpack_update_call!(mute_notifications);
 generate_msgpack_update_call!(pay_for_streak_insurance);
 generate_msgpack_update_call!(remove_reaction);
 generate_msgpack_update_call!(save_crypto_account);
+generate_msgpack_update_call!(schedule_message);
 generate_msgpack_update_call!(send_message_v2);
 generate_msgpack_update_call!(send_message_with_transfer_to_channel);
 generate_msgpack_update_call!(send_message_with_transfer_to_group);
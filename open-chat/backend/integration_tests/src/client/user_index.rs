@@ -13,6 +13,7 @@ generate_msgpack_query_call!(user_registration_canister);
 generate_msgpack_query_call!(users);
 generate_msgpack_query_call!(users_chit);
 generate_msgpack_query_call!(bot_updates);
+generate_msgpack_query_call!(bot_usage_stats);
 generate_msgpack_query_call!(explore_bots);
 
 // Updates
@@ -321,6 +322,7 @@ pub mod happy_path {
                 avatar: OptionUpdate::NoChange,
                 endpoint,
                 definition,
+                rollout: None,
             },
         );
 
@@ -624,7 +624,10 @@ fn update_container_gate(
                 gate_config: gate_config_update,
                 public: None,
                 messages_visible_to_non_members: None,
+                retain_edit_history: None,
+                slow_mode: OptionUpdate::NoChange,
                 external_url: OptionUpdate::NoChange,
+                slow_mode: OptionUpdate::NoChange,
             };
 
             client::community::happy_path::update_channel(env, principal, *community_id, &args);
@@ -640,6 +643,8 @@ fn update_container_gate(
                 permissions_v2: None,
                 events_ttl: OptionUpdate::NoChange,
                 messages_visible_to_non_members: None,
+                retain_edit_history: None,
+                slow_mode: OptionUpdate::NoChange,
             };
 
             client::group::happy_path::update_group(env, principal, *group_id, &args);
@@ -0,0 +1,138 @@
+use crate::crowdfund_tests::verify_crowdfund_status;
+use crate::env::ENV;
+use crate::utils::{chat_token_info, now_millis, tick_many};
+use crate::{TestEnv, client};
+use candid::Principal;
+use constants::{CHAT_TRANSFER_FEE, DAY_IN_MS};
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::{random_from_u128, random_string};
+use types::{CrowdfundContentInitial, CrowdfundStatus, MessageContentInitial};
+
+#[test]
+fn crowdfund_in_channel_refunded_if_target_not_reached() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+
+    let community_id = client::user::happy_path::create_community(env, &user1, &random_string(), true, vec![random_string()]);
+    let channel_id = client::community::happy_path::summary(env, user1.principal, community_id)
+        .channels
+        .first()
+        .unwrap()
+        .channel_id;
+
+    client::community::happy_path::join_community(env, user2.principal, community_id);
+
+    let initial_balance = 1_100_000_000;
+    for user_id in [user1.user_id, user2.user_id] {
+        client::ledger::happy_path::transfer(
+            env,
+            *controller,
+            canister_ids.chat_ledger,
+            Principal::from(user_id),
+            initial_balance,
+        );
+    }
+
+    let message_id = random_from_u128();
+
+    let send_message_response = client::user::send_message_with_transfer_to_channel(
+        env,
+        user1.principal,
+        user1.canister(),
+        &user_canister::send_message_with_transfer_to_channel::Args {
+            community_id,
+            channel_id,
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Crowdfund(CrowdfundContentInitial {
+                token: chat_token_info(),
+                target: 3_000_000_000,
+                beneficiary: user1.user_id,
+                deadline: now_millis(env) + DAY_IN_MS,
+                initial_contribution: 1_000_000_000,
+                caption: None,
+            }),
+            sender_name: user1.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            block_level_markdown: false,
+            community_rules_accepted: None,
+            channel_rules_accepted: None,
+            message_filter_failed: None,
+            pin: None,
+        },
+    );
+
+    assert!(matches!(
+        send_message_response,
+        user_canister::send_message_with_transfer_to_channel::Response::Success(_)
+    ));
+
+    tick_many(env, 5);
+
+    let contribute_response = client::community::contribute_to_crowdfund(
+        env,
+        user2.principal,
+        community_id.into(),
+        &community_canister::contribute_to_crowdfund::Args {
+            channel_id,
+            thread_root_message_index: None,
+            message_id,
+            amount: 500_000_000,
+            pin: None,
+        },
+    );
+
+    assert!(matches!(
+        contribute_response,
+        community_canister::contribute_to_crowdfund::Response::Success(_)
+    ));
+
+    env.advance_time(Duration::from_millis(DAY_IN_MS + 1));
+    tick_many(env, 10);
+
+    let event = client::community::happy_path::events_by_index(env, &user1, community_id, channel_id, vec![2.into()])
+        .events
+        .pop()
+        .unwrap()
+        .event;
+
+    verify_crowdfund_status(event, |status| matches!(status, CrowdfundStatus::Refunded));
+
+    // Each contributor pays the fee to deposit, to sweep into the pool, and on the refund
+    for user_id in [user1.user_id, user2.user_id] {
+        assert_eq!(
+            client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, Principal::from(user_id)),
+            initial_balance - 3 * CHAT_TRANSFER_FEE
+        );
+    }
+
+    // Further contributions are rejected once the deadline has passed
+    let contribute_response = client::community::contribute_to_crowdfund(
+        env,
+        user2.principal,
+        community_id.into(),
+        &community_canister::contribute_to_crowdfund::Args {
+            channel_id,
+            thread_root_message_index: None,
+            message_id,
+            amount: 500_000_000,
+            pin: None,
+        },
+    );
+
+    assert!(matches!(
+        contribute_response,
+        community_canister::contribute_to_crowdfund::Response::Error(_)
+    ));
+}
@@ -36,7 +36,9 @@ fn disappearing_messages_in_channel() {
             gate_config: OptionUpdate::NoChange,
             public: None,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
             external_url: OptionUpdate::NoChange,
+            slow_mode: OptionUpdate::NoChange,
         },
     );
 
@@ -85,7 +87,9 @@ fn disappearing_messages_in_channel() {
             gate_config: OptionUpdate::NoChange,
             public: None,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
             external_url: OptionUpdate::NoChange,
+            slow_mode: OptionUpdate::NoChange,
         },
     );
 
@@ -141,7 +145,9 @@ fn stable_memory_garbage_collected_after_messages_disappear() {
             gate_config: OptionUpdate::NoChange,
             public: None,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
             external_url: OptionUpdate::NoChange,
+            slow_mode: OptionUpdate::NoChange,
         },
     );
 
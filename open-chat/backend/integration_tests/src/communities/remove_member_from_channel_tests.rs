@@ -49,6 +49,7 @@ fn remove_member_from_channel_succeeds(user_joins_channel: bool) {
         &community_canister::remove_member_from_channel::Args {
             channel_id,
             user_id: user2.user_id,
+            reason: None,
         },
     );
 
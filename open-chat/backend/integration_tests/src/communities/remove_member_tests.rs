@@ -37,7 +37,10 @@ fn block_user_succeeds(user_has_left_community: bool) {
         env,
         user1.principal,
         community_id.into(),
-        &community_canister::block_user::Args { user_id: user2.user_id },
+        &community_canister::block_user::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
@@ -98,7 +101,10 @@ fn block_user_fails_for_private_communities() {
         env,
         user1.principal,
         community_id.into(),
-        &community_canister::block_user::Args { user_id: user2.user_id },
+        &community_canister::block_user::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
@@ -129,7 +135,10 @@ fn remove_user_succeeds() {
         env,
         user1.principal,
         community_id.into(),
-        &community_canister::remove_member::Args { user_id: user2.user_id },
+        &community_canister::remove_member::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
@@ -201,7 +210,10 @@ fn community_referral_added_and_removed() {
         env,
         user1.principal,
         community_id.into(),
-        &community_canister::remove_member::Args { user_id: user2.user_id },
+        &community_canister::remove_member::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
@@ -243,7 +255,10 @@ fn remove_invite_succeeds(user_joins_community: bool) {
         env,
         user1.principal,
         community_id.into(),
-        &community_canister::remove_member::Args { user_id: user2.user_id },
+        &community_canister::remove_member::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
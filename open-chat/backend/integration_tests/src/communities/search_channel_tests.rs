@@ -2,6 +2,7 @@ use crate::env::ENV;
 use crate::{CanisterIds, TestEnv, User, client};
 use candid::Principal;
 use pocket_ic::PocketIc;
+use std::collections::HashSet;
 use std::ops::Deref;
 use testing::rng::random_string;
 use types::{ChannelId, CommunityId, MessageIndex};
@@ -44,6 +45,129 @@ fn search_channel_returns_expected_message() {
     assert_eq!(matches[0].message_index, MessageIndex::from(1));
 }
 
+#[test]
+fn search_channel_supports_phrases_and_filters() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData {
+        user1,
+        user2,
+        community_id,
+        channel_id,
+    } = init_test_data(env, canister_ids, *controller);
+
+    let search = |env: &PocketIc, search_term: String| {
+        let response = client::community::search_channel(
+            env,
+            user1.principal,
+            community_id.into(),
+            &community_canister::search_channel::Args {
+                channel_id,
+                search_term,
+                max_results: 10,
+                users: None,
+            },
+        );
+
+        match response {
+            community_canister::search_channel::Response::Success(result) => {
+                result.matches.into_iter().map(|m| m.message_index).collect::<Vec<_>>()
+            }
+            response => panic!("'search_channel' error: {response:?}"),
+        }
+    };
+
+    assert_eq!(search(env, "world".to_string()).len(), 2);
+    assert_eq!(search(env, "\"cruel world\"".to_string()), vec![MessageIndex::from(1)]);
+    assert!(search(env, "\"world cruel\"".to_string()).is_empty());
+    assert_eq!(
+        search(env, format!("world from:@UserId({})", user2.user_id)),
+        vec![MessageIndex::from(1)]
+    );
+    assert!(search(env, "world has:image".to_string()).is_empty());
+}
+
+#[test]
+fn search_community_merges_results_across_visible_channels() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData {
+        user1,
+        user2,
+        community_id,
+        channel_id,
+    } = init_test_data(env, canister_ids, *controller);
+
+    let public_channel_id =
+        client::community::happy_path::create_channel(env, user1.principal, community_id, true, random_string());
+    let private_channel_id =
+        client::community::happy_path::create_channel(env, user1.principal, community_id, false, random_string());
+
+    client::community::happy_path::send_text_message(env, &user1, community_id, public_channel_id, None, "World peace", None);
+    client::community::happy_path::send_text_message(
+        env,
+        &user1,
+        community_id,
+        private_channel_id,
+        None,
+        "A secret world",
+        None,
+    );
+
+    let search = |env: &PocketIc, sender: Principal, offset: u32| {
+        let response = client::community::search_community(
+            env,
+            sender,
+            community_id.into(),
+            &community_canister::search_community::Args {
+                search_term: "world".to_string(),
+                max_results: 2,
+                users: None,
+                offset,
+            },
+        );
+
+        match response {
+            community_canister::search_community::Response::Success(result) => result,
+            response => panic!("'search_community' error: {response:?}"),
+        }
+    };
+
+    let first_page = search(env, user1.principal, 0);
+    assert_eq!(first_page.matches.len(), 2);
+    assert_eq!(first_page.next_offset, Some(2));
+
+    let second_page = search(env, user1.principal, 2);
+    assert_eq!(second_page.matches.len(), 2);
+    assert!(second_page.next_offset.is_none());
+
+    let channels: HashSet<_> = first_page
+        .matches
+        .iter()
+        .chain(second_page.matches.iter())
+        .map(|m| m.channel_id)
+        .collect();
+    assert_eq!(channels, HashSet::from([channel_id, public_channel_id, private_channel_id]));
+
+    // User2 isn't a member of the private channel, so should only see matches from the public channels
+    let results = search(env, user2.principal, 0);
+    assert!(results.matches.iter().all(|m| m.channel_id != private_channel_id));
+    assert_eq!(results.next_offset, Some(2));
+    assert_eq!(search(env, user2.principal, 2).matches.len(), 1);
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
@@ -75,7 +199,6 @@ fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Pr
     }
 }
 
-#[expect(dead_code)]
 struct TestData {
     user1: User,
     user2: User,
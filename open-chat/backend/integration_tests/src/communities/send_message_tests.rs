@@ -771,7 +771,9 @@ fn set_channel_rules(env: &mut PocketIc, sender: Principal, community_id: Commun
         public: None,
         channel_id,
         messages_visible_to_non_members: None,
+        retain_edit_history: None,
         external_url: OptionUpdate::NoChange,
+        slow_mode: OptionUpdate::NoChange,
     };
 
     client::community::happy_path::update_channel(env, sender, community_id, &args);
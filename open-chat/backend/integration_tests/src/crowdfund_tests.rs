@@ -0,0 +1,142 @@
+use crate::env::ENV;
+use crate::utils::{chat_token_info, now_millis, tick_many};
+use crate::{TestEnv, client};
+use candid::Principal;
+use constants::{CHAT_TRANSFER_FEE, DAY_IN_MS};
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::{random_from_u128, random_string};
+use types::{ChatEvent, CrowdfundContentInitial, CrowdfundStatus, MessageContent, MessageContentInitial};
+
+#[test]
+fn crowdfund_in_group_paid_out_at_deadline_if_target_reached() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    client::ledger::happy_path::transfer(
+        env,
+        *controller,
+        canister_ids.chat_ledger,
+        Principal::from(user1.user_id),
+        1_100_000_000,
+    );
+    client::ledger::happy_path::transfer(
+        env,
+        *controller,
+        canister_ids.chat_ledger,
+        Principal::from(user2.user_id),
+        2_100_000_000,
+    );
+
+    let message_id = random_from_u128();
+    let target = 3_000_000_000;
+
+    let send_message_response = client::user::send_message_with_transfer_to_group(
+        env,
+        user1.principal,
+        user1.canister(),
+        &user_canister::send_message_with_transfer_to_group::Args {
+            group_id,
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Crowdfund(CrowdfundContentInitial {
+                token: chat_token_info(),
+                target,
+                beneficiary: user3.user_id,
+                deadline: now_millis(env) + DAY_IN_MS,
+                initial_contribution: 1_000_000_000,
+                caption: None,
+            }),
+            sender_name: user1.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            block_level_markdown: false,
+            rules_accepted: None,
+            message_filter_failed: None,
+            pin: None,
+        },
+    );
+
+    assert!(matches!(
+        send_message_response,
+        user_canister::send_message_with_transfer_to_group::Response::Success(_)
+    ));
+
+    tick_many(env, 5);
+
+    let contribute_response = client::group::contribute_to_crowdfund(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::contribute_to_crowdfund::Args {
+            thread_root_message_index: None,
+            message_id,
+            amount: 2_000_000_000,
+            pin: None,
+        },
+    );
+
+    assert!(matches!(
+        contribute_response,
+        group_canister::contribute_to_crowdfund::Response::Success(_)
+    ));
+
+    tick_many(env, 10);
+
+    // The crowdfund stays open until the deadline, so further contributions can still be made
+    let event = client::group::happy_path::events_by_index(env, &user1, group_id, vec![3.into()])
+        .events
+        .pop()
+        .unwrap()
+        .event;
+
+    verify_crowdfund_status(event, |status| matches!(status, CrowdfundStatus::Open));
+
+    // The funds are held in escrow until the deadline
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, Principal::from(user3.user_id)),
+        0
+    );
+
+    env.advance_time(Duration::from_millis(DAY_IN_MS + 1));
+    tick_many(env, 10);
+
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, Principal::from(user3.user_id)),
+        target - CHAT_TRANSFER_FEE
+    );
+
+    let event = client::group::happy_path::events_by_index(env, &user1, group_id, vec![3.into()])
+        .events
+        .pop()
+        .unwrap()
+        .event;
+
+    verify_crowdfund_status(event, |status| matches!(status, CrowdfundStatus::PaidOut(_)));
+}
+
+pub(crate) fn verify_crowdfund_status<F: FnOnce(&CrowdfundStatus) -> bool>(event: ChatEvent, predicate: F) {
+    let ChatEvent::Message(m) = event else {
+        panic!("Event is not a message. Event: {event:?}")
+    };
+
+    let MessageContent::Crowdfund(c) = m.content else {
+        panic!("Message is not a Crowdfund. Message: {:?}", m.content)
+    };
+
+    assert!(predicate(&c.status), "{:?}", c.status);
+}
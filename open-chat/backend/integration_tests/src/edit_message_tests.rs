@@ -1,9 +1,12 @@
 use crate::env::ENV;
 use crate::{TestEnv, client};
+use candid::Principal;
+use oc_error_codes::OCErrorCode;
+use pocket_ic::PocketIc;
 use std::ops::Deref;
 use test_case::test_case;
-use testing::rng::random_from_u128;
-use types::{ChatEvent, MessageContentInitial, TextContent};
+use testing::rng::{random_from_u128, random_string};
+use types::{ChatEvent, ChatId, MessageContentInitial, MessageId, OptionUpdate, TextContent, UnitResult};
 
 #[test]
 fn edit_message_succeeds() {
@@ -100,3 +103,100 @@ fn update_block_level_markdown_succeeds(starting_value: bool) {
     assert!(m2.edited);
     assert_eq!(m2.block_level_markdown, new_value);
 }
+
+#[test]
+fn group_edit_history_visible_to_sender_and_moderators_when_enabled() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    let message_id = random_from_u128();
+    client::group::happy_path::send_text_message(env, &user2, group_id, None, "v1", Some(message_id));
+    edit_group_text_message(env, user2.principal, group_id, message_id, "v2");
+
+    // History isn't retained until the setting is enabled
+    let response = message_edit_history(env, user2.principal, group_id, message_id);
+    assert!(matches!(
+        response,
+        group_canister::message_edit_history::Response::Error(e) if e.matches_code(OCErrorCode::EditHistoryNotRetained)
+    ));
+
+    client::group::happy_path::update_group(
+        env,
+        user1.principal,
+        group_id,
+        &group_canister::update_group_v2::Args {
+            name: None,
+            description: None,
+            rules: None,
+            avatar: OptionUpdate::NoChange,
+            permissions_v2: None,
+            events_ttl: OptionUpdate::NoChange,
+            gate_config: OptionUpdate::NoChange,
+            public: None,
+            messages_visible_to_non_members: None,
+            retain_edit_history: Some(true),
+            slow_mode: OptionUpdate::NoChange,
+        },
+    );
+    assert!(client::group::happy_path::summary(env, user1.principal, group_id).retain_edit_history);
+
+    edit_group_text_message(env, user2.principal, group_id, message_id, "v3");
+    edit_group_text_message(env, user2.principal, group_id, message_id, "v4");
+
+    for principal in [user1.principal, user2.principal] {
+        let group_canister::message_edit_history::Response::Success(result) =
+            message_edit_history(env, principal, group_id, message_id)
+        else {
+            panic!()
+        };
+        let texts: Vec<_> = result.revisions.iter().map(|r| r.content.text().unwrap()).collect();
+        assert_eq!(texts, vec!["v2", "v3"]);
+        assert!(result.revisions[0].replaced_at <= result.revisions[1].replaced_at);
+    }
+
+    let response = message_edit_history(env, user3.principal, group_id, message_id);
+    assert!(matches!(
+        response,
+        group_canister::message_edit_history::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorNotAuthorized)
+    ));
+}
+
+fn edit_group_text_message(env: &mut PocketIc, sender: Principal, group_id: ChatId, message_id: MessageId, text: &str) {
+    let response = client::group::edit_message_v2(
+        env,
+        sender,
+        group_id.into(),
+        &group_canister::edit_message_v2::Args {
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Text(TextContent { text: text.to_string() }),
+            block_level_markdown: None,
+            new_achievement: false,
+        },
+    );
+    assert!(matches!(response, UnitResult::Success));
+}
+
+fn message_edit_history(
+    env: &PocketIc,
+    sender: Principal,
+    group_id: ChatId,
+    message_id: MessageId,
+) -> group_canister::message_edit_history::Response {
+    client::group::message_edit_history(
+        env,
+        sender,
+        group_id.into(),
+        &group_canister::message_edit_history::Args {
+            thread_root_message_index: None,
+            message_id,
+        },
+    )
+}
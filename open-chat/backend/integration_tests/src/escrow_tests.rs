@@ -1,10 +1,11 @@
 use crate::env::ENV;
 use crate::utils::{chat_token_info, icp_token_info, now_millis, tick_many};
 use crate::{TestEnv, client};
-use constants::DAY_IN_MS;
-use escrow_canister::deposit_subaccount;
+use constants::{CHAT_TRANSFER_FEE, DAY_IN_MS};
+use escrow_canister::{PayoutRecipient, deposit_subaccount, payout_deposit_subaccount};
 use icrc_ledger_types::icrc1::account::Account;
 use std::ops::Deref;
+use std::time::Duration;
 use types::{Chat, P2PSwapLocation};
 
 #[test]
@@ -78,3 +79,245 @@ fn swap_via_escrow_canister_succeeds() {
         icp_amount
     );
 }
+
+#[test]
+fn time_locked_payout_released_to_selected_recipient() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let now = now_millis(env);
+
+    let payout_id = client::escrow::happy_path::create_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        P2PSwapLocation::from_message(Chat::Direct(user2.user_id.into()), None, 0u64.into()),
+        chat_token_info(),
+        Some(now + DAY_IN_MS),
+        now + 2 * DAY_IN_MS,
+    );
+
+    let contribution = 1_000_000_000;
+    for user_id in [user1.user_id, user2.user_id] {
+        client::ledger::happy_path::transfer(
+            env,
+            *controller,
+            canister_ids.chat_ledger,
+            Account {
+                owner: canister_ids.escrow,
+                subaccount: Some(payout_deposit_subaccount(user_id, payout_id)),
+            },
+            contribution + CHAT_TRANSFER_FEE,
+        );
+        let result = client::escrow::happy_path::notify_payout_deposit(env, user_id, canister_ids.escrow, payout_id);
+        assert_eq!(result.contributed, contribution);
+    }
+
+    let prize = 1_500_000_000;
+    let response = client::escrow::settle_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        &escrow_canister::settle_payout::Args {
+            payout_id,
+            recipients: vec![PayoutRecipient {
+                user_id: user3.user_id,
+                amount: prize,
+            }],
+            remainder_recipient: None,
+        },
+    );
+    assert!(matches!(response, escrow_canister::settle_payout::Response::Success));
+
+    // Nothing is paid out until the release time
+    tick_many(env, 5);
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user3.user_id),
+        0
+    );
+
+    env.advance_time(Duration::from_millis(DAY_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user3.user_id),
+        prize
+    );
+    // The remainder is returned to the creator
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user1.user_id),
+        2 * contribution - prize - 2 * CHAT_TRANSFER_FEE
+    );
+}
+
+#[test]
+fn payout_refunded_to_all_contributors_after_deadline() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let now = now_millis(env);
+
+    let payout_id = client::escrow::happy_path::create_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        P2PSwapLocation::from_message(Chat::Direct(user2.user_id.into()), None, 0u64.into()),
+        chat_token_info(),
+        None,
+        now + DAY_IN_MS,
+    );
+
+    let contribution = 1_000_000_000;
+    for user_id in [user1.user_id, user2.user_id] {
+        client::ledger::happy_path::transfer(
+            env,
+            *controller,
+            canister_ids.chat_ledger,
+            Account {
+                owner: canister_ids.escrow,
+                subaccount: Some(payout_deposit_subaccount(user_id, payout_id)),
+            },
+            contribution + CHAT_TRANSFER_FEE,
+        );
+        client::escrow::happy_path::notify_payout_deposit(env, user_id, canister_ids.escrow, payout_id);
+    }
+
+    env.advance_time(Duration::from_millis(DAY_IN_MS + 1));
+    tick_many(env, 5);
+
+    for user_id in [user1.user_id, user2.user_id] {
+        assert_eq!(
+            client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user_id),
+            contribution - CHAT_TRANSFER_FEE
+        );
+    }
+}
+
+#[test]
+fn payout_remainder_paid_to_remainder_recipient() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let now = now_millis(env);
+
+    let payout_id = client::escrow::happy_path::create_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        P2PSwapLocation::from_message(Chat::Direct(user2.user_id.into()), None, 0u64.into()),
+        chat_token_info(),
+        None,
+        now + DAY_IN_MS,
+    );
+
+    let contribution = 1_000_000_000;
+    for user_id in [user1.user_id, user2.user_id] {
+        client::ledger::happy_path::transfer(
+            env,
+            *controller,
+            canister_ids.chat_ledger,
+            Account {
+                owner: canister_ids.escrow,
+                subaccount: Some(payout_deposit_subaccount(user_id, payout_id)),
+            },
+            contribution + CHAT_TRANSFER_FEE,
+        );
+        client::escrow::happy_path::notify_payout_deposit(env, user_id, canister_ids.escrow, payout_id);
+    }
+
+    let response = client::escrow::settle_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        &escrow_canister::settle_payout::Args {
+            payout_id,
+            recipients: Vec::new(),
+            remainder_recipient: Some(user3.user_id),
+        },
+    );
+    assert!(matches!(response, escrow_canister::settle_payout::Response::Success));
+
+    tick_many(env, 5);
+
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user3.user_id),
+        2 * contribution - CHAT_TRANSFER_FEE
+    );
+}
+
+#[test]
+fn deposit_refunded_if_payout_closed_before_notification() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+    } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let now = now_millis(env);
+
+    let payout_id = client::escrow::happy_path::create_payout(
+        env,
+        user1.user_id.into(),
+        canister_ids.escrow,
+        P2PSwapLocation::from_message(Chat::Direct(user2.user_id.into()), None, 0u64.into()),
+        chat_token_info(),
+        None,
+        now + DAY_IN_MS,
+    );
+
+    let contribution = 1_000_000_000;
+    client::ledger::happy_path::transfer(
+        env,
+        *controller,
+        canister_ids.chat_ledger,
+        Account {
+            owner: canister_ids.escrow,
+            subaccount: Some(payout_deposit_subaccount(user2.user_id, payout_id)),
+        },
+        contribution + CHAT_TRANSFER_FEE,
+    );
+
+    env.advance_time(Duration::from_millis(DAY_IN_MS));
+
+    let response = client::escrow::notify_payout_deposit(
+        env,
+        user2.user_id.into(),
+        canister_ids.escrow,
+        &escrow_canister::notify_payout_deposit::Args {
+            payout_id,
+            user_id: None,
+        },
+    );
+    assert!(matches!(
+        response,
+        escrow_canister::notify_payout_deposit::Response::PayoutExpired
+    ));
+
+    assert_eq!(
+        client::ledger::happy_path::balance_of(env, canister_ids.chat_ledger, user2.user_id),
+        contribution
+    );
+}
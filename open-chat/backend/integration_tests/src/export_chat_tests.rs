@@ -0,0 +1,84 @@
+use crate::env::ENV;
+use crate::{TestEnv, client};
+use candid::Principal;
+use oc_error_codes::OCErrorCode;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use testing::rng::random_string;
+use types::{ChatEvent, EventIndex, EventWrapper, GroupRole, MessageIndex};
+
+#[test]
+fn owner_can_export_full_group_history() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let first_message = client::group::happy_path::send_text_message(env, &user1, group_id, None, "Proposal #1", None);
+    for i in 0..5 {
+        client::group::happy_path::send_text_message(env, &user2, group_id, None, format!("Message {i}"), None);
+    }
+    client::group::happy_path::send_text_message(env, &user2, group_id, Some(0.into()), "Thread reply", None);
+    client::group::happy_path::add_reaction(env, &user2, group_id, "👍", first_message.message_id);
+
+    let export =
+        |env: &PocketIc, principal: Principal, thread_root_message_index: Option<MessageIndex>, start_index: EventIndex| {
+            client::group::export_events(
+                env,
+                principal,
+                group_id.into(),
+                &group_canister::export_events::Args {
+                    thread_root_message_index,
+                    start_index,
+                    max_events: 3,
+                },
+            )
+        };
+
+    let response = export(env, user2.principal, None, EventIndex::default());
+    assert!(matches!(
+        response,
+        group_canister::export_events::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorNotAuthorized)
+    ));
+
+    let mut events: Vec<EventWrapper<ChatEvent>> = Vec::new();
+    let mut next_index = Some(EventIndex::default());
+    while let Some(start_index) = next_index {
+        let group_canister::export_events::Response::Success(page) = export(env, user1.principal, None, start_index) else {
+            panic!()
+        };
+        assert!(page.events.len() <= 3);
+        events.extend(page.events);
+        next_index = page.next_index;
+    }
+
+    let event_indexes: Vec<u32> = events.iter().map(|e| e.index.into()).collect();
+    assert_eq!(event_indexes, (0..events.len() as u32).collect::<Vec<_>>());
+    assert!(events.iter().any(|e| matches!(e.event, ChatEvent::ParticipantJoined(_))));
+
+    let messages: Vec<_> = events
+        .iter()
+        .filter_map(|e| if let ChatEvent::Message(m) = &e.event { Some(m) } else { None })
+        .collect();
+    assert_eq!(messages.len(), 6);
+    assert_eq!(messages[0].reactions.len(), 1);
+    assert_eq!(messages[0].thread_summary.as_ref().unwrap().reply_count, 1);
+
+    let group_canister::export_events::Response::Success(thread) =
+        export(env, user1.principal, Some(0.into()), EventIndex::default())
+    else {
+        panic!()
+    };
+    assert_eq!(thread.events.len(), 1);
+    assert!(thread.next_index.is_none());
+
+    // Admins can also export the chat
+    client::group::happy_path::change_role(env, user1.principal, group_id, user2.user_id, GroupRole::Admin);
+    assert!(matches!(
+        export(env, user2.principal, None, EventIndex::default()),
+        group_canister::export_events::Response::Success(_)
+    ));
+}
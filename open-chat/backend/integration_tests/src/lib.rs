@@ -14,6 +14,7 @@ mod change_group_role_tests;
 mod chit_tests;
 mod client;
 mod communities;
+mod crowdfund_tests;
 mod cycles_dispenser_tests;
 mod delete_direct_chat_tests;
 mod delete_group_tests;
@@ -24,6 +25,7 @@ mod disappearing_message_tests;
 mod edit_message_tests;
 mod env;
 mod escrow_tests;
+mod export_chat_tests;
 mod fire_and_forget_handler_tests;
 mod freeze_group_tests;
 mod gated_group_tests;
@@ -32,6 +34,8 @@ mod identity_tests;
 mod join_group_tests;
 mod mentions_tests;
 mod message_activity_tests;
+mod moderation_log_tests;
+mod mute_tests;
 mod notification_tests;
 mod online_users_tests;
 mod p2p_swap_tests;
@@ -42,10 +46,12 @@ mod register_user_tests;
 mod registry_tests;
 mod remove_from_group_tests;
 mod save_crypto_account_tests;
+mod scheduled_message_tests;
 mod send_crypto_tests;
 mod send_direct_message_tests;
 mod set_message_reminder_tests;
 mod setup;
+mod slow_mode_tests;
 mod stable_memory;
 mod storage;
 mod storage_tests;
@@ -55,6 +61,7 @@ mod update_group_tests;
 mod update_profile_tests;
 mod utils;
 mod video_call_tests;
+mod warning_tests;
 mod wasms;
 mod webhook_tests;
 
@@ -297,10 +297,14 @@ fn vote_on_poll_and_check_activity_feed(chat_type: ChatType) {
             show_votes_before_end_date: true,
             allow_multiple_votes_per_user: false,
             allow_user_to_change_vote: false,
+            voting_mode: None,
+            secret_ballot: None,
+            weighting: None,
         },
         votes: PollVotes {
             total: TotalVotes::Visible(HashMap::new()),
             user: Vec::new(),
+            weighted: None,
         },
         ended: false,
     });
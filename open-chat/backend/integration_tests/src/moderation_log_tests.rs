@@ -0,0 +1,77 @@
+use crate::env::ENV;
+use crate::{TestEnv, client};
+use oc_error_codes::OCErrorCode;
+use std::ops::Deref;
+use testing::rng::{random_from_u128, random_string};
+use types::{ModerationAction, UnitResult};
+
+#[test]
+fn moderation_actions_are_recorded_in_log() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let user3 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+    client::group::happy_path::join_group(env, user3.principal, group_id);
+
+    let message_id = random_from_u128();
+    client::group::happy_path::send_text_message(env, &user2, group_id, None, "spam", Some(message_id));
+    client::group::happy_path::delete_messages(env, user1.principal, group_id, None, vec![message_id]);
+
+    let reason = "Posting spam".to_string();
+    let response = client::group::block_user(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::block_user::Args {
+            user_id: user2.user_id,
+            reason: Some(reason.clone()),
+        },
+    );
+    assert!(matches!(response, UnitResult::Success));
+
+    let response = client::group::moderation_log(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::moderation_log::Args {
+            start_index: 0,
+            max_entries: 10,
+        },
+    );
+    let group_canister::moderation_log::Response::Success(page) = response else {
+        panic!("'moderation_log' error: {response:?}");
+    };
+
+    assert_eq!(page.total, 2);
+    assert!(page.next_index.is_none());
+
+    let deleted = &page.entries[0];
+    assert_eq!(deleted.action, ModerationAction::MessagesDeleted);
+    assert_eq!(deleted.actor, user1.user_id);
+    assert_eq!(deleted.target, user2.user_id);
+    assert_eq!(deleted.message_ids, vec![message_id]);
+
+    let blocked = &page.entries[1];
+    assert_eq!(blocked.action, ModerationAction::UserBlocked);
+    assert_eq!(blocked.target, user2.user_id);
+    assert_eq!(blocked.reason, Some(reason));
+
+    // Regular members can't view the log
+    let response = client::group::moderation_log(
+        env,
+        user3.principal,
+        group_id.into(),
+        &group_canister::moderation_log::Args {
+            start_index: 0,
+            max_entries: 10,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::moderation_log::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorNotAuthorized)
+    ));
+}
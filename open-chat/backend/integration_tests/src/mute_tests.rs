@@ -0,0 +1,162 @@
+use crate::env::ENV;
+use crate::{TestEnv, client};
+use constants::MINUTE_IN_MS;
+use oc_error_codes::OCErrorCode;
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::{random_from_u128, random_string};
+use types::{ChatEvent, MessageContentInitial, Reaction, TextContent, UnitResult};
+
+#[test]
+fn muted_member_cannot_post_until_mute_expires() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let message_id = random_from_u128();
+    client::group::happy_path::send_text_message(env, &user1, group_id, None, random_string(), Some(message_id));
+
+    let response = client::group::mute_member(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user2.user_id,
+            duration: Some(MINUTE_IN_MS),
+            reason: Some("Flooding the chat".to_string()),
+        },
+    );
+    assert!(matches!(response, UnitResult::Success));
+
+    let response = client::group::send_message_v2(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id: random_from_u128(),
+            content: MessageContentInitial::Text(TextContent { text: random_string() }),
+            sender_name: user2.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            block_level_markdown: false,
+            rules_accepted: None,
+            message_filter_failed: None,
+            new_achievement: false,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::send_message_v2::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorMuted)
+    ));
+
+    let response = client::group::add_reaction(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::add_reaction::Args {
+            thread_root_message_index: None,
+            message_id,
+            reaction: Reaction::new("👍".to_string()),
+            username: user2.username(),
+            display_name: None,
+            new_achievement: false,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::add_reaction::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorMuted)
+    ));
+
+    // Members can't mute owners
+    let response = client::group::mute_member(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user1.user_id,
+            duration: Some(MINUTE_IN_MS),
+            reason: None,
+        },
+    );
+    assert!(matches!(response, UnitResult::Error(e) if e.matches_code(OCErrorCode::InitiatorNotAuthorized)));
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+    env.tick();
+
+    client::group::happy_path::send_text_message(env, &user2, group_id, None, random_string(), None);
+
+    let events = client::group::happy_path::events(env, &user1, group_id, 0.into(), true, 100, 100);
+    let mute_events: Vec<_> = events
+        .events
+        .into_iter()
+        .filter_map(|e| if let ChatEvent::MemberMuted(m) = e.event { Some(m) } else { None })
+        .collect();
+
+    assert_eq!(mute_events.len(), 2);
+    assert_eq!(mute_events[0].muted_by, user1.user_id);
+    assert!(mute_events[0].muted_until.is_some());
+    assert_eq!(mute_events[1].user_id, user2.user_id);
+    assert!(mute_events[1].muted_until.is_none());
+}
+
+#[test]
+fn mute_is_retained_if_member_leaves_and_rejoins() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let response = client::group::mute_member(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user2.user_id,
+            duration: Some(MINUTE_IN_MS),
+            reason: None,
+        },
+    );
+    assert!(matches!(response, UnitResult::Success));
+
+    client::user::happy_path::leave_group(env, &user2, group_id);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let response = client::group::send_message_v2(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id: random_from_u128(),
+            content: MessageContentInitial::Text(TextContent { text: random_string() }),
+            sender_name: user2.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            block_level_markdown: false,
+            rules_accepted: None,
+            message_filter_failed: None,
+            new_achievement: false,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::send_message_v2::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorMuted)
+    ));
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+    env.tick();
+
+    client::group::happy_path::send_text_message(env, &user2, group_id, None, random_string(), None);
+}
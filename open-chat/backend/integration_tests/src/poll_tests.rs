@@ -6,7 +6,10 @@ use std::collections::HashMap;
 use std::ops::Deref;
 use std::time::{Duration, SystemTime};
 use testing::rng::random_from_u128;
-use types::{ChatEvent, ChatId, MessageContent, MessageContentInitial, PollConfig, PollContent, PollVotes, TotalVotes};
+use types::{
+    ChatEvent, ChatId, MessageContent, MessageContentInitial, PollBallot, PollConfig, PollContent, PollRoleWeights, PollVotes,
+    PollVotingMode, PollWeighting, TotalVotes, VoteOperation, secret_ballot_commitment, secret_ballot_merkle_root,
+};
 
 #[test]
 fn allow_multiple_votes_per_user() {
@@ -21,6 +24,9 @@ fn allow_multiple_votes_per_user() {
         show_votes_before_end_date: false,
         allow_multiple_votes_per_user: true,
         allow_user_to_change_vote: true,
+        voting_mode: None,
+        secret_ballot: None,
+        weighting: None,
     };
 
     let TestData {
@@ -52,6 +58,9 @@ fn single_vote_per_user() {
         show_votes_before_end_date: false,
         allow_multiple_votes_per_user: false,
         allow_user_to_change_vote: true,
+        voting_mode: None,
+        secret_ballot: None,
+        weighting: None,
     };
 
     let TestData {
@@ -70,6 +79,221 @@ fn single_vote_per_user() {
     }
 }
 
+#[test]
+fn ranked_choice_poll() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let poll_config = PollConfig {
+        text: None,
+        options: vec!["1".to_string(), "2".to_string(), "3".to_string()],
+        end_date: None,
+        anonymous: false,
+        show_votes_before_end_date: true,
+        allow_multiple_votes_per_user: false,
+        allow_user_to_change_vote: true,
+        voting_mode: Some(PollVotingMode::RankedChoice),
+        secret_ballot: None,
+        weighting: None,
+    };
+
+    let TestData {
+        user1,
+        user2,
+        group,
+        create_poll_result,
+    } = init_test_data(env, canister_ids, poll_config);
+
+    let group_canister::send_message_v2::Response::Success(r) = create_poll_result else {
+        panic!("{create_poll_result:?}");
+    };
+
+    let mut vote = |user: &User, ballot: Option<PollBallot>| {
+        client::group::register_poll_vote(
+            env,
+            user.principal,
+            group.into(),
+            &group_canister::register_poll_vote::Args {
+                thread_root_message_index: None,
+                message_index: r.message_index,
+                poll_option: 0,
+                ballot,
+                operation: VoteOperation::RegisterVote,
+                new_achievement: false,
+            },
+        )
+    };
+
+    // Ranked choice polls require a ballot
+    assert!(matches!(
+        vote(&user1, None),
+        group_canister::register_poll_vote::Response::Error(_)
+    ));
+
+    // Ballots must not rank the same option twice
+    assert!(matches!(
+        vote(&user1, Some(PollBallot::Ranked(vec![1, 1]))),
+        group_canister::register_poll_vote::Response::Error(_)
+    ));
+
+    vote(&user1, Some(PollBallot::Ranked(vec![1, 0])));
+    let response = vote(&user2, Some(PollBallot::Ranked(vec![1, 2])));
+
+    let group_canister::register_poll_vote::Response::Success(votes) = response else {
+        panic!("{response:?}");
+    };
+    assert_eq!(votes.user, vec![1, 2]);
+
+    let TotalVotes::RankedChoice(results) = votes.total else {
+        panic!("{:?}", votes.total);
+    };
+    assert_eq!(results.total_ballots, 2);
+    assert_eq!(results.winner, Some(1));
+}
+
+#[test]
+fn secret_ballot_poll() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let system_time: SystemTime = env.get_time().try_into().unwrap();
+    let current_time = system_time.duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis() as u64;
+
+    let poll_config = PollConfig {
+        text: None,
+        options: vec!["1".to_string(), "2".to_string()],
+        end_date: Some(current_time + 1000),
+        anonymous: true,
+        show_votes_before_end_date: false,
+        allow_multiple_votes_per_user: false,
+        allow_user_to_change_vote: false,
+        voting_mode: None,
+        secret_ballot: Some(true),
+        weighting: None,
+    };
+
+    let TestData {
+        user1,
+        user2,
+        group,
+        create_poll_result,
+    } = init_test_data(env, canister_ids, poll_config);
+
+    let group_canister::send_message_v2::Response::Success(r) = create_poll_result else {
+        panic!("{create_poll_result:?}");
+    };
+
+    let salt = b"0123456789abcdef".to_vec();
+    let commitment = secret_ballot_commitment(&[1], &salt);
+
+    let response = client::group::register_poll_vote(
+        env,
+        user2.principal,
+        group.into(),
+        &group_canister::register_poll_vote::Args {
+            thread_root_message_index: None,
+            message_index: r.message_index,
+            poll_option: 0,
+            ballot: Some(PollBallot::Commitment(commitment)),
+            operation: VoteOperation::RegisterVote,
+            new_achievement: false,
+        },
+    );
+    let group_canister::register_poll_vote::Response::Success(votes) = response else {
+        panic!("{response:?}");
+    };
+    assert!(votes.user.is_empty());
+    assert!(matches!(votes.total, TotalVotes::SecretBallot(s) if s.commitments == 1 && s.tallies.is_empty()));
+
+    env.advance_time(Duration::from_millis(1000));
+    env.tick();
+
+    // Any member can reveal a ballot, so user1 reveals user2's ballot
+    let response = client::group::reveal_poll_ballot(
+        env,
+        user1.principal,
+        group.into(),
+        &group_canister::reveal_poll_ballot::Args {
+            thread_root_message_index: None,
+            message_index: r.message_index,
+            options: vec![1],
+            salt: salt.clone(),
+        },
+    );
+    let group_canister::reveal_poll_ballot::Response::Success(votes) = response else {
+        panic!("{response:?}");
+    };
+    let TotalVotes::SecretBallot(results) = votes.total else {
+        panic!("{:?}", votes.total);
+    };
+    assert_eq!(results.revealed, 1);
+    assert_eq!(results.tallies.get(&1), Some(&1));
+    assert_eq!(results.merkle_root, secret_ballot_merkle_root(&[commitment]));
+
+    let response = client::group::secret_ballot_audit(
+        env,
+        user1.principal,
+        group.into(),
+        &group_canister::secret_ballot_audit::Args {
+            thread_root_message_index: None,
+            message_index: r.message_index,
+        },
+    );
+    let group_canister::secret_ballot_audit::Response::Success(audit) = response else {
+        panic!("{response:?}");
+    };
+    assert_eq!(audit.commitments, vec![commitment]);
+    assert_eq!(audit.revealed[0].options, vec![1]);
+    assert_eq!(audit.revealed[0].salt, salt);
+}
+
+#[test]
+fn role_weighted_poll() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let poll_config = PollConfig {
+        text: None,
+        options: vec!["1".to_string(), "2".to_string()],
+        end_date: None,
+        anonymous: true,
+        show_votes_before_end_date: true,
+        allow_multiple_votes_per_user: false,
+        allow_user_to_change_vote: true,
+        voting_mode: None,
+        secret_ballot: None,
+        weighting: Some(PollWeighting::Role(PollRoleWeights {
+            owner: 5,
+            admin: 3,
+            moderator: 2,
+            member: 1,
+        })),
+    };
+
+    let TestData {
+        user1,
+        user2,
+        group,
+        create_poll_result,
+    } = init_test_data(env, canister_ids, poll_config);
+
+    let group_canister::send_message_v2::Response::Success(r) = create_poll_result else {
+        panic!("{create_poll_result:?}");
+    };
+
+    client::group::happy_path::register_poll_vote(env, &user1, group, r.message_index, 0);
+    let votes = client::group::happy_path::register_poll_vote(env, &user2, group, r.message_index, 0);
+
+    let TotalVotes::Anonymous(raw) = votes.total else {
+        panic!("{:?}", votes.total);
+    };
+    assert_eq!(raw.get(&0), Some(&2));
+
+    let weighted = votes.weighted.unwrap();
+    assert!(weighted.snapshot_complete);
+    assert_eq!(weighted.totals.get(&0), Some(&6));
+}
+
 #[test]
 fn polls_ended_correctly() {
     let mut wrapper = ENV.deref().get();
@@ -86,6 +310,9 @@ fn polls_ended_correctly() {
         show_votes_before_end_date: false,
         allow_multiple_votes_per_user: false,
         allow_user_to_change_vote: true,
+        voting_mode: None,
+        secret_ballot: None,
+        weighting: None,
     };
 
     let TestData {
@@ -103,6 +330,9 @@ fn polls_ended_correctly() {
         show_votes_before_end_date: false,
         allow_multiple_votes_per_user: false,
         allow_user_to_change_vote: true,
+        voting_mode: None,
+        secret_ballot: None,
+        weighting: None,
     };
 
     let create_poll_result2 = client::group::send_message_v2(
@@ -117,6 +347,7 @@ fn polls_ended_correctly() {
                 votes: PollVotes {
                     total: TotalVotes::Anonymous(HashMap::default()),
                     user: Vec::new(),
+                    weighted: None,
                 },
                 ended: false,
             }),
@@ -244,6 +475,7 @@ fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, poll_config: P
                 votes: PollVotes {
                     total: TotalVotes::Anonymous(HashMap::default()),
                     user: Vec::new(),
+                    weighted: None,
                 },
                 ended: false,
             }),
@@ -26,7 +26,10 @@ fn remove_group_member_succeeds(user_joins_group: bool) {
         env,
         user1.principal,
         group_id.into(),
-        &group_canister::remove_participant::Args { user_id: user2.user_id },
+        &group_canister::remove_participant::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(
@@ -57,7 +60,10 @@ fn block_user_who_is_no_longer_group_member_succeeds() {
         env,
         user1.principal,
         group_id.into(),
-        &group_canister::block_user::Args { user_id: user2.user_id },
+        &group_canister::block_user::Args {
+            user_id: user2.user_id,
+            reason: None,
+        },
     );
 
     assert!(matches!(block_user_response, group_canister::block_user::Response::Success));
@@ -0,0 +1,179 @@
+use crate::env::ENV;
+use crate::utils::{now_millis, tick_many};
+use crate::{TestEnv, client};
+use constants::{HOUR_IN_MS, MINUTE_IN_MS};
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{Chat, ChatEvent, ChatId, EventIndex, MessageContent, MessageContentInitial, TextContent};
+use user_canister::{MessageRecurrence, RecurrenceSchedule};
+
+#[test]
+fn recurring_scheduled_message_sent_to_group() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let text = random_string();
+    let send_at = now_millis(env) + MINUTE_IN_MS;
+
+    let schedule_response = client::user::schedule_message(
+        env,
+        user1.principal,
+        user1.canister(),
+        &user_canister::schedule_message::Args {
+            chat: Chat::Group(group_id),
+            thread_root_message_index: None,
+            content: MessageContentInitial::Text(TextContent { text: text.clone() }),
+            mentioned: Vec::new(),
+            block_level_markdown: false,
+            send_at,
+            recurrence: Some(MessageRecurrence {
+                schedule: RecurrenceSchedule::Interval(HOUR_IN_MS),
+                until: Some(send_at + HOUR_IN_MS),
+            }),
+        },
+    );
+
+    let user_canister::schedule_message::Response::Success(scheduled_message_id) = schedule_response else {
+        panic!("'schedule_message' error: {schedule_response:?}");
+    };
+
+    let user_canister::scheduled_messages::Response::Success(scheduled_messages) =
+        client::user::scheduled_messages(env, user1.principal, user1.canister(), &types::Empty {});
+    assert_eq!(scheduled_messages.len(), 1);
+    assert_eq!(scheduled_messages[0].id, scheduled_message_id);
+    assert_eq!(scheduled_messages[0].next_send_at, send_at);
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user2, group_id, &text), 1);
+
+    env.advance_time(Duration::from_millis(HOUR_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user2, group_id, &text), 2);
+
+    // The recurrence has ended so the message is no longer scheduled
+    let user_canister::scheduled_messages::Response::Success(scheduled_messages) =
+        client::user::scheduled_messages(env, user1.principal, user1.canister(), &types::Empty {});
+    assert!(scheduled_messages.is_empty());
+
+    env.advance_time(Duration::from_millis(HOUR_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user2, group_id, &text), 2);
+}
+
+#[test]
+fn cron_scheduled_message_sent_at_matching_times() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+
+    let text = random_string();
+    let send_at = now_millis(env) + MINUTE_IN_MS;
+
+    let schedule_response = client::user::schedule_message(
+        env,
+        user1.principal,
+        user1.canister(),
+        &user_canister::schedule_message::Args {
+            chat: Chat::Group(group_id),
+            thread_root_message_index: None,
+            content: MessageContentInitial::Text(TextContent { text: text.clone() }),
+            mentioned: Vec::new(),
+            block_level_markdown: false,
+            send_at,
+            recurrence: Some(MessageRecurrence {
+                schedule: RecurrenceSchedule::Cron("0 * * * *".to_string()),
+                until: None,
+            }),
+        },
+    );
+
+    let user_canister::schedule_message::Response::Success(_) = schedule_response else {
+        panic!("'schedule_message' error: {schedule_response:?}");
+    };
+
+    // The message is first sent at the start of the next hour
+    let first_send_at = ((send_at - 1) / HOUR_IN_MS + 1) * HOUR_IN_MS;
+    let user_canister::scheduled_messages::Response::Success(scheduled_messages) =
+        client::user::scheduled_messages(env, user1.principal, user1.canister(), &types::Empty {});
+    assert_eq!(scheduled_messages[0].next_send_at, first_send_at);
+
+    env.advance_time(Duration::from_millis(first_send_at - now_millis(env)));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user1, group_id, &text), 1);
+
+    env.advance_time(Duration::from_millis(HOUR_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user1, group_id, &text), 2);
+}
+
+#[test]
+fn cancelled_scheduled_message_not_sent() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+
+    let text = random_string();
+
+    let schedule_response = client::user::schedule_message(
+        env,
+        user1.principal,
+        user1.canister(),
+        &user_canister::schedule_message::Args {
+            chat: Chat::Group(group_id),
+            thread_root_message_index: None,
+            content: MessageContentInitial::Text(TextContent { text: text.clone() }),
+            mentioned: Vec::new(),
+            block_level_markdown: false,
+            send_at: now_millis(env) + MINUTE_IN_MS,
+            recurrence: None,
+        },
+    );
+
+    let user_canister::schedule_message::Response::Success(scheduled_message_id) = schedule_response else {
+        panic!("'schedule_message' error: {schedule_response:?}");
+    };
+
+    let cancel_args = user_canister::cancel_scheduled_message::Args { scheduled_message_id };
+    let cancel_response = client::user::cancel_scheduled_message(env, user1.principal, user1.canister(), &cancel_args);
+    assert!(matches!(
+        cancel_response,
+        user_canister::cancel_scheduled_message::Response::Success
+    ));
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+    tick_many(env, 5);
+
+    assert_eq!(messages_sent(env, &user1, group_id, &text), 0);
+
+    let cancel_response = client::user::cancel_scheduled_message(env, user1.principal, user1.canister(), &cancel_args);
+    assert!(matches!(
+        cancel_response,
+        user_canister::cancel_scheduled_message::Response::Error(_)
+    ));
+}
+
+fn messages_sent(env: &pocket_ic::PocketIc, user: &crate::User, group_id: ChatId, text: &str) -> usize {
+    client::group::happy_path::events(env, user, group_id, EventIndex::default(), true, 100, 100)
+        .events
+        .into_iter()
+        .filter(
+            |e| matches!(&e.event, ChatEvent::Message(m) if matches!(&m.content, MessageContent::Text(t) if t.text == text)),
+        )
+        .count()
+}
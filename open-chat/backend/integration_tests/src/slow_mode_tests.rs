@@ -0,0 +1,65 @@
+use crate::env::ENV;
+use crate::{TestEnv, client};
+use candid::Principal;
+use constants::MINUTE_IN_MS;
+use oc_error_codes::OCErrorCode;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{ChatId, GroupPermissionRole, OptionUpdate, SlowMode};
+
+#[test]
+fn slow_mode_limits_how_often_members_can_send_messages() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let slow_mode = SlowMode {
+        interval: MINUTE_IN_MS,
+        exempt: GroupPermissionRole::Admins,
+    };
+    set_slow_mode(env, user1.principal, group_id, OptionUpdate::SetToSome(slow_mode));
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert_eq!(summary.slow_mode, Some(slow_mode));
+
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_ok());
+
+    let error = client::group::try_send_text_message(env, &user2, group_id).unwrap_err();
+    assert!(error.matches_code(OCErrorCode::SlowModeActive));
+
+    // The owner is exempt
+    assert!(client::group::try_send_text_message(env, &user1, group_id).is_ok());
+    assert!(client::group::try_send_text_message(env, &user1, group_id).is_ok());
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_ok());
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_err());
+
+    set_slow_mode(env, user1.principal, group_id, OptionUpdate::SetToNone);
+
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_ok());
+    assert!(
+        client::group::happy_path::summary(env, user2.principal, group_id)
+            .slow_mode
+            .is_none()
+    );
+}
+
+fn set_slow_mode(env: &mut PocketIc, sender: Principal, group_id: ChatId, slow_mode: OptionUpdate<SlowMode>) {
+    client::group::happy_path::update_group(
+        env,
+        sender,
+        group_id,
+        &group_canister::update_group_v2::Args {
+            slow_mode,
+            ..Default::default()
+        },
+    );
+}
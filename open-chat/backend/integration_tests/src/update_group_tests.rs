@@ -2,8 +2,10 @@ use crate::env::ENV;
 use crate::utils::tick_many;
 use crate::{CanisterIds, TestEnv, User, client};
 use candid::Principal;
+use oc_error_codes::OCErrorCode;
 use pocket_ic::PocketIc;
 use std::ops::Deref;
+use std::time::Duration;
 use testing::rng::random_string;
 use types::{ChatId, OptionUpdate::*};
 
@@ -36,6 +38,8 @@ fn update_group_name_succeeds() {
 
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
+            slow_mode: NoChange,
         },
     );
 
@@ -80,6 +84,8 @@ fn change_casing_of_group_name_succeeds() {
 
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
+            slow_mode: NoChange,
         },
     );
 
@@ -127,6 +133,8 @@ fn update_group_privacy_succeeds() {
 
             gate_config: NoChange,
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
+            slow_mode: NoChange,
         },
     );
 
@@ -176,6 +184,8 @@ fn make_private_group_public_succeeds() {
             public: Some(true),
 
             messages_visible_to_non_members: None,
+            retain_edit_history: None,
+            slow_mode: NoChange,
         },
     );
 
@@ -188,6 +198,66 @@ fn make_private_group_public_succeeds() {
     assert_eq!(group_summary.min_visible_message_index, 5.into());
 }
 
+#[test]
+fn set_proposal_delegate_succeeds() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller, &random_string());
+
+    // Only owners and admins can elect a delegate
+    let response = client::group::set_proposal_delegate(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::set_proposal_delegate::Args {
+            delegate: Some(user2.user_id),
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::set_proposal_delegate::Response::Error(e) if e.matches_code(OCErrorCode::InitiatorNotAuthorized)
+    ));
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    let previous_update = summary.last_updated;
+
+    env.advance_time(Duration::from_secs(1));
+
+    client::group::happy_path::set_proposal_delegate(env, user1.principal, group_id, Some(user2.user_id));
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert_eq!(summary.proposal_delegate, Some(user2.user_id));
+
+    let updates = client::group::happy_path::summary_updates(env, user2.principal, group_id, previous_update).unwrap();
+    assert!(matches!(updates.proposal_delegate, SetToSome(user_id) if user_id == user2.user_id));
+
+    // Delegate votes can only be registered on proposals
+    let response = client::group::register_delegate_proposal_vote(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::register_delegate_proposal_vote::Args {
+            message_index: 0.into(),
+            adopt: true,
+        },
+    );
+    assert!(matches!(
+        response,
+        group_canister::register_delegate_proposal_vote::Response::Error(e) if e.matches_code(OCErrorCode::ProposalNotFound)
+    ));
+
+    client::group::happy_path::set_proposal_delegate(env, user1.principal, group_id, None);
+
+    let summary = client::group::happy_path::summary(env, user2.principal, group_id);
+    assert!(summary.proposal_delegate.is_none());
+}
+
 fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal, group_name: &str) -> TestData {
     let user1 = client::register_diamond_user(env, canister_ids, controller);
     let user2 = client::register_user(env, canister_ids);
@@ -0,0 +1,104 @@
+use crate::env::ENV;
+use crate::{TestEnv, client};
+use candid::Principal;
+use constants::MINUTE_IN_MS;
+use oc_error_codes::{OCError, OCErrorCode};
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use testing::rng::random_string;
+use types::{ChatId, EscalationAction, EscalationStep, UnitResult, UserId, WarningPolicy};
+
+#[test]
+fn warnings_escalate_to_mute_then_removal() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv { env, canister_ids, .. } = wrapper.env();
+
+    let user1 = client::register_user(env, canister_ids);
+    let user2 = client::register_user(env, canister_ids);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::group::happy_path::join_group(env, user2.principal, group_id);
+
+    let policy = WarningPolicy {
+        warning_expiry: None,
+        escalation: vec![
+            EscalationStep {
+                warnings: 2,
+                action: EscalationAction::Mute(MINUTE_IN_MS),
+            },
+            EscalationStep {
+                warnings: 3,
+                action: EscalationAction::Remove,
+            },
+        ],
+    };
+    let response = client::group::set_warning_policy(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::set_warning_policy::Args { policy: Some(policy) },
+    );
+    assert!(matches!(response, UnitResult::Success));
+
+    let result = warn_member(env, user1.principal, group_id, user2.user_id).unwrap();
+    assert_eq!(result.active_warnings, 1);
+    assert!(result.escalation.is_none());
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_ok());
+
+    // Members can't warn each other
+    let error = warn_member(env, user2.principal, group_id, user1.user_id).unwrap_err();
+    assert!(error.matches_code(OCErrorCode::InitiatorNotAuthorized));
+
+    let result = warn_member(env, user1.principal, group_id, user2.user_id).unwrap();
+    assert_eq!(result.active_warnings, 2);
+    assert_eq!(result.escalation, Some(EscalationAction::Mute(MINUTE_IN_MS)));
+
+    let error = client::group::try_send_text_message(env, &user2, group_id).unwrap_err();
+    assert!(error.matches_code(OCErrorCode::InitiatorMuted));
+
+    let response = client::group::member_warnings(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::member_warnings::Args { user_id: user2.user_id },
+    );
+    let group_canister::member_warnings::Response::Success(warnings) = response else {
+        panic!("'member_warnings' error: {response:?}");
+    };
+    assert_eq!(warnings.warnings.len(), 2);
+    assert!(warnings.muted_until.is_some());
+
+    env.advance_time(Duration::from_millis(MINUTE_IN_MS));
+
+    assert!(client::group::try_send_text_message(env, &user2, group_id).is_ok());
+
+    let result = warn_member(env, user1.principal, group_id, user2.user_id).unwrap();
+    assert_eq!(result.active_warnings, 3);
+    assert_eq!(result.escalation, Some(EscalationAction::Remove));
+
+    let group = client::group::happy_path::selected_initial(env, user1.principal, group_id);
+    assert!(!group.participants.iter().any(|p| p.user_id == user2.user_id));
+    assert!(!group.basic_members.contains(&user2.user_id));
+}
+
+fn warn_member(
+    env: &mut PocketIc,
+    sender: Principal,
+    group_id: ChatId,
+    user_id: UserId,
+) -> Result<group_canister::warn_member::SuccessResult, OCError> {
+    let response = client::group::warn_member(
+        env,
+        sender,
+        group_id.into(),
+        &group_canister::warn_member::Args {
+            user_id,
+            reason: random_string(),
+        },
+    );
+
+    match response {
+        group_canister::warn_member::Response::Success(result) => Ok(result),
+        group_canister::warn_member::Response::Error(error) => Err(error),
+    }
+}
@@ -1,14 +1,17 @@
 use crate::env::ENV;
-use crate::utils::now_millis;
+use crate::utils::{now_millis, tick_many};
 use crate::{TestEnv, client};
-use group_canister::send_message_v2;
+use group_canister::handle_webhook;
 use reqwest::Url;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::ops::Deref;
 use std::time::Duration;
 use test_case::test_case;
 use testing::rng::random_string;
-use types::{Chat, ChatEvent, ChatType, EventIndex, MessageContent, SenderContext, UserId};
+use types::{
+    BotDefinition, BotInstallationLocation, BotPermissions, Chat, ChatEvent, ChatType, EventIndex, MessageContent,
+    NotificationEnvelope, SenderContext, UserId,
+};
 
 #[test_case(ChatType::Group)]
 #[test_case(ChatType::Channel)]
@@ -47,13 +50,21 @@ fn e2e_webhook_test(chat_type: ChatType) {
     let name = random_string();
     let webhook_details = match chat {
         Chat::Group(group_id) => {
-            client::group::happy_path::register_webhook(env, owner.principal, group_id, name.clone(), None);
+            client::group::happy_path::register_webhook(env, owner.principal, group_id, name.clone(), None, None);
             let updates = client::group::happy_path::selected_updates(env, owner.principal, group_id, start);
             assert!(updates.is_some());
             updates.unwrap().webhooks
         }
         Chat::Channel(community_id, channel_id) => {
-            client::community::happy_path::register_webhook(env, owner.principal, community_id, channel_id, name.clone(), None);
+            client::community::happy_path::register_webhook(
+                env,
+                owner.principal,
+                community_id,
+                channel_id,
+                name.clone(),
+                None,
+                None,
+            );
             let updates =
                 client::community::happy_path::selected_channel_updates(env, owner.principal, community_id, channel_id, start);
             assert!(updates.is_some());
@@ -88,7 +99,7 @@ fn e2e_webhook_test(chat_type: ChatType) {
         Err(e) => panic!("Failed to post a message to webhook: {e}"),
     };
 
-    let send_message_v2::Response::Success(_) = response else {
+    let handle_webhook::Response::Success(_) = response else {
         panic!("Expected a success response, but got: {response:?}");
     };
 
@@ -119,13 +130,148 @@ fn e2e_webhook_test(chat_type: ChatType) {
     assert_eq!(content.text, message_text);
 }
 
+#[test_case(ChatType::Group)]
+#[test_case(ChatType::Channel)]
+fn webhook_bound_to_bot_delivers_payload_to_bot(chat_type: ChatType) {
+    let mut wrapper = ENV.deref().create_new();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let owner = client::register_diamond_user(env, canister_ids, *controller);
+
+    let (chat, location) = match chat_type {
+        ChatType::Group => {
+            let group_id = client::user::happy_path::create_group(env, &owner, &random_string(), true, true);
+            (Chat::Group(group_id), BotInstallationLocation::Group(group_id))
+        }
+        ChatType::Channel => {
+            let community_id =
+                client::user::happy_path::create_community(env, &owner, &random_string(), true, vec!["General".to_string()]);
+            let channel_id =
+                client::community::happy_path::create_channel(env, owner.principal, community_id, true, random_string());
+            (
+                Chat::Channel(community_id, channel_id),
+                BotInstallationLocation::Community(community_id),
+            )
+        }
+        ChatType::Direct => unreachable!(),
+    };
+
+    let local_user_index = canister_ids.local_user_index(env, location.canister_id());
+
+    let (bot_id, _) = client::user_index::happy_path::register_bot(
+        env,
+        owner.principal,
+        canister_ids.user_index,
+        random_string(),
+        "https://my.bot.xyz/".to_string(),
+        BotDefinition {
+            description: random_string(),
+            commands: Vec::new(),
+            autonomous_config: None,
+            default_subscriptions: None,
+            data_encoding: None,
+            installation_config: None,
+        },
+    );
+
+    tick_many(env, 3);
+
+    client::local_user_index::happy_path::install_bot(
+        env,
+        owner.principal,
+        local_user_index,
+        location,
+        bot_id,
+        BotPermissions::default(),
+        None,
+    );
+
+    // Register a webhook which is bound to the bot
+    let name = random_string();
+    let webhook_details = match chat {
+        Chat::Group(group_id) => {
+            client::group::happy_path::register_webhook(env, owner.principal, group_id, name, None, Some(bot_id));
+            client::group::happy_path::selected_initial(env, owner.principal, group_id).webhooks
+        }
+        Chat::Channel(community_id, channel_id) => {
+            client::community::happy_path::register_webhook(
+                env,
+                owner.principal,
+                community_id,
+                channel_id,
+                name,
+                None,
+                Some(bot_id),
+            );
+            client::community::happy_path::selected_channel_initial(env, &owner, community_id, channel_id).webhooks
+        }
+        _ => unreachable!(),
+    };
+
+    let webhook_details = webhook_details.first().unwrap();
+    assert_eq!(webhook_details.bot_id, Some(bot_id));
+
+    let webhook_secret = match chat {
+        Chat::Group(group_id) => client::group::happy_path::webhook(env, owner.principal, group_id, webhook_details.id),
+        Chat::Channel(community_id, channel_id) => {
+            client::community::happy_path::webhook(env, owner.principal, community_id, channel_id, webhook_details.id)
+        }
+        _ => unreachable!(),
+    };
+
+    let latest_notification_index_at_start =
+        client::local_user_index::happy_path::latest_notification_index(env, *controller, local_user_index);
+
+    let gateway_url = env.make_live(None);
+    let invalid_response = post_message_to_webhook(
+        chat,
+        webhook_details.id,
+        webhook_secret.clone(),
+        "not json".to_string(),
+        gateway_url.clone(),
+    );
+    let response = post_message_to_webhook(
+        chat,
+        webhook_details.id,
+        webhook_secret,
+        r#"{"proposal_id":123,"status":"executed"}"#.to_string(),
+        gateway_url,
+    );
+    env.stop_live();
+
+    assert!(matches!(invalid_response, Ok(handle_webhook::Response::Error(_))));
+    assert!(matches!(response, Ok(handle_webhook::Response::DeliveredToBot)));
+
+    tick_many(env, 3);
+
+    // The payload should be delivered to the bot rather than being posted as a message
+    let notifications = client::local_user_index::happy_path::notifications(
+        env,
+        *controller,
+        local_user_index,
+        latest_notification_index_at_start + 1,
+    );
+
+    assert!(
+        notifications
+            .notifications
+            .iter()
+            .any(|n| matches!(&n.value, NotificationEnvelope::Bot(n) if n.recipients.contains_key(&bot_id)))
+    );
+}
+
 fn post_message_to_webhook(
     chat: Chat,
     webhook_id: UserId,
     webhook_secret: String,
     message: String,
     gateway_url: Url,
-) -> Result<send_message_v2::Response, String> {
+) -> Result<handle_webhook::Response, String> {
     let port = gateway_url.port_or_known_default().ok_or("Unknown port")?;
 
     // Build the webhook URL
@@ -176,5 +322,5 @@ fn post_message_to_webhook(
         ));
     }
 
-    response.json::<send_message_v2::Response>().map_err(|e| e.to_string())
+    response.json::<handle_webhook::Response>().map_err(|e| e.to_string())
 }
@@ -8,10 +8,10 @@ use types::{
     ChatEventCategory, ChatEventType, ChatId, CommunityId, DeletedBy, DirectChatCreated, EventIndex, EventWrapperInternal,
     EventsTimeToLiveUpdated, ExternalUrlUpdated, GroupCreated, GroupDescriptionChanged, GroupFrozen, GroupGateUpdated,
     GroupInviteCodeChanged, GroupNameChanged, GroupReplyContext, GroupRulesChanged, GroupUnfrozen, GroupVisibilityChanged,
-    MemberJoinedInternal, MemberLeft, MembersAdded, MembersAddedToDefaultChannel, MembersRemoved, Message, MessageContent,
-    MessageContentType, MessageId, MessageIndex, MessagePinned, MessageUnpinned, MultiUserChat, PermissionsChanged,
-    PushIfNotContains, Reaction, ReplyContext, RoleChanged, SenderContext, ThreadSummary, TimestampMillis, Tips, UserId,
-    UsersBlocked, UsersInvited, UsersUnblocked, is_default,
+    MemberJoinedInternal, MemberLeft, MemberMuted, MemberWarned, MembersAdded, MembersAddedToDefaultChannel, MembersRemoved,
+    Message, MessageContent, MessageContentType, MessageId, MessageIndex, MessagePinned, MessageUnpinned, MultiUserChat,
+    PermissionsChanged, PushIfNotContains, Reaction, ReplyContext, RoleChanged, SenderContext, ThreadSummary, TimestampMillis,
+    Tips, UserId, UsersBlocked, UsersInvited, UsersUnblocked, is_default,
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -74,6 +74,10 @@ pub enum ChatEventInternal {
     BotRemoved(Box<BotRemoved>),
     #[serde(rename = "bu")]
     BotUpdated(Box<BotUpdated>),
+    #[serde(rename = "mw")]
+    MemberWarned(Box<MemberWarned>),
+    #[serde(rename = "mm")]
+    MemberMuted(Box<MemberMuted>),
     #[serde(rename = "e")]
     Empty,
     // This should never happen!
@@ -124,6 +128,8 @@ impl ChatEventInternal {
                 | ChatEventInternal::BotAdded(_)
                 | ChatEventInternal::BotRemoved(_)
                 | ChatEventInternal::BotUpdated(_)
+                | ChatEventInternal::MemberWarned(_)
+                | ChatEventInternal::MemberMuted(_)
         )
     }
 
@@ -176,6 +182,8 @@ impl ChatEventInternal {
             ChatEventInternal::BotAdded(e) => ChatEvent::BotAdded(e),
             ChatEventInternal::BotRemoved(e) => ChatEvent::BotRemoved(e),
             ChatEventInternal::BotUpdated(e) => ChatEvent::BotUpdated(e),
+            ChatEventInternal::MemberWarned(e) => ChatEvent::MemberWarned(e),
+            ChatEventInternal::MemberMuted(e) => ChatEvent::MemberMuted(e),
         }
     }
 
@@ -209,7 +217,9 @@ impl ChatEventInternal {
             | ChatEventInternal::MembersAddedToPublicChannel(_)
             | ChatEventInternal::BotAdded(_)
             | ChatEventInternal::BotRemoved(_)
-            | ChatEventInternal::BotUpdated(_) => Some(ChatEventCategory::Membership),
+            | ChatEventInternal::BotUpdated(_)
+            | ChatEventInternal::MemberWarned(_)
+            | ChatEventInternal::MemberMuted(_) => Some(ChatEventCategory::Membership),
             ChatEventInternal::Empty | ChatEventInternal::FailedToDeserialize => None,
         }
     }
@@ -245,6 +255,8 @@ impl ChatEventInternal {
             ChatEventInternal::BotAdded(_) => Some(ChatEventType::BotAdded),
             ChatEventInternal::BotRemoved(_) => Some(ChatEventType::BotRemoved),
             ChatEventInternal::BotUpdated(_) => Some(ChatEventType::BotUpdated),
+            ChatEventInternal::MemberWarned(_) => Some(ChatEventType::MemberWarned),
+            ChatEventInternal::MemberMuted(_) => Some(ChatEventType::MemberMuted),
             ChatEventInternal::FailedToDeserialize => None,
             ChatEventInternal::Empty => None,
         }
@@ -374,6 +386,9 @@ impl MessageInternal {
             MessageContentType::VideoCall => {
                 metrics.incr(MetricKey::VideoCalls, 1);
             }
+            MessageContentType::Crowdfund => {
+                metrics.incr(MetricKey::Crowdfunds, 1);
+            }
             MessageContentType::Custom(_) => {
                 metrics.incr(MetricKey::CustomTypeMessages, 1);
             }
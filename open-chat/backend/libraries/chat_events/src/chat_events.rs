@@ -1,13 +1,15 @@
 use crate::chat_events_list::Reader;
 use crate::expiring_events::ExpiringEvents;
 use crate::last_updated_timestamps::LastUpdatedTimestamps;
+use crate::message_content_internal::MessageContentInternalSubtype;
+use crate::message_revisions;
 use crate::metrics::{ChatMetricsInternal, MetricKey};
-use crate::search_index::SearchIndex;
+use crate::search_index::{SearchDocument, SearchIndex};
 use crate::*;
 use constants::{ONE_MB, OPENCHAT_BOT_USER_ID};
 use event_store_types::EventBuilder;
 use oc_error_codes::{OCError, OCErrorCode};
-use search::simple::{Document, Query};
+use search::full_text::Query;
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::cmp::max;
@@ -18,14 +20,15 @@ use std::ops::DerefMut;
 use tracing::error;
 use types::{
     BlobReference, BotChatEvent, BotNotification, CallParticipant, CanisterId, Chat, ChatEvent, ChatEventCategory,
-    ChatEventType, ChatType, CompletedCryptoTransaction, DirectChatCreated, EventContext, EventIndex, EventMetaData,
-    EventWrapper, EventWrapperInternal, EventsTimeToLiveUpdated, GroupCanisterThreadDetails, GroupCreated, GroupFrozen,
-    GroupUnfrozen, HydratedMention, Mention, Message, MessageEditedEventPayload, MessageEventPayload, MessageId, MessageIndex,
-    MessageMatch, MessageTippedEventPayload, Milliseconds, MultiUserChat, OCResult, OptionUpdate, P2PSwapAccepted,
-    P2PSwapCompleted, P2PSwapCompletedEventPayload, P2PSwapContent, P2PSwapStatus, PendingCryptoTransaction, PollVotes,
+    ChatEventType, ChatType, CompletedCryptoTransaction, CrowdfundContent, CrowdfundStatus, DirectChatCreated, EventContext,
+    EventIndex, EventMetaData, EventWrapper, EventWrapperInternal, EventsTimeToLiveUpdated, GroupCanisterThreadDetails,
+    GroupCreated, GroupFrozen, GroupUnfrozen, HydratedMention, Mention, Message, MessageEditedEventPayload,
+    MessageEventPayload, MessageId, MessageIndex, MessageMatch, MessageRevision, MessageTippedEventPayload, Milliseconds,
+    MultiUserChat, OCResult, OptionUpdate, P2PSwapAccepted, P2PSwapCompleted, P2PSwapCompletedEventPayload, P2PSwapContent,
+    P2PSwapStatus, PendingCryptoTransaction, PollBallot, PollVotes, PollVotingMode, ProposalDecisionStatus,
     ProposalRewardStatus, ProposalUpdate, Reaction, ReactionAddedEventPayload, RegisterVoteResult, ReserveP2PSwapSuccess,
-    SenderContext, Tally, TimestampMillis, TimestampNanos, Timestamped, Tips, UserId, VideoCall, VideoCallEndedEventPayload,
-    VideoCallParticipants, VideoCallPresence, VideoCallType, VoteOperation,
+    SecretBallotAudit, SenderContext, Tally, TimestampMillis, TimestampNanos, Timestamped, Tips, UserId, VideoCall,
+    VideoCallEndedEventPayload, VideoCallParticipants, VideoCallPresence, VideoCallType, VoteOperation,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -45,6 +48,8 @@ pub struct ChatEvents {
     bot_subscriptions: BTreeMap<ChatEventType, HashSet<UserId>>,
     #[serde(rename = "pt", default, skip_serializing_if = "BTreeMap::is_empty")]
     active_proposal_tallies: BTreeMap<EventIndex, Tally>,
+    #[serde(rename = "eh", default)]
+    retain_edit_history: Timestamped<bool>,
 }
 
 impl ChatEvents {
@@ -71,9 +76,10 @@ impl ChatEvents {
             last_updated_timestamps: LastUpdatedTimestamps::default(),
             video_call_in_progress: Timestamped::default(),
             anonymized_id: hex::encode(anonymized_id.to_be_bytes()),
-            search_index: SearchIndex::default(),
+            search_index: SearchIndex::new(chat),
             bot_subscriptions: BTreeMap::new(),
             active_proposal_tallies: BTreeMap::new(),
+            retain_edit_history: Timestamped::default(),
         };
 
         events.push_event(None, ChatEventInternal::DirectChatCreated(DirectChatCreated {}), now);
@@ -103,9 +109,10 @@ impl ChatEvents {
             last_updated_timestamps: LastUpdatedTimestamps::default(),
             video_call_in_progress: Timestamped::default(),
             anonymized_id: hex::encode(anonymized_id.to_be_bytes()),
-            search_index: SearchIndex::default(),
+            search_index: SearchIndex::new(chat),
             bot_subscriptions: BTreeMap::new(),
             active_proposal_tallies: BTreeMap::new(),
+            retain_edit_history: Timestamped::default(),
         };
 
         events.push_event(
@@ -131,6 +138,8 @@ impl ChatEvents {
         for (message_index, events_list) in self.threads.iter_mut() {
             events_list.set_stable_memory_prefix(chat, Some(*message_index));
         }
+        // The search index isn't exported along with the events so it must be rebuilt for the new chat
+        self.search_index = SearchIndex::default();
     }
 
     pub fn read_events_as_bytes_from_stable_memory(&self, after: Option<EventContext>) -> Vec<(EventContext, ByteBuf)> {
@@ -279,6 +288,7 @@ impl ChatEvents {
         let now = args.now;
         let chat = self.chat;
         let anonymized_id = self.anonymized_id.clone();
+        let retain_edit_history = self.retain_edit_history.value;
 
         match self.update_message(
             thread_root_message_index,
@@ -287,13 +297,18 @@ impl ChatEvents {
             now,
             true,
             ChatEventType::MessageEdited,
-            |message, event| Self::edit_message_inner(message, event, args, chat, anonymized_id, event_pusher),
+            |message, event| {
+                Self::edit_message_inner(message, event, args, chat, anonymized_id, retain_edit_history, event_pusher)
+            },
         ) {
             Ok(result) => {
                 let bot_notification = result.bot_notification;
-                let (message_index, event, document) = result.value;
+                let (message_index, event, document, previous_content) = result.value;
                 if thread_root_message_index.is_none() {
-                    self.search_index.push(message_index, sender, document);
+                    self.search_index.push(message_index, document);
+                }
+                if let Some(content) = previous_content {
+                    message_revisions::push(chat, thread_root_message_index, message_index, content, now);
                 }
 
                 add_to_metrics(
@@ -326,8 +341,12 @@ impl ChatEvents {
         args: EditMessageArgs,
         chat: Chat,
         anonymized_id: String,
+        retain_edit_history: bool,
         mut event_pusher: Option<P>,
-    ) -> Result<(MessageIndex, EventMetaData, Document), UpdateEventError<OCResult<(MessageIndex, EventMetaData)>>> {
+    ) -> Result<
+        (MessageIndex, EventMetaData, SearchDocument, Option<MessageContentInternal>),
+        UpdateEventError<OCResult<(MessageIndex, EventMetaData)>>,
+    > {
         if message.sender != args.sender || matches!(message.content, MessageContentInternal::Deleted(_)) {
             return Err(UpdateEventError::NoChange(Err(OCErrorCode::InitiatorNotAuthorized.into())));
         }
@@ -342,9 +361,12 @@ impl ChatEvents {
                 || block_level_markdown_update.is_some();
 
             let old_length = message.content.text_length();
-            message.content = args.content;
+            let previous_content = std::mem::replace(&mut message.content, args.content);
 
-            let document = Document::from(&message.content);
+            let document = SearchDocument::new(message, event.timestamp);
+
+            // Bot messages are edited repeatedly while they are being streamed, so only record revisions once finalised
+            let retain_previous_content = edited && retain_edit_history && message.bot_context().is_none_or(|b| b.finalised);
 
             if edited {
                 if let Some(block_level_markdown) = block_level_markdown_update {
@@ -381,7 +403,12 @@ impl ChatEvents {
                     );
                 }
             }
-            return Ok((message.message_index, event, document));
+            return Ok((
+                message.message_index,
+                event,
+                document,
+                retain_previous_content.then_some(previous_content),
+            ));
         }
 
         Err(UpdateEventError::NoChange(Ok((message.message_index, event))))
@@ -481,7 +508,7 @@ impl ChatEvents {
             args.now,
             true,
             ChatEventType::MessageDeleted,
-            |message, _| Self::undelete_message_inner(message, &args),
+            |message, event| Self::undelete_message_inner(message, event, &args),
         ) {
             Ok(result) => {
                 let (sender, message_index, document) = result.value;
@@ -502,7 +529,7 @@ impl ChatEvents {
                     args.now,
                 );
                 if args.thread_root_message_index.is_none() {
-                    self.search_index.push(message_index, sender, document);
+                    self.search_index.push(message_index, document);
                 }
                 Ok(result.bot_notification)
             }
@@ -513,8 +540,9 @@ impl ChatEvents {
 
     fn undelete_message_inner(
         message: &mut MessageInternal,
+        event: EventMetaData,
         args: &DeleteUndeleteMessageArgs,
-    ) -> Result<(UserId, MessageIndex, Document), UpdateEventError<OCErrorCode>> {
+    ) -> Result<(UserId, MessageIndex, SearchDocument), UpdateEventError<OCErrorCode>> {
         let Some(deleted_by) = message.deleted_by.as_ref().map(|db| db.deleted_by) else {
             return Err(UpdateEventError::NoChange(OCErrorCode::NoChange));
         };
@@ -526,7 +554,7 @@ impl ChatEvents {
                 _ => {
                     let sender = message.sender;
                     message.deleted_by = None;
-                    Ok((sender, message.message_index, Document::from(&message.content)))
+                    Ok((sender, message.message_index, SearchDocument::new(message, event.timestamp)))
                 }
             }
         } else {
@@ -541,7 +569,7 @@ impl ChatEvents {
         message_id: MessageId,
         now: TimestampMillis,
     ) -> Option<(MessageContentInternal, UserId)> {
-        if let Ok((content, sender)) = self
+        if let Ok((content, sender, message_index)) = self
             .update_message(
                 thread_root_message_index,
                 message_id.into(),
@@ -553,6 +581,7 @@ impl ChatEvents {
             )
             .map(|r| r.value)
         {
+            message_revisions::remove(self.chat, thread_root_message_index, message_index);
             Some((content, sender))
         } else {
             None
@@ -561,7 +590,7 @@ impl ChatEvents {
 
     fn remove_deleted_message_content_inner(
         message: &mut MessageInternal,
-    ) -> Result<(MessageContentInternal, UserId), UpdateEventError> {
+    ) -> Result<(MessageContentInternal, UserId, MessageIndex), UpdateEventError> {
         let Some(deleted_by) = message.deleted_by.clone() else {
             return Err(UpdateEventError::NoChange(()));
         };
@@ -569,7 +598,7 @@ impl ChatEvents {
         let content = std::mem::replace(&mut message.content, MessageContentInternal::Deleted(deleted_by));
         let sender = message.sender;
 
-        Ok((content, sender))
+        Ok((content, sender, message.message_index))
     }
 
     pub fn register_poll_vote(
@@ -625,7 +654,14 @@ impl ChatEvents {
             return Err(UpdateEventError::NotFound);
         };
 
-        let result = p.register_vote(args.user_id, args.option_index, args.operation);
+        let result = if let Some(PollBallot::Commitment(commitment)) = args.ballot {
+            p.commit_secret_ballot(args.user_id, commitment, args.operation)
+        } else if p.config.voting_mode == PollVotingMode::Plurality {
+            p.register_vote(args.user_id, args.option_index, args.operation)
+        } else {
+            let ballot = args.ballot.clone().map(|b| b.into_inner()).unwrap_or_default();
+            p.register_ballot(args.user_id, ballot, args.operation)
+        };
 
         match result {
             RegisterVoteResult::Success(existing_vote_removed) => Ok(RegisterPollVoteSuccess {
@@ -643,6 +679,56 @@ impl ChatEvents {
             RegisterVoteResult::PollEnded => Err(UpdateEventError::NoChange(OCErrorCode::PollEnded)),
             RegisterVoteResult::OptionIndexOutOfRange => Err(UpdateEventError::NoChange(OCErrorCode::PollOptionNotFound)),
             RegisterVoteResult::UserCannotChangeVote => Err(UpdateEventError::NoChange(OCErrorCode::CannotChangeVote)),
+            RegisterVoteResult::DuplicateCommitment => Err(UpdateEventError::NoChange(OCErrorCode::DuplicateBallotCommitment)),
+        }
+    }
+
+    pub fn reveal_poll_ballot(&mut self, args: RevealPollBallotArgs) -> OCResult<UpdateMessageSuccess<PollVotes>> {
+        match self.update_message(
+            args.thread_root_message_index,
+            args.message_index.into(),
+            args.min_visible_event_index,
+            args.now,
+            true,
+            ChatEventType::MessagePollVote,
+            |message, _| Self::reveal_poll_ballot_inner(message, &args),
+        ) {
+            Ok(result) => Ok(result),
+            Err(UpdateEventError::NoChange(error)) => Err(error),
+            Err(UpdateEventError::NotFound) => Err(OCErrorCode::PollNotFound.into()),
+        }
+    }
+
+    fn reveal_poll_ballot_inner(
+        message: &mut MessageInternal,
+        args: &RevealPollBallotArgs,
+    ) -> Result<PollVotes, UpdateEventError<OCError>> {
+        let MessageContentInternal::Poll(p) = &mut message.content else {
+            return Err(UpdateEventError::NotFound);
+        };
+
+        p.reveal_secret_ballot(args.options.clone(), args.salt.clone(), args.now)
+            .map_err(|error| UpdateEventError::NoChange(error.into()))?;
+
+        Ok(p.votes(None))
+    }
+
+    pub fn secret_ballot_audit(
+        &self,
+        min_visible_event_index: EventIndex,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+    ) -> OCResult<SecretBallotAudit> {
+        let Some((message, _)) =
+            self.message_internal(min_visible_event_index, thread_root_message_index, message_index.into())
+        else {
+            return Err(OCErrorCode::PollNotFound.into());
+        };
+
+        if let MessageContentInternal::Poll(p) = message.content {
+            p.secret_ballot_audit().ok_or(OCErrorCode::PollNotFound.into())
+        } else {
+            Err(OCErrorCode::PollNotFound.into())
         }
     }
 
@@ -676,12 +762,41 @@ impl ChatEvents {
 
         if !p.ended && p.config.end_date.is_some() {
             p.ended = true;
+            p.end_secret_ballot();
             Ok(())
         } else {
             Err(UpdateEventError::NoChange(()))
         }
     }
 
+    pub fn record_poll_weights(
+        &mut self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+        weights: Vec<(UserId, u128)>,
+        default_weight: u128,
+        snapshot_complete: bool,
+        now: TimestampMillis,
+    ) -> bool {
+        self.update_message(
+            thread_root_message_index,
+            message_index.into(),
+            EventIndex::default(),
+            now,
+            snapshot_complete,
+            ChatEventType::MessageOther,
+            |message, _| {
+                if let MessageContentInternal::Poll(p) = &mut message.content {
+                    p.record_weights(weights, default_weight, snapshot_complete);
+                    Ok(())
+                } else {
+                    Err(UpdateEventError::<()>::NotFound)
+                }
+            },
+        )
+        .is_ok()
+    }
+
     pub fn final_payments(&mut self, message_index: MessageIndex, now_nanos: TimestampNanos) -> Vec<PendingCryptoTransaction> {
         self.update_message(
             None,
@@ -752,20 +867,85 @@ impl ChatEvents {
         }
     }
 
-    pub fn update_proposals(&mut self, user_id: UserId, updates: Vec<ProposalUpdate>, now: TimestampMillis) -> bool {
-        let mut mark_chat_updated = false;
+    pub fn record_delegate_proposal_vote(
+        &mut self,
+        delegate: UserId,
+        message_index: MessageIndex,
+        adopt: bool,
+        now: TimestampMillis,
+    ) -> OCResult<UpdateMessageSuccess> {
+        match self.update_message(
+            None,
+            message_index.into(),
+            EventIndex::default(),
+            now,
+            true,
+            ChatEventType::MessageOther,
+            |message, _| Self::record_delegate_proposal_vote_inner(message, delegate, adopt, now),
+        ) {
+            Ok(success) => Ok(success),
+            Err(UpdateEventError::NoChange(error)) => Err(error),
+            Err(UpdateEventError::NotFound) => Err(OCErrorCode::ProposalNotFound.into()),
+        }
+    }
+
+    fn record_delegate_proposal_vote_inner(
+        message: &mut MessageInternal,
+        delegate: UserId,
+        adopt: bool,
+        now: TimestampMillis,
+    ) -> Result<(), UpdateEventError<OCError>> {
+        let MessageContentInternal::GovernanceProposal(proposal) = &mut message.content else {
+            return Err(UpdateEventError::NotFound);
+        };
+
+        if proposal.proposal.status().is_decided() {
+            return Err(UpdateEventError::NoChange(OCErrorCode::ProposalNotAcceptingVotes.into()));
+        }
+
+        if proposal
+            .delegate_vote
+            .is_some_and(|v| v.delegate == delegate && v.adopt == adopt)
+        {
+            return Err(UpdateEventError::NoChange(OCErrorCode::NoChange.into()));
+        }
+
+        proposal.delegate_vote = Some(ProposalDelegateVoteInternal {
+            delegate,
+            adopt,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    pub fn update_proposals(
+        &mut self,
+        user_id: UserId,
+        updates: Vec<ProposalUpdate>,
+        now: TimestampMillis,
+    ) -> UpdateProposalsResult {
+        let mut result = UpdateProposalsResult::default();
         for update in updates {
             // If only the tally has been updated, skip marking the message as having been updated
             let should_mark_updated = update.deadline.is_some() || update.reward_status.is_some() || update.status.is_some();
             let tally_update = update.latest_tally.clone();
 
+            // Bots are only notified when the proposal first moves from being open to being decided
+            let event_type = if update.status.is_some_and(|s| s.is_decided())
+                && self.proposal_status(update.message_id).is_some_and(|s| !s.is_decided())
+            {
+                ChatEventType::MessageProposalDecided
+            } else {
+                ChatEventType::MessageOther
+            };
+
             if let Ok(success) = self.update_message(
                 None,
                 update.message_id.into(),
                 EventIndex::default(),
                 now,
                 should_mark_updated,
-                ChatEventType::MessageOther,
+                event_type,
                 |message, _| Self::update_proposal_inner(message, user_id, update, now),
             ) {
                 if !matches!(success.value, ProposalRewardStatus::AcceptVotes) {
@@ -774,11 +954,21 @@ impl ChatEvents {
                     self.active_proposal_tallies.insert(success.event_index, tally);
                 }
                 if should_mark_updated {
-                    mark_chat_updated = true;
+                    result.chat_updated = true;
                 }
+                result.bot_notifications.push(success.bot_notification);
             }
         }
-        mark_chat_updated
+        result
+    }
+
+    fn proposal_status(&self, message_id: MessageId) -> Option<ProposalDecisionStatus> {
+        let (message, _) = self.message_internal(EventIndex::default(), None, message_id.into())?;
+        if let MessageContentInternal::GovernanceProposal(p) = message.content {
+            Some(p.proposal.status())
+        } else {
+            None
+        }
     }
 
     fn update_proposal_inner(
@@ -1520,6 +1710,74 @@ impl ChatEvents {
         }
     }
 
+    pub fn get_crowdfund(
+        &self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        min_visible_event_index: EventIndex,
+    ) -> Option<CrowdfundContent> {
+        self.message_internal(min_visible_event_index, thread_root_message_index, message_id.into())
+            .and_then(|(m, _)| if let MessageContentInternal::Crowdfund(c) = m.content { Some(c.hydrate(None)) } else { None })
+    }
+
+    // Returns true if the contribution takes the crowdfund to its target
+    pub fn record_crowdfund_contribution(
+        &mut self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        amount: u128,
+        now: TimestampMillis,
+    ) -> Result<UpdateMessageSuccess<bool>, UpdateEventError> {
+        self.update_message(
+            thread_root_message_index,
+            message_id.into(),
+            EventIndex::default(),
+            now,
+            true,
+            ChatEventType::MessageOther,
+            |message, _| Self::record_crowdfund_contribution_inner(message, user_id, amount),
+        )
+    }
+
+    fn record_crowdfund_contribution_inner(
+        message: &mut MessageInternal,
+        user_id: UserId,
+        amount: u128,
+    ) -> Result<bool, UpdateEventError> {
+        let MessageContentInternal::Crowdfund(content) = &mut message.content else {
+            return Err(UpdateEventError::NotFound);
+        };
+
+        Ok(content.contribute(user_id, amount))
+    }
+
+    pub fn set_crowdfund_status(
+        &mut self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        status: CrowdfundStatus,
+        now: TimestampMillis,
+    ) -> Result<UpdateMessageSuccess, UpdateEventError> {
+        self.update_message(
+            thread_root_message_index,
+            message_id.into(),
+            EventIndex::default(),
+            now,
+            true,
+            ChatEventType::MessageOther,
+            |message, _| Self::set_crowdfund_status_inner(message, status),
+        )
+    }
+
+    fn set_crowdfund_status_inner(message: &mut MessageInternal, status: CrowdfundStatus) -> Result<(), UpdateEventError> {
+        let MessageContentInternal::Crowdfund(content) = &mut message.content else {
+            return Err(UpdateEventError::NotFound);
+        };
+
+        if content.set_status(status) { Ok(()) } else { Err(UpdateEventError::NoChange(())) }
+    }
+
     // Used when a group is imported into a community
     pub fn migrate_reply(&mut self, message_index: MessageIndex, old: ChatInternal, new: ChatInternal, now: TimestampMillis) {
         if self
@@ -1655,7 +1913,7 @@ impl ChatEvents {
             self.threads.get_mut(&root_message_index).unwrap()
         } else {
             if let ChatEventInternal::Message(m) = &event {
-                self.search_index.push(m.message_index, m.sender, Document::from(&m.content));
+                self.search_index.push(m.message_index, SearchDocument::new(m, now));
             }
             &mut self.main
         };
@@ -1666,7 +1924,16 @@ impl ChatEvents {
             self.expiring_events.insert(event_index, timestamp);
         }
 
-        let bots_to_notify = self.bots_to_notify(&event_type);
+        let mut bots_to_notify = self.bots_to_notify(&event_type);
+        if let ChatEventInternal::Message(m) = &event {
+            if matches!(m.content, MessageContentInternal::GovernanceProposal(_)) {
+                for bot_id in self.bots_to_notify(&ChatEventType::MessageProposalCreated) {
+                    if !bots_to_notify.contains(&bot_id) {
+                        bots_to_notify.push(bot_id);
+                    }
+                }
+            }
+        }
         let bot_notification = if bots_to_notify.is_empty() {
             None
         } else {
@@ -1692,6 +1959,25 @@ impl ChatEvents {
         }
     }
 
+    pub fn retain_edit_history(&self) -> &Timestamped<bool> {
+        &self.retain_edit_history
+    }
+
+    pub fn set_retain_edit_history(&mut self, retain_edit_history: bool, now: TimestampMillis) {
+        if self.retain_edit_history.value != retain_edit_history {
+            self.retain_edit_history = Timestamped::new(retain_edit_history, now);
+        }
+    }
+
+    pub fn message_revisions(
+        &self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+        my_user_id: Option<UserId>,
+    ) -> Vec<MessageRevision> {
+        message_revisions::get(self.chat, thread_root_message_index, message_index, my_user_id)
+    }
+
     pub fn get_events_time_to_live(&self) -> &Timestamped<Option<Milliseconds>> {
         &self.events_ttl
     }
@@ -1716,18 +2002,52 @@ impl ChatEvents {
         }
     }
 
+    // Returns the matching messages along with the timestamps at which they were sent, ordered by relevance
     pub fn search_messages(
         &self,
         min_visible_message_index: MessageIndex,
-        query: Query,
-        users: HashSet<UserId>,
-        max_results: u8,
-    ) -> Vec<MessageMatch> {
-        self.search_index
-            .search_messages(min_visible_message_index, query, users)
-            .map(|message_index| MessageMatch { message_index, score: 1 })
-            .take(max_results as usize)
-            .collect()
+        query: &Query,
+        users: &HashSet<UserId>,
+        max_results: usize,
+    ) -> Vec<(MessageMatch, TimestampMillis)> {
+        self.search_index.search(
+            min_visible_message_index,
+            query,
+            users,
+            |message_index| !query.in_thread || self.threads.contains_key(&message_index),
+            max_results,
+        )
+    }
+
+    pub fn search_index_requires_backfill(&self) -> bool {
+        !self.search_index.is_initialised() || self.search_index.backfill_next().is_some()
+    }
+
+    // Indexes up to `max_messages` of the messages which were sent before the search index was built, returning
+    // true once every message has been indexed
+    pub fn backfill_search_index(&mut self, max_messages: usize) -> bool {
+        if !self.search_index.is_initialised() {
+            self.search_index.start_backfill(self.chat, self.main.latest_message_index());
+        }
+
+        for _ in 0..max_messages {
+            let Some(message_index) = self.search_index.backfill_next() else {
+                return true;
+            };
+
+            if let Some(event) = self.main.get_event(message_index.into(), EventIndex::default(), None) {
+                if let ChatEventInternal::Message(m) = &event.event {
+                    if m.deleted_by.is_none() {
+                        self.search_index
+                            .push(m.message_index, SearchDocument::new(m, event.timestamp));
+                    }
+                }
+            }
+
+            self.search_index
+                .set_backfill_next(u32::from(message_index).checked_sub(1).map(MessageIndex::from));
+        }
+        self.search_index.backfill_next().is_none()
     }
 
     pub fn push_main_event(&mut self, event: ChatEventInternal, now: TimestampMillis) -> PushEventResultInternal {
@@ -1896,8 +2216,11 @@ impl ChatEvents {
             if let Some(event) = self.main.remove(event_index) {
                 result.events.push(event_index);
                 if let ChatEventInternal::Message(m) = event.event {
+                    self.search_index.remove(m.message_index);
+                    message_revisions::remove(self.chat, None, m.message_index);
                     if let Some(thread) = m.thread_summary {
                         self.threads.remove(&m.message_index);
+                        message_revisions::remove_thread(self.chat, m.message_index);
                         result.threads.push(ExpiredThread {
                             root_message_index: m.message_index,
                             followers: thread.followers,
@@ -2460,10 +2783,20 @@ pub struct RegisterPollVoteArgs {
     pub thread_root_message_index: Option<MessageIndex>,
     pub message_index: MessageIndex,
     pub option_index: u32,
+    pub ballot: Option<PollBallot>,
     pub operation: VoteOperation,
     pub now: TimestampMillis,
 }
 
+pub struct RevealPollBallotArgs {
+    pub min_visible_event_index: EventIndex,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub options: Vec<u32>,
+    pub salt: Vec<u8>,
+    pub now: TimestampMillis,
+}
+
 pub struct RegisterPollVoteSuccess {
     pub poll_creator: UserId,
     pub votes: PollVotes,
@@ -2512,6 +2845,12 @@ pub struct ReservePrizeSuccess {
     pub message_index: MessageIndex,
 }
 
+#[derive(Default)]
+pub struct UpdateProposalsResult {
+    pub chat_updated: bool,
+    pub bot_notifications: Vec<Option<BotNotification>>,
+}
+
 #[derive(Default)]
 pub struct RemoveExpiredEventsResult {
     pub events: Vec<EventIndex>,
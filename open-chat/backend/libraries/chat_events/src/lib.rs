@@ -9,6 +9,7 @@ mod expiring_events;
 mod hybrid_map;
 mod last_updated_timestamps;
 mod message_content_internal;
+mod message_revisions;
 mod metrics;
 mod search_index;
 mod stable_memory;
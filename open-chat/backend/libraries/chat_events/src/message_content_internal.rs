@@ -1,29 +1,36 @@
 #![expect(deprecated)]
 use crate::DeletedByInternal;
 use candid::{CandidType, Principal};
-use constants::{MEMO_PRIZE_FEE, MEMO_PRIZE_REFUND, OPENCHAT_TREASURY_CANISTER_ID, PRIZE_FEE_PERCENT};
-use ledger_utils::{create_pending_transaction, format_crypto_amount};
-use search::simple::Document;
+use constants::{DAY_IN_MS, MEMO_PRIZE_FEE, MEMO_PRIZE_REFUND, OPENCHAT_TREASURY_CANISTER_ID, PRIZE_FEE_PERCENT};
+use ledger_utils::format_crypto_amount;
+use oc_error_codes::OCErrorCode;
+use search::full_text::Document;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_bytes::ByteBuf;
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use types::icrc1::{Account, CryptoAccount};
 use types::{
     AudioContent, BlobReference, CallParticipant, CanisterId, CompletedCryptoTransaction, ContentValidationError,
-    ContentWithCaptionEventPayload, CryptoContent, CryptoContentEventPayload, CryptoTransaction, Cryptocurrency, CustomContent,
-    EncryptedContent, EncryptedContentEventPayload, EncryptedMessageContentType, EncryptionKey, FileContent,
-    FileContentEventPayload, GiphyContent, GiphyImageVariant, GovernanceProposalContentEventPayload, ImageContent,
+    ContentWithCaptionEventPayload, CrowdfundContent, CrowdfundContentEventPayload, CrowdfundContentInitial, CrowdfundStatus,
+    CryptoContent, CryptoContentEventPayload, CryptoTransaction, Cryptocurrency, CustomContent, EncryptedContent,
+    EncryptedContentEventPayload, EncryptedMessageContentType, EncryptionKey, FileContent, FileContentEventPayload,
+    GiphyContent, GiphyImageVariant, GovernanceProposalContentEventPayload, Hash, ImageContent,
     ImageOrVideoContentEventPayload, MAX_TEXT_LENGTH, MAX_TEXT_LENGTH_USIZE, MessageContent, MessageContentEventPayload,
     MessageContentInitial, MessageContentType, MessageIndex, MessageReminderContent, MessageReminderContentEventPayload,
-    MessageReminderCreatedContent, MessageReport, P2PSwapAccepted, P2PSwapCancelled, P2PSwapCompleted, P2PSwapContent,
-    P2PSwapContentEventPayload, P2PSwapContentInitial, P2PSwapExpired, P2PSwapReserved, P2PSwapStatus,
-    PendingCryptoTransaction, PollConfig, PollContent, PollContentEventPayload, PollVotes, PrizeContent,
-    PrizeContentEventPayload, PrizeContentInitial, PrizeWinnerContent, PrizeWinnerContentEventPayload, Proposal,
-    ProposalContent, RegisterVoteResult, ReportedMessage, ReportedMessageContentEventPayload, TextContent,
-    TextContentEventPayload, ThumbnailData, TimestampMillis, TimestampNanos, TokenInfo, TotalVotes, TransactionHash, UserId,
-    UserType, VideoCallContent, VideoCallPresence, VideoCallType, VideoContent, VoteOperation, is_default,
+    MessageReminderCreatedContent, MessageReport, Milliseconds, P2PSwapAccepted, P2PSwapCancelled, P2PSwapCompleted,
+    P2PSwapContent, P2PSwapContentEventPayload, P2PSwapContentInitial, P2PSwapExpired, P2PSwapReserved, P2PSwapStatus,
+    PendingCryptoTransaction, PollBallot, PollConfig, PollContent, PollContentEventPayload, PollVotes, PollVotingMode,
+    PollWeighting, PrizeContent, PrizeContentEventPayload, PrizeContentInitial, PrizeWinnerContent,
+    PrizeWinnerContentEventPayload, Proposal, ProposalContent, ProposalDelegateVote, RankedChoiceResults, RankedChoiceRound,
+    RegisterVoteResult, ReportedMessage, ReportedMessageContentEventPayload, RevealedBallot, ScoreResults, SecretBallotAudit,
+    SecretBallotResults, TextContent, TextContentEventPayload, ThumbnailData, TimestampMillis, TimestampNanos, TokenInfo,
+    TotalVotes, TransactionHash, UserId, UserType, VideoCallContent, VideoCallPresence, VideoCallType, VideoContent,
+    VoteOperation, WeightedVotes, is_default, secret_ballot_commitment, secret_ballot_merkle_root,
 };
 
+// Once a secret ballot poll has ended, voters have this long to reveal their ballots
+const SECRET_BALLOT_REVEAL_PERIOD: Milliseconds = 2 * DAY_IN_MS;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum MessageContentInternal {
     #[serde(rename = "t")]
@@ -60,6 +67,8 @@ pub enum MessageContentInternal {
     P2PSwap(P2PSwapContentInternal),
     #[serde(rename = "vc")]
     VideoCall(VideoCallContentInternal),
+    #[serde(rename = "cf")]
+    Crowdfund(CrowdfundContentInternal),
     #[serde(rename = "e")]
     Encrypted(EncryptedContentInternal),
     #[serde(rename = "cu")]
@@ -104,6 +113,14 @@ impl MessageContentInternal {
                     return ValidateNewMessageContentResult::Error(ContentValidationError::PrizeEndDateInThePast);
                 }
             }
+            MessageContentInitial::Crowdfund(c) => {
+                if c.deadline <= now {
+                    return ValidateNewMessageContentResult::Error(ContentValidationError::CrowdfundDeadlineInThePast);
+                }
+                if c.initial_contribution == 0 || c.initial_contribution >= c.target {
+                    return ValidateNewMessageContentResult::Error(ContentValidationError::InvalidCrowdfundTarget);
+                }
+            }
             MessageContentInitial::Encrypted(e) => {
                 if e.encrypted_data.len() > MAX_TEXT_LENGTH_USIZE {
                     return ValidateNewMessageContentResult::Error(ContentValidationError::TextTooLong(MAX_TEXT_LENGTH));
@@ -133,6 +150,7 @@ impl MessageContentInternal {
             | MessageContentInitial::MessageReminderCreated(_)
             | MessageContentInitial::MessageReminder(_)
             | MessageContentInitial::P2PSwap(_)
+            | MessageContentInitial::Crowdfund(_)
             | MessageContentInitial::Custom(_) => false,
         };
 
@@ -163,6 +181,7 @@ impl MessageContentInternal {
                 _ => ValidateNewMessageContentResult::Error(ContentValidationError::TransferMustBePending),
             },
             MessageContentInitial::P2PSwap(c) => ValidateNewMessageContentResult::SuccessP2PSwap(c),
+            MessageContentInitial::Crowdfund(c) => ValidateNewMessageContentResult::SuccessCrowdfund(c),
             content => ValidateNewMessageContentResult::Success(content.into()),
         }
     }
@@ -206,6 +225,7 @@ impl MessageContentInternal {
             MessageContentInternal::ReportedMessage(r) => MessageContent::ReportedMessage(r.hydrate(my_user_id)),
             MessageContentInternal::P2PSwap(p) => MessageContent::P2PSwap(p.hydrate(my_user_id)),
             MessageContentInternal::VideoCall(c) => MessageContent::VideoCall(c.hydrate()),
+            MessageContentInternal::Crowdfund(c) => MessageContent::Crowdfund(c.hydrate(my_user_id)),
             MessageContentInternal::Encrypted(e) => MessageContent::Encrypted(e.hydrate(my_user_id)),
             MessageContentInternal::Custom(c) => MessageContent::Custom(c.hydrate(my_user_id)),
         }
@@ -226,6 +246,7 @@ impl MessageContentInternal {
             MessageContentInternal::MessageReminderCreated(r) => r.notes.as_deref(),
             MessageContentInternal::MessageReminder(r) => r.notes.as_deref(),
             MessageContentInternal::P2PSwap(p) => p.caption.as_deref(),
+            MessageContentInternal::Crowdfund(c) => c.caption.as_deref(),
             MessageContentInternal::PrizeWinner(_)
             | MessageContentInternal::Deleted(_)
             | MessageContentInternal::ReportedMessage(_)
@@ -279,6 +300,7 @@ impl MessageContentInternal {
             | MessageContentInternal::ReportedMessage(_)
             | MessageContentInternal::P2PSwap(_)
             | MessageContentInternal::VideoCall(_)
+            | MessageContentInternal::Crowdfund(_)
             | MessageContentInternal::Encrypted(_)
             | MessageContentInternal::Custom(_) => {}
         }
@@ -366,6 +388,11 @@ impl MessageContentInternal {
                 token1: c.token1.symbol.clone(),
                 token1_amount: c.token1_amount,
             }),
+            MessageContentInternal::Crowdfund(c) => MessageContentEventPayload::Crowdfund(CrowdfundContentEventPayload {
+                token: c.token.symbol.clone(),
+                target: c.target,
+                caption_length: option_string_length(&c.caption),
+            }),
             MessageContentInternal::Encrypted(e) => MessageContentEventPayload::Encrypted(EncryptedContentEventPayload {
                 content_type: MessageContentType::from(e.content_type.clone()).to_string(),
                 encrypted_length: e.encrypted_data.len() as u32,
@@ -386,6 +413,7 @@ pub enum ValidateNewMessageContentResult {
     SuccessCrypto(CryptoContent),
     SuccessPrize(PrizeContentInitial),
     SuccessP2PSwap(P2PSwapContentInitial),
+    SuccessCrowdfund(CrowdfundContentInitial),
     Error(ContentValidationError),
 }
 
@@ -453,6 +481,11 @@ impl From<&MessageContentInternal> for Document {
                 document.add_field(&p.token1.symbol);
                 try_add_caption(&mut document, p.caption.as_ref())
             }
+            MessageContentInternal::Crowdfund(c) => {
+                document.add_field("crowdfund");
+                document.add_field(&c.token.symbol);
+                try_add_caption(&mut document, c.caption.as_ref())
+            }
             MessageContentInternal::Custom(c) => {
                 document.add_field(&c.kind);
             }
@@ -664,14 +697,77 @@ pub struct PollContentInternal {
     pub votes: HashMap<u32, Vec<UserId>>,
     #[serde(rename = "e")]
     pub ended: bool,
+    // Used instead of `votes` for every voting mode other than `Plurality`
+    #[serde(rename = "b", default, skip_serializing_if = "HashMap::is_empty")]
+    pub ballots: HashMap<UserId, Vec<u32>>,
+    #[serde(rename = "s", default, skip_serializing_if = "Option::is_none")]
+    pub secret_ballot: Option<SecretBallotInternal>,
+    #[serde(rename = "w", default, skip_serializing_if = "Option::is_none")]
+    pub weights: Option<PollWeightsInternal>,
+}
+
+// Members not in `weights` (eg. those who joined after the poll was created) get the default weight
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct PollWeightsInternal {
+    #[serde(rename = "w")]
+    weights: HashMap<UserId, u128>,
+    #[serde(rename = "d", default, skip_serializing_if = "is_default")]
+    default_weight: u128,
+    #[serde(rename = "c", default, skip_serializing_if = "is_default")]
+    snapshot_complete: bool,
+}
+
+impl PollWeightsInternal {
+    fn weighted_votes(&self, votes: &HashMap<u32, Vec<UserId>>) -> WeightedVotes {
+        WeightedVotes {
+            totals: votes
+                .iter()
+                .map(|(option, users)| {
+                    let total = users
+                        .iter()
+                        .map(|u| self.weights.get(u).copied().unwrap_or(self.default_weight))
+                        .sum();
+                    (*option, total)
+                })
+                .collect(),
+            snapshot_complete: self.snapshot_complete,
+        }
+    }
+}
+
+// The voters and their commitments are stored separately so that neither can be linked to the other
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SecretBallotInternal {
+    #[serde(rename = "v")]
+    voters: HashSet<UserId>,
+    #[serde(rename = "c")]
+    commitments: BTreeSet<Hash>,
+    #[serde(rename = "r", default, skip_serializing_if = "BTreeMap::is_empty")]
+    revealed: BTreeMap<Hash, RevealedBallotInternal>,
+    #[serde(rename = "m", default, skip_serializing_if = "Option::is_none")]
+    merkle_root: Option<Hash>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct RevealedBallotInternal {
+    #[serde(rename = "o")]
+    options: Vec<u32>,
+    #[serde(rename = "s")]
+    salt: ByteBuf,
 }
 
 impl From<PollContent> for PollContentInternal {
     fn from(value: PollContent) -> Self {
+        let secret_ballot = value.config.is_secret_ballot().then(SecretBallotInternal::default);
+        let weights = value.config.weighting.is_some().then(PollWeightsInternal::default);
+
         PollContentInternal {
             config: value.config.into(),
             votes: HashMap::new(),
             ended: false,
+            ballots: HashMap::new(),
+            secret_ballot,
+            weights,
         }
     }
 }
@@ -733,34 +829,307 @@ impl PollContentInternal {
         }
     }
 
+    // The ballot must have already been validated against the poll's voting mode
+    pub fn register_ballot(&mut self, user_id: UserId, ballot: Vec<u32>, operation: VoteOperation) -> RegisterVoteResult {
+        if self.ended {
+            return RegisterVoteResult::PollEnded;
+        }
+
+        match operation {
+            VoteOperation::RegisterVote => {
+                let existing = self.ballots.get(&user_id);
+                if existing == Some(&ballot) {
+                    RegisterVoteResult::SuccessNoChange
+                } else if existing.is_some() && !self.config.allow_user_to_change_vote {
+                    RegisterVoteResult::UserCannotChangeVote
+                } else {
+                    RegisterVoteResult::Success(self.ballots.insert(user_id, ballot).is_some())
+                }
+            }
+            VoteOperation::DeleteVote => {
+                if self.ballots.remove(&user_id).is_some() {
+                    RegisterVoteResult::Success(true)
+                } else {
+                    RegisterVoteResult::SuccessNoChange
+                }
+            }
+        }
+    }
+
+    pub fn commit_secret_ballot(&mut self, user_id: UserId, commitment: Hash, operation: VoteOperation) -> RegisterVoteResult {
+        let Some(secret_ballot) = self.secret_ballot.as_mut() else {
+            return RegisterVoteResult::OptionIndexOutOfRange;
+        };
+
+        if self.ended {
+            RegisterVoteResult::PollEnded
+        } else if matches!(operation, VoteOperation::DeleteVote) || secret_ballot.voters.contains(&user_id) {
+            // Commitments can't be linked back to their voters so can never be changed or removed
+            RegisterVoteResult::UserCannotChangeVote
+        } else if !secret_ballot.commitments.insert(commitment) {
+            // Prevents a voter copying somebody else's commitment
+            RegisterVoteResult::DuplicateCommitment
+        } else {
+            secret_ballot.voters.insert(user_id);
+            RegisterVoteResult::Success(false)
+        }
+    }
+
+    pub fn reveal_secret_ballot(&mut self, options: Vec<u32>, salt: Vec<u8>, now: TimestampMillis) -> Result<(), OCErrorCode> {
+        let Some(secret_ballot) = self.secret_ballot.as_mut() else {
+            return Err(OCErrorCode::PollNotFound);
+        };
+
+        if !self.ended {
+            return Err(OCErrorCode::PollNotEnded);
+        }
+        if self
+            .config
+            .end_date
+            .is_some_and(|end| now > end + SECRET_BALLOT_REVEAL_PERIOD)
+        {
+            return Err(OCErrorCode::PollEnded);
+        }
+
+        let commitment = secret_ballot_commitment(&options, &salt);
+        if !secret_ballot.commitments.contains(&commitment) {
+            return Err(OCErrorCode::InvalidPollBallot.with_message("commitment not found"));
+        }
+        if secret_ballot.revealed.contains_key(&commitment) {
+            return Err(OCErrorCode::BallotAlreadyRevealed);
+        }
+
+        let option_count = self.config.options.len();
+        let valid_ballot = PollBallot::Approval(options.clone()).validate(PollVotingMode::Approval, option_count);
+        if let Err(reason) = valid_ballot {
+            return Err(OCErrorCode::InvalidPollBallot.with_message(format!("{reason:?}")));
+        }
+        if self.config.voting_mode == PollVotingMode::Plurality
+            && !self.config.allow_multiple_votes_per_user
+            && options.len() > 1
+        {
+            return Err(OCErrorCode::InvalidPollBallot.with_message("only one option allowed"));
+        }
+
+        secret_ballot.revealed.insert(
+            commitment,
+            RevealedBallotInternal {
+                options,
+                salt: ByteBuf::from(salt),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn end_secret_ballot(&mut self) {
+        if let Some(secret_ballot) = self.secret_ballot.as_mut() {
+            let commitments: Vec<_> = secret_ballot.commitments.iter().copied().collect();
+            secret_ballot.merkle_root = secret_ballot_merkle_root(&commitments);
+        }
+    }
+
+    pub fn secret_ballot_audit(&self) -> Option<SecretBallotAudit> {
+        let secret_ballot = self.secret_ballot.as_ref()?;
+
+        Some(SecretBallotAudit {
+            commitments: secret_ballot.commitments.iter().copied().collect(),
+            revealed: secret_ballot
+                .revealed
+                .values()
+                .map(|r| RevealedBallot {
+                    options: r.options.clone(),
+                    salt: r.salt.to_vec(),
+                })
+                .collect(),
+            merkle_root: secret_ballot.merkle_root,
+        })
+    }
+
     pub fn votes(&self, my_user_id: Option<UserId>) -> PollVotes {
+        if let Some(secret_ballot) = &self.secret_ballot {
+            return self.secret_ballot_votes(secret_ballot);
+        }
+
+        let voting_mode = self.config.voting_mode;
         let user_votes = if let Some(user_id) = my_user_id {
-            self.votes
-                .iter()
-                .filter(|(_, v)| v.contains(&user_id))
-                .map(|(k, _)| *k)
-                .collect()
+            if voting_mode == PollVotingMode::Plurality {
+                self.votes
+                    .iter()
+                    .filter(|(_, v)| v.contains(&user_id))
+                    .map(|(k, _)| *k)
+                    .collect()
+            } else {
+                self.ballots.get(&user_id).cloned().unwrap_or_default()
+            }
         } else {
             Vec::new()
         };
 
         let total_votes: TotalVotes;
+        let mut weighted = None;
         let hide_votes = self.config.end_date.is_some() && !self.ended && !self.config.show_votes_before_end_date;
         if hide_votes {
-            total_votes = TotalVotes::Hidden(self.votes.values().map(|v| v.len() as u32).sum());
-        } else if self.config.anonymous {
-            total_votes = TotalVotes::Anonymous(self.votes.iter().map(|(k, v)| (*k, v.len() as u32)).collect());
+            let count = if voting_mode == PollVotingMode::Plurality {
+                self.votes.values().map(|v| v.len() as u32).sum()
+            } else {
+                self.ballots.len() as u32
+            };
+            total_votes = TotalVotes::Hidden(count);
         } else {
-            total_votes = TotalVotes::Visible(self.votes.clone());
+            let option_count = self.config.options.len() as u32;
+            let votes_per_option = match voting_mode {
+                PollVotingMode::Plurality => Some(self.votes.clone()),
+                PollVotingMode::Approval => Some(self.approval_votes()),
+                _ => None,
+            };
+            if let (Some(votes), Some(weights)) = (&votes_per_option, &self.weights) {
+                weighted = Some(weights.weighted_votes(votes));
+            }
+            total_votes = match voting_mode {
+                PollVotingMode::Plurality | PollVotingMode::Approval => {
+                    self.total_votes_per_option(votes_per_option.unwrap_or_default())
+                }
+                PollVotingMode::RankedChoice => {
+                    TotalVotes::RankedChoice(tally_ranked_choice(option_count, self.ballots.values().map(|b| b.as_slice())))
+                }
+                PollVotingMode::Score(_) => {
+                    TotalVotes::Score(tally_scores(option_count, self.ballots.values().map(|b| b.as_slice())))
+                }
+            };
         }
 
         PollVotes {
             user: user_votes,
             total: total_votes,
+            weighted,
+        }
+    }
+
+    pub fn record_weights(&mut self, weights: Vec<(UserId, u128)>, default_weight: u128, snapshot_complete: bool) {
+        if let Some(poll_weights) = self.weights.as_mut() {
+            poll_weights
+                .weights
+                .extend(weights.into_iter().filter(|(_, w)| *w != default_weight));
+            poll_weights.default_weight = default_weight;
+            poll_weights.snapshot_complete = snapshot_complete;
+        }
+    }
+
+    fn secret_ballot_votes(&self, secret_ballot: &SecretBallotInternal) -> PollVotes {
+        let mut results = SecretBallotResults {
+            commitments: secret_ballot.commitments.len() as u32,
+            revealed: secret_ballot.revealed.len() as u32,
+            merkle_root: secret_ballot.merkle_root,
+            reveal_deadline: self.config.end_date.map(|end| end + SECRET_BALLOT_REVEAL_PERIOD),
+            ..Default::default()
+        };
+
+        if self.ended {
+            for ballot in secret_ballot.revealed.values() {
+                for option in ballot.options.iter() {
+                    *results.tallies.entry(*option).or_default() += 1;
+                }
+            }
+        }
+
+        PollVotes {
+            total: TotalVotes::SecretBallot(results),
+            user: Vec::new(),
+            weighted: None,
+        }
+    }
+
+    fn total_votes_per_option(&self, votes: HashMap<u32, Vec<UserId>>) -> TotalVotes {
+        if self.config.anonymous {
+            TotalVotes::Anonymous(votes.iter().map(|(k, v)| (*k, v.len() as u32)).collect())
+        } else {
+            TotalVotes::Visible(votes)
+        }
+    }
+
+    fn approval_votes(&self) -> HashMap<u32, Vec<UserId>> {
+        let mut votes: HashMap<u32, Vec<UserId>> = HashMap::new();
+        for (user_id, options) in self.ballots.iter() {
+            for option in options {
+                votes.entry(*option).or_default().push(*user_id);
+            }
+        }
+        votes
+    }
+}
+
+// Runs an instant-runoff count. Each round every ballot counts towards its most preferred option which is still in
+// the running. If an option has a majority of the non-exhausted ballots it wins, otherwise the option(s) with the
+// fewest votes are eliminated and the count is repeated.
+fn tally_ranked_choice<'a>(option_count: u32, ballots: impl Iterator<Item = &'a [u32]> + Clone) -> RankedChoiceResults {
+    let mut remaining: Vec<u32> = (0..option_count).collect();
+    let mut results = RankedChoiceResults {
+        total_ballots: ballots.clone().count() as u32,
+        ..Default::default()
+    };
+
+    if results.total_ballots == 0 {
+        return results;
+    }
+
+    loop {
+        let mut round = RankedChoiceRound {
+            tallies: remaining.iter().map(|o| (*o, 0)).collect(),
+            ..Default::default()
+        };
+
+        for ballot in ballots.clone() {
+            if let Some(option) = ballot.iter().find(|o| remaining.contains(o)) {
+                *round.tallies.entry(*option).or_default() += 1;
+            } else {
+                round.exhausted += 1;
+            }
+        }
+
+        let active = results.total_ballots - round.exhausted;
+        let (leader, leader_votes) = remaining
+            .iter()
+            .map(|o| (*o, round.tallies[o]))
+            .max_by_key(|(o, v)| (*v, u32::MAX - o))
+            .unwrap();
+
+        if leader_votes * 2 > active || remaining.len() == 1 {
+            results.winner = (leader_votes > 0).then_some(leader);
+            results.rounds.push(round);
+            return results;
+        }
+
+        let fewest = remaining.iter().map(|o| round.tallies[o]).min().unwrap();
+        let eliminated: Vec<u32> = remaining.iter().copied().filter(|o| round.tallies[o] == fewest).collect();
+
+        if eliminated.len() == remaining.len() {
+            // Every remaining option is tied so there is no winner
+            results.rounds.push(round);
+            return results;
         }
+
+        remaining.retain(|o| !eliminated.contains(o));
+        round.eliminated = eliminated;
+        results.rounds.push(round);
     }
 }
 
+fn tally_scores<'a>(option_count: u32, ballots: impl Iterator<Item = &'a [u32]>) -> ScoreResults {
+    let mut results = ScoreResults {
+        total_ballots: 0,
+        totals: (0..option_count).map(|o| (o, 0)).collect(),
+    };
+
+    for ballot in ballots {
+        results.total_ballots += 1;
+        for (option, score) in ballot.iter().enumerate() {
+            *results.totals.entry(option as u32).or_default() += *score as u64;
+        }
+    }
+
+    results
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PollConfigInternal {
     #[serde(rename = "t", alias = "text")]
@@ -792,6 +1161,12 @@ pub struct PollConfigInternal {
         skip_serializing_if = "is_default"
     )]
     pub allow_user_to_change_vote: bool,
+    #[serde(rename = "vm", default, skip_serializing_if = "is_default")]
+    pub voting_mode: PollVotingMode,
+    #[serde(rename = "sb", default, skip_serializing_if = "is_default")]
+    pub secret_ballot: bool,
+    #[serde(rename = "w", default, skip_serializing_if = "Option::is_none")]
+    pub weighting: Option<PollWeighting>,
 }
 
 impl From<PollConfig> for PollConfigInternal {
@@ -804,6 +1179,9 @@ impl From<PollConfig> for PollConfigInternal {
             show_votes_before_end_date: value.show_votes_before_end_date,
             allow_multiple_votes_per_user: value.allow_multiple_votes_per_user,
             allow_user_to_change_vote: value.allow_user_to_change_vote,
+            voting_mode: value.voting_mode.unwrap_or_default(),
+            secret_ballot: value.secret_ballot.unwrap_or_default(),
+            weighting: value.weighting,
         }
     }
 }
@@ -818,6 +1196,9 @@ impl From<PollConfigInternal> for PollConfig {
             show_votes_before_end_date: value.show_votes_before_end_date,
             allow_multiple_votes_per_user: value.allow_multiple_votes_per_user,
             allow_user_to_change_vote: value.allow_user_to_change_vote,
+            voting_mode: (value.voting_mode != PollVotingMode::Plurality).then_some(value.voting_mode),
+            secret_ballot: value.secret_ballot.then_some(true),
+            weighting: value.weighting,
         }
     }
 }
@@ -1296,6 +1677,28 @@ pub struct ProposalContentInternal {
     pub proposal: Proposal,
     #[serde(rename = "v", default, skip_serializing_if = "BTreeMap::is_empty")]
     pub votes: BTreeMap<UserId, bool>,
+    #[serde(rename = "d", default, skip_serializing_if = "Option::is_none")]
+    pub delegate_vote: Option<ProposalDelegateVoteInternal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ProposalDelegateVoteInternal {
+    #[serde(rename = "d")]
+    pub delegate: UserId,
+    #[serde(rename = "a")]
+    pub adopt: bool,
+    #[serde(rename = "t")]
+    pub timestamp: TimestampMillis,
+}
+
+impl From<ProposalDelegateVoteInternal> for ProposalDelegateVote {
+    fn from(value: ProposalDelegateVoteInternal) -> Self {
+        ProposalDelegateVote {
+            delegate: value.delegate,
+            adopt: value.adopt,
+            timestamp: value.timestamp,
+        }
+    }
 }
 
 impl From<ProposalContent> for ProposalContentInternal {
@@ -1304,6 +1707,7 @@ impl From<ProposalContent> for ProposalContentInternal {
             governance_canister_id: value.governance_canister_id,
             proposal: value.proposal,
             votes: BTreeMap::new(),
+            delegate_vote: None,
         }
     }
 }
@@ -1316,6 +1720,7 @@ impl MessageContentInternalSubtype for ProposalContentInternal {
             governance_canister_id: self.governance_canister_id,
             proposal: self.proposal,
             my_vote: my_user_id.and_then(|u| self.votes.get(&u)).copied(),
+            delegate_vote: self.delegate_vote.map(|v| v.into()),
         }
     }
 }
@@ -1410,19 +1815,29 @@ impl PrizeContentInternal {
         }
 
         if refund > transaction_fee {
-            payments.push(create_pending_transaction(
-                token_symbol,
+            payments.push(PendingCryptoTransaction::ICRC1(types::icrc1::PendingCryptoTransaction {
                 ledger,
-                refund - transaction_fee,
-                transaction_fee,
-                sender,
-                Some(&MEMO_PRIZE_REFUND),
-                now_nanos,
-            ));
+                fee: transaction_fee,
+                token_symbol,
+                amount: refund - transaction_fee,
+                to: self.refund_account(sender),
+                memo: Some(MEMO_PRIZE_REFUND.to_vec().into()),
+                created: now_nanos,
+            }));
         }
 
         payments
     }
+
+    // Prizes funded by bots are taken from the bot's account via ICRC-2, so any refund goes back to that account
+    fn refund_account(&self, sender: UserId) -> Account {
+        if let CompletedCryptoTransactionInternal::ICRC2(t) = &self.transaction {
+            if let icrc1::CryptoAccountInternal::Account(a) = &t.from {
+                return a.clone().into();
+            }
+        }
+        Account::from(sender)
+    }
 }
 
 impl MessageContentInternalSubtype for PrizeContentInternal {
@@ -1725,6 +2140,89 @@ impl From<P2PSwapContent> for P2PSwapContentInternal {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CrowdfundContentInternal {
+    #[serde(rename = "i")]
+    pub payout_id: u32,
+    #[serde(rename = "t")]
+    pub token: TokenInfo,
+    #[serde(rename = "ta")]
+    pub target: u128,
+    #[serde(rename = "b")]
+    pub beneficiary: UserId,
+    #[serde(rename = "d")]
+    pub deadline: TimestampMillis,
+    #[serde(rename = "c", skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(rename = "cs", default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contributions: BTreeMap<UserId, u128>,
+    #[serde(rename = "s")]
+    pub status: CrowdfundStatus,
+}
+
+impl CrowdfundContentInternal {
+    pub fn new(payout_id: u32, created_by: UserId, content: CrowdfundContentInitial) -> CrowdfundContentInternal {
+        CrowdfundContentInternal {
+            payout_id,
+            token: content.token,
+            target: content.target,
+            beneficiary: content.beneficiary,
+            deadline: content.deadline,
+            caption: content.caption,
+            contributions: BTreeMap::from([(created_by, content.initial_contribution)]),
+            status: CrowdfundStatus::Open,
+        }
+    }
+
+    pub fn raised(&self) -> u128 {
+        self.contributions.values().sum()
+    }
+
+    // Contributions are only recorded once the escrow canister has accepted the funds, so they are always counted.
+    // Returns true if this contribution takes an open crowdfund to its target.
+    pub fn contribute(&mut self, user_id: UserId, amount: u128) -> bool {
+        *self.contributions.entry(user_id).or_default() += amount;
+
+        self.status.is_open() && self.raised() >= self.target
+    }
+
+    pub fn set_status(&mut self, status: CrowdfundStatus) -> bool {
+        let allowed = match (&self.status, &status) {
+            (CrowdfundStatus::Open, CrowdfundStatus::Funded(_)) => true,
+            (CrowdfundStatus::Open | CrowdfundStatus::Funded(_), CrowdfundStatus::PaidOut(_) | CrowdfundStatus::Refunded) => {
+                true
+            }
+            _ => false,
+        };
+
+        if allowed {
+            self.status = status;
+        }
+        allowed
+    }
+}
+
+impl MessageContentInternalSubtype for CrowdfundContentInternal {
+    type ContentType = CrowdfundContent;
+
+    fn hydrate(self, my_user_id: Option<UserId>) -> Self::ContentType {
+        CrowdfundContent {
+            payout_id: self.payout_id,
+            raised: self.raised(),
+            contributors: self.contributions.len() as u32,
+            user_contribution: my_user_id
+                .and_then(|u| self.contributions.get(&u).copied())
+                .unwrap_or_default(),
+            token: self.token,
+            target: self.target,
+            beneficiary: self.beneficiary,
+            deadline: self.deadline,
+            caption: self.caption,
+            status: self.status,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct VideoCallContentInternal {
     #[serde(rename = "t", default, skip_serializing_if = "is_default")]
@@ -1894,7 +2392,7 @@ impl From<MessageContentInitial> for MessageContentInternal {
                 .try_into()
                 .map(MessageContentInternal::Crypto)
                 .expect("Crypto transfer must be completed"),
-            MessageContentInitial::P2PSwap(_) | MessageContentInitial::Prize(_) => {
+            MessageContentInitial::P2PSwap(_) | MessageContentInitial::Prize(_) | MessageContentInitial::Crowdfund(_) => {
                 unreachable!()
             }
         }
@@ -1921,6 +2419,7 @@ impl From<&MessageContentInternal> for MessageContentType {
             MessageContentInternal::ReportedMessage(_) => MessageContentType::ReportedMessage,
             MessageContentInternal::P2PSwap(_) => MessageContentType::P2PSwap,
             MessageContentInternal::VideoCall(_) => MessageContentType::VideoCall,
+            MessageContentInternal::Crowdfund(_) => MessageContentType::Crowdfund,
             MessageContentInternal::Encrypted(e) => e.content_type.clone().into(),
             MessageContentInternal::Custom(c) => MessageContentType::Custom(c.kind.clone()),
         }
@@ -1956,3 +2455,165 @@ impl From<TokenInfoCombined> for TokenInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{CrowdfundFunded, CrowdfundPaidOut};
+
+    #[test]
+    fn ranked_choice_eliminates_until_majority() {
+        let ballots = [vec![0, 1], vec![0, 2], vec![1, 0], vec![2, 1], vec![2, 1]];
+
+        let results = tally_ranked_choice(3, ballots.iter().map(|b| b.as_slice()));
+
+        assert_eq!(results.total_ballots, 5);
+        assert_eq!(results.rounds.len(), 2);
+        assert_eq!(results.rounds[0].eliminated, vec![1]);
+        assert_eq!(results.rounds[1].tallies[&0], 3);
+        assert_eq!(results.rounds[1].tallies[&2], 2);
+        assert_eq!(results.winner, Some(0));
+    }
+
+    #[test]
+    fn ranked_choice_ignores_exhausted_ballots() {
+        let ballots = [vec![0], vec![0], vec![1], vec![2]];
+
+        let results = tally_ranked_choice(3, ballots.iter().map(|b| b.as_slice()));
+
+        assert_eq!(results.rounds.len(), 2);
+        assert_eq!(results.rounds[0].eliminated, vec![1, 2]);
+        assert_eq!(results.rounds[1].exhausted, 2);
+        assert_eq!(results.winner, Some(0));
+    }
+
+    #[test]
+    fn ranked_choice_tie_has_no_winner() {
+        let ballots = [vec![0], vec![1]];
+
+        let results = tally_ranked_choice(2, ballots.iter().map(|b| b.as_slice()));
+
+        assert_eq!(results.rounds.len(), 1);
+        assert!(results.winner.is_none());
+    }
+
+    #[test]
+    fn scores_are_summed_per_option() {
+        let ballots = [vec![5, 0, 3], vec![1, 4, 3]];
+
+        let results = tally_scores(3, ballots.iter().map(|b| b.as_slice()));
+
+        assert_eq!(results.total_ballots, 2);
+        assert_eq!(results.totals[&0], 6);
+        assert_eq!(results.totals[&1], 4);
+        assert_eq!(results.totals[&2], 6);
+    }
+
+    #[test]
+    fn secret_ballots_are_tallied_once_revealed() {
+        let mut poll = PollContentInternal::from(PollContent {
+            config: PollConfig {
+                text: None,
+                options: vec!["a".to_string(), "b".to_string()],
+                end_date: Some(1000),
+                anonymous: true,
+                show_votes_before_end_date: false,
+                allow_multiple_votes_per_user: false,
+                allow_user_to_change_vote: false,
+                voting_mode: None,
+                secret_ballot: Some(true),
+                weighting: None,
+            },
+            votes: PollVotes {
+                total: TotalVotes::Hidden(0),
+                user: Vec::new(),
+                weighted: None,
+            },
+            ended: false,
+        });
+
+        let user1: UserId = Principal::from_slice(&[1]).into();
+        let user2: UserId = Principal::from_slice(&[2]).into();
+        let commitment1 = secret_ballot_commitment(&[0], b"salt1");
+        let commitment2 = secret_ballot_commitment(&[1], b"salt2");
+
+        assert!(matches!(
+            poll.commit_secret_ballot(user1, commitment1, VoteOperation::RegisterVote),
+            RegisterVoteResult::Success(false)
+        ));
+        assert!(matches!(
+            poll.commit_secret_ballot(user1, commitment2, VoteOperation::RegisterVote),
+            RegisterVoteResult::UserCannotChangeVote
+        ));
+        assert!(matches!(
+            poll.commit_secret_ballot(user2, commitment1, VoteOperation::RegisterVote),
+            RegisterVoteResult::DuplicateCommitment
+        ));
+        assert!(matches!(
+            poll.commit_secret_ballot(user2, commitment2, VoteOperation::RegisterVote),
+            RegisterVoteResult::Success(false)
+        ));
+
+        // Ballots can only be revealed once the poll has ended
+        assert!(poll.reveal_secret_ballot(vec![0], b"salt1".to_vec(), 500).is_err());
+
+        poll.ended = true;
+        poll.end_secret_ballot();
+
+        assert!(poll.reveal_secret_ballot(vec![1], b"salt1".to_vec(), 1500).is_err());
+        assert!(poll.reveal_secret_ballot(vec![0], b"salt1".to_vec(), 1500).is_ok());
+        assert!(poll.reveal_secret_ballot(vec![0], b"salt1".to_vec(), 1500).is_err());
+
+        let TotalVotes::SecretBallot(results) = poll.votes(Some(user1)).total else {
+            panic!();
+        };
+        assert_eq!(results.commitments, 2);
+        assert_eq!(results.revealed, 1);
+        assert_eq!(results.tallies[&0], 1);
+        assert_eq!(results.merkle_root, secret_ballot_merkle_root(&[commitment1, commitment2]));
+
+        let audit = poll.secret_ballot_audit().unwrap();
+        assert_eq!(audit.revealed.len(), 1);
+        assert_eq!(
+            secret_ballot_commitment(&audit.revealed[0].options, &audit.revealed[0].salt),
+            commitment1
+        );
+    }
+
+    #[test]
+    fn crowdfund_tracks_contributions_until_funded() {
+        let creator: UserId = Principal::from_slice(&[1]).into();
+        let contributor: UserId = Principal::from_slice(&[2]).into();
+        let mut crowdfund = CrowdfundContentInternal::new(
+            1,
+            creator,
+            CrowdfundContentInitial {
+                token: TokenInfo {
+                    symbol: "CHAT".to_string(),
+                    ledger: Principal::anonymous(),
+                    decimals: 8,
+                    fee: 100_000,
+                },
+                target: 1_000,
+                beneficiary: creator,
+                deadline: 1_000,
+                initial_contribution: 400,
+                caption: None,
+            },
+        );
+
+        assert!(!crowdfund.contribute(contributor, 300));
+        assert!(crowdfund.contribute(contributor, 300));
+        assert!(crowdfund.set_status(CrowdfundStatus::Funded(CrowdfundFunded { funded_at: 500 })));
+        assert!(!crowdfund.contribute(creator, 100));
+
+        let content = crowdfund.clone().hydrate(Some(contributor));
+        assert_eq!(content.raised, 1_100);
+        assert_eq!(content.contributors, 2);
+        assert_eq!(content.user_contribution, 600);
+
+        assert!(!crowdfund.set_status(CrowdfundStatus::Open));
+        assert!(crowdfund.set_status(CrowdfundStatus::PaidOut(CrowdfundPaidOut { txn_out: 1 })));
+        assert!(!crowdfund.set_status(CrowdfundStatus::Refunded));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::MessageContentInternal;
+use serde::{Deserialize, Serialize};
+use stable_memory_map::{KeyPrefix, MessageRevisionId, MessageRevisionKey, MessageRevisionKeyPrefix, with_map, with_map_mut};
+use std::ops::RangeInclusive;
+use types::{Chat, MessageIndex, MessageRevision, TimestampMillis, UserId};
+
+const MAX_REVISIONS_PER_MESSAGE: usize = 10;
+
+// Prior versions of edited messages are stored in stable memory, keyed by message then revision number, so that they
+// don't count towards the heap memory of the chat
+#[derive(Serialize, Deserialize)]
+struct MessageRevisionInternal {
+    #[serde(rename = "c")]
+    content: MessageContentInternal,
+    #[serde(rename = "r")]
+    replaced_at: TimestampMillis,
+}
+
+pub(crate) fn push(
+    chat: Chat,
+    thread_root_message_index: Option<MessageIndex>,
+    message_index: MessageIndex,
+    content: MessageContentInternal,
+    replaced_at: TimestampMillis,
+) {
+    let prefix = MessageRevisionKeyPrefix::new_from_chat(chat);
+    let (start, end) = prefix.message_range(thread_root_message_index, message_index);
+
+    with_map_mut(|m| {
+        let existing: Vec<_> = m.range(start..=end).map(|(k, _)| k).collect();
+        let revision = existing.last().map_or(0, |k| k.revision_id().revision + 1);
+
+        m.insert(
+            prefix.create_key(&MessageRevisionId {
+                thread_root_message_index,
+                message_index,
+                revision,
+            }),
+            msgpack::serialize_then_unwrap(&MessageRevisionInternal { content, replaced_at }),
+        );
+
+        // Drop the oldest revisions once the limit is reached
+        let excess = (existing.len() + 1).saturating_sub(MAX_REVISIONS_PER_MESSAGE);
+        for key in existing.into_iter().take(excess) {
+            m.remove(key);
+        }
+    });
+}
+
+pub(crate) fn get(
+    chat: Chat,
+    thread_root_message_index: Option<MessageIndex>,
+    message_index: MessageIndex,
+    my_user_id: Option<UserId>,
+) -> Vec<MessageRevision> {
+    let prefix = MessageRevisionKeyPrefix::new_from_chat(chat);
+    let (start, end) = prefix.message_range(thread_root_message_index, message_index);
+
+    with_map(|m| {
+        m.range(start..=end)
+            .map(|(_, bytes)| {
+                let revision: MessageRevisionInternal = msgpack::deserialize_then_unwrap(&bytes);
+                MessageRevision {
+                    content: revision.content.hydrate(my_user_id),
+                    replaced_at: revision.replaced_at,
+                }
+            })
+            .collect()
+    })
+}
+
+pub(crate) fn remove(chat: Chat, thread_root_message_index: Option<MessageIndex>, message_index: MessageIndex) {
+    let prefix = MessageRevisionKeyPrefix::new_from_chat(chat);
+    let (start, end) = prefix.message_range(thread_root_message_index, message_index);
+    remove_range(start..=end);
+}
+
+pub(crate) fn remove_thread(chat: Chat, thread_root_message_index: MessageIndex) {
+    let prefix = MessageRevisionKeyPrefix::new_from_chat(chat);
+    let (start, end) = prefix.thread_range(thread_root_message_index);
+    remove_range(start..=end);
+}
+
+fn remove_range(range: RangeInclusive<MessageRevisionKey>) {
+    with_map_mut(|m| {
+        let keys: Vec<_> = m.range(range).map(|(k, _)| k).collect();
+        for key in keys {
+            m.remove(key);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChatEvents, EditMessageArgs, MessageContentInternal, NullEventPusher, PushMessageArgs, TextContentInternal};
+    use candid::Principal;
+    use ic_stable_structures::DefaultMemoryImpl;
+    use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
+    use types::{EventIndex, MessageContent, MessageId, MessageIndex, UserId};
+
+    #[test]
+    fn edits_are_retained_only_when_enabled_and_are_bounded() {
+        let memory = MemoryManager::init(DefaultMemoryImpl::default());
+        stable_memory_map::init(memory.get(MemoryId::new(1)));
+
+        let sender: UserId = Principal::from_slice(&[2]).into();
+        let mut events = ChatEvents::new_direct_chat(Principal::from_slice(&[1]).into(), None, 1, 0);
+        let text = |t: String| MessageContentInternal::Text(TextContentInternal { text: t });
+
+        events.push_message::<NullEventPusher>(
+            PushMessageArgs {
+                sender,
+                thread_root_message_index: None,
+                message_id: MessageId::from(1u128),
+                content: text("v0".to_string()),
+                sender_context: None,
+                mentioned: Vec::new(),
+                replies_to: None,
+                now: 0,
+                forwarded: false,
+                sender_is_bot: false,
+                block_level_markdown: false,
+            },
+            None,
+        );
+
+        let edit = |events: &mut ChatEvents, version: u64| {
+            events
+                .edit_message::<NullEventPusher>(
+                    EditMessageArgs {
+                        sender,
+                        min_visible_event_index: EventIndex::default(),
+                        thread_root_message_index: None,
+                        message_id: MessageId::from(1u128),
+                        content: text(format!("v{version}")),
+                        block_level_markdown: None,
+                        finalise_bot_message: false,
+                        now: version,
+                    },
+                    None,
+                )
+                .unwrap();
+        };
+
+        let revision_texts = |events: &ChatEvents| -> Vec<String> {
+            events
+                .message_revisions(None, MessageIndex::from(0), None)
+                .into_iter()
+                .map(|r| match r.content {
+                    MessageContent::Text(t) => t.text,
+                    _ => panic!(),
+                })
+                .collect()
+        };
+
+        edit(&mut events, 1);
+        assert!(revision_texts(&events).is_empty());
+
+        events.set_retain_edit_history(true, 1);
+        for version in 2..=15 {
+            edit(&mut events, version);
+        }
+
+        let expected: Vec<_> = (5..=14).map(|v| format!("v{v}")).collect();
+        assert_eq!(revision_texts(&events), expected);
+
+        let revisions = events.message_revisions(None, MessageIndex::from(0), None);
+        assert_eq!(revisions.last().unwrap().replaced_at, 15);
+    }
+}
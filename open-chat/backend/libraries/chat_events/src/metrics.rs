@@ -74,6 +74,7 @@ pub enum MetricKey {
     P2pSwaps = 20,
     VideoCalls = 21,
     CustomTypeMessages = 22,
+    Crowdfunds = 23,
 }
 
 impl From<u8> for MetricKey {
@@ -101,6 +102,7 @@ impl From<u8> for MetricKey {
             20 => Self::P2pSwaps,
             21 => Self::VideoCalls,
             22 => Self::CustomTypeMessages,
+            23 => Self::Crowdfunds,
             _ => Self::Unknown,
         }
     }
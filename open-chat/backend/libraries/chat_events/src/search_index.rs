@@ -1,34 +1,494 @@
-use search::simple::{Document, Query};
+use crate::{MessageContentInternal, MessageInternal};
+use search::full_text::{Document, Query, TermScorer, is_phrase_match};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashSet};
-use types::{MessageIndex, UserId};
+use stable_memory_map::{KeyPrefix, SearchIndexEntry, SearchIndexKey, SearchIndexKeyPrefix, with_map, with_map_mut};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use types::{Chat, MessageIndex, MessageMatch, TimestampMillis, UserId};
 
+// Shorter terms are only matched exactly, otherwise a single letter would match most of the index
+const MIN_PREFIX_MATCH_LENGTH: usize = 3;
+
+// The index is held in stable memory. Each message has a document entry holding its metadata (used for filtering
+// and ranking) plus a posting entry per distinct term, keyed by term then message index, holding the positions of
+// that term within the message.
 #[derive(Serialize, Deserialize, Default)]
 pub struct SearchIndex {
-    map: BTreeMap<MessageIndex, (UserId, Document)>,
+    // This will be None for chats whose index has not yet been built, in which case `backfill_search_index` must
+    // be called before the index can be used
+    #[serde(default)]
+    prefix: Option<SearchIndexKeyPrefix>,
+    #[serde(default)]
+    document_count: u32,
+    #[serde(default)]
+    total_length: u64,
+    // The next message to be indexed while backfilling, working backwards from the latest message
+    #[serde(default)]
+    backfill_next: Option<MessageIndex>,
+}
+
+pub struct SearchDocument {
+    sender: UserId,
+    timestamp: TimestampMillis,
+    content_kinds: u16,
+    document: Document,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexedMessage {
+    #[serde(rename = "s")]
+    sender: UserId,
+    #[serde(rename = "t")]
+    timestamp: TimestampMillis,
+    #[serde(rename = "k", default)]
+    content_kinds: u16,
+    #[serde(rename = "l")]
+    length: u32,
+    #[serde(rename = "w")]
+    terms: Vec<String>,
+}
+
+mod content_kind {
+    pub const IMAGE: u16 = 1;
+    pub const VIDEO: u16 = 1 << 1;
+    pub const AUDIO: u16 = 1 << 2;
+    pub const FILE: u16 = 1 << 3;
+    pub const POLL: u16 = 1 << 4;
+    pub const CRYPTO: u16 = 1 << 5;
+    pub const PRIZE: u16 = 1 << 6;
+    pub const GIPHY: u16 = 1 << 7;
+    pub const SWAP: u16 = 1 << 8;
+    pub const PROPOSAL: u16 = 1 << 9;
+    pub const LINK: u16 = 1 << 10;
+    pub const CROWDFUND: u16 = 1 << 11;
+
+    pub fn from_filter(value: &str) -> Option<u16> {
+        match value {
+            "image" | "images" => Some(IMAGE),
+            "video" | "videos" => Some(VIDEO),
+            "media" => Some(IMAGE | VIDEO),
+            "audio" => Some(AUDIO),
+            "file" | "files" => Some(FILE),
+            "poll" | "polls" => Some(POLL),
+            "crypto" => Some(CRYPTO),
+            "prize" | "prizes" => Some(PRIZE),
+            "gif" | "giphy" => Some(GIPHY),
+            "swap" => Some(SWAP),
+            "proposal" | "proposals" => Some(PROPOSAL),
+            "link" | "links" => Some(LINK),
+            "crowdfund" | "crowdfunds" => Some(CROWDFUND),
+            _ => None,
+        }
+    }
+}
+
+impl SearchDocument {
+    pub fn new(message: &MessageInternal, timestamp: TimestampMillis) -> SearchDocument {
+        let content_kind = match &message.content {
+            MessageContentInternal::Image(_) => content_kind::IMAGE,
+            MessageContentInternal::Video(_) => content_kind::VIDEO,
+            MessageContentInternal::Audio(_) => content_kind::AUDIO,
+            MessageContentInternal::File(_) => content_kind::FILE,
+            MessageContentInternal::Poll(_) => content_kind::POLL,
+            MessageContentInternal::Crypto(_) => content_kind::CRYPTO,
+            MessageContentInternal::Prize(_) | MessageContentInternal::PrizeWinner(_) => content_kind::PRIZE,
+            MessageContentInternal::Giphy(_) => content_kind::GIPHY,
+            MessageContentInternal::P2PSwap(_) => content_kind::SWAP,
+            MessageContentInternal::GovernanceProposal(_) => content_kind::PROPOSAL,
+            MessageContentInternal::Crowdfund(_) => content_kind::CROWDFUND,
+            _ => 0,
+        };
+        let has_link = message
+            .content
+            .text()
+            .is_some_and(|t| t.contains("https://") || t.contains("http://"));
+
+        SearchDocument {
+            sender: message.sender,
+            timestamp,
+            content_kinds: if has_link { content_kind | content_kind::LINK } else { content_kind },
+            document: Document::from(&message.content),
+        }
+    }
 }
 
 impl SearchIndex {
-    pub fn push(&mut self, message_index: MessageIndex, sender: UserId, document: Document) {
-        self.map.insert(message_index, (sender, document));
+    pub fn new(chat: Chat) -> SearchIndex {
+        SearchIndex {
+            prefix: Some(SearchIndexKeyPrefix::new_from_chat(chat)),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_initialised(&self) -> bool {
+        self.prefix.is_some()
+    }
+
+    pub fn start_backfill(&mut self, chat: Chat, latest_message_index: Option<MessageIndex>) {
+        *self = SearchIndex::new(chat);
+        self.backfill_next = latest_message_index;
+    }
+
+    pub fn backfill_next(&self) -> Option<MessageIndex> {
+        self.backfill_next
+    }
+
+    pub fn set_backfill_next(&mut self, next: Option<MessageIndex>) {
+        self.backfill_next = next;
+    }
+
+    pub fn push(&mut self, message_index: MessageIndex, document: SearchDocument) {
+        let Some(prefix) = self.prefix.clone() else {
+            return;
+        };
+
+        // Remove any existing entries first so that edited messages are reindexed cleanly
+        self.remove(message_index);
+
+        let length = document.document.len();
+        let indexed = IndexedMessage {
+            sender: document.sender,
+            timestamp: document.timestamp,
+            content_kinds: document.content_kinds,
+            length,
+            terms: document.document.terms().map(|(t, _)| t.clone()).collect(),
+        };
+
+        with_map_mut(|m| {
+            for (term, positions) in document.document.terms() {
+                m.insert(
+                    prefix.create_key(&SearchIndexEntry::Posting(term.clone(), message_index)),
+                    msgpack::serialize_then_unwrap(positions),
+                );
+            }
+            m.insert(
+                prefix.create_key(&SearchIndexEntry::Document(message_index)),
+                msgpack::serialize_then_unwrap(&indexed),
+            );
+        });
+
+        self.document_count += 1;
+        self.total_length += length as u64;
     }
 
     pub fn remove(&mut self, message_index: MessageIndex) {
-        self.map.remove(&message_index);
+        let Some(prefix) = self.prefix.clone() else {
+            return;
+        };
+
+        let removed = with_map_mut(|m| {
+            let bytes = m.remove(prefix.create_key(&SearchIndexEntry::Document(message_index)))?;
+            let indexed: IndexedMessage = msgpack::deserialize_then_unwrap(&bytes);
+            for term in indexed.terms {
+                m.remove(prefix.create_key(&SearchIndexEntry::Posting(term, message_index)));
+            }
+            Some(indexed.length)
+        });
+
+        if let Some(length) = removed {
+            self.document_count = self.document_count.saturating_sub(1);
+            self.total_length = self.total_length.saturating_sub(length as u64);
+        }
     }
 
-    pub fn search_messages(
+    pub fn search<F: Fn(MessageIndex) -> bool>(
         &self,
         min_visible_message_index: MessageIndex,
-        query: Query,
-        users: HashSet<UserId>,
-    ) -> impl Iterator<Item = MessageIndex> + '_ {
-        self.map
-            .range(min_visible_message_index..)
-            .rev()
-            .filter(move |(_, (sender, doc))| {
-                (users.is_empty() || users.contains(sender)) && (query.tokens.is_empty() || doc.is_match(&query))
+        query: &Query,
+        users: &HashSet<UserId>,
+        filter: F,
+        max_results: usize,
+    ) -> Vec<(MessageMatch, TimestampMillis)> {
+        let Some(prefix) = &self.prefix else {
+            return Vec::new();
+        };
+
+        let Some(content_kind_filters) = query
+            .has
+            .iter()
+            .map(|h| content_kind::from_filter(h))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Vec::new();
+        };
+
+        let is_match = |message_index: MessageIndex, indexed: &IndexedMessage| {
+            (users.is_empty() || users.contains(&indexed.sender))
+                && query.is_in_date_range(indexed.timestamp)
+                && content_kind_filters.iter().all(|k| indexed.content_kinds & k != 0)
+                && filter(message_index)
+        };
+
+        if !query.has_text() {
+            // With no text to rank by, return the most recent messages which satisfy the filters
+            let start = prefix.create_key(&SearchIndexEntry::Document(min_visible_message_index));
+            let end = prefix.create_key(&SearchIndexEntry::Document(MessageIndex::from(u32::MAX)));
+
+            return with_map(|m| {
+                m.range::<SearchIndexKey, _>(start..=end)
+                    .rev()
+                    .filter_map(|(key, bytes)| {
+                        let message_index = key.message_index();
+                        let indexed: IndexedMessage = msgpack::deserialize_then_unwrap(&bytes);
+                        is_match(message_index, &indexed)
+                            .then_some((MessageMatch { message_index, score: 0 }, indexed.timestamp))
+                    })
+                    .take(max_results)
+                    .collect()
+            });
+        }
+
+        let clauses: Vec<_> = query
+            .terms
+            .iter()
+            .map(|t| self.term_matches(prefix, t, min_visible_message_index))
+            .chain(
+                query
+                    .phrases
+                    .iter()
+                    .map(|p| self.phrase_matches(prefix, p, min_visible_message_index)),
+            )
+            .collect();
+
+        // Start from the most selective clause and keep only the messages matched by every clause
+        let Some(smallest) = clauses.iter().min_by_key(|c| c.len()) else {
+            return Vec::new();
+        };
+
+        let average_document_length =
+            if self.document_count == 0 { 1.0 } else { self.total_length as f64 / self.document_count as f64 };
+        let scorers: Vec<_> = clauses
+            .iter()
+            .map(|c| TermScorer::new(self.document_count, c.len() as u32, average_document_length))
+            .collect();
+
+        let mut matches: Vec<_> = with_map(|m| {
+            smallest
+                .keys()
+                .filter(|message_index| clauses.iter().all(|c| c.contains_key(message_index)))
+                .filter_map(|message_index| {
+                    let bytes = m.get(prefix.create_key(&SearchIndexEntry::Document(*message_index)))?;
+                    let indexed: IndexedMessage = msgpack::deserialize_then_unwrap(&bytes);
+                    if !is_match(*message_index, &indexed) {
+                        return None;
+                    }
+                    let score: f64 = clauses
+                        .iter()
+                        .zip(scorers.iter())
+                        .filter_map(|(c, s)| c.get(message_index).map(|t| s.score(t.frequency, indexed.length, t.is_exact)))
+                        .sum();
+                    Some((
+                        MessageMatch {
+                            message_index: *message_index,
+                            score: (score * 1000.0).round() as u32,
+                        },
+                        indexed.timestamp,
+                    ))
+                })
+                .collect()
+        });
+
+        // Highest scores first, with ties going to the most recent message
+        matches.sort_unstable_by(|(m1, _), (m2, _)| match m2.score.cmp(&m1.score) {
+            Ordering::Equal => m2.message_index.cmp(&m1.message_index),
+            ordering => ordering,
+        });
+        matches.truncate(max_results);
+        matches
+    }
+
+    // Finds every message containing a term which starts with `term` (or exactly matches it for short terms)
+    fn term_matches(
+        &self,
+        prefix: &SearchIndexKeyPrefix,
+        term: &str,
+        min_visible_message_index: MessageIndex,
+    ) -> HashMap<MessageIndex, TermMatch> {
+        let mut matches: HashMap<MessageIndex, TermMatch> = HashMap::new();
+
+        if term.chars().count() < MIN_PREFIX_MATCH_LENGTH {
+            for (message_index, positions) in self.exact_term_postings(prefix, term, min_visible_message_index) {
+                matches.insert(
+                    message_index,
+                    TermMatch {
+                        frequency: positions.len() as u32,
+                        is_exact: true,
+                    },
+                );
+            }
+            return matches;
+        }
+
+        let (start, end) = prefix.postings_range(term);
+        with_map(|m| {
+            for (key, bytes) in m.range::<SearchIndexKey, _>(start..end) {
+                if let SearchIndexEntry::Posting(matched_term, message_index) = key.entry() {
+                    if message_index < min_visible_message_index {
+                        continue;
+                    }
+                    let positions: Vec<u16> = msgpack::deserialize_then_unwrap(&bytes);
+                    let entry = matches.entry(message_index).or_default();
+                    entry.frequency += positions.len() as u32;
+                    entry.is_exact |= matched_term == term;
+                }
+            }
+        });
+        matches
+    }
+
+    fn phrase_matches(
+        &self,
+        prefix: &SearchIndexKeyPrefix,
+        phrase: &[String],
+        min_visible_message_index: MessageIndex,
+    ) -> HashMap<MessageIndex, TermMatch> {
+        let postings: Vec<_> = phrase
+            .iter()
+            .map(|t| self.exact_term_postings(prefix, t, min_visible_message_index))
+            .collect();
+
+        let Some((first, rest)) = postings.split_first() else {
+            return HashMap::new();
+        };
+
+        first
+            .iter()
+            .filter_map(|(message_index, first_positions)| {
+                let mut term_positions = vec![first_positions.as_slice()];
+                for p in rest {
+                    term_positions.push(p.get(message_index)?.as_slice());
+                }
+                is_phrase_match(&term_positions).then_some((
+                    *message_index,
+                    TermMatch {
+                        frequency: first_positions.len() as u32,
+                        is_exact: true,
+                    },
+                ))
             })
-            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    fn exact_term_postings(
+        &self,
+        prefix: &SearchIndexKeyPrefix,
+        term: &str,
+        min_visible_message_index: MessageIndex,
+    ) -> BTreeMap<MessageIndex, Vec<u16>> {
+        let start = prefix.create_key(&SearchIndexEntry::Posting(term.to_string(), min_visible_message_index));
+        let end = prefix.create_key(&SearchIndexEntry::Posting(term.to_string(), MessageIndex::from(u32::MAX)));
+
+        with_map(|m| {
+            m.range::<SearchIndexKey, _>(start..=end)
+                .map(|(key, bytes)| (key.message_index(), msgpack::deserialize_then_unwrap(&bytes)))
+                .collect()
+        })
+    }
+}
+
+#[derive(Default)]
+struct TermMatch {
+    frequency: u32,
+    is_exact: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ChatEvents, DeleteUndeleteMessagesArgs, MessageContentInternal, NullEventPusher, PushMessageArgs, TextContentInternal,
+    };
+    use candid::Principal;
+    use constants::DAY_IN_MS;
+    use ic_stable_structures::DefaultMemoryImpl;
+    use ic_stable_structures::memory_manager::{MemoryId, MemoryManager};
+    use search::full_text::Query;
+    use std::collections::HashSet;
+    use types::{Chat, EventIndex, MessageId, MessageIndex, UserId};
+
+    #[test]
+    fn search_ranks_and_filters_messages() {
+        let user1: UserId = Principal::from_slice(&[2]).into();
+        let user2: UserId = Principal::from_slice(&[3]).into();
+        let mut events = setup_events(&[
+            (user1, "the quick brown fox"),
+            (user2, "a lazy dog"),
+            (user1, "quick quick quick"),
+            (user2, "brown fox jumps over the quick dog"),
+        ]);
+
+        let search = |events: &ChatEvents, text: &str, users: &[UserId]| -> Vec<u32> {
+            events
+                .search_messages(
+                    MessageIndex::default(),
+                    &Query::parse(text),
+                    &users.iter().copied().collect(),
+                    10,
+                )
+                .into_iter()
+                .map(|(m, _)| u32::from(m.message_index))
+                .collect()
+        };
+
+        let results = search(&events, "quick", &[]);
+        assert_eq!(results.first(), Some(&2));
+        assert_eq!(results.iter().copied().collect::<HashSet<_>>(), HashSet::from([0, 2, 3]));
+
+        assert_eq!(search(&events, "qui", &[]).len(), 3);
+        assert_eq!(sorted(search(&events, "\"brown fox\"", &[])), vec![0, 3]);
+        assert!(search(&events, "\"fox brown\"", &[]).is_empty());
+        assert_eq!(search(&events, "quick", &[user2]), vec![3]);
+        assert_eq!(sorted(search(&events, "quick after:1970-01-02", &[])), vec![2, 3]);
+        assert_eq!(search(&events, "", &[user2]), vec![3, 1]);
+
+        events.delete_messages(DeleteUndeleteMessagesArgs {
+            caller: user1,
+            is_admin: false,
+            min_visible_event_index: EventIndex::default(),
+            thread_root_message_index: None,
+            message_ids: vec![MessageId::from(2u128)],
+            now: 10 * DAY_IN_MS,
+        });
+        assert_eq!(sorted(search(&events, "quick", &[])), vec![0, 3]);
+
+        // Resetting the chat clears the index, which is then rebuilt from the existing messages
+        events.set_chat(Chat::Direct(Principal::from_slice(&[1]).into()));
+        assert!(search(&events, "quick", &[]).is_empty());
+        assert!(events.search_index_requires_backfill());
+        assert!(events.backfill_search_index(10));
+        assert!(!events.search_index_requires_backfill());
+        assert_eq!(sorted(search(&events, "quick", &[])), vec![0, 3]);
+    }
+
+    fn sorted(mut values: Vec<u32>) -> Vec<u32> {
+        values.sort_unstable();
+        values
+    }
+
+    fn setup_events(messages: &[(UserId, &str)]) -> ChatEvents {
+        let memory = MemoryManager::init(DefaultMemoryImpl::default());
+        stable_memory_map::init(memory.get(MemoryId::new(1)));
+
+        let mut events = ChatEvents::new_direct_chat(Principal::from_slice(&[1]).into(), None, 1, 0);
+
+        for (i, (sender, text)) in messages.iter().enumerate() {
+            events.push_message::<NullEventPusher>(
+                PushMessageArgs {
+                    sender: *sender,
+                    thread_root_message_index: None,
+                    message_id: MessageId::from(i as u128),
+                    content: MessageContentInternal::Text(TextContentInternal { text: text.to_string() }),
+                    sender_context: None,
+                    mentioned: Vec::new(),
+                    replies_to: None,
+                    now: i as u64 * DAY_IN_MS,
+                    forwarded: false,
+                    sender_is_bot: false,
+                    block_level_markdown: false,
+                },
+                None,
+            );
+        }
+
+        events
     }
 }
@@ -24,8 +24,9 @@ use rand::rngs::StdRng;
 use rand::{Rng, RngCore, SeedableRng};
 use testing::rng::deterministic::{random_from_principal, random_from_u32, random_from_u128, random_principal, random_string};
 use types::{
-    EventIndex, EventWrapperInternal, MessageReport, P2PSwapCompleted, P2PSwapStatus, Proposal, ProposalDecisionStatus,
-    ProposalRewardStatus, Reaction, SnsProposal, Tally, ThumbnailData, Tips, TokenInfo, VideoCallPresence, VideoCallType,
+    EventIndex, EventWrapperInternal, MessageReport, P2PSwapCompleted, P2PSwapStatus, PollVotingMode, Proposal,
+    ProposalDecisionStatus, ProposalRewardStatus, Reaction, SnsProposal, Tally, ThumbnailData, Tips, TokenInfo,
+    VideoCallPresence, VideoCallType,
 };
 
 mod test_values;
@@ -144,6 +145,9 @@ fn poll_content() {
             show_votes_before_end_date: true,
             allow_multiple_votes_per_user: true,
             allow_user_to_change_vote: true,
+            voting_mode: PollVotingMode::Plurality,
+            secret_ballot: false,
+            weighting: None,
         },
         votes: [(
             rng.r#gen(),
@@ -152,6 +156,9 @@ fn poll_content() {
         .into_iter()
         .collect(),
         ended: true,
+        ballots: Default::default(),
+        secret_ballot: None,
+        weights: None,
     });
     let bytes = generate_then_serialize_value(content, &mut rng);
     assert_eq!(bytes, POLL_CURRENT);
@@ -268,6 +275,7 @@ fn governance_proposal() {
         ]
         .into_iter()
         .collect(),
+        delegate_vote: None,
     });
     let bytes = generate_then_serialize_value(content, &mut rng);
     assert_eq!(bytes, GOVERNANCE_PROPOSAL_CURRENT);
@@ -5,7 +5,7 @@ pub const USER_LIMIT: usize = 300_000;
 pub const SECOND_IN_MS: Milliseconds = 1000;
 pub const MINUTE_IN_MS: Milliseconds = SECOND_IN_MS * 60;
 pub const HOUR_IN_MS: Milliseconds = MINUTE_IN_MS * 60;
-pub const DAY_IN_MS: Milliseconds = HOUR_IN_MS * 24;
+pub use types::DAY_IN_MS;
 pub const WEEK_IN_MS: Milliseconds = DAY_IN_MS * 7;
 pub const NANOS_PER_MILLISECOND: u64 = 1_000_000;
 pub const ONE_MB: u64 = 1024 * 1024;
@@ -56,6 +56,7 @@ pub const MEMO_SWAP_APPROVAL: [u8; 8] = [0x4F, 0x43, 0x5F, 0x53, 0x57, 0x41, 0x5
 pub const MEMO_JOINING_FEE: [u8; 7] = [0x4f, 0x43, 0x5f, 0x4A, 0x4F, 0x49, 0x4E]; // OC_JOIN
 pub const MEMO_P2P_SWAP_CREATE: [u8; 8] = [0x4f, 0x43, 0x5f, 0x50, 0x32, 0x50, 0x53, 0x43]; // OC_P2PSC
 pub const MEMO_P2P_SWAP_ACCEPT: [u8; 8] = [0x4f, 0x43, 0x5f, 0x50, 0x32, 0x50, 0x53, 0x41]; // OC_P2PSA
+pub const MEMO_CROWDFUND_CONTRIBUTION: [u8; 7] = [0x4f, 0x43, 0x5f, 0x43, 0x52, 0x57, 0x44]; // OC_CRWD
 pub const MEMO_TRANSLATION_PAYMENT: [u8; 7] = [0x4f, 0x43, 0x5f, 0x54, 0x52, 0x41, 0x4e]; // OC_TRAN
 pub const MEMO_GROUP_IMPORT_INTO_COMMUNITY: [u8; 6] = [0x4f, 0x43, 0x5f, 0x47, 0x32, 0x43]; // OC_G2C
 pub const MEMO_CHIT_FOR_CHAT_AIRDROP: [u8; 6] = [0x4f, 0x43, 0x5f, 0x41, 0x49, 0x52]; // OC_AIR
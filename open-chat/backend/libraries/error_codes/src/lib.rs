@@ -40,6 +40,8 @@ pub enum OCErrorCode {
     InitiatorLapsed = 105,
     InitiatorBlocked = 106,
     BotNotAuthenticated = 107,
+    BotPermissionsMissing = 108,
+    InitiatorMuted = 109,
 
     // Invalid
     ChatNotFound = 200,
@@ -183,6 +185,18 @@ pub enum OCErrorCode {
     WebhookNotFound = 339,
     InvalidWebhook = 340,
     InvalidOriginatingCanister = 341,
+    InvalidBotConfig = 342,
+    BotVersionNotFound = 343,
+    InvalidPollBallot = 344,
+    PollNotEnded = 345,
+    BallotAlreadyRevealed = 346,
+    DuplicateBallotCommitment = 347,
+    EditHistoryNotRetained = 348,
+    CrowdfundClosed = 349,
+    ScheduledMessageNotFound = 350,
+    TooManyScheduledMessages = 351,
+    SlowModeActive = 352,
+    TransferMadeButMessageNotSent = 353,
 
     // InternalError
     C2CError = 500,
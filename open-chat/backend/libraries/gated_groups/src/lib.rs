@@ -10,9 +10,10 @@ use icrc_ledger_types::icrc2::transfer_from::TransferFromArgs;
 use oc_error_codes::{OCError, OCErrorCode};
 use sns_governance_canister::types::Neuron;
 use sns_governance_canister::types::neuron::DissolveState;
+use tracing::error;
 use types::{
     AccessGate, AccessGateNonComposite, AccessGateScope, CanisterId, CompositeGate, GateCheckFailedReason, PaymentGate,
-    SnsNeuronGate, TimestampMillis, TokenBalanceGate, UserId, VerifiedCredentialGate,
+    PollWeighting, SnsNeuronGate, TimestampMillis, TokenBalanceGate, UserId, VerifiedCredentialGate,
 };
 
 const SNS_FEE_SHARE_PERCENT: u128 = 2;
@@ -276,10 +277,7 @@ async fn check_sns_neuron_gate(gate: &SnsNeuronGate, user_id: UserId) -> CheckIf
             }
 
             if let Some(stake_required) = gate.min_stake_e8s {
-                let total_stake: u64 = valid_neurons
-                    .iter()
-                    .map(|n| n.cached_neuron_stake_e8s + n.staked_maturity_e8s_equivalent.unwrap_or_default())
-                    .sum();
+                let total_stake: u64 = valid_neurons.iter().map(neuron_stake_e8s).sum();
 
                 if total_stake < stake_required {
                     return CheckIfPassesGateResult::Failed(GateCheckFailedReason::NoSnsNeuronsWithRequiredStakeFound);
@@ -292,6 +290,55 @@ async fn check_sns_neuron_gate(gate: &SnsNeuronGate, user_id: UserId) -> CheckIf
     }
 }
 
+// Looks up the weight of each user's votes, returning the weights which were found along with the users whose lookups
+// failed. Users whose weight is 0 are excluded from the weights, so they get the poll's default weight.
+pub async fn lookup_poll_weights(weighting: &PollWeighting, users: &[UserId]) -> (Vec<(UserId, u128)>, Vec<UserId>) {
+    let futures: Vec<_> = users.iter().map(|u| lookup_poll_weight(weighting, *u)).collect();
+
+    let mut weights = Vec::new();
+    let mut failed = Vec::new();
+    for (result, user_id) in futures::future::join_all(futures).await.into_iter().zip(users.iter()) {
+        match result {
+            Ok(weight) if weight > 0 => weights.push((*user_id, weight)),
+            Ok(_) => {}
+            Err(error) => {
+                error!(%user_id, "Failed to look up poll weight: {error:?}");
+                failed.push(*user_id);
+            }
+        }
+    }
+    (weights, failed)
+}
+
+async fn lookup_poll_weight(weighting: &PollWeighting, user_id: UserId) -> Result<u128, OCError> {
+    match weighting {
+        PollWeighting::SnsNeuronStake(governance_canister_id) => {
+            lookup_sns_neuron_stake(*governance_canister_id, user_id).await
+        }
+        PollWeighting::TokenBalance(ledger_canister_id) => lookup_token_balance(*ledger_canister_id, user_id).await,
+        PollWeighting::Role(_) => Ok(0),
+    }
+}
+
+// The total stake of the user's neurons
+async fn lookup_sns_neuron_stake(governance_canister_id: CanisterId, user_id: UserId) -> Result<u128, OCError> {
+    let args = sns_governance_canister::list_neurons::Args {
+        limit: 100,
+        start_page_at: None,
+        of_principal: Some(Principal::from(user_id)),
+    };
+
+    let response = sns_governance_canister_c2c_client::list_neurons(governance_canister_id, &args).await?;
+
+    Ok(response.neurons.iter().map(|n| neuron_stake_e8s(n) as u128).sum())
+}
+
+async fn lookup_token_balance(ledger_canister_id: CanisterId, user_id: UserId) -> Result<u128, OCError> {
+    let balance = icrc_ledger_canister_c2c_client::icrc1_balance_of(ledger_canister_id, &Account::from(user_id)).await?;
+
+    Ok(balance.0.try_into().unwrap_or(u128::MAX))
+}
+
 async fn try_transfer_from(
     gate: &PaymentGate,
     user_id: UserId,
@@ -331,6 +378,10 @@ async fn check_token_balance_gate(gate: &TokenBalanceGate, user_id: UserId) -> C
     }
 }
 
+fn neuron_stake_e8s(neuron: &Neuron) -> u64 {
+    neuron.cached_neuron_stake_e8s + neuron.staked_maturity_e8s_equivalent.unwrap_or_default()
+}
+
 fn dissolve_delay_seconds(neuron: &Neuron, now_seconds: u64) -> u64 {
     match neuron.dissolve_state {
         Some(DissolveState::DissolveDelaySeconds(d)) => d,
@@ -1,28 +1,34 @@
+use candid::Principal;
 use chat_events::{
     AddRemoveReactionArgs, ChatEventInternal, ChatEvents, ChatEventsListReader, DeleteMessageSuccess,
     DeleteUndeleteMessagesArgs, EditMessageArgs, EventPusher, GroupGateUpdatedInternal, MessageContentInternal,
     NullEventPusher, PushEventResultInternal, PushMessageArgs, Reader, RegisterPollVoteArgs, RegisterPollVoteSuccess,
-    RemoveExpiredEventsResult, ReservePrizeSuccess, TipMessageArgs, UndeleteMessageSuccess, UpdateMessageSuccess,
+    RemoveExpiredEventsResult, ReservePrizeSuccess, RevealPollBallotArgs, TipMessageArgs, UndeleteMessageSuccess,
+    UpdateMessageSuccess,
 };
+use constants::{DAY_IN_MS, OPENCHAT_BOT_USER_ID};
 use group_community_common::MemberUpdate;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use oc_error_codes::OCErrorCode;
 use regex_lite::Regex;
-use search::simple::Query;
+use search::full_text::Query;
 use serde::{Deserialize, Serialize};
 use std::cmp::{Reverse, max, min};
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use types::{
     AccessGateConfig, AccessGateConfigInternal, AvatarChanged, BotMessageContext, BotNotification, Caller, Chat,
-    CustomPermission, Document, EventIndex, EventOrExpiredRange, EventWrapper, EventsCaller, EventsResponse,
-    ExternalUrlUpdated, GroupDescriptionChanged, GroupMember, GroupNameChanged, GroupPermissions, GroupReplyContext, GroupRole,
-    GroupRulesChanged, GroupSubtype, GroupVisibilityChanged, HydratedMention, MAX_RETURNED_MENTIONS, MemberLeft,
-    MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessageMatch, MessagePermissions, MessagePinned,
-    MessageUnpinned, MessagesResponse, Milliseconds, MultiUserChat, OCResult, OptionUpdate, OptionalGroupPermissions,
-    OptionalMessagePermissions, PermissionsChanged, Reaction, ReserveP2PSwapSuccess, RoleChanged, Rules, SelectedGroupUpdates,
-    SenderContext, ThreadPreview, TimestampMillis, Timestamped, UpdatedRules, UserId, UserType, UsersBlocked, UsersInvited,
-    Version, Versioned, VersionedRules, VideoCall, VideoCallPresence, VoteOperation, WebhookDetails,
+    ChatExportPage, CrowdfundContent, CustomPermission, DEFAULT_WARNING_EXPIRY, Document, EscalationAction, EventIndex,
+    EventOrExpiredRange, EventWrapper, EventsCaller, EventsResponse, ExternalUrlUpdated, GroupDescriptionChanged, GroupMember,
+    GroupNameChanged, GroupPermissions, GroupReplyContext, GroupRole, GroupRulesChanged, GroupSubtype, GroupVisibilityChanged,
+    HydratedMention, MAX_MUTE_DURATION, MAX_RETURNED_MENTIONS, MemberLeft, MemberMuted, MemberWarned, MemberWarning,
+    MemberWarnings, MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessageMatch, MessagePermissions,
+    MessagePinned, MessageRevision, MessageUnpinned, MessagesResponse, Milliseconds, ModerationAction, ModerationLogPage,
+    MultiUserChat, OCResult, OptionUpdate, OptionalGroupPermissions, OptionalMessagePermissions, PermissionsChanged,
+    PollBallot, PollRoleWeights, PollVotes, PollVotingMode, PollWeighting, Reaction, ReserveP2PSwapSuccess, RoleChanged, Rules,
+    SecretBallotAudit, SelectedGroupUpdates, SenderContext, SlowMode, ThreadPreview, TimestampMillis, Timestamped,
+    UpdatedRules, UserId, UserType, UsersBlocked, UsersInvited, Version, Versioned, VersionedRules, VideoCall,
+    VideoCallPresence, VoteOperation, WarningPolicy, WebhookDetails,
 };
 use utils::document::validate_avatar;
 use utils::text_validation::{
@@ -32,12 +38,14 @@ use utils::text_validation::{
 mod invited_users;
 mod members;
 mod mentions;
+mod moderation_log;
 mod roles;
 mod webhooks;
 
 pub use invited_users::*;
 pub use members::*;
 pub use mentions::*;
+pub use moderation_log::*;
 pub use roles::*;
 pub use webhooks::*;
 
@@ -65,6 +73,14 @@ pub struct GroupChatCore {
     pub external_url: Timestamped<Option<String>>,
     at_everyone_mentions: BTreeMap<TimestampMillis, AtEveryoneMention>,
     pub webhooks: Webhooks,
+    #[serde(default)]
+    pub proposal_delegate: Timestamped<Option<UserId>>,
+    #[serde(default)]
+    pub slow_mode: Timestamped<Option<SlowMode>>,
+    #[serde(default)]
+    pub moderation_log: ModerationLog,
+    #[serde(default)]
+    pub warning_policy: Timestamped<Option<WarningPolicy>>,
 }
 
 #[expect(clippy::too_many_arguments)]
@@ -122,6 +138,10 @@ impl GroupChatCore {
             external_url: Timestamped::new(external_url, now),
             at_everyone_mentions: BTreeMap::new(),
             webhooks: Webhooks::default(),
+            proposal_delegate: Timestamped::default(),
+            slow_mode: Timestamped::default(),
+            moderation_log: ModerationLog::default(),
+            warning_policy: Timestamped::default(),
         }
     }
 
@@ -161,12 +181,25 @@ impl GroupChatCore {
         }
     }
 
+    pub fn min_visible_message_index(&self, user_id: UserId) -> OCResult<MessageIndex> {
+        let hidden_for_non_members = !self.is_public.value || !self.messages_visible_to_non_members.value;
+
+        match self.members.get_verified_member(user_id) {
+            Ok(member) => Ok(member.min_visible_message_index()),
+            Err(error) if hidden_for_non_members => Err(error.into()),
+            _ => Ok(self.min_visible_indexes_for_new_members.map(|(_, m)| m).unwrap_or_default()),
+        }
+    }
+
     pub fn details_last_updated(&self) -> TimestampMillis {
         [
             self.events.last_updated().unwrap_or_default(),
             self.invited_users.last_updated(),
             self.members.last_updated().unwrap_or_default(),
             self.webhooks.last_updated(),
+            self.events.retain_edit_history().timestamp,
+            self.proposal_delegate.timestamp,
+            self.slow_mode.timestamp,
         ]
         .into_iter()
         .max()
@@ -243,6 +276,7 @@ impl GroupChatCore {
             updated_events,
             is_public: self.is_public.if_set_after(since).copied(),
             messages_visible_to_non_members: self.messages_visible_to_non_members.if_set_after(since).copied(),
+            retain_edit_history: self.events.retain_edit_history().if_set_after(since).copied(),
             date_last_pinned: self.date_last_pinned.filter(|ts| *ts > since),
             events_ttl: events_ttl
                 .if_set_after(since)
@@ -261,6 +295,16 @@ impl GroupChatCore {
                 .if_set_after(since)
                 .cloned()
                 .map_or(OptionUpdate::NoChange, OptionUpdate::from_update),
+            proposal_delegate: self
+                .proposal_delegate
+                .if_set_after(since)
+                .copied()
+                .map_or(OptionUpdate::NoChange, OptionUpdate::from_update),
+            slow_mode: self
+                .slow_mode
+                .if_set_after(since)
+                .copied()
+                .map_or(OptionUpdate::NoChange, OptionUpdate::from_update),
             any_updates_missed: self.members.any_updates_removed(since)
                 || member.as_ref().map(|m| m.any_updates_removed(since)).unwrap_or_default()
                 || self.events.latest_event_update_removed() > since,
@@ -313,6 +357,7 @@ impl GroupChatCore {
                         id: *id,
                         name: webhook.name.clone(),
                         avatar_id: webhook.avatar.as_ref().map(|avatar| avatar.id),
+                        bot_id: webhook.bot_id,
                     })
                     .collect(),
             );
@@ -384,6 +429,52 @@ impl GroupChatCore {
         })
     }
 
+    // Allows owners and admins to page through the full history of the chat (or of a thread) in ascending order so
+    // that it can be archived
+    pub fn export_events(
+        &self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        start_index: EventIndex,
+        max_events: u32,
+    ) -> OCResult<ChatExportPage> {
+        const MAX_EVENTS_PER_PAGE: u32 = 500;
+
+        let member = self.members.get_verified_member(user_id)?;
+        if !member.role().can_export_chat() {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        let reader = self.events_reader(&EventsCaller::User(user_id), thread_root_message_index)?;
+        let max_events = max_events.min(MAX_EVENTS_PER_PAGE) as usize;
+
+        let (events, expired_event_ranges, _) =
+            EventOrExpiredRange::split(reader.scan(Some(start_index.into()), true, max_events, max_events, Some(user_id)));
+        let latest_event_index = reader.latest_event_index().unwrap();
+        let last_included = events
+            .iter()
+            .map(|e| e.index)
+            .chain(expired_event_ranges.iter().map(|(_, to)| *to))
+            .max();
+        let next_index = last_included.filter(|i| *i < latest_event_index).map(|i| i.incr());
+
+        Ok(ChatExportPage {
+            events,
+            expired_event_ranges,
+            latest_event_index,
+            next_index,
+        })
+    }
+
+    pub fn moderation_log_page(&self, user_id: UserId, start_index: u32, max_entries: u32) -> OCResult<ModerationLogPage> {
+        let member = self.members.get_verified_member(user_id)?;
+        if !member.role().can_view_moderation_log() {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        Ok(self.moderation_log.page(start_index, max_entries))
+    }
+
     pub fn events_by_index(
         &self,
         caller: EventsCaller,
@@ -494,6 +585,83 @@ impl GroupChatCore {
         }
     }
 
+    pub fn message_edit_history(
+        &self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+    ) -> OCResult<Vec<MessageRevision>> {
+        if !self.events.retain_edit_history().value {
+            return Err(OCErrorCode::EditHistoryNotRetained.into());
+        }
+
+        let member = self.members.get_verified_member(user_id)?;
+        let events_reader = self
+            .events
+            .events_reader(member.min_visible_event_index(), thread_root_message_index, None)
+            .ok_or(OCErrorCode::ThreadNotFound)?;
+
+        let message = events_reader
+            .message_internal(message_id.into())
+            .filter(|m| m.deleted_by.is_none())
+            .ok_or(OCErrorCode::MessageNotFound)?;
+
+        if user_id != message.sender && !member.role().can_delete_messages(&self.permissions) {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        Ok(self
+            .events
+            .message_revisions(thread_root_message_index, message.message_index, Some(user_id)))
+    }
+
+    pub fn set_proposal_delegate(&mut self, user_id: UserId, delegate: Option<UserId>, now: TimestampMillis) -> OCResult {
+        let member = self.members.get_verified_member(user_id)?;
+        if !member.role().can_set_proposal_delegate() {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        if let Some(delegate) = delegate {
+            if self.members.get_verified_member(delegate).is_err() {
+                return Err(OCErrorCode::TargetUserNotInChat.into());
+            }
+        }
+
+        if self.proposal_delegate.value == delegate {
+            return Err(OCErrorCode::NoChange.into());
+        }
+
+        self.proposal_delegate = Timestamped::new(delegate, now);
+        Ok(())
+    }
+
+    pub fn is_proposal_delegate(&self, user_id: UserId) -> bool {
+        self.proposal_delegate.value == Some(user_id)
+    }
+
+    pub fn register_delegate_proposal_vote(
+        &mut self,
+        caller: Caller,
+        message_index: MessageIndex,
+        adopt: bool,
+        now: TimestampMillis,
+    ) -> OCResult<UpdateMessageSuccess> {
+        if matches!(caller, Caller::Webhook(_) | Caller::Bot(_) | Caller::OCBot(_)) {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        let delegate = self.proposal_delegate.value.ok_or(OCErrorCode::InitiatorNotAuthorized)?;
+
+        // Autonomous bots have no initiator, in which case they must have been granted permission to vote as the delegate
+        if caller.initiator().is_some_and(|initiator| initiator != delegate) {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        self.members.get_verified_member(delegate)?;
+
+        self.events.record_delegate_proposal_vote(delegate, message_index, adopt, now)
+    }
+
     pub fn thread_previews(&self, user_id: UserId, threads: Vec<MessageIndex>) -> OCResult<Vec<ThreadPreview>> {
         let member = self.members.get(&user_id).ok_or(OCErrorCode::InitiatorNotInChat)?;
 
@@ -512,18 +680,68 @@ impl GroupChatCore {
         users: Option<HashSet<UserId>>,
         max_results: u8,
     ) -> OCResult<Vec<MessageMatch>> {
+        let (query, users) = GroupChatCore::parse_search_query(&search_term, users)?;
+
+        let member = match self.members.get(&user_id) {
+            None => return Err(OCErrorCode::InitiatorNotInChat.into()),
+            Some(p) => p,
+        };
+
+        let matches = self
+            .events
+            .search_messages(member.min_visible_message_index(), &query, &users, max_results as usize)
+            .into_iter()
+            .map(|(m, _)| m)
+            .collect();
+
+        Ok(matches)
+    }
+
+    // Searches the messages visible to the user, which for public chats whose messages are visible to non-members
+    // doesn't require the user to be a member
+    pub fn search_visible_messages(
+        &self,
+        user_id: UserId,
+        query: &Query,
+        users: &HashSet<UserId>,
+        max_results: usize,
+    ) -> OCResult<Vec<(MessageMatch, TimestampMillis)>> {
+        let min_visible_message_index = self.min_visible_message_index(user_id)?;
+
+        Ok(self
+            .events
+            .search_messages(min_visible_message_index, query, users, max_results))
+    }
+
+    pub fn parse_search_query(search_term: &str, users: Option<HashSet<UserId>>) -> OCResult<(Query, HashSet<UserId>)> {
         const MIN_TERM_LENGTH: u8 = 3;
         const MAX_TERM_LENGTH: u8 = 30;
+        const MAX_SEARCH_TERM_LENGTH: u8 = 200;
         const MAX_USERS: u8 = 5;
 
-        let term_length = search_term.len() as u8;
-        let users = users.unwrap_or_default();
+        if search_term.len() > MAX_SEARCH_TERM_LENGTH as usize {
+            return Err(OCErrorCode::TermTooLong.with_message(MAX_SEARCH_TERM_LENGTH));
+        }
+
+        let query = Query::parse(search_term);
+        let term_length = query.text_length();
+        let mut users = users.unwrap_or_default();
+
+        // `from:` filters may reference users either as `@UserId(<id>)`, as found in mentions, or by their raw id
+        for from in query.from.iter() {
+            let user_id = from.strip_prefix("UserId(").and_then(|s| s.strip_suffix(')')).unwrap_or(from);
+
+            match Principal::from_text(user_id) {
+                Ok(principal) => users.insert(principal.into()),
+                Err(_) => return Err(OCErrorCode::TargetUserNotFound.with_message(from)),
+            };
+        }
 
-        if users.is_empty() && term_length < MIN_TERM_LENGTH {
+        if term_length < MIN_TERM_LENGTH as usize && users.is_empty() && (query.has_text() || query.is_empty()) {
             return Err(OCErrorCode::TermTooShort.with_message(MIN_TERM_LENGTH));
         }
 
-        if term_length > MAX_TERM_LENGTH {
+        if term_length > MAX_TERM_LENGTH as usize {
             return Err(OCErrorCode::TermTooLong.with_message(MAX_TERM_LENGTH));
         }
 
@@ -531,18 +749,7 @@ impl GroupChatCore {
             return Err(OCErrorCode::TooManyUsers.with_message(MAX_USERS));
         }
 
-        let member = match self.members.get(&user_id) {
-            None => return Err(OCErrorCode::InitiatorNotInChat.into()),
-            Some(p) => p,
-        };
-
-        let query = Query::new(&search_term);
-
-        let matches = self
-            .events
-            .search_messages(member.min_visible_message_index(), query, users, max_results);
-
-        Ok(matches)
+        Ok((query, users))
     }
 
     pub fn send_message<P: EventPusher>(
@@ -597,6 +804,7 @@ impl GroupChatCore {
         let PrepareSendMessageSuccess {
             min_visible_event_index,
             everyone_mentioned,
+            slow_mode_applies,
         } = self.prepare_send_message(caller, thread_root_message_index, &content, rules_accepted, now)?;
 
         if let Some(root_message_index) = thread_root_message_index {
@@ -616,6 +824,13 @@ impl GroupChatCore {
             _ => None,
         };
 
+        let mut content = content;
+        if let MessageContentInternal::Poll(p) = &mut content {
+            if let Some(PollWeighting::Role(role_weights)) = p.config.weighting {
+                p.record_weights(self.role_poll_weights(role_weights), role_weights.member as u128, true);
+            }
+        }
+
         let push_message_args = PushMessageArgs {
             sender,
             thread_root_message_index,
@@ -632,6 +847,10 @@ impl GroupChatCore {
 
         let (message_event, bot_notification) = self.events.push_message(push_message_args, Some(event_pusher));
 
+        if let Some(slow_mode) = self.slow_mode.value.filter(|_| slow_mode_applies) {
+            self.members.record_slow_mode_message(sender, slow_mode.interval, now);
+        }
+
         let unfinalised_bot_message = if let Caller::BotV2(_) = caller { !finalised } else { false };
 
         let users_to_notify = if unfinalised_bot_message {
@@ -674,6 +893,7 @@ impl GroupChatCore {
         let PrepareSendMessageSuccess {
             min_visible_event_index,
             everyone_mentioned,
+            ..
         } = self.prepare_send_message(caller, thread_root_message_index, &content, rules_accepted, now)?;
 
         let edit_message_args = EditMessageArgs {
@@ -827,6 +1047,7 @@ impl GroupChatCore {
             return Ok(PrepareSendMessageSuccess {
                 min_visible_event_index: EventIndex::default(),
                 everyone_mentioned: false,
+                slow_mode_applies: false,
             });
         }
 
@@ -837,9 +1058,13 @@ impl GroupChatCore {
             });
         }
 
-        let (min_visible_event_index, can_mention) = if let Some(initiator) = caller.initiator() {
+        let (min_visible_event_index, can_mention, slow_mode_applies) = if let Some(initiator) = caller.initiator() {
             let member = self.members.get_verified_member(initiator)?;
 
+            if let Some(muted_until) = self.members.muted_until(&initiator, now) {
+                return Err(OCErrorCode::InitiatorMuted.with_message(muted_until));
+            }
+
             if !matches!(content, MessageContentInternal::VideoCall(_)) && !member.check_rules(&self.rules.value) {
                 return Err(OCErrorCode::ChatRulesNotAccepted.into());
             }
@@ -853,20 +1078,42 @@ impl GroupChatCore {
                 return Err(OCErrorCode::InitiatorNotAuthorized.into());
             }
 
+            let slow_mode_applies = matches!(caller, Caller::User(_))
+                && !matches!(content, MessageContentInternal::VideoCall(_))
+                && self
+                    .slow_mode
+                    .value
+                    .is_some_and(|slow_mode| !member.role().is_permitted(slow_mode.exempt));
+
+            if slow_mode_applies {
+                if let Some(next_allowed) = self.next_message_allowed_at(initiator) {
+                    if now < next_allowed {
+                        return Err(OCErrorCode::SlowModeActive.with_message(next_allowed));
+                    }
+                }
+            }
+
             (
                 member.min_visible_event_index(),
                 member.role().can_mention_everyone(permissions),
+                slow_mode_applies,
             )
         } else {
-            (EventIndex::default(), true)
+            (EventIndex::default(), true, false)
         };
 
         Ok(PrepareSendMessageSuccess {
             min_visible_event_index,
             everyone_mentioned: can_mention && is_everyone_mentioned(content),
+            slow_mode_applies,
         })
     }
 
+    fn next_message_allowed_at(&self, user_id: UserId) -> Option<TimestampMillis> {
+        let interval = self.slow_mode.value?.interval;
+        self.members.slow_mode_last_sent(&user_id).map(|ts| ts + interval)
+    }
+
     pub fn add_reaction<P: EventPusher>(
         &mut self,
         caller: Caller,
@@ -888,6 +1135,9 @@ impl GroupChatCore {
                 if !member.role().can_react_to_messages(&self.permissions) {
                     return Err(OCErrorCode::InitiatorNotAuthorized.into());
                 }
+                if let Some(muted_until) = self.members.muted_until(&initiator, now) {
+                    return Err(OCErrorCode::InitiatorMuted.with_message(muted_until));
+                }
                 min_visible_event_index = member.min_visible_event_index()
             }
         }
@@ -974,8 +1224,9 @@ impl GroupChatCore {
             _ => (true, EventIndex::default()),
         };
 
+        let agent = caller.agent();
         let results = self.events.delete_messages(DeleteUndeleteMessagesArgs {
-            caller: caller.agent(),
+            caller: agent,
             is_admin,
             min_visible_event_index,
             thread_root_message_index,
@@ -1012,6 +1263,24 @@ impl GroupChatCore {
             }
         }
 
+        // Deleting other users' messages is a moderation action so is recorded in the log, one entry per sender
+        if !matches!(caller, Caller::OCBot(_)) {
+            let mut deleted_by_sender: BTreeMap<UserId, Vec<MessageId>> = BTreeMap::new();
+            for (message_id, result) in results.iter() {
+                if let Ok(success) = result {
+                    if success.sender != agent {
+                        deleted_by_sender.entry(success.sender).or_default().push(*message_id);
+                    }
+                }
+            }
+            for (sender, message_ids) in deleted_by_sender {
+                let mut entry = ModerationLogEntryInternal::new(agent, sender, ModerationAction::MessagesDeleted, None, now);
+                entry.thread_root_message_index = thread_root_message_index;
+                entry.message_ids = message_ids;
+                self.moderation_log.push(entry);
+            }
+        }
+
         Ok(results)
     }
 
@@ -1061,6 +1330,7 @@ impl GroupChatCore {
         caller: UserId,
         target_user: UserId,
         new_role: GroupRole,
+        expires: Option<TimestampMillis>,
         is_caller_platform_moderator: bool,
         is_user_platform_moderator: bool,
         now: TimestampMillis,
@@ -1069,29 +1339,58 @@ impl GroupChatCore {
             caller,
             target_user,
             new_role.into(),
+            expires,
             &self.permissions,
             is_caller_platform_moderator,
             is_user_platform_moderator,
             now,
         )?;
 
-        let event = RoleChanged {
-            user_ids: vec![target_user],
-            old_role: prev_role.into(),
-            new_role,
-            changed_by: caller,
+        // If only the term of the role has changed then there is no event to push
+        let bot_notification = if prev_role != GroupRoleInternal::from(new_role) {
+            self.push_role_changed_event(target_user, prev_role, new_role, caller, now)
+        } else {
+            None
         };
 
-        let result = self
-            .events
-            .push_main_event(ChatEventInternal::RoleChanged(Box::new(event)), now);
-
         Ok(ChangeRoleSuccess {
             prev_role,
-            bot_notification: result.bot_notification,
+            bot_notification,
+        })
+    }
+
+    pub fn expire_role(&mut self, user_id: UserId, now: TimestampMillis) -> Option<ExpireRoleSuccess> {
+        let (prev_role, new_role) = self.members.expire_role(user_id, now)?;
+
+        let bot_notification = self.push_role_changed_event(user_id, prev_role, new_role.into(), OPENCHAT_BOT_USER_ID, now);
+
+        Some(ExpireRoleSuccess {
+            prev_role,
+            new_role,
+            bot_notification,
         })
     }
 
+    fn push_role_changed_event(
+        &mut self,
+        user_id: UserId,
+        prev_role: GroupRoleInternal,
+        new_role: GroupRole,
+        changed_by: UserId,
+        now: TimestampMillis,
+    ) -> Option<BotNotification> {
+        let event = RoleChanged {
+            user_ids: vec![user_id],
+            old_role: prev_role.into(),
+            new_role,
+            changed_by,
+        };
+
+        self.events
+            .push_main_event(ChatEventInternal::RoleChanged(Box::new(event)), now)
+            .bot_notification
+    }
+
     pub fn pin_message(
         &mut self,
         user_id: UserId,
@@ -1330,10 +1629,13 @@ impl GroupChatCore {
         caller: Caller,
         target_user_id: UserId,
         block: bool,
+        reason: Option<String>,
         now: TimestampMillis,
     ) -> OCResult<Option<BotNotification>> {
         let agent = caller.agent();
 
+        validate_moderation_reason(reason.as_ref())?;
+
         if agent == target_user_id {
             return Err(OCErrorCode::CannotRemoveSelf.into());
         }
@@ -1342,7 +1644,10 @@ impl GroupChatCore {
             return Err(OCErrorCode::InitiatorNotAuthorized.into());
         }
 
-        if let Some(initiator) = caller.initiator() {
+        // Removals performed by the OpenChat bot (eg. due to an escalation policy) skip the permission checks
+        let initiator = caller.initiator().filter(|_| !matches!(caller, Caller::OCBot(_)));
+
+        if let Some(initiator) = initiator {
             let member = self.members.get_verified_member(initiator)?;
 
             let target_member_role = match self.members.get(&target_user_id) {
@@ -1368,19 +1673,25 @@ impl GroupChatCore {
         let blocked = block && self.members.block(target_user_id, now);
 
         // Push relevant event
-        let event = if blocked {
+        let (event, action) = if blocked {
             let event = UsersBlocked {
                 user_ids: vec![target_user_id],
                 blocked_by: agent,
             };
 
-            ChatEventInternal::UsersBlocked(Box::new(event))
+            (
+                ChatEventInternal::UsersBlocked(Box::new(event)),
+                ModerationAction::UserBlocked,
+            )
         } else if removed {
             let event = MembersRemoved {
                 user_ids: vec![target_user_id],
                 removed_by: agent,
             };
-            ChatEventInternal::ParticipantsRemoved(Box::new(event))
+            (
+                ChatEventInternal::ParticipantsRemoved(Box::new(event)),
+                ModerationAction::MemberRemoved,
+            )
         } else if invite_removed {
             // An existing invite was removed but there is no corresponding event for this
             return Ok(None);
@@ -1390,9 +1701,252 @@ impl GroupChatCore {
 
         let result = self.events.push_main_event(event, now);
 
+        if !matches!(caller, Caller::OCBot(_)) {
+            self.moderation_log
+                .push(ModerationLogEntryInternal::new(agent, target_user_id, action, reason, now));
+        }
+
         Ok(result.bot_notification)
     }
 
+    pub fn warn_member(
+        &mut self,
+        user_id: UserId,
+        target_user_id: UserId,
+        reason: String,
+        now: TimestampMillis,
+    ) -> OCResult<WarnMemberSuccess> {
+        if reason.trim().is_empty() {
+            return Err(OCErrorCode::InvalidRequest.with_message("Reason required"));
+        }
+        validate_moderation_reason(Some(&reason))?;
+
+        if user_id == target_user_id {
+            return Err(OCErrorCode::InvalidRequest.with_message("Cannot warn self"));
+        }
+
+        let member = self.members.get_verified_member(user_id)?;
+        let Some(target) = self.members.get(&target_user_id) else {
+            return Err(OCErrorCode::TargetUserNotInChat.into());
+        };
+
+        if !member
+            .role()
+            .can_warn_members_with_role(target.role().value, &self.permissions)
+        {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        let policy = self.warning_policy.value.clone();
+        let warning_expiry = policy.as_ref().map_or(DEFAULT_WARNING_EXPIRY, |p| p.warning_expiry());
+        let active_warnings = self.members.active_warnings(&target_user_id, now).len() as u32 + 1;
+        let escalation = policy.and_then(|p| p.escalation_for(active_warnings));
+
+        // Apply the escalation first so that if it fails, the warning is not recorded
+        let escalation_reason = Some(format!("Reached {active_warnings} warnings"));
+        let mut escalation_bot_notification = None;
+        let mut muted_until = None;
+        let mut removed = false;
+        match escalation {
+            Some(EscalationAction::Mute(duration)) => {
+                let result = self.mute_member(
+                    Caller::OCBot(OPENCHAT_BOT_USER_ID),
+                    target_user_id,
+                    Some(duration),
+                    escalation_reason,
+                    now,
+                )?;
+                muted_until = result.muted_until;
+                escalation_bot_notification = Some(result.bot_notification);
+            }
+            Some(EscalationAction::Remove) => {
+                escalation_bot_notification =
+                    Some(self.remove_member(Caller::OCBot(OPENCHAT_BOT_USER_ID), target_user_id, false, None, now)?);
+                self.moderation_log.push(ModerationLogEntryInternal::new(
+                    OPENCHAT_BOT_USER_ID,
+                    target_user_id,
+                    ModerationAction::MemberRemoved,
+                    escalation_reason,
+                    now,
+                ));
+                removed = true;
+            }
+            None => {}
+        }
+
+        self.members.add_warning(
+            target_user_id,
+            MemberWarning {
+                warned_by: user_id,
+                reason: reason.clone(),
+                timestamp: now,
+                expires_at: now + warning_expiry,
+            },
+            now,
+        );
+
+        let event = MemberWarned {
+            user_id: target_user_id,
+            warned_by: user_id,
+            reason: reason.clone(),
+            active_warnings,
+            escalation,
+        };
+        let mut bot_notifications = vec![
+            self.events
+                .push_main_event(ChatEventInternal::MemberWarned(Box::new(event)), now)
+                .bot_notification,
+        ];
+        bot_notifications.extend(escalation_bot_notification);
+
+        self.moderation_log.push(ModerationLogEntryInternal::new(
+            user_id,
+            target_user_id,
+            ModerationAction::MemberWarned,
+            Some(reason),
+            now,
+        ));
+
+        Ok(WarnMemberSuccess {
+            active_warnings,
+            escalation,
+            muted_until,
+            removed,
+            bot_notifications,
+        })
+    }
+
+    // Mutes the member for the given duration, or lifts their mute if `duration` is None
+    pub fn mute_member(
+        &mut self,
+        caller: Caller,
+        target_user_id: UserId,
+        duration: Option<Milliseconds>,
+        reason: Option<String>,
+        now: TimestampMillis,
+    ) -> OCResult<MuteMemberSuccess> {
+        let agent = caller.agent();
+
+        validate_moderation_reason(reason.as_ref())?;
+
+        if agent == target_user_id {
+            return Err(OCErrorCode::InvalidRequest.with_message("Cannot mute self"));
+        }
+
+        if matches!(caller, Caller::Webhook(_) | Caller::Bot(_)) {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        if duration.is_some_and(|d| d == 0 || d > MAX_MUTE_DURATION) {
+            return Err(
+                OCErrorCode::InvalidRequest.with_message(format!("Duration must be between 1ms and {MAX_MUTE_DURATION}ms"))
+            );
+        }
+
+        let Some(target) = self.members.get(&target_user_id) else {
+            return Err(OCErrorCode::TargetUserNotInChat.into());
+        };
+
+        if let Some(initiator) = caller.initiator().filter(|_| !matches!(caller, Caller::OCBot(_))) {
+            let member = self.members.get_verified_member(initiator)?;
+            if !member
+                .role()
+                .can_mute_members_with_role(target.role().value, &self.permissions)
+            {
+                return Err(OCErrorCode::InitiatorNotAuthorized.into());
+            }
+        }
+
+        let (muted_until, action) = match duration {
+            Some(duration) => {
+                let muted_until = now + duration;
+                self.members.mute(target_user_id, muted_until);
+                (Some(muted_until), ModerationAction::MemberMuted)
+            }
+            None if self.members.unmute(&target_user_id) => (None, ModerationAction::MemberUnmuted),
+            None => return Err(OCErrorCode::NoChange.into()),
+        };
+
+        let event = MemberMuted {
+            user_id: target_user_id,
+            muted_by: agent,
+            muted_until,
+            reason: reason.clone(),
+        };
+        let bot_notification = self
+            .events
+            .push_main_event(ChatEventInternal::MemberMuted(Box::new(event)), now)
+            .bot_notification;
+
+        self.moderation_log
+            .push(ModerationLogEntryInternal::new(agent, target_user_id, action, reason, now));
+
+        Ok(MuteMemberSuccess {
+            muted_until,
+            bot_notification,
+        })
+    }
+
+    pub fn expire_mute(&mut self, user_id: UserId, now: TimestampMillis) -> Option<Option<BotNotification>> {
+        // The mute is lifted even if the user has since left, but the event is only pushed if they are a member
+        if !self.members.expire_mute(user_id, now) || self.members.get(&user_id).is_none() {
+            return None;
+        }
+
+        let event = MemberMuted {
+            user_id,
+            muted_by: OPENCHAT_BOT_USER_ID,
+            muted_until: None,
+            reason: None,
+        };
+        Some(
+            self.events
+                .push_main_event(ChatEventInternal::MemberMuted(Box::new(event)), now)
+                .bot_notification,
+        )
+    }
+
+    pub fn member_warnings(&self, caller: UserId, user_id: UserId, now: TimestampMillis) -> OCResult<MemberWarnings> {
+        let member = self.members.get_verified_member(caller)?;
+
+        if caller != user_id {
+            let target_role = self
+                .members
+                .get(&user_id)
+                .map_or(GroupRoleInternal::Member, |m| m.role().value);
+
+            if !member.role().can_warn_members_with_role(target_role, &self.permissions) {
+                return Err(OCErrorCode::InitiatorNotAuthorized.into());
+            }
+        }
+
+        Ok(MemberWarnings {
+            warnings: self.members.active_warnings(&user_id, now),
+            muted_until: self.members.muted_until(&user_id, now),
+            policy: self.warning_policy.value.clone(),
+        })
+    }
+
+    pub fn set_warning_policy(&mut self, user_id: UserId, policy: Option<WarningPolicy>, now: TimestampMillis) -> OCResult {
+        let member = self.members.get_verified_member(user_id)?;
+        if !member.role().can_set_warning_policy() {
+            return Err(OCErrorCode::InitiatorNotAuthorized.into());
+        }
+
+        if let Some(policy) = &policy {
+            policy
+                .validate()
+                .map_err(|error| OCErrorCode::InvalidRequest.with_message(error))?;
+        }
+
+        if self.warning_policy.value == policy {
+            return Err(OCErrorCode::NoChange.into());
+        }
+
+        self.warning_policy = Timestamped::new(policy, now);
+        Ok(())
+    }
+
     pub fn update(
         &mut self,
         user_id: UserId,
@@ -1404,11 +1958,22 @@ impl GroupChatCore {
         gate_config: OptionUpdate<AccessGateConfigInternal>,
         public: Option<bool>,
         messages_visible_to_non_members: Option<bool>,
+        retain_edit_history: Option<bool>,
         events_ttl: OptionUpdate<Milliseconds>,
         external_url: OptionUpdate<String>,
+        slow_mode: OptionUpdate<SlowMode>,
         now: TimestampMillis,
     ) -> OCResult<UpdateSuccessResult> {
-        self.can_update(user_id, &name, &description, &rules, &avatar, permissions.as_ref(), &public)?;
+        self.can_update(
+            user_id,
+            &name,
+            &description,
+            &rules,
+            &avatar,
+            permissions.as_ref(),
+            &public,
+            &slow_mode,
+        )?;
 
         Ok(self.do_update(
             user_id,
@@ -1420,8 +1985,10 @@ impl GroupChatCore {
             gate_config,
             public,
             messages_visible_to_non_members,
+            retain_edit_history,
             events_ttl,
             external_url,
+            slow_mode,
             now,
         ))
     }
@@ -1435,6 +2002,7 @@ impl GroupChatCore {
         avatar: &OptionUpdate<Document>,
         permissions: Option<&OptionalGroupPermissions>,
         public: &Option<bool>,
+        slow_mode: &OptionUpdate<SlowMode>,
     ) -> OCResult {
         let avatar_update = avatar.as_ref().expand();
 
@@ -1467,6 +2035,12 @@ impl GroupChatCore {
             return Err(OCErrorCode::AvatarTooBig.with_json(&error));
         }
 
+        if let OptionUpdate::SetToSome(slow_mode) = slow_mode {
+            if slow_mode.interval == 0 || slow_mode.interval > DAY_IN_MS {
+                return Err(OCErrorCode::InvalidRequest.with_message("Slow mode interval must be between 1ms and 1 day"));
+            }
+        }
+
         let member = self.members.get_verified_member(user_id)?;
 
         let group_permissions = &self.permissions;
@@ -1491,8 +2065,10 @@ impl GroupChatCore {
         gate_config: OptionUpdate<AccessGateConfigInternal>,
         public: Option<bool>,
         messages_visible_to_non_members: Option<bool>,
+        retain_edit_history: Option<bool>,
         events_ttl: OptionUpdate<Milliseconds>,
         external_url: OptionUpdate<String>,
+        slow_mode: OptionUpdate<SlowMode>,
         now: TimestampMillis,
     ) -> UpdateSuccessResult {
         let mut result = UpdateSuccessResult {
@@ -1668,6 +2244,10 @@ impl GroupChatCore {
             }
         }
 
+        if let Some(retain_edit_history) = retain_edit_history {
+            events.set_retain_edit_history(retain_edit_history, now);
+        }
+
         if let Some(new_events_ttl) = events_ttl.expand() {
             if new_events_ttl != events.get_events_time_to_live().value {
                 let push_result = events.set_events_time_to_live(user_id, new_events_ttl, now);
@@ -1675,6 +2255,15 @@ impl GroupChatCore {
             }
         }
 
+        if let Some(slow_mode) = slow_mode.expand() {
+            if self.slow_mode.value != slow_mode {
+                if slow_mode.is_none() {
+                    self.members.clear_slow_mode();
+                }
+                self.slow_mode = Timestamped::new(slow_mode, now);
+            }
+        }
+
         result
     }
 
@@ -1722,23 +2311,91 @@ impl GroupChatCore {
         thread_root_message_index: Option<MessageIndex>,
         message_index: MessageIndex,
         option_index: u32,
+        ballot: Option<PollBallot>,
         operation: VoteOperation,
         now: TimestampMillis,
     ) -> OCResult<UpdateMessageSuccess<RegisterPollVoteSuccess>> {
         let member = self.members.get_verified_member(user_id)?;
         let min_visible_event_index = member.min_visible_event_index();
 
+        if matches!(operation, VoteOperation::RegisterVote) {
+            if let Some((message, _)) =
+                self.events
+                    .message_internal(min_visible_event_index, thread_root_message_index, message_index.into())
+            {
+                if let MessageContentInternal::Poll(p) = &message.content {
+                    match (&ballot, p.config.voting_mode) {
+                        _ if p.config.secret_ballot => {
+                            if !matches!(ballot, Some(PollBallot::Commitment(_))) {
+                                return Err(OCErrorCode::InvalidPollBallot.with_message("commitment required"));
+                            }
+                        }
+                        (None, PollVotingMode::Plurality) => {}
+                        (None, _) => return Err(OCErrorCode::InvalidPollBallot.with_message("ballot required")),
+                        (Some(ballot), voting_mode) => ballot
+                            .validate(voting_mode, p.config.options.len())
+                            .map_err(|reason| OCErrorCode::InvalidPollBallot.with_message(format!("{reason:?}")))?,
+                    }
+                }
+            }
+        }
+
         self.events.register_poll_vote(RegisterPollVoteArgs {
             user_id,
             min_visible_event_index,
             thread_root_message_index,
             message_index,
             option_index,
+            ballot,
             operation,
             now,
         })
     }
 
+    pub fn reveal_poll_ballot(
+        &mut self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+        options: Vec<u32>,
+        salt: Vec<u8>,
+        now: TimestampMillis,
+    ) -> OCResult<UpdateMessageSuccess<PollVotes>> {
+        let member = self.members.get_verified_member(user_id)?;
+        let min_visible_event_index = member.min_visible_event_index();
+
+        // Reveals aren't tied to the voter, but in practice voters reveal their own ballots, so the caller must never
+        // be recorded or traced alongside the ballot, otherwise the reveal would link the voter to their vote
+        self.events.reveal_poll_ballot(RevealPollBallotArgs {
+            min_visible_event_index,
+            thread_root_message_index,
+            message_index,
+            options,
+            salt,
+            now,
+        })
+    }
+
+    pub fn secret_ballot_audit(
+        &self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+    ) -> OCResult<SecretBallotAudit> {
+        let member = self.members.get(&user_id).ok_or(OCErrorCode::InitiatorNotInChat)?;
+
+        self.events
+            .secret_ballot_audit(member.min_visible_event_index(), thread_root_message_index, message_index)
+    }
+
+    fn role_poll_weights(&self, role_weights: PollRoleWeights) -> Vec<(UserId, u128)> {
+        let owners = self.members.owners().iter().map(|u| (*u, role_weights.owner as u128));
+        let admins = self.members.admins().iter().map(|u| (*u, role_weights.admin as u128));
+        let moderators = self.members.moderators().iter().map(|u| (*u, role_weights.moderator as u128));
+
+        owners.chain(admins).chain(moderators).collect()
+    }
+
     pub fn reserve_prize(
         &mut self,
         user_id: UserId,
@@ -1765,6 +2422,28 @@ impl GroupChatCore {
             .reserve_p2p_swap(user_id, thread_root_message_index, message_id, min_visible_event_index, now)
     }
 
+    pub fn open_crowdfund(
+        &self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        now: TimestampMillis,
+    ) -> OCResult<CrowdfundContent> {
+        let member = self.members.get_verified_member(user_id)?;
+        let min_visible_event_index = member.min_visible_event_index();
+
+        let content = self
+            .events
+            .get_crowdfund(thread_root_message_index, message_id, min_visible_event_index)
+            .ok_or(OCErrorCode::MessageNotFound)?;
+
+        if content.status.is_open() && now < content.deadline {
+            Ok(content)
+        } else {
+            Err(OCErrorCode::CrowdfundClosed.into())
+        }
+    }
+
     pub fn cancel_p2p_swap(
         &mut self,
         user_id: UserId,
@@ -1986,6 +2665,7 @@ impl GroupChatCore {
                 id: *id,
                 name: webhook.name.clone(),
                 avatar_id: webhook.avatar.as_ref().map(|avatar| avatar.id),
+                bot_id: webhook.bot_id,
             })
             .collect()
     }
@@ -1998,6 +2678,25 @@ pub struct SendMessageSuccess {
     pub bot_notification: Option<BotNotification>,
 }
 
+pub struct WarnMemberSuccess {
+    pub active_warnings: u32,
+    pub escalation: Option<EscalationAction>,
+    pub muted_until: Option<TimestampMillis>,
+    pub removed: bool,
+    pub bot_notifications: Vec<Option<BotNotification>>,
+}
+
+pub struct MuteMemberSuccess {
+    pub muted_until: Option<TimestampMillis>,
+    pub bot_notification: Option<BotNotification>,
+}
+
+pub struct ExpireRoleSuccess {
+    pub prev_role: GroupRoleInternal,
+    pub new_role: GroupRoleInternal,
+    pub bot_notification: Option<BotNotification>,
+}
+
 pub struct UpdateSuccessResult {
     pub newly_public: bool,
     pub gate_config_update: OptionUpdate<AccessGateConfigInternal>,
@@ -2036,6 +2735,7 @@ pub struct SummaryUpdates {
     pub updated_events: Vec<(Option<MessageIndex>, EventIndex, TimestampMillis)>,
     pub is_public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    pub retain_edit_history: Option<bool>,
     pub date_last_pinned: Option<TimestampMillis>,
     pub events_ttl: OptionUpdate<Milliseconds>,
     pub events_ttl_last_updated: Option<TimestampMillis>,
@@ -2043,6 +2743,8 @@ pub struct SummaryUpdates {
     pub rules_changed: bool,
     pub video_call_in_progress: OptionUpdate<VideoCall>,
     pub external_url: OptionUpdate<String>,
+    pub proposal_delegate: OptionUpdate<UserId>,
+    pub slow_mode: OptionUpdate<SlowMode>,
     pub any_updates_missed: bool,
 }
 
@@ -2115,6 +2817,7 @@ fn is_everyone_mentioned(content: &MessageContentInternal) -> bool {
 struct PrepareSendMessageSuccess {
     min_visible_event_index: EventIndex,
     everyone_mentioned: bool,
+    slow_mode_applies: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
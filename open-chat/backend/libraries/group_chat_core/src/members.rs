@@ -4,7 +4,7 @@ use crate::mentions::Mentions;
 use crate::roles::GroupRoleInternal;
 use candid::Principal;
 use constants::{ONE_MB, calculate_summary_updates_data_removal_cutoff};
-use group_community_common::{Member, MemberUpdate, Members};
+use group_community_common::{Member, MemberUpdate, Members, RoleExpiry};
 use oc_error_codes::OCErrorCode;
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
@@ -14,8 +14,8 @@ use std::cmp::max;
 use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Deref;
 use types::{
-    BotNotification, EventIndex, GroupMember, GroupPermissions, GroupRole, MessageIndex, MultiUserChat, OCResult,
-    TimestampMillis, Timestamped, UserId, UserType, Version, is_default,
+    BotNotification, EventIndex, GroupMember, GroupPermissions, GroupRole, MemberWarning, MessageIndex, Milliseconds,
+    MultiUserChat, OCResult, TimestampMillis, Timestamped, UserId, UserType, Version, is_default,
 };
 use utils::timestamped_set::TimestampedSet;
 
@@ -40,6 +40,14 @@ pub struct GroupMembers {
     suspended: BTreeSet<UserId>,
     updates: BTreeSet<(TimestampMillis, UserId, MemberUpdate)>,
     latest_update_removed: TimestampMillis,
+    #[serde(default)]
+    role_expiries: BTreeMap<UserId, RoleExpiry<GroupRoleInternal>>,
+    #[serde(default)]
+    warnings: BTreeMap<UserId, Vec<MemberWarning>>,
+    #[serde(default)]
+    muted_until: BTreeMap<UserId, TimestampMillis>,
+    #[serde(default)]
+    slow_mode_last_sent: BTreeMap<UserId, TimestampMillis>,
 }
 
 #[expect(clippy::too_many_arguments)]
@@ -80,6 +88,10 @@ impl GroupMembers {
             suspended: BTreeSet::new(),
             updates: BTreeSet::new(),
             latest_update_removed: 0,
+            role_expiries: BTreeMap::new(),
+            warnings: BTreeMap::new(),
+            muted_until: BTreeMap::new(),
+            slow_mode_last_sent: BTreeMap::new(),
         }
     }
 
@@ -164,6 +176,8 @@ impl GroupMembers {
         if member.suspended.value {
             self.suspended.remove(&user_id);
         }
+        self.role_expiries.remove(&user_id);
+        self.slow_mode_last_sent.remove(&user_id);
         self.member_ids.remove(&user_id);
         self.prune_then_insert_member_update(user_id, MemberUpdate::Removed, now);
         Some(member)
@@ -187,6 +201,63 @@ impl GroupMembers {
         }
     }
 
+    // Warnings are retained when a member leaves so that they still count if the user rejoins
+    pub fn add_warning(&mut self, user_id: UserId, warning: MemberWarning, now: TimestampMillis) -> u32 {
+        let warnings = self.warnings.entry(user_id).or_default();
+        warnings.retain(|w| w.expires_at > now);
+        warnings.push(warning);
+        warnings.len() as u32
+    }
+
+    pub fn active_warnings(&self, user_id: &UserId, now: TimestampMillis) -> Vec<MemberWarning> {
+        self.warnings
+            .get(user_id)
+            .map(|w| w.iter().filter(|w| w.expires_at > now).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Like warnings, mutes are retained when a member leaves so that they can't be escaped by leaving and rejoining
+    pub fn mute(&mut self, user_id: UserId, until: TimestampMillis) {
+        self.muted_until.insert(user_id, until);
+    }
+
+    pub fn unmute(&mut self, user_id: &UserId) -> bool {
+        self.muted_until.remove(user_id).is_some()
+    }
+
+    // Lifts the member's mute if it has ended, returning true if it was lifted
+    pub fn expire_mute(&mut self, user_id: UserId, now: TimestampMillis) -> bool {
+        if self.muted_until.get(&user_id).is_some_and(|until| *until <= now) {
+            self.muted_until.remove(&user_id);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn mute_expiries(&self) -> impl Iterator<Item = (UserId, TimestampMillis)> + '_ {
+        self.muted_until.iter().map(|(user_id, until)| (*user_id, *until))
+    }
+
+    pub fn muted_until(&self, user_id: &UserId, now: TimestampMillis) -> Option<TimestampMillis> {
+        self.muted_until.get(user_id).copied().filter(|until| *until > now)
+    }
+
+    // Records when a member who is subject to slow mode sent a message, first dropping the entries which no longer
+    // hold anyone back
+    pub fn record_slow_mode_message(&mut self, user_id: UserId, interval: Milliseconds, now: TimestampMillis) {
+        self.slow_mode_last_sent.retain(|_, sent| *sent + interval > now);
+        self.slow_mode_last_sent.insert(user_id, now);
+    }
+
+    pub fn slow_mode_last_sent(&self, user_id: &UserId) -> Option<TimestampMillis> {
+        self.slow_mode_last_sent.get(user_id).copied()
+    }
+
+    pub fn clear_slow_mode(&mut self) {
+        self.slow_mode_last_sent.clear();
+    }
+
     pub fn blocked(&self) -> Vec<UserId> {
         self.blocked.iter().copied().collect()
     }
@@ -262,11 +333,16 @@ impl GroupMembers {
         caller_id: UserId,
         user_id: UserId,
         new_role: GroupRoleInternal,
+        expires: Option<TimestampMillis>,
         permissions: &GroupPermissions,
         is_caller_platform_moderator: bool,
         is_user_platform_moderator: bool,
         now: TimestampMillis,
     ) -> OCResult<GroupRoleInternal> {
+        if expires.is_some_and(|e| e <= now) {
+            return Err(OCErrorCode::DateInThePast.into());
+        }
+
         // Is the caller authorized to change the user to this role
         let member = self.get_verified_member(caller_id)?;
 
@@ -295,11 +371,78 @@ impl GroupMembers {
         }
 
         let prev_role = member.role.value;
+        let prev_expiry = self.role_expiries.get(&user_id).copied();
 
-        if prev_role == new_role {
+        if prev_role == new_role && prev_expiry.map(|e| e.expires) == expires {
             return Err(OCErrorCode::NoChange.into());
         }
 
+        match expires {
+            Some(expires) => {
+                // If the current role is itself term-limited, the member still reverts to their original role
+                let revert_to = prev_expiry.map_or(prev_role, |e| e.revert_to);
+                if revert_to == new_role {
+                    return Err(OCErrorCode::InvalidRoleChange.into());
+                }
+                self.role_expiries.insert(user_id, RoleExpiry { expires, revert_to });
+            }
+            None => {
+                self.role_expiries.remove(&user_id);
+            }
+        }
+
+        if prev_role != new_role {
+            self.set_role(user_id, prev_role, new_role, member.lapsed.value, now);
+        }
+
+        Ok(prev_role)
+    }
+
+    // Reverts the member's role if their term has ended, returning the previous and new roles
+    pub fn expire_role(&mut self, user_id: UserId, now: TimestampMillis) -> Option<(GroupRoleInternal, GroupRoleInternal)> {
+        let expiry = self.role_expiries.get(&user_id).copied()?;
+        if expiry.expires > now {
+            return None;
+        }
+
+        let Some(member) = self.members_map.get(&user_id) else {
+            self.role_expiries.remove(&user_id);
+            return None;
+        };
+        let prev_role = member.role.value;
+
+        // It is not possible to change the role of the last owner, so the expiry is kept until there is another owner
+        if prev_role != expiry.revert_to && prev_role.is_owner() && self.owners.len() <= 1 {
+            return None;
+        }
+
+        self.role_expiries.remove(&user_id);
+
+        if prev_role == expiry.revert_to {
+            return None;
+        }
+
+        self.set_role(user_id, prev_role, expiry.revert_to, member.lapsed.value, now);
+        Some((prev_role, expiry.revert_to))
+    }
+
+    // True if the member's term has ended but their role couldn't be reverted because they are the last owner
+    pub fn is_role_expiry_deferred(&self, user_id: UserId, now: TimestampMillis) -> bool {
+        self.role_expiries.get(&user_id).is_some_and(|e| e.expires <= now)
+    }
+
+    pub fn role_expiries(&self) -> impl Iterator<Item = (UserId, TimestampMillis)> + '_ {
+        self.role_expiries.iter().map(|(user_id, e)| (*user_id, e.expires))
+    }
+
+    fn set_role(
+        &mut self,
+        user_id: UserId,
+        prev_role: GroupRoleInternal,
+        new_role: GroupRoleInternal,
+        lapsed: bool,
+        now: TimestampMillis,
+    ) {
         match prev_role {
             GroupRoleInternal::Owner => self.owners.remove(&user_id),
             GroupRoleInternal::Admin => self.admins.remove(&user_id),
@@ -314,7 +457,7 @@ impl GroupMembers {
 
         match new_role {
             GroupRoleInternal::Owner => {
-                if member.lapsed.value {
+                if lapsed {
                     self.update_lapsed(user_id, false, now);
                 }
                 self.owners.insert(user_id)
@@ -325,8 +468,6 @@ impl GroupMembers {
         };
 
         self.prune_then_insert_member_update(user_id, MemberUpdate::RoleChanged, now);
-
-        Ok(prev_role)
     }
 
     pub fn toggle_notifications_muted(
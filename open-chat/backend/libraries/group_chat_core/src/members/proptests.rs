@@ -102,7 +102,16 @@ fn execute_operation(members: &mut GroupMembers, op: Operation, timestamp: Times
         } => {
             let owner = get(&members.owners, owner_index);
             let user_id = get(&members.member_ids, user_index);
-            let _ = members.change_role(owner, user_id, role, &GroupPermissions::default(), false, false, timestamp);
+            let _ = members.change_role(
+                owner,
+                user_id,
+                role,
+                None,
+                &GroupPermissions::default(),
+                false,
+                false,
+                timestamp,
+            );
         }
         Operation::ToggleMuteNotifications { user_index, mute } => {
             let user_id = get(&members.member_ids, user_index);
@@ -0,0 +1,113 @@
+use oc_error_codes::OCErrorCode;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use types::{
+    FieldTooLongResult, MAX_MODERATION_REASON_LENGTH, MessageId, MessageIndex, ModerationAction, ModerationLogEntry,
+    ModerationLogPage, OCResult, TimestampMillis, UserId,
+};
+
+const MAX_ENTRIES_PER_PAGE: u32 = 500;
+const MAX_ENTRIES: usize = 10_000;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ModerationLog {
+    entries: VecDeque<ModerationLogEntryInternal>,
+    // The number of entries dropped from the front of the log, so that entry indexes stay stable once the log is full
+    #[serde(default)]
+    pruned: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ModerationLogEntryInternal {
+    pub timestamp: TimestampMillis,
+    pub actor: UserId,
+    pub target: UserId,
+    pub action: ModerationAction,
+    pub reason: Option<String>,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_ids: Vec<MessageId>,
+}
+
+impl ModerationLog {
+    pub fn push(&mut self, entry: ModerationLogEntryInternal) -> u32 {
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.pop_front();
+            self.pruned += 1;
+        }
+        self.entries.push_back(entry);
+        self.len() - 1
+    }
+
+    // The total number of entries ever recorded, including those which have since been pruned
+    pub fn len(&self) -> u32 {
+        self.pruned + self.entries.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Returns the entries in ascending order starting from `start_index` so that the full log can be paged through.
+    // If `start_index` refers to a pruned entry, the page starts from the oldest entry still retained.
+    pub fn page(&self, start_index: u32, max_entries: u32) -> ModerationLogPage {
+        let max_entries = max_entries.min(MAX_ENTRIES_PER_PAGE) as usize;
+
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(start_index.saturating_sub(self.pruned) as usize)
+            .take(max_entries)
+            .map(|(index, entry)| entry.hydrate(self.pruned + index as u32))
+            .collect();
+
+        let total = self.len();
+        let next_index = entries.last().map(|e| e.index + 1).filter(|i| *i < total);
+
+        ModerationLogPage {
+            entries,
+            total,
+            next_index,
+        }
+    }
+}
+
+impl ModerationLogEntryInternal {
+    pub fn new(actor: UserId, target: UserId, action: ModerationAction, reason: Option<String>, now: TimestampMillis) -> Self {
+        ModerationLogEntryInternal {
+            timestamp: now,
+            actor,
+            target,
+            action,
+            reason,
+            thread_root_message_index: None,
+            message_ids: Vec::new(),
+        }
+    }
+
+    fn hydrate(&self, index: u32) -> ModerationLogEntry {
+        ModerationLogEntry {
+            index,
+            timestamp: self.timestamp,
+            actor: self.actor,
+            target: self.target,
+            action: self.action,
+            reason: self.reason.clone(),
+            thread_root_message_index: self.thread_root_message_index,
+            message_ids: self.message_ids.clone(),
+        }
+    }
+}
+
+pub fn validate_moderation_reason(reason: Option<&String>) -> OCResult {
+    if let Some(reason) = reason {
+        let length = reason.chars().count() as u32;
+        if length > MAX_MODERATION_REASON_LENGTH {
+            return Err(OCErrorCode::TextTooLong.with_json(&FieldTooLongResult {
+                length_provided: length,
+                max_length: MAX_MODERATION_REASON_LENGTH,
+            }));
+        }
+    }
+    Ok(())
+}
@@ -85,6 +85,15 @@ impl GroupRoleInternal {
         self.is_permitted(permissions.remove_members)
     }
 
+    // Warnings can escalate to removal so they require the same permission as removing members
+    pub fn can_warn_members_with_role(&self, member_role: GroupRoleInternal, permissions: &GroupPermissions) -> bool {
+        !member_role.is_owner() && self.can_remove_members_with_role(member_role, permissions)
+    }
+
+    pub fn can_mute_members_with_role(&self, member_role: GroupRoleInternal, permissions: &GroupPermissions) -> bool {
+        !member_role.is_owner() && self.can_remove_members_with_role(member_role, permissions)
+    }
+
     pub fn can_delete_messages(&self, permissions: &GroupPermissions) -> bool {
         self.is_permitted(permissions.delete_messages)
     }
@@ -115,6 +124,7 @@ impl GroupRoleInternal {
             MessageContentType::Giphy => ps.giphy.unwrap_or(ps.default),
             MessageContentType::Prize => ps.prize.unwrap_or(ps.default),
             MessageContentType::P2PSwap => ps.p2p_swap.unwrap_or(ps.default),
+            MessageContentType::Crowdfund => ps.crypto.unwrap_or(ps.default),
             MessageContentType::VideoCall => permissions.start_video_call,
             MessageContentType::Custom(c) => ps
                 .custom
@@ -149,6 +159,22 @@ impl GroupRoleInternal {
         self.is_owner()
     }
 
+    pub fn can_export_chat(&self) -> bool {
+        self.is_owner() || self.is_admin()
+    }
+
+    pub fn can_view_moderation_log(&self) -> bool {
+        self.is_owner() || self.is_admin()
+    }
+
+    pub fn can_set_warning_policy(&self) -> bool {
+        self.is_owner() || self.is_admin()
+    }
+
+    pub fn can_set_proposal_delegate(&self) -> bool {
+        self.is_owner() || self.is_admin()
+    }
+
     pub fn can_invite_users(&self, permissions: &GroupPermissions) -> bool {
         self.is_permitted(permissions.invite_users)
     }
@@ -191,6 +217,7 @@ impl GroupRoleInternal {
             (role_permissions.delete_messages, ChatPermission::DeleteMessages),
             (role_permissions.invite_users, ChatPermission::InviteUsers),
             (role_permissions.mention_all_members, ChatPermission::MentionAllMembers),
+            (role_permissions.remove_members, ChatPermission::MuteMembers),
             (role_permissions.pin_messages, ChatPermission::PinMessages),
             (role_permissions.react_to_messages, ChatPermission::ReactToMessages),
             (role_permissions.remove_members, ChatPermission::RemoveMembers),
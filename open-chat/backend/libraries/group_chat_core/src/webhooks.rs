@@ -15,6 +15,8 @@ pub struct Webhook {
     pub name: String,
     pub avatar: Option<Document>,
     pub secret: String,
+    #[serde(default)]
+    pub bot_id: Option<UserId>,
 }
 
 impl Webhooks {
@@ -22,6 +24,7 @@ impl Webhooks {
         &mut self,
         name: String,
         avatar: Option<Document>,
+        bot_id: Option<UserId>,
         rng: &mut StdRng,
         now: TimestampMillis,
     ) -> Option<UserId> {
@@ -37,6 +40,7 @@ impl Webhooks {
                 name,
                 avatar,
                 secret: Self::generate_secret(rng),
+                bot_id,
             },
         );
 
@@ -55,7 +59,14 @@ impl Webhooks {
         }
     }
 
-    pub fn update(&mut self, id: UserId, name: Option<String>, avatar: OptionUpdate<Document>, now: TimestampMillis) -> bool {
+    pub fn update(
+        &mut self,
+        id: UserId,
+        name: Option<String>,
+        avatar: OptionUpdate<Document>,
+        bot_id: OptionUpdate<UserId>,
+        now: TimestampMillis,
+    ) -> bool {
         if let Some(webhook) = self.map.get_mut(&id) {
             if let Some(name) = name {
                 webhook.name = name;
@@ -67,6 +78,10 @@ impl Webhooks {
                 OptionUpdate::NoChange => {}
             }
 
+            if let Some(bot_id) = bot_id.expand() {
+                webhook.bot_id = bot_id;
+            }
+
             self.last_updated = now;
             true
         } else {
@@ -83,6 +98,18 @@ impl Webhooks {
         }
     }
 
+    // Called when a bot is uninstalled so that its webhooks revert to posting messages
+    pub fn unbind_bot(&mut self, bot_id: &UserId, now: TimestampMillis) {
+        let mut updated = false;
+        for webhook in self.map.values_mut().filter(|w| w.bot_id.as_ref() == Some(bot_id)) {
+            webhook.bot_id = None;
+            updated = true;
+        }
+        if updated {
+            self.last_updated = now;
+        }
+    }
+
     pub fn get(&self, id: &UserId) -> Option<&Webhook> {
         self.map.get(id)
     }
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, TimestampMillis, UserId};
+use utils::min_heap::MinBinaryHeap;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ExpiringMutes {
+    heap: MinBinaryHeap<ExpiringMute>,
+}
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ExpiringMute {
+    pub expires: TimestampMillis,
+    pub channel_id: Option<ChannelId>,
+    pub user_id: UserId,
+}
+
+impl ExpiringMutes {
+    pub fn push(&mut self, mute: ExpiringMute) {
+        self.heap.push(mute);
+    }
+
+    pub fn pop_if_expires_before(&mut self, expires_before: TimestampMillis) -> Option<ExpiringMute> {
+        if let Some(mute) = self.heap.peek() {
+            if mute.expires < expires_before {
+                return self.heap.pop();
+            }
+        }
+
+        None
+    }
+
+    pub fn next_expiry(&self) -> Option<TimestampMillis> {
+        self.heap.peek().map(|m| m.expires)
+    }
+}
@@ -0,0 +1,46 @@
+use constants::HOUR_IN_MS;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, Milliseconds, TimestampMillis, UserId};
+use utils::min_heap::MinBinaryHeap;
+
+// How long to wait before trying again to end the term of a member who is the last owner
+pub const DEFERRED_ROLE_EXPIRY_RETRY_INTERVAL: Milliseconds = HOUR_IN_MS;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ExpiringRoles {
+    heap: MinBinaryHeap<ExpiringRole>,
+}
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ExpiringRole {
+    pub expires: TimestampMillis,
+    pub channel_id: Option<ChannelId>,
+    pub user_id: UserId,
+}
+
+// A role granted for a limited term along with the role the member reverts to once the term ends
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct RoleExpiry<R> {
+    pub expires: TimestampMillis,
+    pub revert_to: R,
+}
+
+impl ExpiringRoles {
+    pub fn push(&mut self, role: ExpiringRole) {
+        self.heap.push(role);
+    }
+
+    pub fn pop_if_expires_before(&mut self, expires_before: TimestampMillis) -> Option<ExpiringRole> {
+        if let Some(role) = self.heap.peek() {
+            if role.expires < expires_before {
+                return self.heap.pop();
+            }
+        }
+
+        None
+    }
+
+    pub fn next_expiry(&self) -> Option<TimestampMillis> {
+        self.heap.peek().map(|r| r.expires)
+    }
+}
@@ -1,15 +1,23 @@
 mod achievements;
 mod expiring_member_actions;
 mod expiring_members;
+mod expiring_mutes;
+mod expiring_roles;
 mod member;
 mod payment_receipts;
 mod pending_payments_queue;
+mod poll_weight_snapshots;
+mod unsent_bot_transfers;
 mod user_cache;
 
 pub use achievements::*;
 pub use expiring_member_actions::*;
 pub use expiring_members::*;
+pub use expiring_mutes::*;
+pub use expiring_roles::*;
 pub use member::*;
 pub use payment_receipts::*;
 pub use pending_payments_queue::*;
+pub use poll_weight_snapshots::*;
+pub use unsent_bot_transfers::*;
 pub use user_cache::*;
@@ -43,4 +43,5 @@ pub enum PaymentRecipient {
 pub enum PendingPaymentReason {
     AccessGate,
     TransferToCommunityBeingImportedInto,
+    BotPrizeRefund,
 }
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use types::{ChannelId, MessageIndex, PollWeighting, UserId};
+
+const BATCH_SIZE: usize = 50;
+const MAX_LOOKUP_ATTEMPTS: u32 = 5;
+
+// Polls whose members' weights are still to be looked up. Each batch is only looked up once the previous one has been
+// recorded, so for large chats the snapshot is spread over several rounds of calls. A batch stays at the front of the
+// queue until its results have been recorded, so that it is looked up again if it was interrupted (eg. by an upgrade).
+#[derive(Serialize, Deserialize, Default)]
+pub struct PollWeightSnapshots {
+    queue: VecDeque<PendingPollWeights>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PendingPollWeights {
+    pub channel_id: Option<ChannelId>,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub weighting: PollWeighting,
+    pub users: Vec<UserId>,
+    // Users whose lookups failed, which are retried once the rest of the users have been looked up
+    #[serde(default)]
+    pub failed: Vec<UserId>,
+    #[serde(default)]
+    pub attempts: u32,
+}
+
+pub struct PollWeightsBatch {
+    pub channel_id: Option<ChannelId>,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub weighting: PollWeighting,
+    pub users: Vec<UserId>,
+}
+
+impl PollWeightSnapshots {
+    pub fn push(&mut self, pending: PendingPollWeights) {
+        self.queue.push_back(pending);
+    }
+
+    pub fn next_batch(&self) -> Option<PollWeightsBatch> {
+        let next = self.queue.front()?;
+
+        Some(PollWeightsBatch {
+            channel_id: next.channel_id,
+            thread_root_message_index: next.thread_root_message_index,
+            message_index: next.message_index,
+            weighting: next.weighting.clone(),
+            users: next.users.iter().take(BATCH_SIZE).copied().collect(),
+        })
+    }
+
+    // Removes the batch from the queue once its results have been recorded. Returns true if the snapshot of the poll
+    // is complete. If some lookups are still failing after the maximum number of attempts, the poll is removed from the
+    // queue but its snapshot is left incomplete, so those users are never treated as having no stake.
+    pub fn complete_batch(&mut self, batch: &PollWeightsBatch, failed: Vec<UserId>) -> bool {
+        let Some(next) = self.queue.front_mut().filter(|p| {
+            p.channel_id == batch.channel_id
+                && p.thread_root_message_index == batch.thread_root_message_index
+                && p.message_index == batch.message_index
+        }) else {
+            return false;
+        };
+
+        next.users.retain(|u| !batch.users.contains(u));
+        next.failed.extend(failed);
+
+        if !next.users.is_empty() {
+            return false;
+        }
+
+        if next.failed.is_empty() {
+            self.queue.pop_front();
+            true
+        } else if next.attempts + 1 < MAX_LOOKUP_ATTEMPTS {
+            next.attempts += 1;
+            next.users = std::mem::take(&mut next.failed);
+            false
+        } else {
+            self.queue.pop_front();
+            false
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
@@ -0,0 +1,108 @@
+use constants::{DAY_IN_MS, MINUTE_IN_MS};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::error;
+use types::icrc1::{Account, CryptoAccount};
+use types::{MessageId, Milliseconds, TimestampMillis, icrc2};
+
+// Long enough for the ledger call to complete, after which a reservation is assumed to have been abandoned (eg. due to
+// a trap) and the message id is released
+const RESERVATION_TIMEOUT: Milliseconds = 10 * MINUTE_IN_MS;
+
+// Crypto transfers made on behalf of bots whose messages then failed to send. The funds have already reached the
+// recipient, so if the bot retries the message using the same message id, the existing transfer is reused rather than
+// the recipient being paid twice. While a bot's transfer is being made its message id is reserved, so that concurrent
+// attempts to send the same message can't each make a transfer.
+#[derive(Serialize, Deserialize, Default)]
+pub struct UnsentBotTransfers {
+    transfers: BTreeMap<MessageId, UnsentBotTransfer>,
+    #[serde(default)]
+    reserved: BTreeMap<MessageId, TimestampMillis>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UnsentBotTransfer {
+    timestamp: TimestampMillis,
+    transfer: icrc2::CompletedCryptoTransaction,
+}
+
+pub enum ReserveBotTransferResult {
+    // A transfer must be made, the message id is reserved until `release` is called
+    Reserved,
+    // The transfer previously made for this message can be reused, the message id is reserved until `release` is called
+    Reuse(icrc2::CompletedCryptoTransaction),
+    // A transfer for this message is already in progress
+    AlreadyInProgress,
+}
+
+impl UnsentBotTransfers {
+    // Reserves the message id, taking the transfer previously made for this message if it matches the pending transfer
+    pub fn reserve(
+        &mut self,
+        message_id: MessageId,
+        pending: &icrc2::PendingCryptoTransaction,
+        now: TimestampMillis,
+    ) -> ReserveBotTransferResult {
+        self.prune(now);
+
+        if self.reserved.contains_key(&message_id) {
+            return ReserveBotTransferResult::AlreadyInProgress;
+        }
+        self.reserved.insert(message_id, now);
+
+        if let Some(existing) = self.transfers.get(&message_id).map(|t| &t.transfer) {
+            if existing.ledger == pending.ledger
+                && existing.amount == pending.amount
+                && is_account(&existing.from, &pending.from)
+                && is_account(&existing.to, &pending.to)
+            {
+                if let Some(unsent) = self.transfers.remove(&message_id) {
+                    return ReserveBotTransferResult::Reuse(unsent.transfer);
+                }
+            }
+        }
+
+        ReserveBotTransferResult::Reserved
+    }
+
+    // Releases the message id, recording the transfer if it was made but the message then failed to send
+    pub fn release(
+        &mut self,
+        message_id: MessageId,
+        unsent_transfer: Option<icrc2::CompletedCryptoTransaction>,
+        now: TimestampMillis,
+    ) {
+        self.reserved.remove(&message_id);
+
+        if let Some(transfer) = unsent_transfer {
+            self.transfers.insert(
+                message_id,
+                UnsentBotTransfer {
+                    timestamp: now,
+                    transfer,
+                },
+            );
+        }
+    }
+
+    // Bots are told about the transfer when their message fails to send and are expected to retry promptly, after a
+    // day the message is assumed to have been abandoned
+    fn prune(&mut self, now: TimestampMillis) {
+        self.reserved.retain(|_, ts| *ts + RESERVATION_TIMEOUT > now);
+        self.transfers.retain(|message_id, t| {
+            let retain = t.timestamp + DAY_IN_MS > now;
+            if !retain {
+                error!(
+                    ?message_id,
+                    block_index = t.transfer.block_index,
+                    "Bot's message was never sent after its transfer was made"
+                );
+            }
+            retain
+        });
+    }
+}
+
+fn is_account(crypto_account: &CryptoAccount, account: &Account) -> bool {
+    matches!(crypto_account, CryptoAccount::Account(a) if a.owner == account.owner && a.subaccount == account.subaccount)
+}
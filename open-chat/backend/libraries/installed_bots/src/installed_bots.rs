@@ -3,7 +3,7 @@ use constants::calculate_summary_updates_data_removal_cutoff;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
-use types::{BotPermissions, BotSubscriptions, TimestampMillis, UserId};
+use types::{BotCommandArg, BotInitiator, BotPermissions, BotPermissionsRequest, BotSubscriptions, TimestampMillis, UserId};
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct InstalledBots {
@@ -20,6 +20,7 @@ impl InstalledBots {
         permissions: BotPermissions,
         autonomous_permissions: Option<BotPermissions>,
         default_subscriptions: Option<BotSubscriptions>,
+        config: Vec<BotCommandArg>,
         now: TimestampMillis,
     ) -> bool {
         if self.bots.contains_key(&bot_id) {
@@ -33,6 +34,9 @@ impl InstalledBots {
                 permissions,
                 autonomous_permissions,
                 default_subscriptions,
+                config,
+                permissions_request: None,
+                pinned_version: None,
             },
         );
         self.prune_then_insert_member_update(bot_id, BotUpdate::Added, now);
@@ -53,12 +57,53 @@ impl InstalledBots {
                 let bot = o.get_mut();
                 bot.permissions = permissions;
                 bot.autonomous_permissions = autonomous_permissions;
+                bot.permissions_request = None;
                 self.prune_then_insert_member_update(bot_id, BotUpdate::Updated, now);
                 true
             }
         }
     }
 
+    pub fn update_config(&mut self, bot_id: UserId, config: Vec<BotCommandArg>, now: TimestampMillis) -> bool {
+        match self.bots.get_mut(&bot_id) {
+            None => false,
+            Some(bot) => {
+                bot.config = config;
+                self.prune_then_insert_member_update(bot_id, BotUpdate::Updated, now);
+                true
+            }
+        }
+    }
+
+    // Replaces any existing pending request from the bot
+    pub fn set_permissions_request(&mut self, bot_id: UserId, request: BotPermissionsRequest, now: TimestampMillis) -> bool {
+        match self.bots.get_mut(&bot_id) {
+            None => false,
+            Some(bot) => {
+                bot.permissions_request = Some(request);
+                self.prune_then_insert_member_update(bot_id, BotUpdate::Updated, now);
+                true
+            }
+        }
+    }
+
+    pub fn set_pinned_version(&mut self, bot_id: UserId, version: Option<u32>, now: TimestampMillis) -> bool {
+        match self.bots.get_mut(&bot_id) {
+            None => false,
+            Some(bot) => {
+                bot.pinned_version = version;
+                self.prune_then_insert_member_update(bot_id, BotUpdate::Updated, now);
+                true
+            }
+        }
+    }
+
+    pub fn take_permissions_request(&mut self, bot_id: UserId, now: TimestampMillis) -> Option<BotPermissionsRequest> {
+        let request = self.bots.get_mut(&bot_id)?.permissions_request.take()?;
+        self.prune_then_insert_member_update(bot_id, BotUpdate::Updated, now);
+        Some(request)
+    }
+
     pub fn remove(&mut self, bot_id: UserId, now: TimestampMillis) -> bool {
         let removed = self.bots.remove(&bot_id).is_some();
 
@@ -130,4 +175,22 @@ pub struct BotInternal {
     pub autonomous_permissions: Option<BotPermissions>,
     #[serde(default)]
     pub default_subscriptions: Option<BotSubscriptions>,
+    #[serde(default)]
+    pub config: Vec<BotCommandArg>,
+    #[serde(default)]
+    pub permissions_request: Option<BotPermissionsRequest>,
+    #[serde(default)]
+    pub pinned_version: Option<u32>,
+}
+
+impl BotInternal {
+    // Returns those of the `required` permissions which have not been granted to the bot for the given initiator
+    pub fn missing_permissions(&self, initiator: &BotInitiator, required: &BotPermissions) -> BotPermissions {
+        let granted = match initiator {
+            BotInitiator::Command(_) => Some(&self.permissions),
+            BotInitiator::Autonomous => self.autonomous_permissions.as_ref(),
+        };
+
+        granted.map_or_else(|| required.clone(), |granted| required.difference(granted))
+    }
 }
@@ -0,0 +1,269 @@
+use constants::DAY_IN_MS;
+use std::collections::BTreeMap;
+
+const MAX_TOKEN_CHARS: usize = 40;
+// Leaves a gap between fields so that phrases can't match across the end of one field and the start of the next
+const FIELD_POSITION_GAP: u16 = 8;
+
+// BM25 parameters
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+const PREFIX_MATCH_WEIGHT: f64 = 0.5;
+
+#[derive(Default)]
+pub struct Document {
+    terms: BTreeMap<String, Vec<u16>>,
+    next_position: u16,
+}
+
+impl Document {
+    pub fn add_field(&mut self, value: &str) -> &mut Document {
+        for token in tokenize(value) {
+            self.terms.entry(token).or_default().push(self.next_position);
+            self.next_position = self.next_position.saturating_add(1);
+        }
+        self.next_position = self.next_position.saturating_add(FIELD_POSITION_GAP);
+        self
+    }
+
+    pub fn len(&self) -> u32 {
+        self.terms.values().map(|p| p.len() as u32).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn terms(&self) -> impl Iterator<Item = (&String, &Vec<u16>)> {
+        self.terms.iter()
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Query {
+    // Matched against every term starting with the given text
+    pub terms: Vec<String>,
+    // Each phrase must appear as consecutive whole terms
+    pub phrases: Vec<Vec<String>>,
+    pub from: Vec<String>,
+    pub has: Vec<String>,
+    pub in_thread: bool,
+    pub after: Option<u64>,
+    pub before: Option<u64>,
+}
+
+impl Query {
+    // Supports free text, "quoted phrases" and the filters `from:`, `has:`, `in:thread`, `after:YYYY-MM-DD` and
+    // `before:YYYY-MM-DD`. Anything which isn't a recognised filter is treated as free text.
+    pub fn parse(text: &str) -> Query {
+        let mut query = Query::default();
+        let mut remaining = text.trim_start();
+
+        while !remaining.is_empty() {
+            let word = if let Some(quoted) = remaining.strip_prefix('"') {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                query.push_phrase(tokenize(&quoted[..end]).collect());
+                remaining = quoted.get(end + 1..).unwrap_or_default();
+                None
+            } else {
+                let end = remaining.find(char::is_whitespace).unwrap_or(remaining.len());
+                let word = &remaining[..end];
+                remaining = &remaining[end..];
+                Some(word)
+            };
+
+            if let Some(word) = word {
+                if !query.try_push_filter(word) {
+                    let tokens: Vec<_> = tokenize(word).collect();
+                    if tokens.len() > 1 {
+                        query.push_phrase(tokens);
+                    } else {
+                        query.terms.extend(tokens);
+                    }
+                }
+            }
+            remaining = remaining.trim_start();
+        }
+        query
+    }
+
+    pub fn has_text(&self) -> bool {
+        !self.terms.is_empty() || !self.phrases.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.has_text()
+            && self.from.is_empty()
+            && self.has.is_empty()
+            && !self.in_thread
+            && self.after.is_none()
+            && self.before.is_none()
+    }
+
+    pub fn text_length(&self) -> usize {
+        self.terms.iter().chain(self.phrases.iter().flatten()).map(|t| t.len()).sum()
+    }
+
+    pub fn is_in_date_range(&self, timestamp: u64) -> bool {
+        self.after.is_none_or(|a| timestamp >= a) && self.before.is_none_or(|b| timestamp < b)
+    }
+
+    fn push_phrase(&mut self, tokens: Vec<String>) {
+        match tokens.len() {
+            0 => {}
+            1 => self.terms.extend(tokens),
+            _ => self.phrases.push(tokens),
+        }
+    }
+
+    fn try_push_filter(&mut self, word: &str) -> bool {
+        let Some((key, value)) = word.split_once(':') else {
+            return false;
+        };
+        if value.is_empty() {
+            return false;
+        }
+        match key.to_lowercase().as_str() {
+            "from" => self.from.push(value.trim_start_matches('@').to_string()),
+            "has" => self.has.push(value.to_lowercase()),
+            "in" if value.eq_ignore_ascii_case("thread") => self.in_thread = true,
+            "after" => match parse_date(value) {
+                Some(date) => self.after = Some(date + DAY_IN_MS),
+                None => return false,
+            },
+            "before" => match parse_date(value) {
+                Some(date) => self.before = Some(date),
+                None => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+}
+
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.chars().take(MAX_TOKEN_CHARS).flat_map(char::to_lowercase).collect())
+}
+
+// Returns true if the phrase appears in order at consecutive positions
+pub fn is_phrase_match(term_positions: &[&[u16]]) -> bool {
+    let Some((first, rest)) = term_positions.split_first() else {
+        return false;
+    };
+    first.iter().any(|start| {
+        rest.iter().enumerate().all(|(i, positions)| {
+            start
+                .checked_add(i as u16 + 1)
+                .is_some_and(|p| positions.binary_search(&p).is_ok())
+        })
+    })
+}
+
+pub struct TermScorer {
+    idf: f64,
+    average_document_length: f64,
+}
+
+impl TermScorer {
+    pub fn new(document_count: u32, matching_document_count: u32, average_document_length: f64) -> TermScorer {
+        let n = document_count.max(matching_document_count) as f64;
+        let m = matching_document_count as f64;
+        TermScorer {
+            idf: (1.0 + (n - m + 0.5) / (m + 0.5)).ln(),
+            average_document_length: average_document_length.max(1.0),
+        }
+    }
+
+    pub fn score(&self, term_frequency: u32, document_length: u32, is_exact_match: bool) -> f64 {
+        let tf = term_frequency as f64;
+        let length_ratio = document_length as f64 / self.average_document_length;
+        let score = self.idf * (tf * (K1 + 1.0)) / (tf + K1 * (1.0 - B + B * length_ratio));
+        if is_exact_match { score } else { score * PREFIX_MATCH_WEIGHT }
+    }
+}
+
+// Parses a date in the format YYYY-MM-DD, returning the start of that day in milliseconds since the Unix epoch
+fn parse_date(value: &str) -> Option<u64> {
+    let mut parts = value.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days from civil, see http://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    u64::try_from(days).ok().map(|d| d * DAY_IN_MS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("Hello, World!", vec!["hello", "world"])]
+    #[test_case("don't-stop", vec!["don", "t", "stop"])]
+    #[test_case("  ÉCOLE  ", vec!["école"])]
+    fn tokenize_splits_and_lowercases(text: &str, expected: Vec<&str>) {
+        assert_eq!(tokenize(text).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn parse_query_with_phrases_and_filters() {
+        let query =
+            Query::parse(r#"cats "big dog" from:@julian has:image in:thread after:2024-01-31 before:2024-03-01 foo:bar"#);
+
+        assert_eq!(query.terms, vec!["cats"]);
+        assert_eq!(query.phrases, vec![vec!["big", "dog"], vec!["foo", "bar"]]);
+        assert_eq!(query.from, vec!["julian"]);
+        assert_eq!(query.has, vec!["image"]);
+        assert!(query.in_thread);
+        assert_eq!(query.after, Some(1706745600000));
+        assert_eq!(query.before, Some(1709251200000));
+    }
+
+    #[test_case("\"unterminated phrase", vec![], vec![vec!["unterminated", "phrase"]])]
+    #[test_case("\"single\"", vec!["single"], vec![])]
+    #[test_case("after:yesterday", vec![], vec![vec!["after", "yesterday"]])]
+    #[test_case("one-two", vec![], vec![vec!["one", "two"]])]
+    fn parse_query_edge_cases(text: &str, terms: Vec<&str>, phrases: Vec<Vec<&str>>) {
+        let query = Query::parse(text);
+
+        assert_eq!(query.terms, terms);
+        assert_eq!(query.phrases, phrases);
+    }
+
+    #[test]
+    fn phrase_matches_consecutive_positions_only() {
+        let mut doc = Document::default();
+        doc.add_field("the big dog saw a big cat").add_field("dog house");
+
+        let positions = |term: &str| doc.terms.get(term).unwrap().as_slice();
+
+        assert!(is_phrase_match(&[positions("big"), positions("dog")]));
+        assert!(is_phrase_match(&[positions("big"), positions("cat")]));
+        assert!(!is_phrase_match(&[positions("dog"), positions("big")]));
+        assert!(!is_phrase_match(&[positions("cat"), positions("dog")]));
+        assert_eq!(doc.len(), 9);
+    }
+
+    #[test]
+    fn rarer_terms_and_shorter_documents_score_higher() {
+        let common = TermScorer::new(100, 50, 10.0);
+        let rare = TermScorer::new(100, 2, 10.0);
+
+        assert!(rare.score(1, 10, true) > common.score(1, 10, true));
+        assert!(rare.score(1, 5, true) > rare.score(1, 20, true));
+        assert!(rare.score(1, 10, true) > rare.score(1, 10, false));
+    }
+}
@@ -1,2 +1,2 @@
-pub mod simple;
+pub mod full_text;
 pub mod weighted;
@@ -104,19 +104,6 @@ impl Document {
     }
 }
 
-impl From<Document> for crate::simple::Document {
-    fn from(value: Document) -> Self {
-        let tokens = value
-            .fields
-            .into_iter()
-            .flat_map(|f| f.tokens)
-            .map(|t| crate::simple::Token(t.value_lower))
-            .collect();
-
-        crate::simple::Document(tokens)
-    }
-}
-
 fn score_field(query: &Query, field: &Field) -> f32 {
     if query.tokens.is_empty() {
         return 0.0;
@@ -6,13 +6,17 @@ use std::borrow::Cow;
 mod chat_event;
 mod community_event;
 mod macros;
+mod message_revision;
 mod principal;
+mod search_index;
 mod storage;
 mod user_id;
 
 pub use chat_event::*;
 pub use community_event::*;
+pub use message_revision::*;
 pub use principal::*;
+pub use search_index::*;
 pub use storage::*;
 pub use user_id::*;
 
@@ -98,6 +102,12 @@ pub enum KeyType {
     FilesPerAccessor = 14,
     UserStorageRecord = 15,
     BlockedUsers = 16,
+    DirectChatSearchIndex = 17,
+    GroupChatSearchIndex = 18,
+    ChannelSearchIndex = 19,
+    DirectChatMessageRevision = 20,
+    GroupChatMessageRevision = 21,
+    ChannelMessageRevision = 22,
 }
 
 fn extract_key_type(bytes: &[u8]) -> Option<KeyType> {
@@ -125,6 +135,12 @@ impl TryFrom<u8> for KeyType {
             14 => Ok(KeyType::FilesPerAccessor),
             15 => Ok(KeyType::UserStorageRecord),
             16 => Ok(KeyType::BlockedUsers),
+            17 => Ok(KeyType::DirectChatSearchIndex),
+            18 => Ok(KeyType::GroupChatSearchIndex),
+            19 => Ok(KeyType::ChannelSearchIndex),
+            20 => Ok(KeyType::DirectChatMessageRevision),
+            21 => Ok(KeyType::GroupChatMessageRevision),
+            22 => Ok(KeyType::ChannelMessageRevision),
             _ => Err(()),
         }
     }
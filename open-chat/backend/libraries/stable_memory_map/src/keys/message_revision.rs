@@ -0,0 +1,173 @@
+use crate::keys::macros::key;
+use crate::{KeyPrefix, KeyType};
+use ic_principal::Principal;
+use types::{ChannelId, Chat, MessageIndex, UserId};
+
+key!(
+    MessageRevisionKey,
+    MessageRevisionKeyPrefix,
+    KeyType::DirectChatMessageRevision | KeyType::GroupChatMessageRevision | KeyType::ChannelMessageRevision
+);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MessageRevisionId {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub revision: u32,
+}
+
+impl MessageRevisionKeyPrefix {
+    pub fn new_from_chat(chat: Chat) -> Self {
+        match chat {
+            Chat::Direct(user_id) => Self::new_from_direct_chat(Principal::from(user_id).into()),
+            Chat::Group(_) => Self::new_from_group_chat(),
+            Chat::Channel(_, channel_id) => Self::new_from_channel(channel_id),
+        }
+    }
+
+    pub fn new_from_direct_chat(user_id: UserId) -> Self {
+        // KeyType::DirectChatMessageRevision   1 byte
+        // UserId length                        1 byte
+        // UserId bytes                         UserId length bytes
+        let user_id_bytes = user_id.as_slice();
+        let mut bytes = Vec::with_capacity(user_id_bytes.len() + 2);
+        bytes.push(KeyType::DirectChatMessageRevision as u8);
+        bytes.push(user_id_bytes.len() as u8);
+        bytes.extend_from_slice(user_id_bytes);
+        MessageRevisionKeyPrefix(bytes)
+    }
+
+    pub fn new_from_group_chat() -> Self {
+        // KeyType::GroupChatMessageRevision    1 byte
+        MessageRevisionKeyPrefix(vec![KeyType::GroupChatMessageRevision as u8])
+    }
+
+    pub fn new_from_channel(channel_id: ChannelId) -> Self {
+        // KeyType::ChannelMessageRevision      1 byte
+        // ChannelId                            4 bytes
+        let mut bytes = Vec::with_capacity(5);
+        bytes.push(KeyType::ChannelMessageRevision as u8);
+        bytes.extend_from_slice(&channel_id.as_u32().to_be_bytes());
+        MessageRevisionKeyPrefix(bytes)
+    }
+
+    // The range of keys holding the revisions of a single message
+    pub fn message_range(
+        &self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+    ) -> (MessageRevisionKey, MessageRevisionKey) {
+        let revision_id = |revision| MessageRevisionId {
+            thread_root_message_index,
+            message_index,
+            revision,
+        };
+        (self.create_key(&revision_id(0)), self.create_key(&revision_id(u32::MAX)))
+    }
+
+    // The range of keys holding the revisions of every message within a thread
+    pub fn thread_range(&self, thread_root_message_index: MessageIndex) -> (MessageRevisionKey, MessageRevisionKey) {
+        let revision_id = |message_index, revision| MessageRevisionId {
+            thread_root_message_index: Some(thread_root_message_index),
+            message_index,
+            revision,
+        };
+        (
+            self.create_key(&revision_id(MessageIndex::from(0), 0)),
+            self.create_key(&revision_id(MessageIndex::from(u32::MAX), u32::MAX)),
+        )
+    }
+}
+
+impl KeyPrefix for MessageRevisionKeyPrefix {
+    type Key = MessageRevisionKey;
+    type Suffix = MessageRevisionId;
+
+    fn create_key(&self, id: &MessageRevisionId) -> MessageRevisionKey {
+        // Prefix                       prefix length bytes
+        // Is thread marker             1 byte
+        // Thread root message index    4 bytes (zero if not in a thread)
+        // Message index                4 bytes
+        // Revision                     4 bytes
+        let mut bytes = Vec::with_capacity(self.0.len() + 13);
+        bytes.extend_from_slice(self.0.as_slice());
+        bytes.push(id.thread_root_message_index.is_some() as u8);
+        bytes.extend_from_slice(&id.thread_root_message_index.map_or(0, u32::from).to_be_bytes());
+        bytes.extend_from_slice(&u32::from(id.message_index).to_be_bytes());
+        bytes.extend_from_slice(&id.revision.to_be_bytes());
+        MessageRevisionKey(bytes)
+    }
+}
+
+impl MessageRevisionKey {
+    pub fn revision_id(&self) -> MessageRevisionId {
+        let start = self.0.len() - 13;
+        let read_u32 = |offset: usize| u32::from_be_bytes(self.0[start + offset..start + offset + 4].try_into().unwrap());
+
+        MessageRevisionId {
+            thread_root_message_index: (self.0[start] == 1).then(|| read_u32(1).into()),
+            message_index: read_u32(5).into(),
+            revision: read_u32(9),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseKey, Key};
+    use rand::{Rng, RngCore, thread_rng};
+
+    #[test]
+    fn message_revision_key_e2e() {
+        for _ in 0..100 {
+            let user_id_bytes: [u8; 10] = thread_rng().r#gen();
+            let user_id = UserId::from(Principal::from_slice(&user_id_bytes));
+            let channel_id = ChannelId::from(thread_rng().next_u32());
+
+            for prefix in [
+                MessageRevisionKeyPrefix::new_from_direct_chat(user_id),
+                MessageRevisionKeyPrefix::new_from_group_chat(),
+                MessageRevisionKeyPrefix::new_from_channel(channel_id),
+            ] {
+                let thread_root_message_index = thread_rng().r#gen::<bool>().then(|| thread_rng().next_u32().into());
+                let id = MessageRevisionId {
+                    thread_root_message_index,
+                    message_index: thread_rng().next_u32().into(),
+                    revision: thread_rng().next_u32(),
+                };
+                let key = BaseKey::from(prefix.create_key(&id));
+                let revision_key = MessageRevisionKey::try_from(key.clone()).unwrap();
+
+                assert!(revision_key.matches_prefix(&prefix));
+                assert_eq!(revision_key.revision_id(), id);
+
+                let (start, end) = prefix.message_range(id.thread_root_message_index, id.message_index);
+                assert!(revision_key >= start && revision_key <= end);
+
+                let serialized = msgpack::serialize_then_unwrap(&revision_key);
+                let deserialized: MessageRevisionKey = msgpack::deserialize_then_unwrap(&serialized);
+                assert_eq!(deserialized, revision_key);
+                assert_eq!(deserialized.0, key.0);
+            }
+        }
+    }
+
+    #[test]
+    fn thread_range_excludes_main_messages() {
+        let prefix = MessageRevisionKeyPrefix::new_from_group_chat();
+        let (start, end) = prefix.thread_range(MessageIndex::from(5));
+        let key = |thread_root_message_index: Option<u32>, message_index: u32| {
+            prefix.create_key(&MessageRevisionId {
+                thread_root_message_index: thread_root_message_index.map(MessageIndex::from),
+                message_index: message_index.into(),
+                revision: 0,
+            })
+        };
+
+        assert!(key(Some(5), 3) >= start && key(Some(5), 3) <= end);
+        assert!(key(Some(6), 3) > end);
+        assert!(key(Some(4), 3) < start);
+        assert!(key(None, 5) < start);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::keys::extract_key_type;
+use crate::keys::macros::key;
+use crate::{KeyPrefix, KeyType};
+use ic_principal::Principal;
+use types::{ChannelId, Chat, MessageIndex, UserId};
+
+const DOCUMENT_MARKER: u8 = 0;
+const POSTING_MARKER: u8 = 1;
+const TERM_TERMINATOR: u8 = 0;
+
+key!(
+    SearchIndexKey,
+    SearchIndexKeyPrefix,
+    KeyType::DirectChatSearchIndex | KeyType::GroupChatSearchIndex | KeyType::ChannelSearchIndex
+);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SearchIndexEntry {
+    Document(MessageIndex),
+    Posting(String, MessageIndex),
+}
+
+impl SearchIndexKeyPrefix {
+    pub fn new_from_chat(chat: Chat) -> Self {
+        match chat {
+            Chat::Direct(user_id) => Self::new_from_direct_chat(Principal::from(user_id).into()),
+            Chat::Group(_) => Self::new_from_group_chat(),
+            Chat::Channel(_, channel_id) => Self::new_from_channel(channel_id),
+        }
+    }
+
+    pub fn new_from_direct_chat(user_id: UserId) -> Self {
+        // KeyType::DirectChatSearchIndex   1 byte
+        // UserId length                    1 byte
+        // UserId bytes                     UserId length bytes
+        let user_id_bytes = user_id.as_slice();
+        let mut bytes = Vec::with_capacity(user_id_bytes.len() + 2);
+        bytes.push(KeyType::DirectChatSearchIndex as u8);
+        bytes.push(user_id_bytes.len() as u8);
+        bytes.extend_from_slice(user_id_bytes);
+        SearchIndexKeyPrefix(bytes)
+    }
+
+    pub fn new_from_group_chat() -> Self {
+        // KeyType::GroupChatSearchIndex    1 byte
+        SearchIndexKeyPrefix(vec![KeyType::GroupChatSearchIndex as u8])
+    }
+
+    pub fn new_from_channel(channel_id: ChannelId) -> Self {
+        // KeyType::ChannelSearchIndex      1 byte
+        // ChannelId                        4 bytes
+        let mut bytes = Vec::with_capacity(5);
+        bytes.push(KeyType::ChannelSearchIndex as u8);
+        bytes.extend_from_slice(&channel_id.as_u32().to_be_bytes());
+        SearchIndexKeyPrefix(bytes)
+    }
+
+    // The range of keys holding the postings of every term which starts with `term_prefix`
+    pub fn postings_range(&self, term_prefix: &str) -> (SearchIndexKey, SearchIndexKey) {
+        let mut start = Vec::with_capacity(self.0.len() + term_prefix.len() + 2);
+        start.extend_from_slice(self.0.as_slice());
+        start.push(POSTING_MARKER);
+        start.extend_from_slice(term_prefix.as_bytes());
+
+        // 0xFF never appears in UTF-8 so this is greater than every key whose term has the prefix
+        let mut end = start.clone();
+        end.push(u8::MAX);
+
+        (SearchIndexKey(start), SearchIndexKey(end))
+    }
+}
+
+impl KeyPrefix for SearchIndexKeyPrefix {
+    type Key = SearchIndexKey;
+    type Suffix = SearchIndexEntry;
+
+    fn create_key(&self, entry: &SearchIndexEntry) -> SearchIndexKey {
+        match entry {
+            SearchIndexEntry::Document(message_index) => {
+                // Prefix                   prefix length bytes
+                // Document marker          1 byte
+                // Message index            4 bytes
+                let mut bytes = Vec::with_capacity(self.0.len() + 5);
+                bytes.extend_from_slice(self.0.as_slice());
+                bytes.push(DOCUMENT_MARKER);
+                bytes.extend_from_slice(&u32::from(*message_index).to_be_bytes());
+                SearchIndexKey(bytes)
+            }
+            SearchIndexEntry::Posting(term, message_index) => {
+                // Prefix                   prefix length bytes
+                // Posting marker           1 byte
+                // Term                     term length bytes
+                // Term terminator          1 byte
+                // Message index            4 bytes
+                let mut bytes = Vec::with_capacity(self.0.len() + term.len() + 6);
+                bytes.extend_from_slice(self.0.as_slice());
+                bytes.push(POSTING_MARKER);
+                bytes.extend_from_slice(term.as_bytes());
+                bytes.push(TERM_TERMINATOR);
+                bytes.extend_from_slice(&u32::from(*message_index).to_be_bytes());
+                SearchIndexKey(bytes)
+            }
+        }
+    }
+}
+
+impl SearchIndexKey {
+    pub fn entry(&self) -> SearchIndexEntry {
+        let message_index = self.message_index();
+        let marker_index = self.prefix_len();
+        if self.0[marker_index] == DOCUMENT_MARKER {
+            SearchIndexEntry::Document(message_index)
+        } else {
+            let term_bytes = &self.0[marker_index + 1..self.0.len() - 5];
+            SearchIndexEntry::Posting(String::from_utf8_lossy(term_bytes).into_owned(), message_index)
+        }
+    }
+
+    pub fn message_index(&self) -> MessageIndex {
+        let start = self.0.len() - 4;
+        u32::from_be_bytes(self.0[start..].try_into().unwrap()).into()
+    }
+
+    fn prefix_len(&self) -> usize {
+        match self.key_type() {
+            KeyType::DirectChatSearchIndex => 2 + self.0[1] as usize,
+            KeyType::GroupChatSearchIndex => 1,
+            KeyType::ChannelSearchIndex => 5,
+            _ => unreachable!(),
+        }
+    }
+
+    fn key_type(&self) -> KeyType {
+        extract_key_type(&self.0).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseKey, Key};
+    use rand::{Rng, RngCore, thread_rng};
+
+    #[test]
+    fn search_index_key_e2e() {
+        for _ in 0..100 {
+            let user_id_bytes: [u8; 10] = thread_rng().r#gen();
+            let user_id = UserId::from(Principal::from_slice(&user_id_bytes));
+            let channel_id = ChannelId::from(thread_rng().next_u32());
+
+            for prefix in [
+                SearchIndexKeyPrefix::new_from_direct_chat(user_id),
+                SearchIndexKeyPrefix::new_from_group_chat(),
+                SearchIndexKeyPrefix::new_from_channel(channel_id),
+            ] {
+                let message_index = MessageIndex::from(thread_rng().next_u32());
+                for entry in [
+                    SearchIndexEntry::Document(message_index),
+                    SearchIndexEntry::Posting("héllo".to_string(), message_index),
+                ] {
+                    let key = BaseKey::from(prefix.create_key(&entry));
+                    let search_index_key = SearchIndexKey::try_from(key.clone()).unwrap();
+
+                    assert!(search_index_key.matches_prefix(&prefix));
+                    assert_eq!(search_index_key.entry(), entry);
+                    assert_eq!(search_index_key.message_index(), message_index);
+
+                    let serialized = msgpack::serialize_then_unwrap(&search_index_key);
+                    let deserialized: SearchIndexKey = msgpack::deserialize_then_unwrap(&serialized);
+                    assert_eq!(deserialized, search_index_key);
+                    assert_eq!(deserialized.0, key.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn postings_range_contains_only_terms_with_prefix() {
+        let prefix = SearchIndexKeyPrefix::new_from_group_chat();
+        let (start, end) = prefix.postings_range("cat");
+        let message_index = MessageIndex::from(10);
+
+        for (term, expected) in [("cat", true), ("cats", true), ("catωx", true), ("ca", false), ("cau", false)] {
+            let key = prefix.create_key(&SearchIndexEntry::Posting(term.to_string(), message_index));
+            assert_eq!(key >= start && key < end, expected, "{term}");
+        }
+
+        let document_key = prefix.create_key(&SearchIndexEntry::Document(MessageIndex::from(u32::MAX)));
+        assert!(document_key < start);
+    }
+}
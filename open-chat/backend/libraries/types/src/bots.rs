@@ -1,8 +1,9 @@
 use crate::bitflags::{decode_from_bitflags, encode_as_bitflags};
 use crate::{
     AudioContent, CanisterId, Chat, ChatEventCategory, ChatEventType, ChatId, ChatPermission, CommunityEventCategory,
-    CommunityEventType, CommunityId, CommunityOrGroup, CommunityPermission, FileContent, GiphyContent, GroupRole, ImageContent,
-    MessageContentInitial, MessageId, MessagePermission, PollContent, TextContent, TimestampMillis, UserId, VideoContent,
+    CommunityEventType, CommunityId, CommunityOrGroup, CommunityPermission, CryptoContent, FileContent, GiphyContent,
+    GroupRole, ImageContent, MessageContentInitial, MessageId, MessagePermission, PollContent, PrizeContentInitial,
+    TextContent, TimestampMillis, UserId, VideoContent,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -18,10 +19,70 @@ pub struct BotDefinition {
     pub autonomous_config: Option<AutonomousConfig>,
     pub default_subscriptions: Option<BotSubscriptions>,
     pub data_encoding: Option<BotDataEncoding>,
+    pub installation_config: Option<BotInstallationConfig>,
 }
 
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+pub struct BotDefinitionVersion {
+    pub version: u32,
+    pub commands: Vec<BotCommandDefinition>,
+    pub autonomous_config: Option<AutonomousConfig>,
+    pub published_at: TimestampMillis,
+}
+
+impl BotDefinitionVersion {
+    pub fn command_permissions(&self) -> BotPermissions {
+        command_permissions(&self.commands)
+    }
+
+    pub fn autonomous_permissions(&self) -> BotPermissions {
+        self.autonomous_config
+            .as_ref()
+            .map(|c| c.permissions.clone())
+            .unwrap_or_default()
+    }
+}
+
+pub fn command_permissions(commands: &[BotCommandDefinition]) -> BotPermissions {
+    commands
+        .iter()
+        .fold(BotPermissions::default(), |acc, c| acc.union(&c.permissions))
+}
+
+// While a rollout is staged, the latest version only applies to the listed locations, every other installation
+// continues to use `stable_version`
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+pub struct BotStagedRollout {
+    pub stable_version: u32,
+    pub locations: Vec<BotInstallationLocation>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+pub enum BotRollout {
+    Locations(Vec<BotInstallationLocation>),
+    All,
+}
+
+pub fn resolve_bot_version(
+    latest_version: u32,
+    staged_rollout: Option<&BotStagedRollout>,
+    pinned_version: Option<u32>,
+    location: &BotInstallationLocation,
+) -> u32 {
+    if let Some(version) = pinned_version {
+        version
+    } else if let Some(rollout) = staged_rollout.filter(|r| !r.locations.contains(location)) {
+        rollout.stable_version
+    } else {
+        latest_version
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct BotCommandDefinition {
     pub name: String,
     pub description: Option<String>,
@@ -33,13 +94,28 @@ pub struct BotCommandDefinition {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AutonomousConfig {
     pub permissions: BotPermissions,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BotInstallationConfig {
+    pub fields: Vec<BotInstallationConfigField>,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+pub struct BotInstallationConfigField {
+    pub name: String,
+    pub description: Option<String>,
+    pub param_type: BotCommandParamType,
+    pub default_value: Option<BotCommandArgValue>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct BotCommandParam {
     pub name: String,
     pub description: Option<String>,
@@ -49,7 +125,7 @@ pub struct BotCommandParam {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum BotCommandParamType {
     UserParam,
     BooleanParam,
@@ -59,8 +135,32 @@ pub enum BotCommandParamType {
     DateTimeParam(DateTimeParam),
 }
 
+impl BotCommandParamType {
+    pub fn is_valid_value(&self, value: &BotCommandArgValue) -> bool {
+        match (self, value) {
+            (BotCommandParamType::UserParam, BotCommandArgValue::User(_)) => true,
+            (BotCommandParamType::BooleanParam, BotCommandArgValue::Boolean(_)) => true,
+            (BotCommandParamType::StringParam(p), BotCommandArgValue::String(s)) => {
+                let len = s.chars().count();
+                len >= p.min_length as usize
+                    && len <= p.max_length as usize
+                    && (p.choices.is_empty() || p.choices.iter().any(|c| c.value == *s))
+            }
+            (BotCommandParamType::IntegerParam(p), BotCommandArgValue::Integer(i)) => {
+                let i = *i as i128;
+                i >= p.min_value && i <= p.max_value && (p.choices.is_empty() || p.choices.iter().any(|c| c.value == i))
+            }
+            (BotCommandParamType::DecimalParam(p), BotCommandArgValue::Decimal(d)) => {
+                *d >= p.min_value && *d <= p.max_value && (p.choices.is_empty() || p.choices.iter().any(|c| c.value == *d))
+            }
+            (BotCommandParamType::DateTimeParam(_), BotCommandArgValue::DateTime(_)) => true,
+            _ => false,
+        }
+    }
+}
+
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StringParam {
     pub min_length: u16,
     pub max_length: u16,
@@ -70,7 +170,7 @@ pub struct StringParam {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct IntegerParam {
     pub min_value: i128,
     pub max_value: i128,
@@ -79,7 +179,7 @@ pub struct IntegerParam {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DecimalParam {
     pub min_value: f64,
     pub max_value: f64,
@@ -88,13 +188,13 @@ pub struct DecimalParam {
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DateTimeParam {
     pub future_only: bool,
 }
 
 #[ts_export]
-#[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct BotCommandOptionChoice<T> {
     pub name: String,
     pub value: T,
@@ -180,6 +280,15 @@ impl BotPermissions {
         }
     }
 
+    // Returns the permissions in `self` which are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            community: difference_bits(self.community, other.community),
+            chat: difference_bits(self.chat, other.chat),
+            message: difference_bits(self.message, other.message),
+        }
+    }
+
     pub fn text_only() -> Self {
         Self::from_message_permission(MessagePermission::Text)
     }
@@ -221,6 +330,7 @@ impl BotPermissions {
                 ChatPermission::ReadMessages,
                 ChatPermission::ReadMembership,
                 ChatPermission::ReadSummary,
+                ChatPermission::MuteMembers,
             ]))
             .with_message(&HashSet::from_iter([
                 MessagePermission::Text,
@@ -294,6 +404,14 @@ fn union_bits(x: u32, y: u32) -> u32 {
     u32::from_be_bytes(union)
 }
 
+fn difference_bits(x: u32, y: u32) -> u32 {
+    let mut difference = [0; 4];
+    for (i, (x_byte, y_byte)) in x.to_be_bytes().into_iter().zip(y.to_be_bytes()).enumerate() {
+        difference[i] = x_byte & !y_byte;
+    }
+    u32::from_be_bytes(difference)
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct BotMessage {
@@ -315,6 +433,7 @@ pub struct BotMatch {
     pub avatar_id: Option<u128>,
     pub commands: Vec<BotCommandDefinition>,
     pub autonomous_config: Option<AutonomousConfig>,
+    pub installation_config: Option<BotInstallationConfig>,
 }
 
 macro_rules! slash_command_option_choice {
@@ -339,6 +458,18 @@ pub struct InstalledBotDetails {
     pub added_by: UserId,
     pub permissions: BotPermissions,
     pub autonomous_permissions: Option<BotPermissions>,
+    pub config: Vec<BotCommandArg>,
+    pub permissions_request: Option<BotPermissionsRequest>,
+    pub pinned_version: Option<u32>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct BotPermissionsRequest {
+    pub command_permissions: BotPermissions,
+    pub autonomous_permissions: BotPermissions,
+    pub justification: String,
+    pub requested_at: TimestampMillis,
 }
 
 #[ts_export]
@@ -347,6 +478,7 @@ pub struct WebhookDetails {
     pub id: UserId,
     pub name: String,
     pub avatar_id: Option<u128>,
+    pub bot_id: Option<UserId>,
 }
 
 #[ts_export]
@@ -433,6 +565,8 @@ pub enum BotMessageContent {
     File(FileContent),
     Poll(PollContent),
     Giphy(GiphyContent),
+    Crypto(CryptoContent),
+    Prize(PrizeContentInitial),
 }
 
 #[ts_export]
@@ -468,6 +602,8 @@ impl From<BotMessageContent> for MessageContentInitial {
             BotMessageContent::File(c) => MessageContentInitial::File(c),
             BotMessageContent::Poll(c) => MessageContentInitial::Poll(c),
             BotMessageContent::Giphy(c) => MessageContentInitial::Giphy(c),
+            BotMessageContent::Crypto(c) => MessageContentInitial::Crypto(c),
+            BotMessageContent::Prize(c) => MessageContentInitial::Prize(c),
         }
     }
 }
@@ -549,6 +685,37 @@ impl BotDefinition {
     }
 }
 
+impl BotInstallationConfig {
+    // Validates the supplied values against the config schema, filling in the defaults for any fields which have not
+    // been supplied. Returns an error describing the first invalid field.
+    pub fn resolve(&self, values: Vec<BotCommandArg>) -> Result<Vec<BotCommandArg>, String> {
+        if let Some(unknown) = values.iter().find(|v| !self.fields.iter().any(|f| f.name == v.name)) {
+            return Err(format!("unknown config field: {}", unknown.name));
+        }
+
+        let mut resolved = Vec::with_capacity(self.fields.len());
+        for field in self.fields.iter() {
+            let value = match values.iter().find(|v| v.name == field.name) {
+                Some(v) => v.value.clone(),
+                None => match &field.default_value {
+                    Some(d) => d.clone(),
+                    None => return Err(format!("config field missing: {}", field.name)),
+                },
+            };
+
+            if !field.param_type.is_valid_value(&value) {
+                return Err(format!("config field invalid: {}", field.name));
+            }
+
+            resolved.push(BotCommandArg {
+                name: field.name.clone(),
+                value,
+            });
+        }
+        Ok(resolved)
+    }
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum BotDataEncoding {
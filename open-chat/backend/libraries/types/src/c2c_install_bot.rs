@@ -1,4 +1,4 @@
-use crate::{BotPermissions, BotSubscriptions, UnitResult, UserId};
+use crate::{BotCommandArg, BotPermissions, BotSubscriptions, UnitResult, UserId};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +9,8 @@ pub struct Args {
     pub granted_permissions: BotPermissions,
     pub granted_autonomous_permissions: Option<BotPermissions>,
     pub default_subscriptions: Option<BotSubscriptions>,
+    #[serde(default)]
+    pub config: Vec<BotCommandArg>,
 }
 
 pub type Response = UnitResult;
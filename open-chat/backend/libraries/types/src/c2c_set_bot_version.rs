@@ -0,0 +1,12 @@
+use crate::{UnitResult, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub caller: UserId,
+    pub pinned_version: Option<u32>,
+}
+
+pub type Response = UnitResult;
@@ -0,0 +1,12 @@
+use crate::{BotCommandArg, UnitResult, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub bot_id: UserId,
+    pub caller: UserId,
+    pub config: Vec<BotCommandArg>,
+}
+
+pub type Response = UnitResult;
@@ -1,6 +1,7 @@
 use crate::{
     AccessGateConfig, ChannelId, ChatMetrics, EventIndex, EventWrapper, GroupMembership, GroupMembershipUpdates,
-    GroupPermissions, GroupSubtype, Message, MessageIndex, Milliseconds, OptionUpdate, TimestampMillis, VideoCall,
+    GroupPermissions, GroupSubtype, Message, MessageIndex, Milliseconds, OptionUpdate, SlowMode, TimestampMillis, UserId,
+    VideoCall,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -18,6 +19,12 @@ pub struct CommunityCanisterChannelSummary {
     pub is_public: bool,
     pub history_visible_to_new_joiners: bool,
     pub messages_visible_to_non_members: bool,
+    #[serde(default)]
+    pub retain_edit_history: bool,
+    #[serde(default)]
+    pub proposal_delegate: Option<UserId>,
+    #[serde(default)]
+    pub slow_mode: Option<SlowMode>,
     pub min_visible_event_index: EventIndex,
     pub min_visible_message_index: MessageIndex,
     #[ts(as = "Option<crate::EventWrapperMessage>")]
@@ -51,6 +58,13 @@ pub struct CommunityCanisterChannelSummaryUpdates {
     pub avatar_id: OptionUpdate<u128>,
     pub is_public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    pub retain_edit_history: Option<bool>,
+    #[serde(default)]
+    #[ts(as = "crate::OptionUpdateUserId")]
+    pub proposal_delegate: OptionUpdate<UserId>,
+    #[serde(default)]
+    #[ts(as = "crate::OptionUpdateSlowMode")]
+    pub slow_mode: OptionUpdate<SlowMode>,
     #[ts(as = "Option<crate::EventWrapperMessage>")]
     pub latest_message: Option<EventWrapper<Message>>,
     pub latest_message_sender_display_name: Option<String>,
@@ -1,7 +1,7 @@
 use crate::{
     AccessGateConfig, BuildVersion, CanisterId, ChatId, EventIndex, EventWrapper, FrozenGroupInfo, GroupMember,
     GroupPermissions, GroupRole, HydratedMention, InstalledBotDetails, Message, MessageId, MessageIndex, Milliseconds,
-    OptionUpdate, TimestampMillis, UserId, Version, WebhookDetails,
+    OptionUpdate, SlowMode, TimestampMillis, UserId, Version, WebhookDetails,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -135,6 +135,12 @@ pub struct GroupCanisterGroupChatSummary {
     pub is_public: bool,
     pub history_visible_to_new_joiners: bool,
     pub messages_visible_to_non_members: bool,
+    #[serde(default)]
+    pub retain_edit_history: bool,
+    #[serde(default)]
+    pub proposal_delegate: Option<UserId>,
+    #[serde(default)]
+    pub slow_mode: Option<SlowMode>,
     pub min_visible_event_index: EventIndex,
     pub min_visible_message_index: MessageIndex,
     #[ts(as = "Option<crate::EventWrapperMessage>")]
@@ -187,6 +193,13 @@ pub struct GroupCanisterGroupChatSummaryUpdates {
     pub my_metrics: Option<ChatMetrics>,
     pub is_public: Option<bool>,
     pub messages_visible_to_non_members: Option<bool>,
+    pub retain_edit_history: Option<bool>,
+    #[serde(default)]
+    #[ts(as = "crate::OptionUpdateUserId")]
+    pub proposal_delegate: OptionUpdate<UserId>,
+    #[serde(default)]
+    #[ts(as = "crate::OptionUpdateSlowMode")]
+    pub slow_mode: OptionUpdate<SlowMode>,
     pub latest_threads: Vec<GroupCanisterThreadDetails>,
     pub unfollowed_threads: Vec<MessageIndex>,
     pub notifications_muted: Option<bool>,
@@ -154,6 +154,10 @@ impl CommunityRole {
         self.is_permitted(permissions.manage_user_groups)
     }
 
+    pub fn can_view_moderation_log(&self) -> bool {
+        self.has_admin_rights()
+    }
+
     pub fn can_delete_community(&self) -> bool {
         self.has_owner_rights()
     }
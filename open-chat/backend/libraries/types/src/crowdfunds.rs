@@ -0,0 +1,38 @@
+use crate::TimestampMillis;
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub enum CrowdfundStatus {
+    Open,
+    // The target has been reached, the funds will be paid to the beneficiary at the deadline
+    Funded(CrowdfundFunded),
+    PaidOut(CrowdfundPaidOut),
+    Refunded,
+}
+
+impl CrowdfundStatus {
+    pub fn is_open(&self) -> bool {
+        matches!(self, CrowdfundStatus::Open)
+    }
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CrowdfundFunded {
+    pub funded_at: TimestampMillis,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CrowdfundPaidOut {
+    pub txn_out: u64,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ContributeToCrowdfundSuccess {
+    pub txn_in: u64,
+}
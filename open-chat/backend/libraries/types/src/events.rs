@@ -1,6 +1,6 @@
 use crate::{
-    AccessGateConfig, BotCommand, ChannelId, EventIndex, EventWrapper, GroupPermissions, GroupRole, Message, MessageIndex,
-    Milliseconds, TimestampMillis, UserId,
+    AccessGateConfig, BotCommand, ChannelId, EscalationAction, EventIndex, EventWrapper, GroupPermissions, GroupRole, Message,
+    MessageIndex, Milliseconds, TimestampMillis, UserId,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -39,6 +39,8 @@ pub enum ChatEvent {
     BotAdded(Box<BotAdded>),
     BotRemoved(Box<BotRemoved>),
     BotUpdated(Box<BotUpdated>),
+    MemberWarned(Box<MemberWarned>),
+    MemberMuted(Box<MemberMuted>),
     FailedToDeserialize,
 }
 
@@ -67,6 +69,8 @@ pub enum ChatEventType {
     MessageP2pSwapCompleted,
     MessageP2pSwapCancelled,
     MessageVideoCall,
+    MessageProposalCreated,
+    MessageProposalDecided,
     MessageOther, // Not publishing a bot notification for this event
 
     // Details category
@@ -96,6 +100,8 @@ pub enum ChatEventType {
     BotAdded,   // Not publishing a bot notification for this event
     BotRemoved, // Not publishing a bot notification for this event
     BotUpdated, // Not publishing a bot notification for this event
+    MemberWarned,
+    MemberMuted,
 }
 
 impl From<ChatEventType> for ChatEventCategory {
@@ -113,6 +119,8 @@ impl From<ChatEventType> for ChatEventCategory {
             | ChatEventType::MessageP2pSwapCompleted
             | ChatEventType::MessageP2pSwapCancelled
             | ChatEventType::MessageVideoCall
+            | ChatEventType::MessageProposalCreated
+            | ChatEventType::MessageProposalDecided
             | ChatEventType::MessageOther => ChatEventCategory::Message,
             ChatEventType::Created
             | ChatEventType::NameChanged
@@ -137,7 +145,9 @@ impl From<ChatEventType> for ChatEventCategory {
             | ChatEventType::BotRemoved
             | ChatEventType::BotUpdated
             | ChatEventType::UsersBlocked
-            | ChatEventType::UsersUnblocked => ChatEventCategory::Membership,
+            | ChatEventType::UsersUnblocked
+            | ChatEventType::MemberWarned
+            | ChatEventType::MemberMuted => ChatEventCategory::Membership,
         }
     }
 }
@@ -154,6 +164,17 @@ pub struct EventsResponse {
     pub chat_last_updated: TimestampMillis,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ChatExportPage {
+    #[ts(as = "Vec<crate::EventWrapperChatEvent>")]
+    pub events: Vec<EventWrapper<ChatEvent>>,
+    pub expired_event_ranges: Vec<(EventIndex, EventIndex)>,
+    pub latest_event_index: EventIndex,
+    // The index to pass in as `start_index` to fetch the next page, or None if this is the final page
+    pub next_index: Option<EventIndex>,
+}
+
 #[expect(clippy::large_enum_variant)]
 pub enum EventOrExpiredRange {
     Event(EventWrapper<ChatEvent>),
@@ -423,6 +444,27 @@ pub struct UsersInvited {
     pub invited_by: UserId,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberWarned {
+    pub user_id: UserId,
+    pub warned_by: UserId,
+    pub reason: String,
+    pub active_warnings: u32,
+    // The action taken automatically as a result of the group's escalation policy
+    pub escalation: Option<EscalationAction>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberMuted {
+    pub user_id: UserId,
+    pub muted_by: UserId,
+    // None if the member's mute was lifted
+    pub muted_until: Option<TimestampMillis>,
+    pub reason: Option<String>,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct ChannelCreated {
@@ -555,6 +597,8 @@ impl ChatEvent {
             ChatEvent::BotAdded(_) => Some(ChatEventType::BotAdded),
             ChatEvent::BotRemoved(_) => Some(ChatEventType::BotRemoved),
             ChatEvent::BotUpdated(_) => Some(ChatEventType::BotUpdated),
+            ChatEvent::MemberWarned(_) => Some(ChatEventType::MemberWarned),
+            ChatEvent::MemberMuted(_) => Some(ChatEventType::MemberMuted),
             ChatEvent::FailedToDeserialize => None,
             ChatEvent::Empty => None,
         }
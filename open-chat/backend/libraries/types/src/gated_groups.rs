@@ -1,5 +1,5 @@
 use crate::icrc2::TransferFromError;
-use crate::{CanisterId, Milliseconds};
+use crate::{CanisterId, DAY_IN_MS, Milliseconds};
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -266,8 +266,6 @@ impl From<&AccessGateNonComposite> for AccessGateExpiryBehaviour {
 
 impl AccessGateConfig {
     pub fn validate(&self, test_mode: bool) -> bool {
-        pub const DAY_IN_MS: Milliseconds = 1000 * 60 * 60 * 24;
-
         if let Some(expiry) = self.expiry {
             if !test_mode && expiry < DAY_IN_MS {
                 return false;
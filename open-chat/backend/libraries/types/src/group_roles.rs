@@ -189,6 +189,8 @@ pub enum ChatPermission {
     ReadMessages = 10,
     ReadMembership = 11,
     ReadSummary = 12,
+    CastDelegateVote = 13,
+    MuteMembers = 14,
 }
 
 impl From<ChatPermission> for u8 {
@@ -215,6 +217,8 @@ impl TryFrom<u8> for ChatPermission {
             10 => Ok(ChatPermission::ReadMessages),
             11 => Ok(ChatPermission::ReadMembership),
             12 => Ok(ChatPermission::ReadSummary),
+            13 => Ok(ChatPermission::CastDelegateVote),
+            14 => Ok(ChatPermission::MuteMembers),
             _ => Err(()),
         }
     }
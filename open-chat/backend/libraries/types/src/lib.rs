@@ -11,7 +11,9 @@ mod bots;
 mod build_version;
 pub mod c2c_can_issue_access_token;
 pub mod c2c_install_bot;
+pub mod c2c_set_bot_version;
 pub mod c2c_uninstall_bot;
+pub mod c2c_update_bot_config;
 mod caller;
 mod canister_upgrade_status;
 mod canister_wasm;
@@ -27,6 +29,7 @@ mod community_id;
 mod community_member;
 mod community_roles;
 mod community_summary;
+mod crowdfunds;
 mod cryptocurrency;
 mod cycles;
 mod delegation;
@@ -61,6 +64,7 @@ mod message_content;
 mod message_id;
 mod message_index;
 mod message_match;
+mod moderation;
 mod notifications;
 mod option;
 mod p2p_swaps;
@@ -74,6 +78,7 @@ mod reactions;
 mod referrals;
 mod registration_fee;
 mod relayed_args;
+mod slow_mode;
 mod source_group;
 mod subscription;
 mod suspension;
@@ -87,6 +92,7 @@ mod user_summary;
 mod version;
 mod versioned;
 mod video_calls;
+mod warnings;
 
 pub use access_tokens::*;
 pub use achievement::*;
@@ -109,6 +115,7 @@ pub use community_id::*;
 pub use community_member::*;
 pub use community_roles::*;
 pub use community_summary::*;
+pub use crowdfunds::*;
 pub use cryptocurrency::*;
 pub use cycles::*;
 pub use delegation::*;
@@ -143,6 +150,7 @@ pub use message_content::*;
 pub use message_id::*;
 pub use message_index::*;
 pub use message_match::*;
+pub use moderation::*;
 pub use notifications::*;
 use oc_error_codes::{OCError, OCErrorCode};
 pub use option::*;
@@ -157,6 +165,7 @@ pub use reactions::*;
 pub use referrals::*;
 pub use registration_fee::*;
 pub use relayed_args::*;
+pub use slow_mode::*;
 pub use source_group::*;
 pub use subscription::*;
 pub use suspension::*;
@@ -171,6 +180,7 @@ pub use user_summary::*;
 pub use version::*;
 pub use versioned::*;
 pub use video_calls::*;
+pub use warnings::*;
 
 pub type AccessorId = Principal;
 pub type CanisterId = Principal;
@@ -187,6 +197,9 @@ pub type TimestampNanos = u64;
 
 pub type OCResult<T = ()> = Result<T, OCError>;
 
+// Defined here rather than in the `constants` crate (which re-exports it) because `constants` depends on `types`
+pub const DAY_IN_MS: Milliseconds = 1000 * 60 * 60 * 24;
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum UnitResult {
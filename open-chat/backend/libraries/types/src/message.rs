@@ -1,6 +1,6 @@
 use crate::{
     Achievement, BotCaller, BotCommand, CanisterId, Chat, EventIndex, MessageContent, MessageId, MessageIndex, Reaction,
-    ThreadSummary, UserId,
+    ThreadSummary, TimestampMillis, UserId,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -92,6 +92,14 @@ pub struct MessageTippedEventPayload {
     pub amount: u128,
 }
 
+// A previous version of an edited message, along with the time at which it was replaced
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MessageRevision {
+    pub content: MessageContent,
+    pub replaced_at: TimestampMillis,
+}
+
 #[derive(Serialize)]
 pub struct MessageEditedEventPayload {
     pub message_type: String,
@@ -163,6 +171,7 @@ pub enum MessageContentEventPayload {
     MessageReminder(MessageReminderContentEventPayload),
     ReportedMessage(ReportedMessageContentEventPayload),
     P2PSwap(P2PSwapContentEventPayload),
+    Crowdfund(CrowdfundContentEventPayload),
     Encrypted(EncryptedContentEventPayload),
     Empty,
 }
@@ -256,6 +265,13 @@ pub struct P2PSwapContentEventPayload {
     pub caption_length: u32,
 }
 
+#[derive(Serialize)]
+pub struct CrowdfundContentEventPayload {
+    pub token: String,
+    pub target: u128,
+    pub caption_length: u32,
+}
+
 pub type DeletedContentEventPayload = ();
 pub type VideoCallContentEventPayload = ();
 pub type CustomContentEventPayload = ();
@@ -1,8 +1,8 @@
 use crate::polls::{InvalidPollReason, PollConfig, PollVotes};
 use crate::{
-    Achievement, CanisterId, CompletedCryptoTransaction, CryptoTransaction, CryptoTransferDetails, EncryptionKey, MessageIndex,
-    MessagePermission, Milliseconds, P2PSwapStatus, PendingCryptoTransaction, ProposalContent, TimestampMillis, TokenInfo,
-    TotalVotes, User, UserId, VideoCallType,
+    Achievement, CanisterId, CompletedCryptoTransaction, CrowdfundStatus, CryptoTransaction, CryptoTransferDetails,
+    EncryptionKey, MessageIndex, MessagePermission, Milliseconds, P2PSwapStatus, PendingCryptoTransaction, ProposalContent,
+    TimestampMillis, TokenInfo, TotalVotes, User, UserId, VideoCallType,
 };
 use candid::CandidType;
 use oc_error_codes::{OCError, OCErrorCode};
@@ -31,6 +31,7 @@ pub enum MessageContentInitial {
     MessageReminderCreated(MessageReminderCreatedContent),
     MessageReminder(MessageReminderContent),
     P2PSwap(P2PSwapContentInitial),
+    Crowdfund(CrowdfundContentInitial),
     Encrypted(EncryptedContent),
     Custom(CustomContent),
 }
@@ -55,6 +56,7 @@ pub enum MessageContent {
     ReportedMessage(ReportedMessage),
     P2PSwap(P2PSwapContent),
     VideoCall(VideoCallContent),
+    Crowdfund(CrowdfundContent),
     Encrypted(EncryptedContent),
     Custom(CustomContent),
 }
@@ -79,6 +81,7 @@ pub enum MessageContentType {
     ReportedMessage,
     P2PSwap,
     VideoCall,
+    Crowdfund,
     Custom(String),
 }
 
@@ -120,6 +123,8 @@ pub enum ContentValidationError {
     TransferMustBePending,
     InvalidTypeForForwarding,
     PrizeEndDateInThePast,
+    CrowdfundDeadlineInThePast,
+    InvalidCrowdfundTarget,
     Unauthorized,
 }
 
@@ -170,6 +175,7 @@ impl MessageContent {
             | MessageContent::ReportedMessage(_)
             | MessageContent::P2PSwap(_)
             | MessageContent::VideoCall(_)
+            | MessageContent::Crowdfund(_)
             | MessageContent::Encrypted(_)
             | MessageContent::Custom(_) => {}
         }
@@ -190,6 +196,7 @@ impl MessageContent {
             MessageContent::GovernanceProposal(gp) => Some(gp.proposal.title()),
             MessageContent::Prize(p) => p.caption.as_deref(),
             MessageContent::P2PSwap(p) => p.caption.as_deref(),
+            MessageContent::Crowdfund(c) => c.caption.as_deref(),
             MessageContent::Deleted(_)
             | MessageContent::PrizeWinner(_)
             | MessageContent::MessageReminderCreated(_)
@@ -237,6 +244,7 @@ impl MessageContent {
             | MessageContent::ReportedMessage(_)
             | MessageContent::P2PSwap(_)
             | MessageContent::VideoCall(_)
+            | MessageContent::Crowdfund(_)
             | MessageContent::Encrypted(_)
             | MessageContent::Custom(_) => None,
         }
@@ -284,6 +292,7 @@ impl MessageContentInitial {
             MessageContentInitial::MessageReminderCreated(r) => r.notes.as_deref(),
             MessageContentInitial::MessageReminder(r) => r.notes.as_deref(),
             MessageContentInitial::P2PSwap(p) => p.caption.as_deref(),
+            MessageContentInitial::Crowdfund(c) => c.caption.as_deref(),
             MessageContentInitial::Encrypted(_) | MessageContentInitial::Deleted(_) | MessageContentInitial::Custom(_) => None,
         }
     }
@@ -291,7 +300,10 @@ impl MessageContentInitial {
     pub fn contains_crypto_transfer(&self) -> bool {
         matches!(
             self,
-            MessageContentInitial::Crypto(_) | MessageContentInitial::Prize(_) | MessageContentInitial::P2PSwap(_)
+            MessageContentInitial::Crypto(_)
+                | MessageContentInitial::Prize(_)
+                | MessageContentInitial::P2PSwap(_)
+                | MessageContentInitial::Crowdfund(_)
         )
     }
 
@@ -319,6 +331,8 @@ impl From<&MessageContentInitial> for MessagePermission {
             MessageContentInitial::Giphy(_) => MessagePermission::Giphy,
             MessageContentInitial::Prize(_) => MessagePermission::Prize,
             MessageContentInitial::P2PSwap(_) => MessagePermission::P2pSwap,
+            // Crowdfunds move members' funds so they fall under the crypto permission
+            MessageContentInitial::Crowdfund(_) => MessagePermission::Crypto,
             _ => unreachable!(),
         }
     }
@@ -345,7 +359,7 @@ impl From<MessageContent> for MessageContentInitial {
             MessageContent::ReportedMessage(_) => panic!("Cannot send a 'reported message' message"),
             MessageContent::Encrypted(e) => MessageContentInitial::Encrypted(e),
             MessageContent::Custom(c) => MessageContentInitial::Custom(c),
-            MessageContent::P2PSwap(_) | MessageContent::VideoCall(_) => unimplemented!(),
+            MessageContent::P2PSwap(_) | MessageContent::VideoCall(_) | MessageContent::Crowdfund(_) => unimplemented!(),
         }
     }
 }
@@ -385,7 +399,7 @@ impl From<MessageContentInitial> for MessageContent {
             MessageContentInitial::MessageReminder(r) => MessageContent::MessageReminder(r),
             MessageContentInitial::Encrypted(e) => MessageContent::Encrypted(e),
             MessageContentInitial::Custom(c) => MessageContent::Custom(c),
-            MessageContentInitial::P2PSwap(_) => unimplemented!(),
+            MessageContentInitial::P2PSwap(_) | MessageContentInitial::Crowdfund(_) => unimplemented!(),
         }
     }
 }
@@ -410,6 +424,7 @@ impl MessageContentType {
             MessageContentType::ReportedMessage => None,
             MessageContentType::P2PSwap => Some(Achievement::SentP2PSwapOffer),
             MessageContentType::VideoCall => Some(Achievement::StartedCall),
+            MessageContentType::Crowdfund => None,
             MessageContentType::Custom(c) => {
                 if c == "meme_fighter" {
                     Some(Achievement::SentMeme)
@@ -441,6 +456,7 @@ impl Display for MessageContentType {
             MessageContentType::ReportedMessage => "ReportedMessage",
             MessageContentType::P2PSwap => "P2PSwap",
             MessageContentType::VideoCall => "VideoCall",
+            MessageContentType::Crowdfund => "Crowdfund",
             MessageContentType::Custom(c) => c,
         };
 
@@ -468,6 +484,7 @@ impl From<&MessageContent> for MessageContentType {
             MessageContent::ReportedMessage(_) => MessageContentType::ReportedMessage,
             MessageContent::P2PSwap(_) => MessageContentType::P2PSwap,
             MessageContent::VideoCall(_) => MessageContentType::VideoCall,
+            MessageContent::Crowdfund(_) => MessageContentType::Crowdfund,
             MessageContent::Encrypted(e) => e.content_type.clone().into(),
             MessageContent::Custom(c) => MessageContentType::Custom(c.kind.clone()),
         }
@@ -567,6 +584,7 @@ impl PollContent {
         self.votes = PollVotes {
             total: total_votes,
             user: Vec::new(),
+            weighted: None,
         }
     }
 }
@@ -577,6 +595,7 @@ pub enum RegisterVoteResult {
     PollEnded,
     UserCannotChangeVote,
     OptionIndexOutOfRange,
+    DuplicateCommitment,
 }
 
 #[ts_export]
@@ -687,6 +706,33 @@ pub struct P2PSwapContent {
     pub status: P2PSwapStatus,
 }
 
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CrowdfundContentInitial {
+    pub token: TokenInfo,
+    pub target: u128,
+    pub beneficiary: UserId,
+    pub deadline: TimestampMillis,
+    // The creator's own contribution, transferred into escrow when the crowdfund is created
+    pub initial_contribution: u128,
+    pub caption: Option<String>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct CrowdfundContent {
+    pub payout_id: u32,
+    pub token: TokenInfo,
+    pub target: u128,
+    pub beneficiary: UserId,
+    pub deadline: TimestampMillis,
+    pub caption: Option<String>,
+    pub raised: u128,
+    pub contributors: u32,
+    pub user_contribution: u128,
+    pub status: CrowdfundStatus,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct VideoCallContentInitial {
@@ -0,0 +1,40 @@
+use crate::{MessageId, MessageIndex, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+pub const MAX_MODERATION_REASON_LENGTH: u32 = 500;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationAction {
+    MemberRemoved,
+    UserBlocked,
+    UserUnblocked,
+    MessagesDeleted,
+    MemberWarned,
+    MemberMuted,
+    MemberUnmuted,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ModerationLogEntry {
+    pub index: u32,
+    pub timestamp: TimestampMillis,
+    pub actor: UserId,
+    pub target: UserId,
+    pub action: ModerationAction,
+    pub reason: Option<String>,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_ids: Vec<MessageId>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ModerationLogPage {
+    pub entries: Vec<ModerationLogEntry>,
+    pub total: u32,
+    // The index to pass in as `start_index` to fetch the next page, or None if this is the final page
+    pub next_index: Option<u32>,
+}
@@ -1,6 +1,6 @@
 use crate::{
-    BotDataEncoding, BotInstallationLocation, BotPermissions, CanisterId, ChannelId, Chat, ChatEvent, ChatId, CommunityEvent,
-    CommunityId, EventIndex, FcmData, MessageIndex, Reaction, TimestampMillis, UserId,
+    BotCommandArg, BotDataEncoding, BotInstallationLocation, BotPermissions, CanisterId, ChannelId, Chat, ChatEvent, ChatId,
+    CommunityEvent, CommunityId, EventIndex, FcmData, MessageIndex, Reaction, TimestampMillis, UserId,
 };
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
@@ -51,6 +51,8 @@ pub enum BotEvent {
     Community(BotCommunityEvent),
     #[serde(rename = "l")]
     Lifecycle(BotLifecycleEvent),
+    #[serde(rename = "w")]
+    Webhook(BotWebhookEvent),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -89,6 +91,17 @@ pub struct BotCommunityEvent {
     pub latest_event_index: EventIndex,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct BotWebhookEvent {
+    #[serde(rename = "w")]
+    pub webhook_id: UserId,
+    #[serde(rename = "c")]
+    pub chat: Chat,
+    // The raw JSON payload received by the webhook
+    #[serde(rename = "p")]
+    pub payload: String,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum BotLifecycleEvent {
     #[serde(rename = "r")]
@@ -97,6 +110,8 @@ pub enum BotLifecycleEvent {
     Installed(BotInstalledEvent),
     #[serde(rename = "u")]
     Uninstalled(BotUninstalledEvent),
+    #[serde(rename = "d")]
+    PermissionsDenied(BotPermissionsDeniedEvent),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -111,6 +126,8 @@ pub struct BotInstalledEvent {
     pub granted_command_permissions: BotPermissions,
     #[serde(rename = "a")]
     pub granted_autonomous_permissions: BotPermissions,
+    #[serde(rename = "c", default, skip_serializing_if = "Vec::is_empty")]
+    pub config: Vec<BotCommandArg>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -121,6 +138,18 @@ pub struct BotUninstalledEvent {
     pub location: BotInstallationLocation,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct BotPermissionsDeniedEvent {
+    #[serde(rename = "u")]
+    pub denied_by: UserId,
+    #[serde(rename = "l")]
+    pub location: BotInstallationLocation,
+    #[serde(rename = "p")]
+    pub requested_command_permissions: BotPermissions,
+    #[serde(rename = "a")]
+    pub requested_autonomous_permissions: BotPermissions,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct BotRegisteredEvent {
     #[serde(rename = "i")]
@@ -97,5 +97,7 @@ option_update!(OptionUpdateGroupPermissionRole, crate::GroupPermissionRole);
 option_update!(OptionUpdateGroupSubtype, crate::GroupSubtype);
 option_update!(OptionUpdateOptionalMessagePermissions, crate::OptionalMessagePermissions);
 option_update!(OptionUpdatePinNumberSettings, crate::PinNumberSettings);
+option_update!(OptionUpdateSlowMode, crate::SlowMode);
 option_update!(OptionUpdateStreakInsurance, crate::StreakInsurance);
+option_update!(OptionUpdateUserId, crate::UserId);
 option_update!(OptionUpdateVideoCall, crate::VideoCall);
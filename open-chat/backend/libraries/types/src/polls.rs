@@ -1,6 +1,7 @@
-use crate::{TimestampMillis, UserId};
+use crate::{CanisterId, Hash, TimestampMillis, UserId};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
 use ts_export::ts_export;
 
@@ -14,13 +15,81 @@ pub struct PollConfig {
     pub show_votes_before_end_date: bool,
     pub allow_multiple_votes_per_user: bool,
     pub allow_user_to_change_vote: bool,
+    #[serde(default)]
+    pub voting_mode: Option<PollVotingMode>,
+    // Voters submit a commitment to their ballot which they reveal once the poll has ended
+    #[serde(default)]
+    pub secret_ballot: Option<bool>,
+    // The members' weights are looked up in batches once the poll is created, so each weight reflects the member's
+    // stake or balance when it was looked up rather than at a single point in time. Until the lookups complete, and for
+    // members who join after the poll is created, votes count with the default weight. Failed lookups are retried, and
+    // if some still fail the weighted results are never marked as complete.
+    #[serde(default)]
+    pub weighting: Option<PollWeighting>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum PollWeighting {
+    // Votes are weighted by the voter's total neuron stake in the SNS
+    SnsNeuronStake(CanisterId),
+    // Votes are weighted by the voter's balance of the token
+    TokenBalance(CanisterId),
+    Role(PollRoleWeights),
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PollRoleWeights {
+    pub owner: u32,
+    pub admin: u32,
+    pub moderator: u32,
+    pub member: u32,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PollVotingMode {
+    #[default]
+    Plurality,
+    // Users rank the options in order of preference and the winner is decided by instant-runoff
+    RankedChoice,
+    // Users select every option they approve of
+    Approval,
+    // Users give each option a score between 0 and the specified maximum
+    Score(u32),
+}
+
+// Ballots are used for every voting mode other than `Plurality`, which registers votes one option at a time
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum PollBallot {
+    // Option indexes in order of preference, most preferred first. Not every option needs to be ranked.
+    Ranked(Vec<u32>),
+    // The indexes of the options being approved
+    Approval(Vec<u32>),
+    // A score for each option, indexed by option
+    Score(Vec<u32>),
+    // For secret ballot polls, see `secret_ballot_commitment`
+    Commitment(Hash),
 }
 
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct PollVotes {
     pub total: TotalVotes,
+    // For ranked choice polls these are in order of preference, for score polls they are the scores given to each option
     pub user: Vec<u32>,
+    #[serde(default)]
+    pub weighted: Option<WeightedVotes>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WeightedVotes {
+    pub totals: HashMap<u32, u128>,
+    // False while the voters' weights are still being looked up, or if some of them could not be looked up
+    pub snapshot_complete: bool,
 }
 
 impl PollConfig {
@@ -38,23 +107,184 @@ impl PollConfig {
             Err(InvalidPollReason::DuplicateOptions)
         } else if self.end_date.unwrap_or(u64::MAX) < now {
             Err(InvalidPollReason::EndDateInThePast)
+        } else if matches!(self.voting_mode, Some(PollVotingMode::Score(max)) if max == 0 || max > MAX_POLL_SCORE) {
+            Err(InvalidPollReason::InvalidMaxScore(MAX_POLL_SCORE))
+        } else if self.is_secret_ballot() && self.end_date.is_none() {
+            Err(InvalidPollReason::SecretBallotRequiresEndDate)
+        } else if self.is_secret_ballot() && !matches!(self.voting_mode(), PollVotingMode::Plurality | PollVotingMode::Approval)
+        {
+            Err(InvalidPollReason::SecretBallotNotSupportedForVotingMode)
+        } else if self.weighting.is_some()
+            && (self.is_secret_ballot() || !matches!(self.voting_mode(), PollVotingMode::Plurality | PollVotingMode::Approval))
+        {
+            Err(InvalidPollReason::WeightingNotSupportedForVotingMode)
         } else {
             Ok(())
         }
     }
 
+    pub fn voting_mode(&self) -> PollVotingMode {
+        self.voting_mode.unwrap_or_default()
+    }
+
+    pub fn is_secret_ballot(&self) -> bool {
+        self.secret_ballot.unwrap_or_default()
+    }
+
     fn contains_duplicate_options(&self) -> bool {
         let mut set = HashSet::new();
         self.options.iter().any(|o| !set.insert(o))
     }
 }
 
+impl PollBallot {
+    pub fn validate(&self, voting_mode: PollVotingMode, option_count: usize) -> Result<(), InvalidBallotReason> {
+        let option_count = option_count as u32;
+
+        match (self, voting_mode) {
+            (PollBallot::Ranked(options), PollVotingMode::RankedChoice)
+            | (PollBallot::Approval(options), PollVotingMode::Approval) => {
+                if options.is_empty() {
+                    Err(InvalidBallotReason::Empty)
+                } else if options.iter().any(|o| *o >= option_count) {
+                    Err(InvalidBallotReason::OptionIndexOutOfRange)
+                } else if options.iter().collect::<HashSet<_>>().len() != options.len() {
+                    Err(InvalidBallotReason::DuplicateOptions)
+                } else {
+                    Ok(())
+                }
+            }
+            (PollBallot::Score(scores), PollVotingMode::Score(max_score)) => {
+                if scores.len() as u32 != option_count {
+                    Err(InvalidBallotReason::WrongNumberOfScores)
+                } else if scores.iter().any(|s| *s > max_score) {
+                    Err(InvalidBallotReason::ScoreTooHigh(max_score))
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Err(InvalidBallotReason::WrongVotingMode),
+        }
+    }
+
+    pub fn into_inner(self) -> Vec<u32> {
+        match self {
+            PollBallot::Ranked(v) | PollBallot::Approval(v) | PollBallot::Score(v) => v,
+            PollBallot::Commitment(_) => Vec::new(),
+        }
+    }
+}
+
+// The commitment is the SHA256 hash of the number of options followed by each option index, all as little-endian
+// u32s, followed by the salt. The count prefix stops the bytes of the salt being reinterpreted as extra options.
+pub fn secret_ballot_commitment(options: &[u32], salt: &[u8]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update((options.len() as u32).to_le_bytes());
+    for option in options {
+        hasher.update(option.to_le_bytes());
+    }
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+// Leaves are the commitments sorted in ascending order. Each parent is the SHA256 hash of its two children
+// concatenated, and if a level has an odd number of nodes the last node is carried up to the next level unchanged.
+pub fn secret_ballot_merkle_root(commitments: &[Hash]) -> Option<Hash> {
+    let mut level: Vec<Hash> = commitments.to_vec();
+    level.sort_unstable();
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => {
+                    let mut hasher = Sha256::new();
+                    hasher.update(left);
+                    hasher.update(right);
+                    hasher.finalize().into()
+                }
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level.pop()
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum InvalidBallotReason {
+    Empty,
+    OptionIndexOutOfRange,
+    DuplicateOptions,
+    WrongNumberOfScores,
+    ScoreTooHigh(u32),
+    WrongVotingMode,
+}
+
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum TotalVotes {
     Visible(HashMap<u32, Vec<UserId>>),
     Anonymous(HashMap<u32, u32>),
     Hidden(u32),
+    RankedChoice(RankedChoiceResults),
+    Score(ScoreResults),
+    SecretBallot(SecretBallotResults),
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RankedChoiceResults {
+    pub total_ballots: u32,
+    pub rounds: Vec<RankedChoiceRound>,
+    pub winner: Option<u32>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RankedChoiceRound {
+    // The number of ballots counting towards each option still in the running
+    pub tallies: HashMap<u32, u32>,
+    // Ballots which have no remaining preferences
+    pub exhausted: u32,
+    // The options eliminated at the end of this round
+    pub eliminated: Vec<u32>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ScoreResults {
+    pub total_ballots: u32,
+    pub totals: HashMap<u32, u64>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SecretBallotResults {
+    pub commitments: u32,
+    pub revealed: u32,
+    // Only populated once the poll has ended
+    pub tallies: HashMap<u32, u32>,
+    // The root of the Merkle tree of all commitments, set when the poll ends
+    pub merkle_root: Option<Hash>,
+    pub reveal_deadline: Option<TimestampMillis>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct SecretBallotAudit {
+    pub commitments: Vec<Hash>,
+    pub revealed: Vec<RevealedBallot>,
+    pub merkle_root: Option<Hash>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct RevealedBallot {
+    pub options: Vec<u32>,
+    #[serde(with = "serde_bytes")]
+    pub salt: Vec<u8>,
 }
 
 #[ts_export]
@@ -67,6 +297,7 @@ pub enum VoteOperation {
 const MIN_POLL_OPTIONS: usize = 2;
 const MAX_POLL_OPTIONS: usize = 10;
 const MAX_POLL_OPTION_LENGTH: usize = 100;
+const MAX_POLL_SCORE: u32 = 10;
 
 #[ts_export]
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -77,4 +308,30 @@ pub enum InvalidPollReason {
     DuplicateOptions,
     EndDateInThePast,
     PollsNotValidForDirectChats,
+    InvalidMaxScore(u32),
+    SecretBallotRequiresEndDate,
+    SecretBallotNotSupportedForVotingMode,
+    WeightingNotSupportedForVotingMode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_ballot_commitment_binds_the_options() {
+        // Without the option count prefix these two ballots would hash to the same bytes
+        let salt = b"salt".to_vec();
+        let mut shifted_salt = 2u32.to_le_bytes().to_vec();
+        shifted_salt.extend_from_slice(&salt);
+
+        assert_ne!(
+            secret_ballot_commitment(&[1, 2], &salt),
+            secret_ballot_commitment(&[1], &shifted_salt)
+        );
+        assert_ne!(
+            secret_ballot_commitment(&[], &[0, 0, 0, 0]),
+            secret_ballot_commitment(&[0], &[])
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{CanisterId, EventIndex, MessageId, NnsNeuronId, ProposalId, SnsNeuronId, TimestampMillis};
+use crate::{CanisterId, EventIndex, MessageId, NnsNeuronId, ProposalId, SnsNeuronId, TimestampMillis, UserId};
 use candid::CandidType;
 use oc_error_codes::OCError;
 use serde::{Deserialize, Serialize};
@@ -164,6 +164,17 @@ pub struct ProposalContent {
     pub governance_canister_id: CanisterId,
     pub proposal: Proposal,
     pub my_vote: Option<bool>,
+    #[serde(default)]
+    pub delegate_vote: Option<ProposalDelegateVote>,
+}
+
+// An advisory vote registered on behalf of the whole chat by its elected delegate
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProposalDelegateVote {
+    pub delegate: UserId,
+    pub adopt: bool,
+    pub timestamp: TimestampMillis,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug, Clone)]
@@ -215,6 +226,12 @@ pub enum ProposalDecisionStatus {
     Failed = 5,
 }
 
+impl ProposalDecisionStatus {
+    pub fn is_decided(&self) -> bool {
+        !matches!(self, ProposalDecisionStatus::Unspecified | ProposalDecisionStatus::Open)
+    }
+}
+
 impl TryFrom<i32> for ProposalDecisionStatus {
     type Error = i32;
 
@@ -256,14 +273,14 @@ impl TryFrom<i32> for ProposalRewardStatus {
 }
 
 #[ts_export]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
 pub enum ActiveProposalTalliesResponse {
     Success(ActiveProposalTalliesSuccessResult),
     Error(OCError),
 }
 
 #[ts_export]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct ActiveProposalTalliesSuccessResult {
     pub tallies: Vec<(EventIndex, Tally)>,
 }
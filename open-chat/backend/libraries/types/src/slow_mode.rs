@@ -0,0 +1,13 @@
+use crate::{GroupPermissionRole, Milliseconds};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SlowMode {
+    // The minimum time each member must wait between sending messages
+    pub interval: Milliseconds,
+    // Members whose role satisfies this are not subject to slow mode
+    pub exempt: GroupPermissionRole,
+}
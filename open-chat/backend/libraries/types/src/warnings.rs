@@ -0,0 +1,84 @@
+use crate::{DAY_IN_MS, Milliseconds, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use ts_export::ts_export;
+
+pub const DEFAULT_WARNING_EXPIRY: Milliseconds = 30 * DAY_IN_MS;
+pub const MAX_ESCALATION_STEPS: usize = 10;
+pub const MAX_MUTE_DURATION: Milliseconds = 28 * DAY_IN_MS;
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WarningPolicy {
+    // How long each warning counts towards escalation, defaults to `DEFAULT_WARNING_EXPIRY`
+    pub warning_expiry: Option<Milliseconds>,
+    // When a member is warned, the step with the highest threshold they have reached is applied
+    pub escalation: Vec<EscalationStep>,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EscalationStep {
+    pub warnings: u32,
+    pub action: EscalationAction,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscalationAction {
+    Mute(Milliseconds),
+    Remove,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberWarning {
+    pub warned_by: UserId,
+    pub reason: String,
+    pub timestamp: TimestampMillis,
+    pub expires_at: TimestampMillis,
+}
+
+#[ts_export]
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberWarnings {
+    pub warnings: Vec<MemberWarning>,
+    pub muted_until: Option<TimestampMillis>,
+    pub policy: Option<WarningPolicy>,
+}
+
+impl WarningPolicy {
+    pub fn warning_expiry(&self) -> Milliseconds {
+        self.warning_expiry.unwrap_or(DEFAULT_WARNING_EXPIRY)
+    }
+
+    pub fn escalation_for(&self, active_warnings: u32) -> Option<EscalationAction> {
+        self.escalation
+            .iter()
+            .rev()
+            .find(|s| s.warnings <= active_warnings)
+            .map(|s| s.action)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.warning_expiry == Some(0) {
+            return Err("Warning expiry must be greater than 0".to_string());
+        }
+        if self.escalation.len() > MAX_ESCALATION_STEPS {
+            return Err(format!("Too many escalation steps (max {MAX_ESCALATION_STEPS})"));
+        }
+        let mut previous = 0;
+        for step in self.escalation.iter() {
+            if step.warnings <= previous {
+                return Err("Escalation thresholds must be greater than 0 and strictly increasing".to_string());
+            }
+            if let EscalationAction::Mute(duration) = step.action {
+                if duration == 0 || duration > MAX_MUTE_DURATION {
+                    return Err(format!("Mute duration must be between 1ms and {MAX_MUTE_DURATION}ms"));
+                }
+            }
+            previous = step.warnings;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use constants::MINUTE_IN_MS;
+use std::str::FromStr;
+use time::{Date, OffsetDateTime};
+use types::TimestampMillis;
+
+// The number of days searched for the next occurrence, long enough to cover schedules which only match on 29 February
+const MAX_DAYS_TO_SEARCH: u32 = 366 * 9;
+
+// A standard 5 field cron schedule ("minute hour day-of-month month day-of-week") evaluated in UTC. Each field can be
+// `*`, a number, a range (`1-5`), a list (`1,15`) or have a step (`*/15`, `0-30/10`). Days of the week run from 0
+// (Sunday) to 6, with 7 also accepted for Sunday.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // As with cron, if both day fields are restricted then a day matches if either of them does
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+}
+
+impl FromStr for CronSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields.as_slice() else {
+            return Err(format!("Expected 5 fields but found {}", fields.len()));
+        };
+
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7, "day of week")?;
+        if days_of_week_mask & (1 << 7) != 0 {
+            days_of_week_mask = (days_of_week_mask | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(minutes, 0, 59, "minute")?,
+            hours: parse_field(hours, 0, 23, "hour")?,
+            days_of_month: parse_field(days_of_month, 1, 31, "day of month")?,
+            months: parse_field(months, 1, 12, "month")?,
+            days_of_week: days_of_week_mask,
+            days_of_month_restricted: !days_of_month.starts_with('*'),
+            days_of_week_restricted: !days_of_week.starts_with('*'),
+        })
+    }
+}
+
+impl CronSchedule {
+    // Returns the start of the first matching minute which is after `ts`
+    pub fn next_after(&self, ts: TimestampMillis) -> Option<TimestampMillis> {
+        let start = OffsetDateTime::from_unix_timestamp(((ts / MINUTE_IN_MS + 1) * 60) as i64).ok()?;
+        let mut date = start.date();
+        let mut from = (start.hour(), start.minute());
+
+        for _ in 0..MAX_DAYS_TO_SEARCH {
+            if self.matches_date(date) {
+                if let Some((hour, minute)) = self.first_time_from(from) {
+                    let timestamp = date.with_hms(hour, minute, 0).ok()?.assume_utc().unix_timestamp();
+                    return Some(timestamp as u64 * 1000);
+                }
+            }
+            date = date.next_day()?;
+            from = (0, 0);
+        }
+        None
+    }
+
+    // True if the schedule matches no more than one minute in each hour
+    pub fn is_at_most_hourly(&self) -> bool {
+        self.minutes.count_ones() == 1
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        if !is_set(self.months, u8::from(date.month())) {
+            return false;
+        }
+
+        let day_of_month = is_set(self.days_of_month, date.day());
+        let day_of_week = is_set(self.days_of_week, date.weekday().number_days_from_sunday());
+
+        match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        }
+    }
+
+    fn first_time_from(&self, (from_hour, from_minute): (u8, u8)) -> Option<(u8, u8)> {
+        (from_hour..24).filter(|h| is_set(self.hours, *h)).find_map(|hour| {
+            let first_minute = if hour == from_hour { from_minute } else { 0 };
+            (first_minute..60)
+                .find(|m| is_set(self.minutes, *m))
+                .map(|minute| (hour, minute))
+        })
+    }
+}
+
+fn is_set(mask: u64, value: u8) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn parse_field(field: &str, min: u8, max: u8, name: &str) -> Result<u64, String> {
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, parse_value(step, 1, max, name)?),
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, min, max, name)?, parse_value(end, min, max, name)?)
+        } else {
+            let value = parse_value(range, min, max, name)?;
+            // As with cron, a single value with a step runs from that value to the end of the range
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start > end {
+            return Err(format!("Invalid {name} range: {range}"));
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u8, max: u8, name: &str) -> Result<u8, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|v| (min..=max).contains(v))
+        .ok_or_else(|| format!("Invalid {name}: {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use constants::{DAY_IN_MS as DAY, HOUR_IN_MS as HOUR};
+    use test_case::test_case;
+
+    // 2025-01-01T00:00:00Z, a Wednesday
+    const JAN_1_2025: TimestampMillis = 1735689600000;
+
+    #[test_case("30 9 * * *", JAN_1_2025, JAN_1_2025 + 9 * HOUR + 30 * MINUTE_IN_MS; "daily")]
+    #[test_case("0 * * * *", JAN_1_2025, JAN_1_2025 + HOUR; "strictly after")]
+    #[test_case("0 12 * * 1", JAN_1_2025, JAN_1_2025 + 5 * DAY + 12 * HOUR; "weekly on monday")]
+    #[test_case("0 0 1 * *", JAN_1_2025 + 1, JAN_1_2025 + 31 * DAY; "monthly")]
+    #[test_case("0 0 29 2 *", JAN_1_2025, 1835395200000; "leap day")]
+    #[test_case("0 0 15 * 0", JAN_1_2025, JAN_1_2025 + 4 * DAY; "either day field matches")]
+    #[test_case("*/20 8-9 * * 1-5", JAN_1_2025 + 9 * HOUR + 45 * MINUTE_IN_MS, JAN_1_2025 + DAY + 8 * HOUR; "steps and ranges")]
+    fn next_after(expression: &str, ts: TimestampMillis, expected: TimestampMillis) {
+        let schedule = CronSchedule::from_str(expression).unwrap();
+
+        assert_eq!(schedule.next_after(ts), Some(expected));
+    }
+
+    #[test]
+    fn impossible_date_has_no_next() {
+        let schedule = CronSchedule::from_str("0 0 31 2 *").unwrap();
+
+        assert_eq!(schedule.next_after(JAN_1_2025), None);
+    }
+
+    #[test_case("* * * *"; "too few fields")]
+    #[test_case("60 * * * *"; "minute out of range")]
+    #[test_case("0 0 0 * *"; "day of month out of range")]
+    #[test_case("0 5-1 * * *"; "reversed range")]
+    #[test_case("*/0 * * * *"; "zero step")]
+    #[test_case("0 0 * JAN *"; "names not supported")]
+    fn invalid(expression: &str) {
+        assert!(CronSchedule::from_str(expression).is_err());
+    }
+}
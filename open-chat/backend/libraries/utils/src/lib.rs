@@ -3,6 +3,7 @@ pub mod canister;
 pub mod canister_event_sync_queue;
 pub mod canister_timers;
 pub mod case_insensitive_hash_map;
+pub mod cron;
 pub mod cycles;
 pub mod document;
 pub mod env;
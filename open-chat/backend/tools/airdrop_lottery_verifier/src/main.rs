@@ -0,0 +1,79 @@
+use airdrop_bot_canister::{LOTTERY_ALGORITHM_VERSION, LotteryDraw, draw_lottery_winners, tickets_hash};
+use clap::Parser;
+use std::error::Error;
+use std::{fs, process};
+
+#[derive(Parser, Debug)]
+struct Config {
+    /// The path of a JSON file containing the lottery draw, as served by the AirdropBot's `/lottery` http route
+    /// (`/lottery?airdrop={index}` or `/lottery?community_airdrop={id}` for an earlier draw)
+    #[arg(long)]
+    draw: String,
+
+    /// The hex encoded random seed published in the lottery results message
+    #[arg(long)]
+    seed: Option<String>,
+
+    /// The hex encoded tickets hash published in the lottery results message
+    #[arg(long)]
+    tickets_hash: Option<String>,
+}
+
+fn main() {
+    let config = Config::parse();
+
+    match run(config) {
+        Ok(true) => println!("The lottery draw is valid"),
+        Ok(false) => {
+            println!("The lottery draw is NOT valid");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Application error: {e}");
+            process::exit(2);
+        }
+    }
+}
+
+fn run(config: Config) -> Result<bool, Box<dyn Error>> {
+    let draw: LotteryDraw = serde_json::from_str(&fs::read_to_string(&config.draw)?)?;
+
+    if draw.algorithm_version != LOTTERY_ALGORITHM_VERSION {
+        Err(format!(
+            "Unsupported algorithm version {}, expected {LOTTERY_ALGORITHM_VERSION}",
+            draw.algorithm_version
+        ))?;
+    }
+
+    let mut valid = true;
+
+    if let Some(seed) = config.seed {
+        valid &= check("Seed matches results message", hex::decode(seed)? == draw.seed);
+    }
+    if let Some(hash) = config.tickets_hash {
+        valid &= check(
+            "Tickets hash matches results message",
+            hex::decode(hash)? == draw.tickets_hash,
+        );
+    }
+
+    valid &= check(
+        "Tickets hash matches tickets",
+        tickets_hash(&draw.tickets) == draw.tickets_hash,
+    );
+
+    let winners = draw_lottery_winners(draw.seed, &draw.tickets, draw.prize_count);
+    valid &= check("Winners match draw", winners == draw.winners);
+
+    println!("Tickets: {} held by {} users", draw.ticket_count(), draw.tickets.len());
+    for (index, winner) in winners.iter().enumerate() {
+        println!("Prize {}: {winner}", index + 1);
+    }
+
+    Ok(valid)
+}
+
+fn check(name: &str, passed: bool) -> bool {
+    println!("{name}: {}", if passed { "OK" } else { "FAILED" });
+    passed
+}
@@ -0,0 +1,105 @@
+use crate::client::ExportedChat;
+use ic_agent::Agent;
+use serde::Serialize;
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+use types::{CanisterId, ChannelId, ChatEvent, EventIndex, EventWrapper, MessageIndex, ModerationLogEntry, TimestampMillis};
+
+const PAGE_SIZE: u32 = 500;
+
+#[derive(Serialize)]
+pub struct ChatArchive {
+    pub canister_id: CanisterId,
+    pub channel_id: Option<ChannelId>,
+    pub exported_at: TimestampMillis,
+    pub events: Vec<EventWrapper<ChatEvent>>,
+    pub expired_event_ranges: Vec<(EventIndex, EventIndex)>,
+    pub threads: Vec<ThreadArchive>,
+    pub moderation_log: Vec<ModerationLogEntry>,
+}
+
+#[derive(Serialize)]
+pub struct ThreadArchive {
+    pub root_message_index: MessageIndex,
+    pub events: Vec<EventWrapper<ChatEvent>>,
+}
+
+impl ChatArchive {
+    pub async fn download(agent: &Agent, chat: ExportedChat) -> Result<ChatArchive, Box<dyn Error + Send + Sync>> {
+        let (events, expired_event_ranges) = download_events(agent, chat, None).await?;
+
+        let mut threads = Vec::new();
+        for message in events.iter().filter_map(|e| match &e.event {
+            ChatEvent::Message(m) if m.thread_summary.is_some() => Some(m),
+            _ => None,
+        }) {
+            let (thread_events, _) = download_events(agent, chat, Some(message.message_index)).await?;
+            threads.push(ThreadArchive {
+                root_message_index: message.message_index,
+                events: thread_events,
+            });
+        }
+
+        let moderation_log = download_moderation_log(agent, chat).await?;
+
+        let (canister_id, channel_id) = match chat {
+            ExportedChat::Group(canister_id) => (canister_id, None),
+            ExportedChat::Channel(canister_id, channel_id) => (canister_id, Some(channel_id)),
+        };
+
+        Ok(ChatArchive {
+            canister_id,
+            channel_id,
+            exported_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as TimestampMillis,
+            events,
+            expired_event_ranges,
+            threads,
+            moderation_log,
+        })
+    }
+
+    pub fn thread(&self, root_message_index: MessageIndex) -> Option<&ThreadArchive> {
+        self.threads.iter().find(|t| t.root_message_index == root_message_index)
+    }
+}
+
+type EventsAndExpiredRanges = (Vec<EventWrapper<ChatEvent>>, Vec<(EventIndex, EventIndex)>);
+
+async fn download_events(
+    agent: &Agent,
+    chat: ExportedChat,
+    thread_root_message_index: Option<MessageIndex>,
+) -> Result<EventsAndExpiredRanges, Box<dyn Error + Send + Sync>> {
+    let mut events = Vec::new();
+    let mut expired_event_ranges = Vec::new();
+    let mut next_index = Some(EventIndex::default());
+
+    while let Some(start_index) = next_index {
+        let page = chat
+            .export_events(agent, thread_root_message_index, start_index, PAGE_SIZE)
+            .await?;
+
+        events.extend(page.events);
+        expired_event_ranges.extend(page.expired_event_ranges);
+        next_index = page.next_index;
+    }
+
+    Ok((events, expired_event_ranges))
+}
+
+async fn download_moderation_log(
+    agent: &Agent,
+    chat: ExportedChat,
+) -> Result<Vec<ModerationLogEntry>, Box<dyn Error + Send + Sync>> {
+    let mut entries = Vec::new();
+    let mut next_index = Some(0);
+
+    while let Some(start_index) = next_index {
+        let page = chat.moderation_log(agent, start_index, PAGE_SIZE).await?;
+
+        entries.extend(page.entries);
+        next_index = page.next_index;
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,99 @@
+use ic_agent::Agent;
+use std::error::Error;
+use types::{CanisterId, ChannelId, ChatExportPage, EventIndex, MessageIndex, ModerationLogPage};
+
+mod group {
+    use canister_client::generate_query_call;
+    use group_canister::*;
+
+    generate_query_call!(export_events);
+    generate_query_call!(moderation_log);
+}
+
+mod community {
+    use canister_client::generate_query_call;
+    use community_canister::*;
+
+    generate_query_call!(export_events);
+    generate_query_call!(moderation_log);
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ExportedChat {
+    Group(CanisterId),
+    Channel(CanisterId, ChannelId),
+}
+
+impl ExportedChat {
+    pub fn new(canister_id: CanisterId, channel_id: Option<ChannelId>) -> ExportedChat {
+        match channel_id {
+            Some(channel_id) => ExportedChat::Channel(canister_id, channel_id),
+            None => ExportedChat::Group(canister_id),
+        }
+    }
+
+    pub async fn export_events(
+        &self,
+        agent: &Agent,
+        thread_root_message_index: Option<MessageIndex>,
+        start_index: EventIndex,
+        max_events: u32,
+    ) -> Result<ChatExportPage, Box<dyn Error + Send + Sync>> {
+        match *self {
+            ExportedChat::Group(canister_id) => {
+                let args = group_canister::export_events::Args {
+                    thread_root_message_index,
+                    start_index,
+                    max_events,
+                };
+                match group::export_events(agent, &canister_id, &args).await? {
+                    group_canister::export_events::Response::Success(page) => Ok(page),
+                    group_canister::export_events::Response::Error(error) => Err(format!("{error:?}").into()),
+                }
+            }
+            ExportedChat::Channel(canister_id, channel_id) => {
+                let args = community_canister::export_events::Args {
+                    channel_id,
+                    thread_root_message_index,
+                    start_index,
+                    max_events,
+                };
+                match community::export_events(agent, &canister_id, &args).await? {
+                    community_canister::export_events::Response::Success(page) => Ok(page),
+                    community_canister::export_events::Response::Error(error) => Err(format!("{error:?}").into()),
+                }
+            }
+        }
+    }
+
+    pub async fn moderation_log(
+        &self,
+        agent: &Agent,
+        start_index: u32,
+        max_entries: u32,
+    ) -> Result<ModerationLogPage, Box<dyn Error + Send + Sync>> {
+        match *self {
+            ExportedChat::Group(canister_id) => {
+                let args = group_canister::moderation_log::Args {
+                    start_index,
+                    max_entries,
+                };
+                match group::moderation_log(agent, &canister_id, &args).await? {
+                    group_canister::moderation_log::Response::Success(page) => Ok(page),
+                    group_canister::moderation_log::Response::Error(error) => Err(format!("{error:?}").into()),
+                }
+            }
+            ExportedChat::Channel(canister_id, channel_id) => {
+                let args = community_canister::moderation_log::Args {
+                    channel_id: Some(channel_id),
+                    start_index,
+                    max_entries,
+                };
+                match community::moderation_log(agent, &canister_id, &args).await? {
+                    community_canister::moderation_log::Response::Success(page) => Ok(page),
+                    community_canister::moderation_log::Response::Error(error) => Err(format!("{error:?}").into()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use time::OffsetDateTime;
+use types::{
+    ChatEvent, Message, MessageContent, ModerationAction, ModerationLogEntry, PollContent, TimestampMillis, TotalVotes, UserId,
+};
+
+pub fn format_timestamp(timestamp: TimestampMillis) -> String {
+    match OffsetDateTime::from_unix_timestamp_nanos(timestamp as i128 * 1_000_000) {
+        Ok(datetime) => format!(
+            "{} {:02}:{:02}:{:02} UTC",
+            datetime.date(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second()
+        ),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+pub fn message_text(message: &Message) -> String {
+    match &message.content {
+        MessageContent::Deleted(_) => "[deleted]".to_string(),
+        content => match content.text() {
+            Some(text) if matches!(content, MessageContent::Text(_)) => text.to_string(),
+            Some(text) => format!("[{:?}] {text}", content.content_type()),
+            None => format!("[{:?}]", content.content_type()),
+        },
+    }
+}
+
+pub fn reactions(message: &Message) -> Option<String> {
+    if message.reactions.is_empty() {
+        None
+    } else {
+        Some(
+            message
+                .reactions
+                .iter()
+                .map(|(reaction, users)| format!("{} × {}", reaction.0, users.len()))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+}
+
+// Returns each option along with its vote count, or None if the tallies aren't available as simple counts
+pub fn poll_results(poll: &PollContent) -> Option<Vec<(&str, u32)>> {
+    let count = |option: u32| match &poll.votes.total {
+        TotalVotes::Visible(votes) => votes.get(&option).map_or(0, |v| v.len() as u32),
+        TotalVotes::Anonymous(votes) => votes.get(&option).copied().unwrap_or_default(),
+        _ => 0,
+    };
+
+    match &poll.votes.total {
+        TotalVotes::Visible(_) | TotalVotes::Anonymous(_) => Some(
+            poll.config
+                .options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| (option.as_str(), count(i as u32)))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+// Describes the non-message events which are worth keeping in a readable archive
+pub fn describe_event(event: &ChatEvent) -> Option<String> {
+    let users = |user_ids: &[UserId]| user_ids.iter().map(|u| u.to_string()).collect::<Vec<_>>().join(", ");
+
+    let description = match event {
+        ChatEvent::GroupChatCreated(e) => format!("{} created the chat \"{}\"", e.created_by, e.name),
+        ChatEvent::GroupNameChanged(e) => format!("{} renamed the chat to \"{}\"", e.changed_by, e.new_name),
+        ChatEvent::GroupDescriptionChanged(e) => format!("{} changed the description", e.changed_by),
+        ChatEvent::GroupRulesChanged(e) => format!("{} changed the rules", e.changed_by),
+        ChatEvent::ParticipantsAdded(e) => format!("{} added {}", e.added_by, users(&e.user_ids)),
+        ChatEvent::ParticipantsRemoved(e) => format!("{} removed {}", e.removed_by, users(&e.user_ids)),
+        ChatEvent::ParticipantJoined(e) => format!("{} joined", e.user_id),
+        ChatEvent::ParticipantLeft(e) => format!("{} left", e.user_id),
+        ChatEvent::RoleChanged(e) => format!(
+            "{} changed the role of {} from {:?} to {:?}",
+            e.changed_by,
+            users(&e.user_ids),
+            e.old_role,
+            e.new_role
+        ),
+        ChatEvent::UsersBlocked(e) => format!("{} blocked {}", e.blocked_by, users(&e.user_ids)),
+        ChatEvent::UsersUnblocked(e) => format!("{} unblocked {}", e.unblocked_by, users(&e.user_ids)),
+        ChatEvent::UsersInvited(e) => format!("{} invited {}", e.invited_by, users(&e.user_ids)),
+        ChatEvent::MemberWarned(e) => format!("{} warned {} (reason: {})", e.warned_by, e.user_id, e.reason),
+        ChatEvent::MemberMuted(e) => match e.muted_until {
+            Some(until) => format!("{} muted {} until {}", e.muted_by, e.user_id, format_timestamp(until)),
+            None => format!("{} unmuted {}", e.muted_by, e.user_id),
+        },
+        ChatEvent::MessagePinned(e) => format!("{} pinned message #{}", e.pinned_by, e.message_index),
+        ChatEvent::MessageUnpinned(e) => format!("{} unpinned message #{}", e.unpinned_by, e.message_index),
+        ChatEvent::PermissionsChanged(e) => format!("{} changed the permissions", e.changed_by),
+        ChatEvent::GroupVisibilityChanged(e) => format!("{} changed the visibility", e.changed_by),
+        ChatEvent::ChatFrozen(_) => "The chat was frozen".to_string(),
+        ChatEvent::ChatUnfrozen(_) => "The chat was unfrozen".to_string(),
+        ChatEvent::BotAdded(e) => format!("{} added bot {}", e.added_by, e.user_id),
+        ChatEvent::BotRemoved(e) => format!("{} removed bot {}", e.removed_by, e.user_id),
+        _ => return None,
+    };
+    Some(description)
+}
+
+pub fn describe_moderation_entry(entry: &ModerationLogEntry) -> String {
+    let action = match entry.action {
+        ModerationAction::MemberRemoved => format!("{} removed {}", entry.actor, entry.target),
+        ModerationAction::UserBlocked => format!("{} blocked {}", entry.actor, entry.target),
+        ModerationAction::UserUnblocked => format!("{} unblocked {}", entry.actor, entry.target),
+        ModerationAction::MessagesDeleted => format!(
+            "{} deleted {} message(s) sent by {}",
+            entry.actor,
+            entry.message_ids.len(),
+            entry.target
+        ),
+        ModerationAction::MemberWarned => format!("{} warned {}", entry.actor, entry.target),
+        ModerationAction::MemberMuted => format!("{} muted {}", entry.actor, entry.target),
+        ModerationAction::MemberUnmuted => format!("{} unmuted {}", entry.actor, entry.target),
+    };
+
+    match &entry.reason {
+        Some(reason) => format!("{action} (reason: {reason})"),
+        None => action,
+    }
+}
@@ -0,0 +1,133 @@
+use crate::archive::ChatArchive;
+use crate::format::{describe_event, describe_moderation_entry, format_timestamp, message_text, poll_results, reactions};
+use std::fmt::Write;
+use types::{ChatEvent, EventWrapper, Message, MessageContent};
+
+const STYLES: &str = r#"
+body { font-family: sans-serif; max-width: 48rem; margin: 2rem auto; color: #222; }
+.message { border-left: 3px solid #ccc; padding: 0.25rem 0.75rem; margin: 1rem 0; }
+.meta { color: #666; font-size: 0.85rem; }
+.text { white-space: pre-wrap; margin: 0.5rem 0; }
+.event { color: #666; font-style: italic; margin: 0.5rem 0; }
+.thread { margin-left: 1.5rem; }
+"#;
+
+pub fn render(archive: &ChatArchive) -> String {
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Chat archive</title>\n<style>{STYLES}</style>\n</head>\n<body>"
+    )
+    .unwrap();
+    writeln!(output, "<h1>Chat archive</h1>\n<ul>").unwrap();
+    writeln!(output, "<li>Canister: <code>{}</code></li>", archive.canister_id).unwrap();
+    if let Some(channel_id) = archive.channel_id {
+        writeln!(output, "<li>Channel: <code>{channel_id}</code></li>").unwrap();
+    }
+    writeln!(
+        output,
+        "<li>Exported at: {}</li>\n</ul>",
+        format_timestamp(archive.exported_at)
+    )
+    .unwrap();
+
+    for event in archive.events.iter() {
+        match &event.event {
+            ChatEvent::Message(message) => {
+                render_message(&mut output, event, message);
+
+                if let Some(thread) = archive.thread(message.message_index) {
+                    writeln!(output, "<div class=\"thread\">").unwrap();
+                    for reply in thread.events.iter() {
+                        if let ChatEvent::Message(m) = &reply.event {
+                            render_message(&mut output, reply, m);
+                        }
+                    }
+                    writeln!(output, "</div>").unwrap();
+                }
+            }
+            e => {
+                if let Some(description) = describe_event(e) {
+                    writeln!(
+                        output,
+                        "<div class=\"event\">{} · {}</div>",
+                        format_timestamp(event.timestamp),
+                        escape(&description)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    if !archive.moderation_log.is_empty() {
+        writeln!(output, "<h2>Moderation log</h2>\n<ul>").unwrap();
+        for entry in archive.moderation_log.iter() {
+            writeln!(
+                output,
+                "<li>{} · {}</li>",
+                format_timestamp(entry.timestamp),
+                escape(&describe_moderation_entry(entry))
+            )
+            .unwrap();
+        }
+        writeln!(output, "</ul>").unwrap();
+    }
+
+    writeln!(output, "</body>\n</html>").unwrap();
+    output
+}
+
+fn render_message<T>(output: &mut String, event: &EventWrapper<T>, message: &Message) {
+    writeln!(
+        output,
+        "<div class=\"message\" id=\"message-{}\">\n<div class=\"meta\"><strong>{}</strong> · {} · #{}{}</div>",
+        message.message_index,
+        message.sender,
+        format_timestamp(event.timestamp),
+        message.message_index,
+        if message.edited { " (edited)" } else { "" }
+    )
+    .unwrap();
+
+    writeln!(output, "<div class=\"text\">{}</div>", escape(&message_text(message))).unwrap();
+
+    if let MessageContent::Poll(poll) = &message.content {
+        writeln!(output, "<ul>").unwrap();
+        match poll_results(poll) {
+            Some(results) => {
+                for (option, votes) in results {
+                    writeln!(output, "<li>{}: {votes}</li>", escape(option)).unwrap();
+                }
+            }
+            None => {
+                for option in poll.config.options.iter() {
+                    writeln!(output, "<li>{}</li>", escape(option)).unwrap();
+                }
+            }
+        }
+        writeln!(output, "</ul>").unwrap();
+    }
+
+    if let Some(reactions) = reactions(message) {
+        writeln!(output, "<div class=\"meta\">Reactions: {}</div>", escape(&reactions)).unwrap();
+    }
+
+    writeln!(output, "</div>").unwrap();
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,65 @@
+use crate::archive::ChatArchive;
+use canister_agent_utils::{build_ic_agent, get_dfx_identity};
+use clap::Parser;
+use std::error::Error;
+use std::fs;
+use types::{CanisterId, ChannelId};
+
+mod archive;
+mod client;
+mod format;
+mod html;
+mod markdown;
+
+#[derive(Parser, Debug)]
+pub struct Config {
+    /// The id of the Group or Community canister
+    #[arg(long)]
+    canister_id: CanisterId,
+
+    /// The id of the channel to export, required if `canister_id` is a Community canister
+    #[arg(long)]
+    channel_id: Option<u32>,
+
+    /// "json", "markdown" or "html"
+    #[arg(long, default_value = "json")]
+    format: String,
+
+    /// IC URL
+    #[arg(long)]
+    url: String,
+
+    /// The DFX identity of an owner or admin of the chat
+    #[arg(long)]
+    identity: String,
+
+    /// The path of the file to write the archive to
+    #[arg(long)]
+    output: String,
+}
+
+pub async fn run(config: Config) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let identity = get_dfx_identity(&config.identity);
+    let agent = build_ic_agent(config.url, identity).await;
+    let chat = client::ExportedChat::new(config.canister_id, config.channel_id.map(ChannelId::from));
+
+    let archive = ChatArchive::download(&agent, chat).await?;
+
+    let contents = match config.format.as_str() {
+        "json" => serde_json::to_string_pretty(&archive)?,
+        "markdown" => markdown::render(&archive),
+        "html" => html::render(&archive),
+        _ => Err("Unsupported format")?,
+    };
+
+    fs::write(&config.output, contents)?;
+
+    println!(
+        "Exported {} events, {} threads and {} moderation log entries to {}",
+        archive.events.len(),
+        archive.threads.len(),
+        archive.moderation_log.len(),
+        config.output
+    );
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use chat_exporter::Config;
+use chat_exporter::run;
+use clap::Parser;
+use std::process;
+
+#[tokio::main]
+async fn main() {
+    let config = Config::parse();
+
+    if let Err(e) = run(config).await {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::archive::ChatArchive;
+use crate::format::{describe_event, describe_moderation_entry, format_timestamp, message_text, poll_results, reactions};
+use std::fmt::Write;
+use types::{ChatEvent, EventWrapper, Message, MessageContent};
+
+pub fn render(archive: &ChatArchive) -> String {
+    let mut output = String::new();
+
+    writeln!(output, "# Chat archive\n").unwrap();
+    writeln!(output, "- Canister: `{}`", archive.canister_id).unwrap();
+    if let Some(channel_id) = archive.channel_id {
+        writeln!(output, "- Channel: `{channel_id}`").unwrap();
+    }
+    writeln!(output, "- Exported at: {}\n", format_timestamp(archive.exported_at)).unwrap();
+    writeln!(output, "---\n").unwrap();
+
+    for event in archive.events.iter() {
+        match &event.event {
+            ChatEvent::Message(message) => {
+                render_message(&mut output, event, message, "");
+
+                if let Some(thread) = archive.thread(message.message_index) {
+                    writeln!(output, "> **Thread**\n>").unwrap();
+                    for reply in thread.events.iter() {
+                        if let ChatEvent::Message(m) = &reply.event {
+                            render_message(&mut output, reply, m, "> ");
+                        }
+                    }
+                }
+            }
+            e => {
+                if let Some(description) = describe_event(e) {
+                    writeln!(output, "_{} · {description}_\n", format_timestamp(event.timestamp)).unwrap();
+                }
+            }
+        }
+    }
+
+    if !archive.moderation_log.is_empty() {
+        writeln!(output, "---\n\n## Moderation log\n").unwrap();
+        for entry in archive.moderation_log.iter() {
+            writeln!(
+                output,
+                "- {} · {}",
+                format_timestamp(entry.timestamp),
+                describe_moderation_entry(entry)
+            )
+            .unwrap();
+        }
+    }
+
+    output
+}
+
+fn render_message<T>(output: &mut String, event: &EventWrapper<T>, message: &Message, prefix: &str) {
+    writeln!(
+        output,
+        "{prefix}**{}** · {} · #{}{}\n{prefix}",
+        message.sender,
+        format_timestamp(event.timestamp),
+        message.message_index,
+        if message.edited { " (edited)" } else { "" }
+    )
+    .unwrap();
+
+    for line in message_text(message).lines() {
+        writeln!(output, "{prefix}{line}").unwrap();
+    }
+
+    if let MessageContent::Poll(poll) = &message.content {
+        writeln!(output, "{prefix}").unwrap();
+        match poll_results(poll) {
+            Some(results) => {
+                for (option, votes) in results {
+                    writeln!(output, "{prefix}- {option}: {votes}").unwrap();
+                }
+            }
+            None => {
+                for option in poll.config.options.iter() {
+                    writeln!(output, "{prefix}- {option}").unwrap();
+                }
+            }
+        }
+    }
+
+    if let Some(reactions) = reactions(message) {
+        writeln!(output, "{prefix}\n{prefix}Reactions: {reactions}").unwrap();
+    }
+
+    writeln!(output).unwrap();
+}